@@ -86,15 +86,28 @@ pub fn attr_receiver(props: &AttrReceiverProps) -> Html {
         };
     }
 
+    // A component child can't receive attributes directly, so leave them
+    // unclaimed for an `AttrReceiver` rendered somewhere inside of it
+    let forwards_to_component =
+        props.children.len() == 1 && matches!(props.children.iter().next(), Some(VNode::VComp(_)));
+
     let attributes = use_synchi_channel_subscribe::<MergeAttributes>(
         props.name,
-        if context.name == props.name {
+        if context.name == props.name && !forwards_to_component {
             context.index.clone()
         } else {
             vec![]
         },
     );
 
+    if forwards_to_component {
+        return html! {
+            <ContextProvider<AttrPasserContext> context={context}>
+                {props.children.clone()}
+            </ContextProvider<AttrPasserContext>>
+        };
+    }
+
     if props.children.is_empty() {
         return html! {};
     }
@@ -120,7 +133,7 @@ pub fn attr_receiver(props: &AttrReceiverProps) -> Html {
         };
     }
 
-    log::warn!("AttrReceiver component only accepts a tag element");
+    log::warn!("AttrReceiver component only accepts a tag element or a component");
 
     html! {}
 }
@@ -413,4 +426,32 @@ mod tests {
         let element = t.query_by_role("button");
         assert!(element.exists());
     }
+
+    #[wasm_bindgen_test]
+    async fn test_attr_receiver_with_component_child() {
+        #[function_component(Inner)]
+        fn inner() -> Html {
+            html! {
+                <AttrReceiver name="test">
+                    <div data-testid="inner"></div>
+                </AttrReceiver>
+            }
+        }
+
+        let t = render!({
+            html! {
+                <AttrPasser name="test" ..attributify!{ "role" => "button" }>
+                    <AttrReceiver name="test">
+                        <Inner />
+                    </AttrReceiver>
+                </AttrPasser>
+            }
+        })
+        .await;
+
+        let element = t.query_by_role("button");
+
+        assert!(element.exists());
+        assert_eq!(element.attribute("data-testid"), "inner".to_string().into());
+    }
 }
@@ -0,0 +1,243 @@
+use icons::ChevronRightIcon;
+use yew::prelude::*;
+
+#[derive(Clone, Default, PartialEq)]
+pub struct BreadcrumbContext {
+    pub separator: Option<Html>,
+}
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct BreadcrumbProps {
+    #[prop_or_default]
+    pub children: Children,
+    #[prop_or_default]
+    pub class: Option<AttrValue>,
+    #[prop_or_default]
+    pub separator: Option<Html>,
+}
+
+/// A navigation landmark that shows the path to the current page.
+///
+/// # Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use breadcrumb::*;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Breadcrumb>
+///             <BreadcrumbList>
+///                 <BreadcrumbItem>
+///                     <BreadcrumbLink href="/">{"Home"}</BreadcrumbLink>
+///                 </BreadcrumbItem>
+///                 <BreadcrumbSeparator />
+///                 <BreadcrumbItem>
+///                     <BreadcrumbPage>{"Settings"}</BreadcrumbPage>
+///                 </BreadcrumbItem>
+///             </BreadcrumbList>
+///         </Breadcrumb>
+///     }
+/// }
+/// ```
+#[function_component(Breadcrumb)]
+pub fn breadcrumb(props: &BreadcrumbProps) -> Html {
+    let context = use_memo(props.separator.clone(), |separator| BreadcrumbContext {
+        separator: separator.clone(),
+    });
+
+    html! {
+        <ContextProvider<BreadcrumbContext> context={(*context).clone()}>
+            <nav aria-label="breadcrumb" class={&props.class}>
+                {for props.children.iter()}
+            </nav>
+        </ContextProvider<BreadcrumbContext>>
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct BreadcrumbListProps {
+    #[prop_or_default]
+    pub children: Children,
+    #[prop_or_default]
+    pub class: Option<AttrValue>,
+}
+
+#[function_component(BreadcrumbList)]
+pub fn breadcrumb_list(props: &BreadcrumbListProps) -> Html {
+    html! {
+        <ol class={&props.class}>
+            {for props.children.iter()}
+        </ol>
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct BreadcrumbItemProps {
+    #[prop_or_default]
+    pub children: Children,
+    #[prop_or_default]
+    pub class: Option<AttrValue>,
+}
+
+#[function_component(BreadcrumbItem)]
+pub fn breadcrumb_item(props: &BreadcrumbItemProps) -> Html {
+    html! {
+        <li class={&props.class}>
+            {for props.children.iter()}
+        </li>
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct BreadcrumbLinkProps {
+    #[prop_or_default]
+    pub children: Children,
+    #[prop_or_default]
+    pub class: Option<AttrValue>,
+    pub href: AttrValue,
+}
+
+#[function_component(BreadcrumbLink)]
+pub fn breadcrumb_link(props: &BreadcrumbLinkProps) -> Html {
+    html! {
+        <a href={&props.href} class={&props.class}>
+            {for props.children.iter()}
+        </a>
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct BreadcrumbPageProps {
+    #[prop_or_default]
+    pub children: Children,
+    #[prop_or_default]
+    pub class: Option<AttrValue>,
+}
+
+/// The current page, rendered as a non-interactive link.
+#[function_component(BreadcrumbPage)]
+pub fn breadcrumb_page(props: &BreadcrumbPageProps) -> Html {
+    html! {
+        <span
+            role="link"
+            aria-current="page"
+            aria-disabled="true"
+            class={&props.class}
+        >
+            {for props.children.iter()}
+        </span>
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct BreadcrumbSeparatorProps {
+    #[prop_or_default]
+    pub children: Children,
+    #[prop_or_default]
+    pub class: Option<AttrValue>,
+}
+
+/// Renders its children, the `separator` passed to `Breadcrumb`, or a chevron, in that order.
+#[function_component(BreadcrumbSeparator)]
+pub fn breadcrumb_separator(props: &BreadcrumbSeparatorProps) -> Html {
+    let context = use_context::<BreadcrumbContext>().unwrap_or_default();
+
+    let content = if !props.children.is_empty() {
+        html! { {for props.children.iter()} }
+    } else if let Some(separator) = context.separator {
+        separator
+    } else {
+        html! { <ChevronRightIcon /> }
+    };
+
+    html! {
+        <li role="presentation" aria-hidden="true" class={&props.class}>
+            {content}
+        </li>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+    use yewlish_testing_tools::*;
+
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    async fn test_breadcrumb_marks_the_current_page() {
+        let t = render!({
+            html! {
+                <Breadcrumb>
+                    <BreadcrumbList>
+                        <BreadcrumbItem>
+                            <BreadcrumbLink href="/">{"Home"}</BreadcrumbLink>
+                        </BreadcrumbItem>
+                        <BreadcrumbSeparator />
+                        <BreadcrumbItem>
+                            <BreadcrumbPage>{"Settings"}</BreadcrumbPage>
+                        </BreadcrumbItem>
+                    </BreadcrumbList>
+                </Breadcrumb>
+            }
+        })
+        .await;
+
+        assert_eq!(
+            t.query_by_role("navigation").attribute("aria-label"),
+            Some("breadcrumb".to_string())
+        );
+
+        let links = t.query_all_by_role("link");
+        assert_eq!(links.len(), 2);
+
+        assert_eq!(links[0].attribute("href"), Some("/".to_string()));
+        assert_eq!(links[0].attribute("aria-current"), None);
+
+        assert_eq!(links[1].text(), "Settings");
+        assert_eq!(links[1].attribute("aria-current"), Some("page".to_string()));
+        assert_eq!(
+            links[1].attribute("aria-disabled"),
+            Some("true".to_string())
+        );
+
+        let separator = t.query_by_role("presentation");
+        assert_eq!(separator.attribute("aria-hidden"), Some("true".to_string()));
+        assert!(separator.query_by_selector("svg").exists());
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_breadcrumb_separator_precedence() {
+        let t = render!({
+            html! {
+                <Breadcrumb separator={html! { {"/"} }}>
+                    <BreadcrumbList>
+                        <BreadcrumbItem>
+                            <BreadcrumbLink href="/">{"Home"}</BreadcrumbLink>
+                        </BreadcrumbItem>
+                        <BreadcrumbSeparator />
+                        <BreadcrumbItem>
+                            <BreadcrumbLink href="/docs">{"Docs"}</BreadcrumbLink>
+                        </BreadcrumbItem>
+                        <BreadcrumbSeparator>{">"}</BreadcrumbSeparator>
+                        <BreadcrumbItem>
+                            <BreadcrumbPage>{"Install"}</BreadcrumbPage>
+                        </BreadcrumbItem>
+                    </BreadcrumbList>
+                </Breadcrumb>
+            }
+        })
+        .await;
+
+        let separators = t.query_all_by_role("presentation");
+        assert_eq!(separators.len(), 2);
+
+        // The breadcrumb's separator replaces the chevron, the separator's own children win
+        assert_eq!(separators[0].text(), "/");
+        assert!(!separators[0].query_by_selector("svg").exists());
+        assert_eq!(separators[1].text(), ">");
+    }
+}
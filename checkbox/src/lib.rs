@@ -5,7 +5,10 @@ use web_sys::HtmlButtonElement;
 use yew::prelude::*;
 use yewlish_attr_passer::*;
 use yewlish_presence::*;
-use yewlish_utils::hooks::{use_conditional_attr, use_controllable_state};
+use yewlish_utils::hooks::{
+    use_conditional_attr, use_controllable_reducer, use_controllable_vec_state_with_reset,
+    use_form_reset, ControllableVecAction, UseControllableReducerHandle,
+};
 
 #[derive(Clone, Default, Debug, PartialEq)]
 pub enum CheckedState {
@@ -39,10 +42,13 @@ impl std::fmt::Display for CheckedState {
 pub struct CheckboxContext {
     pub(crate) checked: CheckedState,
     pub(crate) disabled: bool,
+    pub(crate) loading: bool,
 }
 
 pub enum CheckboxAction {
     Toggle,
+    Set(CheckedState),
+    SetLoading(bool),
 }
 
 impl Reducible for CheckboxContext {
@@ -58,6 +64,16 @@ impl Reducible for CheckboxContext {
                 ..(*self).clone()
             }
             .into(),
+            CheckboxAction::Set(checked) => CheckboxContext {
+                checked,
+                ..(*self).clone()
+            }
+            .into(),
+            CheckboxAction::SetLoading(loading) => CheckboxContext {
+                loading,
+                ..(*self).clone()
+            }
+            .into(),
         }
     }
 }
@@ -88,6 +104,12 @@ pub struct CheckboxRenderAsProps {
     pub value: Option<AttrValue>,
     #[prop_or_default]
     pub readonly: bool,
+    #[prop_or_default]
+    pub invalid: bool,
+    #[prop_or_default]
+    pub error_id: Option<AttrValue>,
+    #[prop_or_default]
+    pub loading: bool,
 }
 
 #[derive(Clone, Debug, PartialEq, Properties)]
@@ -116,6 +138,20 @@ pub struct CheckboxProps {
     pub value: Option<AttrValue>,
     #[prop_or_default]
     pub readonly: bool,
+    /// Marks the checkbox as failing validation with `aria-invalid`
+    #[prop_or_default]
+    pub invalid: bool,
+    /// Id of the element holding the validation message, referenced by `aria-describedby`
+    #[prop_or_default]
+    pub error_id: Option<AttrValue>,
+    /// Ties the checkbox to the enclosing `CheckboxGroup`, it's checked while the group's value
+    /// contains this one
+    #[prop_or_default]
+    pub group_value: Option<AttrValue>,
+    /// Marks the checkbox as pending, e.g. while the new value is being saved: it's `aria-busy`,
+    /// ignores toggles and its indicators show their `loading_indicator`
+    #[prop_or_default]
+    pub loading: bool,
     #[prop_or_default]
     pub render_as: Option<Callback<CheckboxRenderAsProps, Html>>,
 }
@@ -139,30 +175,88 @@ pub struct CheckboxProps {
 /// ```
 #[function_component(Checkbox)]
 pub fn checkbox(props: &CheckboxProps) -> Html {
-    let (checked, dispatch) = use_controllable_state(
-        props.default_checked.clone(),
-        props.checked.clone(),
-        props.on_checked_change.clone(),
+    let group = use_context::<CheckboxGroupContext>().filter(|_| props.group_value.is_some());
+
+    use_effect_with(
+        (
+            group.as_ref().map(|group| group.items_dispatcher.clone()),
+            props.group_value.clone(),
+        ),
+        |(items_dispatcher, group_value)| {
+            let registered = items_dispatcher.clone().zip(group_value.clone());
+
+            if let Some((items_dispatcher, value)) = &registered {
+                items_dispatcher.dispatch(CheckboxGroupItemsAction::Register(value.clone()));
+            }
+
+            move || {
+                if let Some((items_dispatcher, value)) = registered {
+                    items_dispatcher.dispatch(CheckboxGroupItemsAction::Unregister(value));
+                }
+            }
+        },
     );
 
-    let context_value = use_reducer(|| CheckboxContext {
-        checked: checked.borrow().clone(),
-        disabled: props.disabled,
+    let checked = group.as_ref().map_or(props.checked.clone(), |group| {
+        Some(match &props.group_value {
+            Some(value) if group.value.contains(value) => CheckedState::Checked,
+            _ => CheckedState::Unchecked,
+        })
     });
 
+    let on_checked_change = use_callback(
+        (
+            props.on_checked_change.clone(),
+            group.as_ref().map(|group| group.dispatch.clone()),
+            props.group_value.clone(),
+        ),
+        |checked: CheckedState, (on_checked_change, group_dispatch, group_value)| {
+            on_checked_change.emit(checked.clone());
+
+            if let (Some(dispatch), Some(value)) = (group_dispatch, group_value) {
+                dispatch.emit(match checked {
+                    CheckedState::Checked => ControllableVecAction::Add(value.clone()),
+                    CheckedState::Unchecked | CheckedState::Indeterminate => {
+                        ControllableVecAction::Remove(value.clone())
+                    }
+                });
+            }
+        },
+    );
+
+    let disabled = props.disabled || group.as_ref().is_some_and(|group| group.disabled);
+
+    let UseControllableReducerHandle {
+        value: checked,
+        dispatch,
+        reducer: context_value,
+        reset,
+    } = use_controllable_reducer(
+        props.default_checked.clone(),
+        checked,
+        on_checked_change,
+        |checked| CheckboxContext {
+            checked,
+            disabled,
+            loading: props.loading,
+        },
+        |context| context.checked.clone(),
+        CheckboxAction::Set,
+    );
+
     use_effect_with(
-        ((*checked).clone().borrow().clone(), context_value.clone()),
-        |(checked, context_value)| {
-            if *checked != context_value.checked {
-                context_value.dispatch(CheckboxAction::Toggle);
+        (props.loading, context_value.clone()),
+        |(loading, context_value)| {
+            if context_value.loading != *loading {
+                context_value.dispatch(CheckboxAction::SetLoading(*loading));
             }
         },
     );
 
     let toggle = use_callback(
-        (dispatch.clone(), context_value.clone(), props.readonly),
-        move |(), (dispatch, context_value, readonly)| {
-            if *readonly {
+        (dispatch.clone(), props.readonly, props.loading),
+        move |(), (dispatch, readonly, loading)| {
+            if *readonly || *loading {
                 return;
             }
 
@@ -170,8 +264,6 @@ pub fn checkbox(props: &CheckboxProps) -> Html {
                 CheckedState::Checked => CheckedState::Unchecked,
                 CheckedState::Unchecked | CheckedState::Indeterminate => CheckedState::Checked,
             }));
-
-            context_value.dispatch(CheckboxAction::Toggle);
         },
     );
 
@@ -179,8 +271,19 @@ pub fn checkbox(props: &CheckboxProps) -> Html {
         toggle.emit(());
     });
 
-    let prevent_checked_by_enter = use_callback((), |event: KeyboardEvent, ()| {
-        if event.key() == "Enter" {
+    // Checkboxes of a group are restored by the group's value instead
+    let reset_standalone = use_callback((reset, group.is_some()), |(), (reset, is_grouped)| {
+        if !is_grouped {
+            reset.emit(());
+        }
+    });
+
+    use_form_reset(&props.r#ref, reset_standalone);
+
+    // Enter never toggles a checkbox, Space doesn't either while it's readonly. A button
+    // activates on Space on `keyup`, so both key events are cancelled
+    let prevent_keyboard_toggle = use_callback(props.readonly, |event: KeyboardEvent, readonly| {
+        if event.key() == "Enter" || (*readonly && event.key() == " ") {
             event.prevent_default();
         }
     });
@@ -192,7 +295,7 @@ pub fn checkbox(props: &CheckboxProps) -> Html {
         .map(|element| element.closest("form").is_ok())
         .is_some();
 
-    use_conditional_attr(props.r#ref.clone(), "data-disabled", None, props.disabled);
+    use_conditional_attr(props.r#ref.clone(), "data-disabled", None, disabled);
 
     let element = if let Some(render_as) = &props.render_as {
         html! {
@@ -203,11 +306,14 @@ pub fn checkbox(props: &CheckboxProps) -> Html {
                 class: props.class.clone(),
                 checked: checked.borrow().clone(),
                 toggle: toggle.clone(),
-                disabled: props.disabled,
+                disabled,
                 required: props.required,
                 name: props.name.clone(),
                 value: props.value.clone(),
                 readonly: props.readonly,
+                invalid: props.invalid,
+                error_id: props.error_id.clone(),
+                loading: props.loading,
             })
         }
     } else {
@@ -219,11 +325,15 @@ pub fn checkbox(props: &CheckboxProps) -> Html {
                     class={&props.class}
                     type="button"
                     role="checkbox"
-                    disabled={props.disabled}
+                    {disabled}
                     name={props.name.clone()}
                     value={props.value.clone()}
                     readonly={props.readonly}
-                    onkeydown={prevent_checked_by_enter}
+                    aria-invalid={props.invalid.then_some("true")}
+                    aria-describedby={props.error_id.clone()}
+                    aria-busy={props.loading.then_some("true")}
+                    onkeydown={&prevent_keyboard_toggle}
+                    onkeyup={&prevent_keyboard_toggle}
                     onclick={&toggle_on_click}
                 >
                     {for props.children.iter()}
@@ -241,7 +351,9 @@ pub fn checkbox(props: &CheckboxProps) -> Html {
                     CheckedState::Indeterminate => "mixed",
                 },
                 "aria-required" => props.required.to_string(),
+                "aria-readonly" => props.readonly.to_string(),
                 "data-state" => checked.borrow().to_string(),
+                "data-loading" => props.loading.to_string(),
             }>
                 {element}
             </AttrPasser>
@@ -259,6 +371,8 @@ pub struct CheckboxIndicatorRenderAsProps {
     pub children: Children,
     #[prop_or_default]
     pub checked: CheckedState,
+    #[prop_or_default]
+    pub loading: bool,
 }
 
 #[derive(Clone, Debug, PartialEq, Properties)]
@@ -271,6 +385,10 @@ pub struct CheckboxIndicatorProps {
     pub children: Children,
     #[prop_or(CheckedState::Checked)]
     pub show_when: CheckedState,
+    /// Shown in place of the children while the checkbox is `loading`, e.g. a spinner. The
+    /// indicator is present while loading whatever `show_when` is
+    #[prop_or_default]
+    pub loading_indicator: Option<Html>,
     #[prop_or_default]
     pub render_as: Option<Callback<CheckboxIndicatorRenderAsProps, Html>>,
 }
@@ -307,15 +425,18 @@ pub fn checkbox_indicator(props: &CheckboxIndicatorProps) -> Html {
                 class: props.class.clone(),
                 children: props.children.clone(),
                 checked: context.checked.clone(),
+                loading: context.loading,
             })
         }
     } else {
+        let loading_indicator = props.loading_indicator.clone().filter(|_| context.loading);
+
         html! {
             <Presence
                 name="checkbox-indicator"
                 r#ref={props.r#ref.clone()}
                 class={&props.class}
-                present={context.checked == props.show_when}
+                present={loading_indicator.is_some() || context.checked == props.show_when}
                 render_as={
                     Callback::from(|PresenceRenderAsProps { r#ref, class, presence, children }| {
                         html! {
@@ -333,7 +454,11 @@ pub fn checkbox_indicator(props: &CheckboxIndicatorProps) -> Html {
                     })
                 }
             >
-                {for props.children.iter()}
+                if let Some(loading_indicator) = loading_indicator {
+                    {loading_indicator}
+                } else {
+                    {for props.children.iter()}
+                }
             </Presence>
         }
     };
@@ -341,12 +466,198 @@ pub fn checkbox_indicator(props: &CheckboxIndicatorProps) -> Html {
     html! {
         <AttrPasser name="checkbox-indicator" ..attributify! {
             "data-state" => context.checked.to_string(),
+            "data-loading" => context.loading.to_string(),
         }>
             {element}
         </AttrPasser>
     }
 }
 
+#[derive(Clone, Debug, PartialEq)]
+pub struct CheckboxGroupContext {
+    pub value: Vec<AttrValue>,
+    /// Values of the checkboxes that joined the group through `group_value`
+    pub items: Vec<AttrValue>,
+    pub disabled: bool,
+    pub dispatch: Callback<ControllableVecAction<AttrValue>>,
+    pub(crate) items_dispatcher: UseReducerDispatcher<CheckboxGroupItems>,
+}
+
+impl CheckboxGroupContext {
+    /// `Checked` when every item is checked, `Unchecked` when none is and `Indeterminate` otherwise
+    #[must_use]
+    pub fn checked_state(&self) -> CheckedState {
+        let checked = self
+            .items
+            .iter()
+            .filter(|item| self.value.contains(item))
+            .count();
+
+        if checked == 0 {
+            CheckedState::Unchecked
+        } else if checked == self.items.len() {
+            CheckedState::Checked
+        } else {
+            CheckedState::Indeterminate
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct CheckboxGroupItems(Vec<AttrValue>);
+
+pub(crate) enum CheckboxGroupItemsAction {
+    Register(AttrValue),
+    Unregister(AttrValue),
+}
+
+impl Reducible for CheckboxGroupItems {
+    type Action = CheckboxGroupItemsAction;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let mut items = self.0.clone();
+
+        match action {
+            CheckboxGroupItemsAction::Register(value) => {
+                if items.contains(&value) {
+                    return self;
+                }
+
+                items.push(value);
+            }
+            CheckboxGroupItemsAction::Unregister(value) => {
+                items.retain(|item| *item != value);
+            }
+        }
+
+        CheckboxGroupItems(items).into()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct CheckboxGroupProps {
+    #[prop_or_default]
+    pub children: Children,
+    #[prop_or_default]
+    pub class: Option<AttrValue>,
+    #[prop_or_default]
+    pub default_value: Option<Vec<AttrValue>>,
+    #[prop_or_default]
+    pub value: Option<Vec<AttrValue>>,
+    #[prop_or_default]
+    pub on_change: Callback<Vec<AttrValue>>,
+    #[prop_or_default]
+    pub disabled: bool,
+}
+
+/// Keeps track of the checked values of related checkboxes.
+///
+/// # Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yewlish_checkbox::{Checkbox, CheckboxGroup, CheckboxGroupAll};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <CheckboxGroup default_value={vec![AttrValue::from("apple")]}>
+///             <CheckboxGroupAll>{"All"}</CheckboxGroupAll>
+///             <Checkbox group_value="apple">{"Apple"}</Checkbox>
+///             <Checkbox group_value="banana">{"Banana"}</Checkbox>
+///         </CheckboxGroup>
+///     }
+/// }
+/// ```
+#[function_component(CheckboxGroup)]
+pub fn checkbox_group(props: &CheckboxGroupProps) -> Html {
+    let (value, dispatch, reset) = use_controllable_vec_state_with_reset(
+        props.default_value.clone(),
+        props.value.clone(),
+        props.on_change.clone(),
+    );
+
+    // Restores the grouped checkboxes, which skip their own reset
+    let node_ref = use_node_ref();
+    use_form_reset(&node_ref, reset);
+
+    let items = use_reducer(CheckboxGroupItems::default);
+
+    let context = CheckboxGroupContext {
+        value: value.borrow().clone(),
+        items: items.0.clone(),
+        disabled: props.disabled,
+        dispatch,
+        items_dispatcher: items.dispatcher(),
+    };
+
+    html! {
+        <ContextProvider<CheckboxGroupContext> {context}>
+            <div ref={node_ref} role="group" class={&props.class}>
+                {for props.children.iter()}
+            </div>
+        </ContextProvider<CheckboxGroupContext>>
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct CheckboxGroupAllProps {
+    #[prop_or_default]
+    pub children: Children,
+    #[prop_or_default]
+    pub r#ref: NodeRef,
+    #[prop_or_default]
+    pub id: Option<AttrValue>,
+    #[prop_or_default]
+    pub class: Option<AttrValue>,
+    #[prop_or_default]
+    pub disabled: bool,
+    #[prop_or_default]
+    pub render_as: Option<Callback<CheckboxRenderAsProps, Html>>,
+}
+
+/// A `Checkbox` that checks or unchecks every item of the enclosing `CheckboxGroup`.
+///
+/// It's `Indeterminate` while only some of the items are checked.
+#[function_component(CheckboxGroupAll)]
+pub fn checkbox_group_all(props: &CheckboxGroupAllProps) -> Html {
+    let group = use_context::<CheckboxGroupContext>()
+        .expect("CheckboxGroupAll must be a child of CheckboxGroup");
+
+    let on_checked_change = use_callback(group.clone(), |checked: CheckedState, group| {
+        let mut value = group.value.clone();
+
+        match checked {
+            CheckedState::Checked => {
+                for item in &group.items {
+                    if !value.contains(item) {
+                        value.push(item.clone());
+                    }
+                }
+            }
+            CheckedState::Unchecked | CheckedState::Indeterminate => {
+                value.retain(|item| !group.items.contains(item));
+            }
+        }
+
+        group.dispatch.emit(ControllableVecAction::Replace(value));
+    });
+
+    html! {
+        <Checkbox
+            r#ref={props.r#ref.clone()}
+            id={props.id.clone()}
+            class={&props.class}
+            checked={group.checked_state()}
+            disabled={props.disabled || group.disabled}
+            {on_checked_change}
+            render_as={props.render_as.clone()}
+        >
+            {for props.children.iter()}
+        </Checkbox>
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -686,6 +997,154 @@ mod tests {
         );
     }
 
+    /// Dispatches a cancelable Space key event on the checkbox and tells whether it was prevented
+    fn dispatch_space_prevented(name: &str) -> bool {
+        let checkbox = gloo_utils::document()
+            .query_selector("[role='checkbox']")
+            .ok()
+            .flatten()
+            .expect("Failed to find the checkbox");
+
+        let init = web_sys::KeyboardEventInit::new();
+        init.set_bubbles(true);
+        init.set_cancelable(true);
+        init.set_key(" ");
+
+        let event = web_sys::KeyboardEvent::new_with_keyboard_event_init_dict(name, &init)
+            .expect("Failed to create the keyboard event");
+
+        let _ = checkbox.dispatch_event(&event);
+
+        event.default_prevented()
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_checkbox_readonly_ignores_click_and_space() {
+        let t = render! {
+            html! {
+                <Checkbox readonly={true}>
+                    <CheckboxIndicator show_when={CheckedState::Checked}>{"X"}</CheckboxIndicator>
+                </Checkbox>
+            }
+        }
+        .await;
+
+        let checkbox = t.query_by_role("checkbox");
+
+        assert_eq!(
+            checkbox.attribute("aria-readonly"),
+            "true".to_string().into()
+        );
+
+        let checkbox = checkbox.click().await;
+
+        assert_eq!(
+            checkbox.attribute("aria-checked"),
+            "false".to_string().into()
+        );
+
+        // A synthetic Space doesn't activate the button, so the browser default is what's checked
+        assert!(dispatch_space_prevented("keydown"));
+        assert!(dispatch_space_prevented("keyup"));
+
+        assert_eq!(
+            checkbox.attribute("aria-checked"),
+            "false".to_string().into()
+        );
+        assert!(!t.query_by_text("X").exists());
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_checkbox_space_is_not_prevented_unless_readonly() {
+        render! {
+            html! {
+                <Checkbox />
+            }
+        }
+        .await;
+
+        assert!(!dispatch_space_prevented("keydown"));
+        assert!(!dispatch_space_prevented("keyup"));
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_checkbox_loading() {
+        let t = render!({
+            let loading = use_state(|| true);
+
+            let done = use_callback(loading.clone(), |_: MouseEvent, loading| {
+                loading.set(false);
+            });
+
+            html! {
+                <>
+                    <button onclick={done}>{"Done"}</button>
+                    <Checkbox loading={*loading}>
+                        <CheckboxIndicator
+                            show_when={CheckedState::Checked}
+                            loading_indicator={html! { {"Saving"} }}
+                        >
+                            {"X"}
+                        </CheckboxIndicator>
+                    </Checkbox>
+                </>
+            }
+        })
+        .await;
+
+        let checkbox = t.query_by_role("checkbox");
+        assert_eq!(checkbox.attribute("aria-busy"), "true".to_string().into());
+        assert_eq!(
+            checkbox.attribute("data-loading"),
+            "true".to_string().into()
+        );
+        assert!(t.query_by_text("Saving").exists());
+
+        // Clicks are ignored while loading
+        let checkbox = checkbox.click().await;
+        assert_eq!(
+            checkbox.attribute("aria-checked"),
+            "false".to_string().into()
+        );
+
+        t.query_by_text("Done").click().await;
+
+        assert_eq!(t.query_by_role("checkbox").attribute("aria-busy"), None);
+        assert!(!t.query_by_text("Saving").exists());
+
+        t.query_by_role("checkbox").click().await;
+        assert!(t.query_by_text("X").exists());
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_checkbox_form_reset_restores_default() {
+        let t = render! {
+            html! {
+                <form>
+                    <Checkbox default_checked={CheckedState::Checked}>
+                        <CheckboxIndicator show_when={CheckedState::Checked}>{"X"}</CheckboxIndicator>
+                    </Checkbox>
+                    <button type="reset">{"Reset"}</button>
+                </form>
+            }
+        }
+        .await;
+
+        let checkbox = t.query_by_role("checkbox").click().await;
+
+        assert_eq!(
+            checkbox.attribute("aria-checked"),
+            "false".to_string().into()
+        );
+
+        t.query_by_text("Reset").click().await;
+
+        assert_eq!(
+            t.query_by_role("checkbox").attribute("aria-checked"),
+            "true".to_string().into()
+        );
+    }
+
     #[wasm_bindgen_test]
     async fn test_checkbox_accept_ref() {
         let t = render!({
@@ -833,4 +1292,108 @@ mod tests {
             "false".to_string().into()
         );
     }
+
+    #[wasm_bindgen_test]
+    async fn test_checkbox_group_select_all() {
+        let t = render!({
+            let value = use_state(Vec::<AttrValue>::new);
+
+            let on_change = use_callback(value.clone(), |next_value, value| {
+                value.set(next_value);
+            });
+
+            use_remember_value(value.clone());
+
+            html! {
+                <CheckboxGroup value={(*value).clone()} {on_change}>
+                    <CheckboxGroupAll>{"All"}</CheckboxGroupAll>
+                    <Checkbox group_value="apple">{"Apple"}</Checkbox>
+                    <Checkbox group_value="banana">{"Banana"}</Checkbox>
+                </CheckboxGroup>
+            }
+        })
+        .await;
+
+        let checkboxes = t.query_all_by_role("checkbox");
+        assert_eq!(checkboxes.len(), 3);
+
+        assert_eq!(
+            checkboxes[0].attribute("aria-checked"),
+            "false".to_string().into()
+        );
+
+        // Checking some of the items makes the parent indeterminate
+        checkboxes[1].clone().click().await;
+
+        assert_eq!(
+            *t.get_remembered_value::<UseStateHandle<Vec<AttrValue>>>(),
+            vec![AttrValue::from("apple")]
+        );
+
+        assert_eq!(
+            checkboxes[0].attribute("aria-checked"),
+            "mixed".to_string().into()
+        );
+
+        // Checking the parent checks every item
+        checkboxes[0].clone().click().await;
+
+        assert_eq!(
+            *t.get_remembered_value::<UseStateHandle<Vec<AttrValue>>>(),
+            vec![AttrValue::from("apple"), AttrValue::from("banana")]
+        );
+
+        assert_eq!(
+            checkboxes[2].attribute("aria-checked"),
+            "true".to_string().into()
+        );
+
+        // Unchecking the parent unchecks every item
+        checkboxes[0].clone().click().await;
+
+        assert!(t
+            .get_remembered_value::<UseStateHandle<Vec<AttrValue>>>()
+            .is_empty());
+
+        assert_eq!(
+            checkboxes[1].attribute("aria-checked"),
+            "false".to_string().into()
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_checkbox_group_form_reset() {
+        let t = render!({
+            html! {
+                <form>
+                    <CheckboxGroup default_value={vec![AttrValue::from("apple")]}>
+                        <Checkbox group_value="apple">{"Apple"}</Checkbox>
+                        <Checkbox group_value="banana">{"Banana"}</Checkbox>
+                    </CheckboxGroup>
+                    <button type="reset">{"Reset"}</button>
+                </form>
+            }
+        })
+        .await;
+
+        t.query_all_by_role("checkbox")[1].clone().click().await;
+
+        assert_eq!(
+            t.query_all_by_role("checkbox")[1].attribute("aria-checked"),
+            "true".to_string().into()
+        );
+
+        t.query_by_text("Reset").click().await;
+
+        let checkboxes = t.query_all_by_role("checkbox");
+
+        assert_eq!(
+            checkboxes[0].attribute("aria-checked"),
+            "true".to_string().into()
+        );
+        assert_eq!(
+            checkboxes[1].attribute("aria-checked"),
+            "false".to_string().into()
+        );
+    }
 }
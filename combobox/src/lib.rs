@@ -0,0 +1,873 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use listbox::{
+    next_active_index, use_listbox_context, ListboxAction, ListboxContext, ListboxSelected,
+};
+use web_sys::{Element, HtmlInputElement, ScrollIntoViewOptions, ScrollLogicalPosition};
+use yew::prelude::*;
+use yewlish_popover::{Popover, PopoverAlign, PopoverContent, PopoverSide};
+use yewlish_utils::hooks::{use_auto_id, use_controllable_state, use_keydown};
+
+/// An enabled option shown for the current input
+#[derive(Clone, Debug, PartialEq)]
+struct ComboboxItem {
+    value: AttrValue,
+    label: AttrValue,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ComboboxContext {
+    pub id: AttrValue,
+    pub input_value: AttrValue,
+    pub value: AttrValue,
+    pub is_open: bool,
+    pub should_filter: bool,
+    pub loading: bool,
+    /// The active option and the selection, kept by the same model as `Listbox`
+    pub listbox: ListboxContext,
+    pub on_input_change: Callback<AttrValue>,
+    /// Selects the option with the given value and writes its label into the input
+    pub on_value_change: Callback<(AttrValue, AttrValue)>,
+    pub on_open_change: Callback<bool>,
+    /// The options to navigate through, filled in by `ComboboxContent`
+    items: Rc<RefCell<Vec<ComboboxItem>>>,
+}
+
+/// Escapes everything but ASCII letters and digits, so any value can be part of an id
+fn escape_id(value: &str) -> String {
+    value
+        .chars()
+        .map(|char| {
+            if char.is_ascii_alphanumeric() {
+                char.to_string()
+            } else {
+                format!("_{:06x}", u32::from(char))
+            }
+        })
+        .collect()
+}
+
+impl ComboboxContext {
+    /// The id of the option with the given value, unique within the combobox for any value
+    pub fn option_id(&self, value: &str) -> AttrValue {
+        format!("{}-option-{}", self.id, escape_id(value)).into()
+    }
+
+    pub fn listbox_id(&self) -> AttrValue {
+        format!("{}-listbox", self.id).into()
+    }
+
+    pub fn matches(&self, label: &str) -> bool {
+        if !self.should_filter || self.input_value.is_empty() {
+            return true;
+        }
+
+        label
+            .to_lowercase()
+            .contains(&self.input_value.to_lowercase())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct ComboboxProps {
+    #[prop_or_default]
+    pub children: Children,
+    #[prop_or_default]
+    pub id: Option<AttrValue>,
+    #[prop_or_default]
+    pub input_value: Option<AttrValue>,
+    #[prop_or_default]
+    pub default_input_value: Option<AttrValue>,
+    #[prop_or_default]
+    pub on_input_change: Callback<AttrValue>,
+    #[prop_or_default]
+    pub value: Option<AttrValue>,
+    #[prop_or_default]
+    pub default_value: Option<AttrValue>,
+    #[prop_or_default]
+    pub on_value_change: Callback<AttrValue>,
+    #[prop_or_default]
+    pub open: Option<bool>,
+    #[prop_or_default]
+    pub default_open: bool,
+    #[prop_or_default]
+    pub on_open_change: Callback<bool>,
+    /// Filter options by the input value on the client. Disable it when the
+    /// options are already filtered, e.g. loaded from the server per query.
+    #[prop_or(true)]
+    pub should_filter: bool,
+    /// Marks the listbox as busy while options are being loaded.
+    #[prop_or_default]
+    pub loading: bool,
+    #[prop_or_default]
+    pub class: Option<AttrValue>,
+}
+
+/// An editable autocomplete built on top of `Popover`.
+///
+/// The options are filtered directly by the text typed into `ComboboxInput`.
+/// Set `should_filter` to `false` and feed the options from `on_input_change`
+/// to load them asynchronously.
+///
+/// # Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use combobox::{Combobox, ComboboxContent, ComboboxInput, ComboboxOption};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Combobox>
+///             <ComboboxInput placeholder="Pick a fruit" />
+///             <ComboboxContent>
+///                 <ComboboxOption value="apple">{"Apple"}</ComboboxOption>
+///                 <ComboboxOption value="banana">{"Banana"}</ComboboxOption>
+///             </ComboboxContent>
+///         </Combobox>
+///     }
+/// }
+/// ```
+#[function_component(Combobox)]
+pub fn combobox(props: &ComboboxProps) -> Html {
+    let auto_id = use_auto_id(Some("combobox"));
+
+    let (input_value, dispatch_input_value) = use_controllable_state(
+        props.default_input_value.clone(),
+        props.input_value.clone(),
+        props.on_input_change.clone(),
+    );
+
+    let (is_open, dispatch_open) = use_controllable_state(
+        props.default_open.into(),
+        props.open,
+        props.on_open_change.clone(),
+    );
+
+    let on_selected_change = use_callback(
+        props.on_value_change.clone(),
+        |selected: ListboxSelected, on_value_change| {
+            if let ListboxSelected::Single(value) = selected {
+                on_value_change.emit(value.unwrap_or_default());
+            }
+        },
+    );
+
+    let as_selected =
+        |value: AttrValue| ListboxSelected::Single(Some(value).filter(|value| !value.is_empty()));
+
+    let listbox = use_listbox_context(
+        props.value.clone().map(as_selected),
+        props.default_value.clone().map(as_selected),
+        on_selected_change,
+        false,
+    );
+
+    let value = match &listbox.selected {
+        ListboxSelected::Single(value) => value.clone().unwrap_or_default(),
+        ListboxSelected::Multiple(values) => values.first().cloned().unwrap_or_default(),
+    };
+
+    let on_input_change = use_callback(
+        dispatch_input_value.clone(),
+        |next: AttrValue, dispatch_input_value| {
+            dispatch_input_value.emit(Box::new(move |_| next.clone()));
+        },
+    );
+
+    let on_value_change = use_callback(
+        (listbox.clone(), dispatch_input_value.clone()),
+        |(next, label): (AttrValue, AttrValue), (listbox, dispatch_input_value)| {
+            listbox.dispatch(ListboxAction::SetSelection(vec![next]));
+            dispatch_input_value.emit(Box::new(move |_| label.clone()));
+        },
+    );
+
+    let on_open_change = use_callback(
+        (dispatch_open.clone(), listbox.clone()),
+        |next: bool, (dispatch_open, listbox)| {
+            if !next {
+                listbox.dispatch(ListboxAction::SetActive(AttrValue::from("")));
+            }
+
+            dispatch_open.emit(Box::new(move |_| next));
+        },
+    );
+
+    let items = use_mut_ref(Vec::new);
+
+    let context_value = ComboboxContext {
+        id: props.id.clone().unwrap_or(auto_id),
+        input_value: input_value.borrow().clone(),
+        value,
+        is_open: *is_open.borrow(),
+        should_filter: props.should_filter,
+        loading: props.loading,
+        listbox,
+        on_input_change,
+        on_value_change,
+        on_open_change: on_open_change.clone(),
+        items,
+    };
+
+    html! {
+        <ContextProvider<ComboboxContext> context={context_value.clone()}>
+            <Popover
+                class={&props.class}
+                open={Some(context_value.is_open)}
+                on_open_change={on_open_change}
+            >
+                {props.children.clone()}
+            </Popover>
+        </ContextProvider<ComboboxContext>>
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct ComboboxInputProps {
+    #[prop_or_default]
+    pub r#ref: NodeRef,
+    #[prop_or_default]
+    pub class: Option<AttrValue>,
+    #[prop_or_default]
+    pub placeholder: Option<AttrValue>,
+    #[prop_or_default]
+    pub disabled: bool,
+}
+
+#[function_component(ComboboxInput)]
+pub fn combobox_input(props: &ComboboxInputProps) -> Html {
+    let context =
+        use_context::<ComboboxContext>().expect("ComboboxInput must be a child of Combobox");
+
+    let oninput = use_callback(context.clone(), |event: InputEvent, context| {
+        let Some(input) = event.target_dyn_into::<HtmlInputElement>() else {
+            return;
+        };
+
+        context.on_input_change.emit(input.value().into());
+        context
+            .listbox
+            .dispatch(ListboxAction::SetActive(AttrValue::from("")));
+
+        if !context.is_open {
+            context.on_open_change.emit(true);
+        }
+    });
+
+    let navigation_handler = {
+        let context = context.clone();
+
+        move |event: KeyboardEvent| match event.key().as_str() {
+            "Escape" => {
+                context.on_open_change.emit(false);
+            }
+            "Enter" => {
+                if !context.is_open || context.listbox.active.is_empty() {
+                    return;
+                }
+
+                let selected = context
+                    .items
+                    .borrow()
+                    .iter()
+                    .find(|item| item.value == context.listbox.active)
+                    .cloned();
+
+                if let Some(item) = selected {
+                    context.on_value_change.emit((item.value, item.label));
+                    context.on_open_change.emit(false);
+                }
+            }
+            key @ ("ArrowDown" | "ArrowUp") => {
+                if !context.is_open {
+                    context.on_open_change.emit(true);
+                    return;
+                }
+
+                let items = context.items.borrow();
+                let current = items
+                    .iter()
+                    .position(|item| item.value == context.listbox.active);
+
+                if let Some(index) = next_active_index(key, current, items.len()) {
+                    context
+                        .listbox
+                        .dispatch(ListboxAction::SetActive(items[index].value.clone()));
+                }
+            }
+            _ => {}
+        }
+    };
+
+    let onkeydown = use_keydown(
+        vec![
+            "ArrowDown".into(),
+            "ArrowUp".into(),
+            "Enter".into(),
+            "Escape".into(),
+        ],
+        navigation_handler,
+    );
+
+    let active_descendant = if context.is_open && !context.listbox.active.is_empty() {
+        Some(context.option_id(&context.listbox.active))
+    } else {
+        None
+    };
+
+    html! {
+        <input
+            ref={props.r#ref.clone()}
+            type="text"
+            id={context.id.clone()}
+            role="combobox"
+            class={&props.class}
+            placeholder={&props.placeholder}
+            disabled={props.disabled}
+            autocomplete="off"
+            aria-autocomplete="list"
+            aria-expanded={if context.is_open { "true" } else { "false" }}
+            aria-controls={context.listbox_id()}
+            aria-activedescendant={active_descendant}
+            data-state={if context.is_open { "open" } else { "closed" }}
+            value={context.input_value.clone()}
+            {oninput}
+            {onkeydown}
+        />
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct ComboboxContentProps {
+    #[prop_or_default]
+    pub children: ChildrenWithProps<ComboboxOption>,
+    #[prop_or_default]
+    pub class: Option<AttrValue>,
+    #[prop_or_default]
+    pub side: PopoverSide,
+    #[prop_or(PopoverAlign::Start)]
+    pub align: PopoverAlign,
+}
+
+#[function_component(ComboboxContent)]
+pub fn combobox_content(props: &ComboboxContentProps) -> Html {
+    let context =
+        use_context::<ComboboxContext>().expect("ComboboxContent must be a child of Combobox");
+
+    let options = props
+        .children
+        .iter()
+        .filter(|option| context.matches(&option.props.label()))
+        .collect::<Vec<_>>();
+
+    let items = options
+        .iter()
+        .filter(|option| !option.props.disabled)
+        .map(|option| ComboboxItem {
+            value: option.props.value.clone(),
+            label: option.props.label(),
+        })
+        .collect::<Vec<_>>();
+
+    // The input navigates through the options shown for its value
+    use_effect_with((items, context.items.clone()), |(items, context_items)| {
+        context_items.replace(items.clone());
+    });
+
+    html! {
+        <PopoverContent class={&props.class} side={props.side.clone()} align={props.align.clone()}>
+            <ul
+                id={context.listbox_id()}
+                role="listbox"
+                aria-busy={if context.loading { "true" } else { "false" }}
+            >
+                {for options}
+            </ul>
+        </PopoverContent>
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct ComboboxOptionProps {
+    pub value: AttrValue,
+    #[prop_or_default]
+    pub label: Option<AttrValue>,
+    #[prop_or_default]
+    pub children: Children,
+    #[prop_or_default]
+    pub class: Option<AttrValue>,
+    #[prop_or_default]
+    pub disabled: bool,
+}
+
+impl ComboboxOptionProps {
+    fn label(&self) -> AttrValue {
+        self.label.clone().unwrap_or_else(|| self.value.clone())
+    }
+}
+
+#[function_component(ComboboxOption)]
+pub fn combobox_option(props: &ComboboxOptionProps) -> Html {
+    let context =
+        use_context::<ComboboxContext>().expect("ComboboxOption must be a child of Combobox");
+
+    let label = props.label();
+    let node_ref = use_node_ref();
+
+    let select_on_click = use_callback(
+        (
+            props.value.clone(),
+            label.clone(),
+            props.disabled,
+            context.clone(),
+        ),
+        |_event: MouseEvent, (value, label, disabled, context)| {
+            if *disabled {
+                return;
+            }
+
+            context.on_value_change.emit((value.clone(), label.clone()));
+
+            context.on_open_change.emit(false);
+        },
+    );
+
+    // Keep the focus on the input while picking an option with the pointer
+    let keep_focus = use_callback((), |event: MouseEvent, ()| {
+        event.prevent_default();
+    });
+
+    let activate_on_hover = use_callback(
+        (props.value.clone(), props.disabled, context.clone()),
+        |_event: MouseEvent, (value, disabled, context)| {
+            if !*disabled && context.listbox.active != *value {
+                context
+                    .listbox
+                    .dispatch(ListboxAction::SetActive(value.clone()));
+            }
+        },
+    );
+
+    let is_selected = context.value == props.value;
+    let is_active = context.listbox.active == props.value;
+
+    // Keeps the option navigated to with the keyboard visible, without moving one that already is
+    use_effect_with((is_active, node_ref.clone()), |(is_active, node_ref)| {
+        if !*is_active {
+            return;
+        }
+
+        if let Some(option) = node_ref.cast::<Element>() {
+            let options = ScrollIntoViewOptions::new();
+            options.set_block(ScrollLogicalPosition::Nearest);
+            option.scroll_into_view_with_scroll_into_view_options(&options);
+        }
+    });
+
+    html! {
+        <li
+            ref={node_ref}
+            id={context.option_id(&props.value)}
+            role="option"
+            class={&props.class}
+            aria-selected={if is_selected { "true" } else { "false" }}
+            aria-disabled={if props.disabled { Some("true") } else { None }}
+            data-active={if is_active { "true" } else { "false" }}
+            onmousedown={keep_focus}
+            onmousemove={activate_on_hover}
+            onclick={select_on_click}
+        >
+            {props.children.clone()}
+        </li>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+    use web_sys::wasm_bindgen::JsCast;
+    use yewlish_testing_tools::*;
+
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    fn input() -> HtmlInputElement {
+        web_sys::window()
+            .and_then(|window| window.document())
+            .and_then(|document| document.query_selector("input[role='combobox']").ok())
+            .flatten()
+            .and_then(|element| element.dyn_into().ok())
+            .expect("Failed to find the input")
+    }
+
+    async fn type_into(input: &HtmlInputElement, text: &str) {
+        input.set_value(text);
+
+        let init = web_sys::InputEventInit::new();
+        init.set_bubbles(true);
+
+        let event = web_sys::InputEvent::new_with_event_init_dict("input", &init)
+            .expect("Failed to create the input event");
+
+        let _ = input.dispatch_event(&event);
+        yew::platform::time::sleep(std::time::Duration::ZERO).await;
+    }
+
+    fn option_texts(t: &Tester) -> Vec<String> {
+        t.query_all_by_role("option")
+            .iter()
+            .map(|option| option.text())
+            .collect()
+    }
+
+    #[test]
+    fn test_escape_id() {
+        assert_eq!(escape_id("apple"), "apple");
+        assert_eq!(escape_id("green apple"), "green_000020apple");
+        assert_eq!(escape_id("a_b"), "a_00005fb");
+        assert_ne!(escape_id("a-1"), escape_id("a_00002d1"));
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_combobox_filters_the_options_by_the_input() {
+        let t = render!({
+            html! {
+                <Combobox>
+                    <ComboboxInput />
+                    <ComboboxContent>
+                        <ComboboxOption value="apple" label="Apple">{"Apple"}</ComboboxOption>
+                        <ComboboxOption value="banana" label="Banana">{"Banana"}</ComboboxOption>
+                        <ComboboxOption value="green apple" label="Green apple">{"Green apple"}</ComboboxOption>
+                    </ComboboxContent>
+                </Combobox>
+            }
+        })
+        .await;
+
+        type_into(&input(), "APP").await;
+
+        assert_eq!(
+            t.query_by_role("combobox").attribute("aria-expanded"),
+            Some("true".to_string())
+        );
+        assert_eq!(option_texts(&t), vec!["Apple", "Green apple"]);
+
+        type_into(&input(), "").await;
+        assert_eq!(option_texts(&t), vec!["Apple", "Banana", "Green apple"]);
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_combobox_keeps_the_options_without_should_filter() {
+        let t = render!({
+            html! {
+                <Combobox should_filter={false}>
+                    <ComboboxInput />
+                    <ComboboxContent>
+                        <ComboboxOption value="apple" label="Apple">{"Apple"}</ComboboxOption>
+                        <ComboboxOption value="banana" label="Banana">{"Banana"}</ComboboxOption>
+                    </ComboboxContent>
+                </Combobox>
+            }
+        })
+        .await;
+
+        type_into(&input(), "kiwi").await;
+
+        assert_eq!(option_texts(&t), vec!["Apple", "Banana"]);
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_combobox_arrow_down_opens_the_list() {
+        let t = render!({
+            html! {
+                <Combobox>
+                    <ComboboxInput />
+                    <ComboboxContent>
+                        <ComboboxOption value="apple" label="Apple">{"Apple"}</ComboboxOption>
+                    </ComboboxContent>
+                </Combobox>
+            }
+        })
+        .await;
+
+        assert!(!t.query_by_role("option").exists());
+
+        let combobox = t.query_by_role("combobox").keydown("ArrowDown").await;
+
+        assert_eq!(
+            combobox.attribute("aria-expanded"),
+            Some("true".to_string())
+        );
+        assert_eq!(combobox.attribute("aria-activedescendant"), None);
+        assert_eq!(option_texts(&t), vec!["Apple"]);
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_combobox_arrow_keys_move_the_active_option() {
+        let t = render!({
+            html! {
+                <Combobox>
+                    <ComboboxInput />
+                    <ComboboxContent>
+                        <ComboboxOption value="apple" label="Apple">{"Apple"}</ComboboxOption>
+                        <ComboboxOption value="banana" label="Banana" disabled={true}>{"Banana"}</ComboboxOption>
+                        <ComboboxOption value="green apple" label="Green apple">{"Green apple"}</ComboboxOption>
+                    </ComboboxContent>
+                </Combobox>
+            }
+        })
+        .await;
+
+        let active = |t: &Tester| {
+            let descendant = t
+                .query_by_role("combobox")
+                .attribute("aria-activedescendant")?;
+
+            let option = t.query_by_selector(&format!("[id='{descendant}']"));
+            assert_eq!(option.attribute("data-active"), Some("true".to_string()));
+
+            Some(option.text())
+        };
+
+        let combobox = t.query_by_role("combobox").keydown("ArrowDown").await;
+        assert_eq!(active(&t), None);
+
+        // The disabled option is skipped, the value with a space still makes a valid id
+        let combobox = combobox.keydown("ArrowDown").await;
+        assert_eq!(active(&t), Some("Apple".to_string()));
+
+        let combobox = combobox.keydown("ArrowDown").await;
+        assert_eq!(active(&t), Some("Green apple".to_string()));
+
+        let combobox = combobox.keydown("ArrowDown").await;
+        assert_eq!(active(&t), Some("Apple".to_string()));
+
+        let combobox = combobox.keydown("ArrowUp").await;
+        assert_eq!(active(&t), Some("Green apple".to_string()));
+
+        combobox.keydown("ArrowUp").await;
+        assert_eq!(active(&t), Some("Apple".to_string()));
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_combobox_enter_selects_the_active_option() {
+        let t = render!({
+            let changes = use_state(Vec::<AttrValue>::new);
+
+            let on_value_change = use_callback(changes.clone(), |value: AttrValue, changes| {
+                let mut next_changes = (**changes).clone();
+                next_changes.push(value);
+                changes.set(next_changes);
+            });
+
+            use_remember_value((*changes).clone());
+
+            html! {
+                <Combobox {on_value_change}>
+                    <ComboboxInput />
+                    <ComboboxContent>
+                        <ComboboxOption value="apple" label="Apple">{"Apple"}</ComboboxOption>
+                        <ComboboxOption value="banana" label="Banana">{"Banana"}</ComboboxOption>
+                    </ComboboxContent>
+                </Combobox>
+            }
+        })
+        .await;
+
+        let combobox = t
+            .query_by_role("combobox")
+            .keydown("ArrowDown")
+            .await
+            .keydown("ArrowDown")
+            .await
+            .keydown("ArrowDown")
+            .await
+            .keydown("Enter")
+            .await;
+
+        assert_eq!(
+            t.get_remembered_value::<Vec<AttrValue>>(),
+            vec![AttrValue::from("banana")]
+        );
+        assert_eq!(input().value(), "Banana");
+        assert_eq!(
+            combobox.attribute("aria-expanded"),
+            Some("false".to_string())
+        );
+
+        // The selected option is marked once the list is opened again
+        t.query_by_role("combobox").keydown("ArrowDown").await;
+
+        assert_eq!(
+            t.query_all_by_role("option")[1].attribute("aria-selected"),
+            Some("true".to_string())
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_combobox_escape_closes_the_list() {
+        let t = render!({
+            html! {
+                <Combobox>
+                    <ComboboxInput />
+                    <ComboboxContent>
+                        <ComboboxOption value="apple" label="Apple">{"Apple"}</ComboboxOption>
+                    </ComboboxContent>
+                </Combobox>
+            }
+        })
+        .await;
+
+        let combobox = t
+            .query_by_role("combobox")
+            .keydown("ArrowDown")
+            .await
+            .keydown("ArrowDown")
+            .await;
+
+        assert!(combobox.attribute("aria-activedescendant").is_some());
+
+        let combobox = combobox.keydown("Escape").await;
+
+        assert_eq!(
+            combobox.attribute("aria-expanded"),
+            Some("false".to_string())
+        );
+        assert_eq!(combobox.attribute("aria-activedescendant"), None);
+        assert!(
+            t.wait_for(1000.0, || !t.query_by_role("option").exists())
+                .await
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_controlled_combobox() {
+        let t = render!({
+            let value = use_state(|| AttrValue::from("banana"));
+            let input_value = use_state(|| AttrValue::from("Banana"));
+
+            let on_value_change = use_callback(value.clone(), |next: AttrValue, value| {
+                value.set(next);
+            });
+
+            let on_input_change = use_callback(input_value.clone(), |next: AttrValue, input_value| {
+                input_value.set(next);
+            });
+
+            let pick_banana = use_callback(
+                (value.clone(), input_value.clone()),
+                |_: MouseEvent, (value, input_value)| {
+                    value.set(AttrValue::from("banana"));
+                    input_value.set(AttrValue::from("Banana"));
+                },
+            );
+
+            use_remember_value(((*value).clone(), (*input_value).clone()));
+
+            html! {
+                <>
+                    <Combobox
+                        value={(*value).clone()}
+                        input_value={(*input_value).clone()}
+                        {on_value_change}
+                        {on_input_change}
+                        should_filter={false}
+                    >
+                        <ComboboxInput />
+                        <ComboboxContent>
+                            <ComboboxOption value="apple" label="Apple">{"Apple"}</ComboboxOption>
+                            <ComboboxOption value="banana" label="Banana">{"Banana"}</ComboboxOption>
+                        </ComboboxContent>
+                    </Combobox>
+                    <button onclick={pick_banana}>{"Pick banana"}</button>
+                </>
+            }
+        })
+        .await;
+
+        assert_eq!(input().value(), "Banana");
+
+        t.query_by_role("combobox").keydown("ArrowDown").await;
+
+        assert_eq!(
+            t.query_all_by_role("option")[1].attribute("aria-selected"),
+            Some("true".to_string())
+        );
+
+        t.query_by_role("combobox")
+            .keydown("ArrowDown")
+            .await
+            .keydown("Enter")
+            .await;
+
+        assert_eq!(
+            t.get_remembered_value::<(AttrValue, AttrValue)>(),
+            (AttrValue::from("apple"), AttrValue::from("Apple"))
+        );
+        assert_eq!(input().value(), "Apple");
+
+        // The parent changing both values moves the input and the selection along
+        t.query_by_text("Pick banana").click().await;
+
+        assert_eq!(
+            t.get_remembered_value::<(AttrValue, AttrValue)>(),
+            (AttrValue::from("banana"), AttrValue::from("Banana"))
+        );
+        assert_eq!(input().value(), "Banana");
+
+        t.query_by_role("combobox").keydown("ArrowDown").await;
+
+        assert_eq!(
+            t.query_all_by_role("option")[1].attribute("aria-selected"),
+            Some("true".to_string())
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_combobox_instances_get_distinct_ids() {
+        let t = render!({
+            html! {
+                <>
+                    <Combobox>
+                        <ComboboxInput placeholder="First" />
+                    </Combobox>
+                    <Combobox>
+                        <ComboboxInput placeholder="Second" />
+                    </Combobox>
+                </>
+            }
+        })
+        .await;
+
+        let inputs = t.query_all_by_role("combobox");
+        assert_eq!(inputs.len(), 2);
+
+        let first = inputs[0].attribute("id").expect("Failed to get the id");
+        let second = inputs[1].attribute("id").expect("Failed to get the id");
+
+        assert_ne!(first, second);
+        assert_eq!(
+            inputs[0].attribute("aria-controls"),
+            Some(format!("{first}-listbox"))
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_combobox_keeps_an_explicit_id() {
+        let t = render!({
+            html! {
+                <Combobox id="fruit">
+                    <ComboboxInput placeholder="Fruit" />
+                </Combobox>
+            }
+        })
+        .await;
+
+        assert_eq!(
+            t.query_by_role("combobox").attribute("id"),
+            Some("fruit".to_string())
+        );
+    }
+}
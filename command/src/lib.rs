@@ -0,0 +1,681 @@
+use std::collections::{BTreeMap, HashSet};
+use std::rc::Rc;
+use web_sys::wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement, HtmlInputElement};
+use yew::prelude::*;
+use yewlish_utils::hooks::{use_auto_id, use_controllable_state, use_keydown};
+
+/// Matches when every character of `search` appears in `label` in the same order, ignoring case.
+///
+/// Plain substrings always match, so typing a whole word behaves as expected.
+pub fn fuzzy_match(label: &str, search: &str) -> bool {
+    let mut label = label.chars().flat_map(char::to_lowercase);
+
+    search
+        .chars()
+        .flat_map(char::to_lowercase)
+        .all(|searched| label.any(|candidate| candidate == searched))
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct CommandEntry {
+    label: AttrValue,
+    group: Option<AttrValue>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+struct CommandRegistry {
+    items: BTreeMap<AttrValue, CommandEntry>,
+}
+
+enum CommandRegistryAction {
+    Register(AttrValue, CommandEntry),
+    Unregister(AttrValue),
+}
+
+impl Reducible for CommandRegistry {
+    type Action = CommandRegistryAction;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let mut items = self.items.clone();
+
+        match action {
+            CommandRegistryAction::Register(value, entry) => {
+                items.insert(value, entry);
+            }
+            CommandRegistryAction::Unregister(value) => {
+                items.remove(&value);
+            }
+        }
+
+        Rc::new(Self { items })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CommandContext {
+    pub id: AttrValue,
+    pub search: AttrValue,
+    pub active: AttrValue,
+    pub should_filter: bool,
+    /// How many registered items match the current search
+    pub matched_count: usize,
+    /// Groups that contain at least one matching item
+    pub matched_groups: Rc<HashSet<AttrValue>>,
+    pub list_ref: NodeRef,
+    pub on_search_change: Callback<AttrValue>,
+    pub on_active_change: Callback<AttrValue>,
+    pub on_register: Callback<(AttrValue, AttrValue, Option<AttrValue>)>,
+    pub on_unregister: Callback<AttrValue>,
+}
+
+impl CommandContext {
+    pub fn item_id(&self, value: &str) -> AttrValue {
+        format!("{}-item-{}", self.id, value).into()
+    }
+
+    pub fn list_id(&self) -> AttrValue {
+        format!("{}-list", self.id).into()
+    }
+
+    pub fn matches(&self, label: &str) -> bool {
+        !self.should_filter || self.search.is_empty() || fuzzy_match(label, &self.search)
+    }
+}
+
+fn collect_items(list_ref: &NodeRef) -> Vec<Element> {
+    let Some(list) = list_ref.cast::<Element>() else {
+        return vec![];
+    };
+
+    let Ok(nodes) =
+        list.query_selector_all("[role='option']:not([hidden]):not([aria-disabled='true'])")
+    else {
+        return vec![];
+    };
+
+    (0..nodes.length())
+        .filter_map(|index| nodes.item(index))
+        .filter_map(|node| node.dyn_into::<Element>().ok())
+        .collect()
+}
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct CommandProps {
+    #[prop_or_default]
+    pub children: Children,
+    #[prop_or_default]
+    pub id: Option<AttrValue>,
+    #[prop_or_default]
+    pub class: Option<AttrValue>,
+    #[prop_or_default]
+    pub search: Option<AttrValue>,
+    #[prop_or_default]
+    pub default_search: Option<AttrValue>,
+    #[prop_or_default]
+    pub on_search_change: Callback<AttrValue>,
+    /// Filter the items by the search on the client. Disable it when the
+    /// items are already filtered, e.g. loaded from the server per query.
+    #[prop_or(true)]
+    pub should_filter: bool,
+}
+
+/// A filterable list of commands, like a command palette.
+///
+/// `Command` only renders the palette itself, so it can be placed inline
+/// or inside any dialog or popover.
+///
+/// # Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use command::{Command, CommandEmpty, CommandGroup, CommandInput, CommandItem, CommandList};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let on_select = Callback::from(|_value: AttrValue| {
+///         // Run the command
+///     });
+///
+///     html! {
+///         <Command>
+///             <CommandInput placeholder="Type a command" />
+///             <CommandList>
+///                 <CommandEmpty>{"No results"}</CommandEmpty>
+///                 <CommandGroup heading="File">
+///                     <CommandItem value="new-file" label="New file" on_select={on_select.clone()}>
+///                         {"New file"}
+///                     </CommandItem>
+///                     <CommandItem value="save" label="Save" {on_select}>{"Save"}</CommandItem>
+///                 </CommandGroup>
+///             </CommandList>
+///         </Command>
+///     }
+/// }
+/// ```
+#[function_component(Command)]
+pub fn command(props: &CommandProps) -> Html {
+    let auto_id = use_auto_id(Some("command"));
+
+    let (search, dispatch_search) = use_controllable_state(
+        props.default_search.clone(),
+        props.search.clone(),
+        props.on_search_change.clone(),
+    );
+
+    let active = use_state(|| AttrValue::from(""));
+    let registry = use_reducer(CommandRegistry::default);
+    let list_ref = use_node_ref();
+
+    let on_search_change = use_callback(dispatch_search.clone(), |next: AttrValue, dispatch| {
+        dispatch.emit(Box::new(move |_| next.clone()));
+    });
+
+    let on_active_change = use_callback(active.clone(), |next: AttrValue, active| {
+        active.set(next);
+    });
+
+    let on_register = use_callback(
+        registry.dispatcher(),
+        |(value, label, group): (AttrValue, AttrValue, Option<AttrValue>), registry| {
+            registry.dispatch(CommandRegistryAction::Register(
+                value,
+                CommandEntry { label, group },
+            ));
+        },
+    );
+
+    let on_unregister = use_callback(registry.dispatcher(), |value: AttrValue, registry| {
+        registry.dispatch(CommandRegistryAction::Unregister(value));
+    });
+
+    let mut context_value = CommandContext {
+        id: props.id.clone().unwrap_or(auto_id),
+        search: search.borrow().clone(),
+        active: (*active).clone(),
+        should_filter: props.should_filter,
+        matched_count: 0,
+        matched_groups: Rc::default(),
+        list_ref: list_ref.clone(),
+        on_search_change,
+        on_active_change,
+        on_register,
+        on_unregister,
+    };
+
+    let matched = registry
+        .items
+        .values()
+        .filter(|entry| context_value.matches(&entry.label))
+        .collect::<Vec<_>>();
+
+    context_value.matched_count = matched.len();
+    context_value.matched_groups = Rc::new(
+        matched
+            .into_iter()
+            .filter_map(|entry| entry.group.clone())
+            .collect(),
+    );
+
+    // Keep the highlight on a visible item, falling back to the first one
+    use_effect_with(
+        (
+            context_value.search.clone(),
+            registry.clone(),
+            active.clone(),
+        ),
+        move |(_, _, active)| {
+            let items = collect_items(&list_ref);
+
+            let is_active_visible = items
+                .iter()
+                .any(|item| item.get_attribute("data-value").as_deref() == Some(active.as_str()));
+
+            if !is_active_visible {
+                let first = items
+                    .first()
+                    .and_then(|item| item.get_attribute("data-value"))
+                    .unwrap_or_default();
+
+                if first != active.as_str() {
+                    active.set(first.into());
+                }
+            }
+        },
+    );
+
+    html! {
+        <ContextProvider<CommandContext> context={context_value}>
+            <div class={&props.class} data-command="">
+                {props.children.clone()}
+            </div>
+        </ContextProvider<CommandContext>>
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct CommandInputProps {
+    #[prop_or_default]
+    pub r#ref: NodeRef,
+    #[prop_or_default]
+    pub class: Option<AttrValue>,
+    #[prop_or_default]
+    pub placeholder: Option<AttrValue>,
+    #[prop_or_default]
+    pub disabled: bool,
+}
+
+#[function_component(CommandInput)]
+pub fn command_input(props: &CommandInputProps) -> Html {
+    let context = use_context::<CommandContext>().expect("CommandInput must be a child of Command");
+
+    let oninput = use_callback(context.clone(), |event: InputEvent, context| {
+        let Some(input) = event.target_dyn_into::<HtmlInputElement>() else {
+            return;
+        };
+
+        context.on_search_change.emit(input.value().into());
+    });
+
+    let navigation_handler = {
+        let context = context.clone();
+
+        move |event: KeyboardEvent| {
+            let items = collect_items(&context.list_ref);
+
+            if items.is_empty() {
+                return;
+            }
+
+            let active_id = context.item_id(&context.active);
+            let current = items.iter().position(|item| item.id() == active_id);
+
+            let next = match (event.key().as_str(), current) {
+                ("Enter", Some(index)) => {
+                    if let Some(item) = items[index].dyn_ref::<HtmlElement>() {
+                        item.click();
+                    }
+
+                    return;
+                }
+                ("Enter", None) => return,
+                ("Home", _) => 0,
+                ("End", _) => items.len() - 1,
+                ("ArrowDown", Some(index)) => (index + 1) % items.len(),
+                ("ArrowDown", None) => 0,
+                (_, Some(0) | None) => items.len() - 1,
+                (_, Some(index)) => index - 1,
+            };
+
+            event.prevent_default();
+
+            if let Some(value) = items[next].get_attribute("data-value") {
+                items[next].scroll_into_view_with_bool(false);
+                context.on_active_change.emit(value.into());
+            }
+        }
+    };
+
+    let onkeydown = use_keydown(
+        vec![
+            "ArrowDown".into(),
+            "ArrowUp".into(),
+            "Home".into(),
+            "End".into(),
+            "Enter".into(),
+        ],
+        navigation_handler,
+    );
+
+    let active_descendant = (!context.active.is_empty()).then(|| context.item_id(&context.active));
+
+    html! {
+        <input
+            ref={props.r#ref.clone()}
+            type="text"
+            role="combobox"
+            class={&props.class}
+            placeholder={&props.placeholder}
+            disabled={props.disabled}
+            autocomplete="off"
+            aria-autocomplete="list"
+            aria-expanded="true"
+            aria-controls={context.list_id()}
+            aria-activedescendant={active_descendant}
+            value={context.search.clone()}
+            {oninput}
+            {onkeydown}
+        />
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct CommandListProps {
+    #[prop_or_default]
+    pub children: Children,
+    #[prop_or_default]
+    pub class: Option<AttrValue>,
+}
+
+#[function_component(CommandList)]
+pub fn command_list(props: &CommandListProps) -> Html {
+    let context = use_context::<CommandContext>().expect("CommandList must be a child of Command");
+
+    html! {
+        <div
+            ref={context.list_ref.clone()}
+            id={context.list_id()}
+            role="listbox"
+            class={&props.class}
+        >
+            {props.children.clone()}
+        </div>
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct CommandGroupContext {
+    heading: AttrValue,
+}
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct CommandGroupProps {
+    #[prop_or_default]
+    pub children: Children,
+    #[prop_or_default]
+    pub class: Option<AttrValue>,
+    pub heading: AttrValue,
+}
+
+/// A titled section of items, hidden while none of its items match the search.
+#[function_component(CommandGroup)]
+pub fn command_group(props: &CommandGroupProps) -> Html {
+    let context = use_context::<CommandContext>().expect("CommandGroup must be a child of Command");
+    let heading_id = format!("{}-group-{}", context.id, props.heading);
+
+    html! {
+        <ContextProvider<CommandGroupContext> context={CommandGroupContext { heading: props.heading.clone() }}>
+            <div
+                role="group"
+                class={&props.class}
+                aria-labelledby={heading_id.clone()}
+                hidden={!context.matched_groups.contains(&props.heading)}
+            >
+                <div id={heading_id} data-command-group-heading="">
+                    {&props.heading}
+                </div>
+                {props.children.clone()}
+            </div>
+        </ContextProvider<CommandGroupContext>>
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct CommandItemProps {
+    pub value: AttrValue,
+    /// The text matched against the search, defaults to `value`
+    #[prop_or_default]
+    pub label: Option<AttrValue>,
+    #[prop_or_default]
+    pub children: Children,
+    #[prop_or_default]
+    pub class: Option<AttrValue>,
+    #[prop_or_default]
+    pub disabled: bool,
+    #[prop_or_default]
+    pub on_select: Callback<AttrValue>,
+}
+
+#[function_component(CommandItem)]
+pub fn command_item(props: &CommandItemProps) -> Html {
+    let context = use_context::<CommandContext>().expect("CommandItem must be a child of Command");
+    let group = use_context::<CommandGroupContext>().map(|group| group.heading);
+
+    let label = props.label.clone().unwrap_or_else(|| props.value.clone());
+
+    // Items stay mounted while filtered out, so they only register once
+    use_effect_with(
+        (
+            props.value.clone(),
+            label.clone(),
+            group,
+            context.on_register.clone(),
+            context.on_unregister.clone(),
+        ),
+        |(value, label, group, on_register, on_unregister)| {
+            on_register.emit((value.clone(), label.clone(), group.clone()));
+
+            let value = value.clone();
+            let on_unregister = on_unregister.clone();
+
+            move || on_unregister.emit(value)
+        },
+    );
+
+    let select_on_click = use_callback(
+        (props.value.clone(), props.disabled, props.on_select.clone()),
+        |_event: MouseEvent, (value, disabled, on_select)| {
+            if *disabled {
+                return;
+            }
+
+            on_select.emit(value.clone());
+        },
+    );
+
+    let activate_on_hover = use_callback(
+        (props.value.clone(), props.disabled, context.clone()),
+        |_event: MouseEvent, (value, disabled, context)| {
+            if !*disabled && context.active != *value {
+                context.on_active_change.emit(value.clone());
+            }
+        },
+    );
+
+    let is_active = context.active == props.value;
+
+    html! {
+        <div
+            id={context.item_id(&props.value)}
+            role="option"
+            class={&props.class}
+            data-value={props.value.clone()}
+            aria-selected={if is_active { "true" } else { "false" }}
+            aria-disabled={if props.disabled { Some("true") } else { None }}
+            data-active={if is_active { "true" } else { "false" }}
+            hidden={!context.matches(&label)}
+            onmousemove={activate_on_hover}
+            onclick={select_on_click}
+        >
+            {props.children.clone()}
+        </div>
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct CommandEmptyProps {
+    #[prop_or_default]
+    pub children: Children,
+    #[prop_or_default]
+    pub class: Option<AttrValue>,
+}
+
+/// Rendered only while no item matches the search.
+#[function_component(CommandEmpty)]
+pub fn command_empty(props: &CommandEmptyProps) -> Html {
+    let context = use_context::<CommandContext>().expect("CommandEmpty must be a child of Command");
+
+    if context.matched_count > 0 {
+        return html! {};
+    }
+
+    html! {
+        <div role="presentation" class={&props.class}>
+            {props.children.clone()}
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+    use yewlish_testing_tools::*;
+
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    fn input() -> HtmlInputElement {
+        web_sys::window()
+            .and_then(|window| window.document())
+            .and_then(|document| document.query_selector("input[role='combobox']").ok())
+            .flatten()
+            .and_then(|element| element.dyn_into().ok())
+            .expect("Failed to find the input")
+    }
+
+    async fn type_into(input: &HtmlInputElement, text: &str) {
+        input.set_value(text);
+
+        let init = web_sys::InputEventInit::new();
+        init.set_bubbles(true);
+
+        let event = web_sys::InputEvent::new_with_event_init_dict("input", &init)
+            .expect("Failed to create the input event");
+
+        let _ = input.dispatch_event(&event);
+        yew::platform::time::sleep(std::time::Duration::ZERO).await;
+    }
+
+    fn is_hidden(t: &Tester, selector: &str) -> bool {
+        t.query_by_selector(selector).attribute("hidden").is_some()
+    }
+
+    #[test]
+    fn test_fuzzy_match() {
+        assert!(fuzzy_match("New file", ""));
+        assert!(fuzzy_match("New file", "file"));
+        assert!(fuzzy_match("New file", "nf"));
+        assert!(fuzzy_match("New file", "NWFL"));
+        assert!(!fuzzy_match("New file", "fn"));
+        assert!(!fuzzy_match("New file", "new files"));
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_command_filters_items_and_groups() {
+        let t = render!({
+            html! {
+                <Command>
+                    <CommandInput placeholder="Type a command" />
+                    <CommandList>
+                        <CommandEmpty>{"No results"}</CommandEmpty>
+                        <CommandGroup heading="File">
+                            <CommandItem value="new-file" label="New file">{"New file"}</CommandItem>
+                            <CommandItem value="save" label="Save">{"Save"}</CommandItem>
+                        </CommandGroup>
+                        <CommandGroup heading="Edit">
+                            <CommandItem value="copy" label="Copy">{"Copy"}</CommandItem>
+                        </CommandGroup>
+                    </CommandList>
+                </Command>
+            }
+        })
+        .await;
+
+        assert!(
+            t.wait_for(100.0, || {
+                t.query_by_selector("[data-value='new-file']")
+                    .attribute("data-active")
+                    == Some("true".to_string())
+            })
+            .await
+        );
+        assert!(!t.query_by_text("No results").exists());
+
+        type_into(&input(), "nf").await;
+
+        assert!(!is_hidden(&t, "[data-value='new-file']"));
+        assert!(is_hidden(&t, "[data-value='save']"));
+        assert!(is_hidden(&t, "[data-value='copy']"));
+        assert!(is_hidden(&t, "[aria-labelledby$='-group-Edit']"));
+        assert!(!is_hidden(&t, "[aria-labelledby$='-group-File']"));
+
+        type_into(&input(), "cpy").await;
+
+        // The highlight moves to the only visible item
+        assert!(
+            t.wait_for(100.0, || {
+                t.query_by_selector("[data-value='copy']")
+                    .attribute("data-active")
+                    == Some("true".to_string())
+            })
+            .await
+        );
+        assert!(is_hidden(&t, "[aria-labelledby$='-group-File']"));
+
+        type_into(&input(), "zzz").await;
+
+        assert!(t.query_by_text("No results").exists());
+        assert!(is_hidden(&t, "[data-value='copy']"));
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_command_keyboard_navigation_selects_the_active_item() {
+        let t = render!({
+            let selected = use_state(Vec::<AttrValue>::new);
+
+            let on_select = use_callback(selected.clone(), |value, selected| {
+                let mut next = (**selected).clone();
+                next.push(value);
+                selected.set(next);
+            });
+
+            use_remember_value((*selected).clone());
+
+            html! {
+                <Command>
+                    <CommandInput />
+                    <CommandList>
+                        <CommandItem value="new-file" on_select={on_select.clone()}>{"New file"}</CommandItem>
+                        <CommandItem value="save" disabled=true on_select={on_select.clone()}>{"Save"}</CommandItem>
+                        <CommandItem value="close" {on_select}>{"Close"}</CommandItem>
+                    </CommandList>
+                </Command>
+            }
+        })
+        .await;
+
+        assert!(
+            t.wait_for(100.0, || {
+                t.query_by_role("combobox")
+                    .attribute("aria-activedescendant")
+                    .is_some_and(|id| id.ends_with("-item-new-file"))
+            })
+            .await
+        );
+
+        // Disabled items are skipped
+        let t = t.query_by_role("combobox").keydown("ArrowDown").await;
+
+        assert_eq!(
+            t.query_by_selector("[data-value='close']")
+                .attribute("data-active"),
+            Some("true".to_string())
+        );
+
+        let t = t.query_by_role("combobox").keydown("Enter").await;
+
+        assert_eq!(
+            t.get_remembered_value::<Vec<AttrValue>>(),
+            vec![AttrValue::from("close")]
+        );
+
+        // Wraps around to the first item
+        let t = t.query_by_role("combobox").keydown("ArrowDown").await;
+        let t = t.query_by_role("combobox").keydown("Enter").await;
+
+        assert_eq!(
+            t.get_remembered_value::<Vec<AttrValue>>(),
+            vec![AttrValue::from("close"), AttrValue::from("new-file")]
+        );
+    }
+}
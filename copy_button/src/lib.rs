@@ -0,0 +1,262 @@
+use web_sys::wasm_bindgen::{closure::Closure, JsCast};
+use yew::prelude::*;
+use yewlish_presence::{Presence, PresenceRenderAsProps};
+use yewlish_utils::hooks::{copy_to_clipboard, ClipboardError};
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct CopyButtonProps {
+    /// The text written to the clipboard on click
+    pub text: AttrValue,
+    #[prop_or_default]
+    pub children: Children,
+    #[prop_or_default]
+    pub class: Option<AttrValue>,
+    #[prop_or_default]
+    pub copied_class: Option<AttrValue>,
+    /// Shown after the children while the button is in the copied state
+    #[prop_or(AttrValue::Static("Copied"))]
+    pub copied_label: AttrValue,
+    /// Milliseconds the button stays in the copied state
+    #[prop_or(2000)]
+    pub duration: u32,
+    #[prop_or_default]
+    pub disabled: bool,
+    /// Receives the copied text, or the error when the browser refused the write
+    #[prop_or_default]
+    pub on_copy: Callback<Result<String, ClipboardError>>,
+}
+
+/// A button that copies `text` to the clipboard and briefly confirms it.
+///
+/// # Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use copy_button::CopyButton;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <CopyButton text="cargo add yewlish-utils">{"Copy"}</CopyButton>
+///     }
+/// }
+/// ```
+#[function_component(CopyButton)]
+pub fn copy_button(props: &CopyButtonProps) -> Html {
+    // Bumped on every successful copy so repeated copies restart the timer
+    let copied_count = use_state_eq(|| 0_u32);
+    let copied = use_state_eq(|| false);
+
+    let copy = use_callback(
+        (
+            props.text.clone(),
+            props.on_copy.clone(),
+            copied_count.clone(),
+            copied.setter(),
+        ),
+        |_event: MouseEvent, (text, on_copy, copied_count, set_copied)| {
+            let text = text.to_string();
+            let on_copy = on_copy.clone();
+            let copied_count = copied_count.clone();
+            let set_copied = set_copied.clone();
+
+            wasm_bindgen_futures::spawn_local(async move {
+                let result = copy_to_clipboard(&text).await.map(|()| text);
+
+                if result.is_ok() {
+                    copied_count.set(*copied_count + 1);
+                    set_copied.set(true);
+                }
+
+                on_copy.emit(result);
+            });
+        },
+    );
+
+    use_effect_with(
+        (*copied_count, props.duration, copied.setter()),
+        |(copied_count, duration, set_copied)| {
+            let window = web_sys::window();
+
+            let timeout_id = match (&window, *copied_count > 0) {
+                (Some(window), true) => {
+                    let set_copied = set_copied.clone();
+                    let callback = Closure::once_into_js(move || set_copied.set(false));
+
+                    match window.set_timeout_with_callback_and_timeout_and_arguments_0(
+                        callback.unchecked_ref(),
+                        *duration as i32,
+                    ) {
+                        Ok(timeout_id) => Some(timeout_id),
+                        Err(err) => {
+                            log::error!("Failed to set timeout {err:?}");
+                            None
+                        }
+                    }
+                }
+                _ => None,
+            };
+
+            move || {
+                if let (Some(window), Some(timeout_id)) = (window, timeout_id) {
+                    window.clear_timeout_with_handle(timeout_id);
+                }
+            }
+        },
+    );
+
+    let copied_ref = use_node_ref();
+
+    html! {
+        <button
+            type="button"
+            class={&props.class}
+            disabled={props.disabled}
+            data-state={if *copied { "copied" } else { "idle" }}
+            onclick={copy}
+        >
+            {for props.children.iter()}
+
+            <Presence
+                name="copy-button-copied"
+                r#ref={copied_ref}
+                class={&props.copied_class}
+                present={*copied}
+                render_as={Callback::from(|presence_props: PresenceRenderAsProps| {
+                    if !presence_props.presence {
+                        return html! {};
+                    }
+
+                    html! {
+                        <span
+                            ref={presence_props.r#ref.clone()}
+                            class={&presence_props.class}
+                            role="status"
+                        >
+                            {presence_props.children.clone()}
+                        </span>
+                    }
+                })}
+            >
+                {props.copied_label.clone()}
+            </Presence>
+        </button>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+    use web_sys::js_sys::Function;
+    use web_sys::wasm_bindgen::JsValue;
+    use yewlish_testing_tools::*;
+
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    /// Replaces `navigator.clipboard` with one whose `writeText` returns `promise`.
+    fn stub_clipboard(promise: &str) {
+        Function::new_no_args(&format!(
+            "Object.defineProperty(navigator, 'clipboard', {{ \
+                configurable: true, \
+                value: {{ writeText: () => {promise} }} \
+            }});"
+        ))
+        .call0(&JsValue::NULL)
+        .expect("Failed to stub the clipboard");
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_copy_button_shows_the_copied_state_and_resets_it() {
+        stub_clipboard("Promise.resolve()");
+
+        let t = render!({
+            let copies = use_state(Vec::<Result<String, ClipboardError>>::new);
+
+            let on_copy = use_callback(copies.clone(), |result, copies| {
+                let mut next = (**copies).clone();
+                next.push(result);
+                copies.set(next);
+            });
+
+            use_remember_value((*copies).clone());
+
+            html! {
+                <CopyButton text="cargo add yewlish-utils" duration={50} {on_copy}>
+                    {"Copy"}
+                </CopyButton>
+            }
+        })
+        .await;
+
+        assert_eq!(
+            t.query_by_role("button").attribute("data-state"),
+            Some("idle".to_string())
+        );
+        assert!(!t.query_by_role("status").exists());
+
+        let t = t.query_by_role("button").click().await;
+
+        assert!(
+            t.wait_for(100.0, || {
+                t.query_by_role("button").attribute("data-state") == Some("copied".to_string())
+            })
+            .await
+        );
+        assert_eq!(t.query_by_role("status").text(), "Copied");
+        assert_eq!(
+            t.get_remembered_value::<Vec<Result<String, ClipboardError>>>(),
+            vec![Ok("cargo add yewlish-utils".to_string())]
+        );
+
+        assert!(
+            t.wait_for(500.0, || {
+                t.query_by_role("button").attribute("data-state") == Some("idle".to_string())
+            })
+            .await
+        );
+        assert!(!t.query_by_role("status").exists());
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_copy_button_reports_a_rejected_write() {
+        stub_clipboard("Promise.reject(new Error('denied'))");
+
+        let t = render!({
+            let copies = use_state(Vec::<Result<String, ClipboardError>>::new);
+
+            let on_copy = use_callback(copies.clone(), |result, copies| {
+                let mut next = (**copies).clone();
+                next.push(result);
+                copies.set(next);
+            });
+
+            use_remember_value((*copies).clone());
+
+            html! {
+                <CopyButton text="secret" {on_copy}>{"Copy"}</CopyButton>
+            }
+        })
+        .await;
+
+        let t = t.query_by_role("button").click().await;
+
+        assert!(
+            t.wait_for(100.0, || {
+                !t.get_remembered_value::<Vec<Result<String, ClipboardError>>>()
+                    .is_empty()
+            })
+            .await
+        );
+
+        let copies = t.get_remembered_value::<Vec<Result<String, ClipboardError>>>();
+
+        assert_eq!(copies.len(), 1);
+        assert!(matches!(copies[0], Err(ClipboardError::Rejected(_))));
+        assert_eq!(
+            t.query_by_role("button").attribute("data-state"),
+            Some("idle".to_string())
+        );
+        assert!(!t.query_by_role("status").exists());
+    }
+}
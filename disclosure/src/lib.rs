@@ -0,0 +1,188 @@
+use web_sys::HtmlDetailsElement;
+use yew::prelude::*;
+use yewlish_utils::hooks::{use_controllable_open, use_event_listener};
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct DisclosureProps {
+    /// Rendered within the `<summary>`, which toggles the disclosure on click, Enter and Space
+    pub summary: Html,
+    #[prop_or_default]
+    pub children: Children,
+    #[prop_or_default]
+    pub open: Option<bool>,
+    #[prop_or_default]
+    pub default_open: bool,
+    /// Called with the new open state whenever the user toggles the disclosure
+    #[prop_or_default]
+    pub on_toggle: Callback<bool>,
+    #[prop_or_default]
+    pub class: Option<AttrValue>,
+    #[prop_or_default]
+    pub summary_class: Option<AttrValue>,
+}
+
+/// A disclosure built on the native `<details>` and `<summary>` elements.
+///
+/// The browser toggles `<details>` on its own, so the native state is reconciled with
+/// `open` after every `toggle` event: a controlled disclosure stays as it is until the
+/// parent passes the new value.
+///
+/// # Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use disclosure::Disclosure;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Disclosure summary={html! { "Details" }}>
+///             {"Hidden until the summary is toggled"}
+///         </Disclosure>
+///     }
+/// }
+/// ```
+#[function_component(Disclosure)]
+pub fn disclosure(props: &DisclosureProps) -> Html {
+    let node_ref = use_node_ref();
+    let rerender = use_force_update();
+
+    let (is_open, set_open) =
+        use_controllable_open(props.default_open, props.open, props.on_toggle.clone());
+
+    let is_open = props.open.unwrap_or(is_open);
+
+    let on_native_toggle = use_callback(
+        (node_ref.clone(), is_open, set_open),
+        move |_event: Event, (node_ref, is_open, set_open)| {
+            let Some(details) = node_ref.cast::<HtmlDetailsElement>() else {
+                return;
+            };
+
+            // Also fired when the effect below applies `is_open`
+            if details.open() == *is_open {
+                return;
+            }
+
+            set_open.emit(details.open());
+
+            // Renders again so the effect restores the state a controlled parent kept
+            rerender.force_update();
+        },
+    );
+
+    use_event_listener(&node_ref, "toggle", on_native_toggle);
+
+    // Yew only patches `open` when the prop changes, not when the browser changed it
+    use_effect({
+        let node_ref = node_ref.clone();
+
+        move || {
+            if let Some(details) = node_ref.cast::<HtmlDetailsElement>() {
+                details.set_open(is_open);
+            }
+        }
+    });
+
+    let data_state = if is_open { "open" } else { "closed" };
+
+    html! {
+        <details ref={node_ref} class={&props.class} open={is_open} data-state={data_state}>
+            <summary class={&props.summary_class} data-state={data_state}>
+                {props.summary.clone()}
+            </summary>
+            {props.children.clone()}
+        </details>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+    use wasm_bindgen_test::*;
+    use yew::platform::time::sleep;
+    use yewlish_testing_tools::*;
+
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    fn details() -> HtmlDetailsElement {
+        web_sys::window()
+            .and_then(|window| window.document())
+            .and_then(|document| document.query_selector("details").ok())
+            .flatten()
+            .and_then(|element| web_sys::wasm_bindgen::JsCast::dyn_into(element).ok())
+            .expect("Failed to find the details element")
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_disclosure_uncontrolled() {
+        let t = render!({
+            let toggles = use_state(Vec::<bool>::new);
+
+            let on_toggle = use_callback(toggles.clone(), |open, toggles| {
+                let mut next = (**toggles).clone();
+                next.push(open);
+                toggles.set(next);
+            });
+
+            use_remember_value((*toggles).clone());
+
+            html! {
+                <Disclosure summary={html! { "Summary" }} {on_toggle}>
+                    {"Content"}
+                </Disclosure>
+            }
+        })
+        .await;
+
+        assert_eq!(
+            t.query_by_selector("details").attribute("data-state"),
+            Some("closed".to_string())
+        );
+
+        details().set_open(true);
+        sleep(Duration::from_millis(50)).await;
+
+        assert_eq!(
+            t.query_by_selector("details").attribute("data-state"),
+            Some("open".to_string())
+        );
+        assert_eq!(t.get_remembered_value::<Vec<bool>>(), vec![true]);
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_disclosure_controlled_keeps_the_prop() {
+        let t = render!({
+            let toggles = use_state(Vec::<bool>::new);
+
+            let on_toggle = use_callback(toggles.clone(), |open, toggles| {
+                let mut next = (**toggles).clone();
+                next.push(open);
+                toggles.set(next);
+            });
+
+            use_remember_value((*toggles).clone());
+
+            html! {
+                <Disclosure summary={html! { "Summary" }} open={true} {on_toggle}>
+                    {"Content"}
+                </Disclosure>
+            }
+        })
+        .await;
+
+        assert!(details().open());
+
+        // The parent ignores the change, so the native state is restored
+        details().set_open(false);
+        sleep(Duration::from_millis(50)).await;
+
+        assert!(details().open());
+        assert_eq!(
+            t.query_by_selector("details").attribute("data-state"),
+            Some("open".to_string())
+        );
+        assert_eq!(t.get_remembered_value::<Vec<bool>>(), vec![false]);
+    }
+}
@@ -14,6 +14,7 @@ struct FetchAttributeArgs {
     query: Option<Type>,
     body: Option<Type>,
     res: Option<Type>,
+    stream: bool,
 }
 
 impl Parse for FetchAttributeArgs {
@@ -23,10 +24,17 @@ impl Parse for FetchAttributeArgs {
         let mut query = None;
         let mut body = None;
         let mut res = None;
+        let mut stream = false;
 
         while !input.is_empty() {
             input.parse::<Token![,]>()?;
             let ident: Ident = input.parse()?;
+
+            if ident == "stream" {
+                stream = true;
+                continue;
+            }
+
             input.parse::<Token![=]>()?;
 
             match ident.to_string().as_str() {
@@ -61,13 +69,16 @@ impl Parse for FetchAttributeArgs {
             query,
             body,
             res,
+            stream,
         })
     }
 }
 
 static ALLOWED_METHODS: [&str; 6] = ["GET", "POST", "PUT", "PATCH", "DELETE", "WS"];
 
-fn extract_attrs(attrs: &[Attribute]) -> SynResult<(String, String, Type, Type, Type, Type)> {
+type ExtractedAttrs = (String, String, Type, Type, Type, Type, bool);
+
+fn extract_attrs(attrs: &[Attribute]) -> SynResult<ExtractedAttrs> {
     for attr in attrs {
         if let Some(ident) = attr.path().get_ident() {
             if ALLOWED_METHODS.contains(&ident.to_string().to_uppercase().as_str()) {
@@ -77,16 +88,36 @@ fn extract_attrs(attrs: &[Attribute]) -> SynResult<(String, String, Type, Type,
                     query,
                     body,
                     res,
+                    stream,
                 } = attr.parse_args()?;
 
                 let method = ident.to_string().to_uppercase();
+
+                if stream && method != "GET" {
+                    return Err(syn::Error::new_spanned(
+                        attr,
+                        "`stream` is only supported for `#[get]` requests",
+                    ));
+                }
+
+                if stream && res.is_some() {
+                    return Err(syn::Error::new_spanned(
+                        attr,
+                        "`stream` responses are always collected as `Vec<String>` chunks, remove `res`",
+                    ));
+                }
+
                 let path = path.value();
                 let slugs = slugs.unwrap_or_else(|| syn::parse_quote! { () });
                 let query = query.unwrap_or_else(|| syn::parse_quote! { () });
                 let body = body.unwrap_or_else(|| syn::parse_quote! { () });
-                let res = res.unwrap_or_else(|| syn::parse_quote! { () });
+                let res = if stream {
+                    syn::parse_quote! { Vec<String> }
+                } else {
+                    res.unwrap_or_else(|| syn::parse_quote! { () })
+                };
 
-                return Ok((method, path, slugs, query, body, res));
+                return Ok((method, path, slugs, query, body, res, stream));
             }
         }
     }
@@ -110,6 +141,9 @@ pub fn fetch_schema(input: TokenStream) -> TokenStream {
     let fetch_client_hook_name =
         format_ident!("use_{}_fetch_client", enum_name.to_string().to_snake_case());
     let fetch_client_options_name = format_ident!("{}Options", fetch_client_name);
+    let cache_target_name = format_ident!("{}CacheTarget", enum_name);
+    let fetch_client_mock_name = format_ident!("{}Mock", fetch_client_name);
+    let mock = cfg!(feature = "mock");
     let fetch_client_context_props_name = format_ident!("{}ProviderProps", fetch_client_name);
     let fetch_client_context_provider_name = format_ident!("{}Provider", fetch_client_name);
     let fetch_client_context_snake_case_provider_name = format_ident!(
@@ -137,10 +171,13 @@ pub fn fetch_schema(input: TokenStream) -> TokenStream {
     let mut ws_data_enum_variants = Vec::new();
     let mut merged_ws_data_enum_variants = HashMap::new();
     let mut res_types = Vec::new();
+    let mut mock_setters = Vec::new();
+    let mut cache_target_variants = Vec::new();
+    let mut invalidate_arms = Vec::new();
 
     for variant in variants {
         match extract_attrs(&variant.attrs) {
-            Ok((verb, _path, _slugs, _query, _body, res)) => {
+            Ok((verb, _path, _slugs, _query, _body, res, _stream)) => {
                 if verb == "WS" {
                     let res_string = quote!(#res).to_string();
                     let variant_name = &variant.ident;
@@ -168,6 +205,7 @@ pub fn fetch_schema(input: TokenStream) -> TokenStream {
         let get_cache_key_method_name = format_ident!("get_{}_cache_entry", variant_snake_case);
         let prepare_url_method_name = format_ident!("prepare_{}_url", variant_snake_case);
         let update_queries_method_name = format_ident!("update_{}_queries", variant_snake_case);
+        let invalidate_method_name = format_ident!("invalidate_{}", variant_snake_case);
         let common_hook_name = format_ident!("use_common_{}", fetch_method_name);
         let hook_handle_name = format_ident!("Use{}Handle", variant_name);
         let hook_async_handle_name = format_ident!("Use{}AsyncHandle", variant_name);
@@ -175,11 +213,110 @@ pub fn fetch_schema(input: TokenStream) -> TokenStream {
         let hook_with_options_name = format_ident!("{}_with_options", hook_name);
         let hook_name_async = format_ident!("{}_async", hook_name);
         let hook_with_options_name_async = format_ident!("{}_with_options_async", hook_name);
+        let hook_name_suspense = format_ident!("{}_suspense", hook_name);
+        let hook_name_lazy = format_ident!("{}_lazy", hook_name);
+        let lazy_state_key = format!("{variant_snake_case}:lazy");
         let hook_options_name = format_ident!("{}Options", variant_name);
         let hook_states_name = format_ident!("use_{}_states", fetch_method_name);
 
         match extract_attrs(&variant.attrs) {
-            Ok((verb, path, slugs, query, body, res)) => {
+            Ok((verb, path, slugs, query, body, res, stream)) => {
+                // Streaming requests expose a `done` flag next to `loading`
+                let done_field = stream.then(|| quote! { pub done: UseStateHandle<bool>, });
+                let done_eq = stream.then(|| quote! { && self.done == other.done });
+                let done_decl = stream.then(|| quote! { let done = use_state(|| false); });
+                let done_init = stream.then(|| quote! { done, });
+                let done_from_hook = stream.then(|| quote! { done: hook.done, });
+
+                // Queries tell when their data last came from the network, for "updated X ago" UIs
+                let freshness = verb == "GET" && !stream;
+
+                let freshness_field = freshness.then(|| {
+                    quote! {
+                        /// When the data was last received from the network, in milliseconds since the epoch.
+                        /// Data served from the cache doesn't update it
+                        pub updated_at: UseStateHandle<Option<f64>>,
+                        /// Whether `updated_at` is missing or older than the cache max age, as of the last render
+                        pub is_stale: bool,
+                    }
+                });
+
+                let freshness_eq = freshness.then(|| {
+                    quote! { && self.updated_at == other.updated_at && self.is_stale == other.is_stale }
+                });
+
+                let freshness_decl =
+                    freshness.then(|| quote! { let updated_at = use_state(|| None::<f64>); });
+                let freshness_clone =
+                    freshness.then(|| quote! { let updated_at = updated_at.clone(); });
+                let mark_updated = freshness.then(|| quote! { updated_at.set(Some(Date::now())); });
+
+                let freshness_stale = freshness.then(|| {
+                    quote! {
+                        let is_stale = {
+                            let max_age = options
+                                .as_ref()
+                                .and_then(|o| o.cache_options.as_ref().and_then(|options| options.max_age))
+                                .unwrap_or_else(|| client.cache.borrow().max_age());
+
+                            (*updated_at).map_or(true, |updated_at| CacheEntry::is_stale_since(updated_at, max_age))
+                        };
+                    }
+                });
+
+                let freshness_init = freshness.then(|| quote! { updated_at, is_stale, });
+
+                let freshness_from_hook = freshness.then(|| {
+                    quote! { updated_at: hook.updated_at, is_stale: hook.is_stale, }
+                });
+
+                // Queries also tell a first load apart from a refresh of the data they already show
+                let fetching_field = freshness.then(|| {
+                    quote! {
+                        /// Whether a request is in flight, including a background revalidation of data that is
+                        /// already shown. `loading` is only `true` while there's no data yet
+                        pub is_fetching: UseStateHandle<bool>,
+                    }
+                });
+
+                let fetching_eq =
+                    freshness.then(|| quote! { && self.is_fetching == other.is_fetching });
+                let fetching_decl =
+                    freshness.then(|| quote! { let is_fetching = use_state(|| false); });
+                let fetching_clone =
+                    freshness.then(|| quote! { let is_fetching = is_fetching.clone(); });
+                let fetching_init = freshness.then(|| quote! { is_fetching, });
+                let fetching_from_hook =
+                    freshness.then(|| quote! { is_fetching: hook.is_fetching, });
+
+                let start_loading = if freshness {
+                    quote! {
+                        is_fetching.set(true);
+                        loading.set(signal.borrow().get().is_none());
+                    }
+                } else {
+                    quote! { loading.set(true); }
+                };
+
+                // The cached data is shown while the request revalidates it
+                let cached_loaded = freshness.then(|| quote! { loading.set(false); });
+                let stop_fetching = freshness.then(|| quote! { is_fetching.set(false); });
+
+                let select_res = quote! {
+                    let res = match options.as_ref().and_then(|o| o.select.clone()) {
+                        Some(select) => select.emit(res),
+                        None => res,
+                    };
+                };
+
+                let invalidate_targets = quote! {
+                    if let Some(options) = options.as_ref() {
+                        for target in &options.invalidate {
+                            client.invalidate(target);
+                        }
+                    }
+                };
+
                 // Structs for hooks and methods
                 if verb == "WS" {
                     structs.push(quote! {
@@ -189,9 +326,30 @@ pub fn fetch_schema(input: TokenStream) -> TokenStream {
                             pub query: #query,
                         }
 
+                        impl #params_struct_name {
+                            #[must_use]
+                            pub fn new() -> Self {
+                                Self::default()
+                            }
+
+                            #[must_use]
+                            pub fn slugs(mut self, slugs: #slugs) -> Self {
+                                self.slugs = slugs;
+                                self
+                            }
+
+                            #[must_use]
+                            pub fn query(mut self, query: #query) -> Self {
+                                self.query = query;
+                                self
+                            }
+                        }
+
                         #[derive(Clone)]
                         pub struct #hook_handle_name {
                             pub data: UseStateHandle<Option<#res>>,
+                            /// Every received message, oldest first, kept when `accumulate` is set in the options
+                            pub messages: WsMessages<#res>,
                             pub status: UseStateHandle<WsStatus>,
                             pub error: UseStateHandle<Option<FetchError>>,
                             pub send: Callback<#body>,
@@ -201,6 +359,7 @@ pub fn fetch_schema(input: TokenStream) -> TokenStream {
                         impl PartialEq for #hook_handle_name {
                             fn eq(&self, other: &Self) -> bool {
                                 self.data == other.data
+                                    && self.messages == other.messages
                                     && self.status == other.status
                             }
                         }
@@ -208,6 +367,8 @@ pub fn fetch_schema(input: TokenStream) -> TokenStream {
                         #[derive(Clone)]
                         pub struct #hook_async_handle_name {
                             pub data: UseStateHandle<Option<#res>>,
+                            /// Every received message, oldest first, kept when `accumulate` is set in the options
+                            pub messages: WsMessages<#res>,
                             pub status: UseStateHandle<WsStatus>,
                             pub error: UseStateHandle<Option<FetchError>>,
                             pub send: Callback<#body>,
@@ -218,6 +379,7 @@ pub fn fetch_schema(input: TokenStream) -> TokenStream {
                         impl PartialEq for #hook_async_handle_name {
                             fn eq(&self, other: &Self) -> bool {
                                 self.data == other.data
+                                    && self.messages == other.messages
                                     && self.status == other.status
                             }
                         }
@@ -233,6 +395,10 @@ pub fn fetch_schema(input: TokenStream) -> TokenStream {
                             pub on_message: Option<Callback<web_sys::MessageEvent>>,
                             pub on_data: Option<Callback<#res>>,
                             pub on_update: Option<Callback<#on_update_struct_name, Option<#res>>>,
+                            /// Appends each incoming message to the handle's `messages`, e.g. for a chat or a log feed
+                            pub accumulate: bool,
+                            /// Drops the oldest accumulated messages past this count
+                            pub max_messages: Option<usize>,
                             pub on_status_change: Option<Callback<WsStatus>>,
                             pub on_error: Option<Callback<FetchError>>,
                             pub on_open: Option<Callback<web_sys::Event>>,
@@ -248,16 +414,50 @@ pub fn fetch_schema(input: TokenStream) -> TokenStream {
                             pub body: #body,
                         }
 
+                        impl #params_struct_name {
+                            #[must_use]
+                            pub fn new() -> Self {
+                                Self::default()
+                            }
+
+                            #[must_use]
+                            pub fn slugs(mut self, slugs: #slugs) -> Self {
+                                self.slugs = slugs;
+                                self
+                            }
+
+                            #[must_use]
+                            pub fn query(mut self, query: #query) -> Self {
+                                self.query = query;
+                                self
+                            }
+
+                            #[must_use]
+                            pub fn body(mut self, body: #body) -> Self {
+                                self.body = body;
+                                self
+                            }
+                        }
+
                         #[derive(Clone, Debug, PartialEq)]
                         pub struct #state_struct_name {
                             pub data: Rc<RefCell<Signal<Option<#res>>>>,
+                            /// The cache key of the params the query was last sent with
+                            pub cache_key: Rc<RefCell<Option<String>>>,
+                            /// Sends the query again with the params it was last sent with
+                            pub refetch: Rc<RefCell<Option<Callback<()>>>>,
                         }
 
                         #[derive(Clone)]
                         pub struct #hook_handle_name {
                             pub data: UseStateHandle<Option<#res>>,
                             pub loading: UseStateHandle<bool>,
+                            #fetching_field
+                            #done_field
+                            #freshness_field
                             pub error: UseStateHandle<Option<FetchError>>,
+                            /// Sends the request with the hook's current params, e.g. when `enabled` is `false`
+                            pub trigger: Callback<()>,
                             pub cancel: Callback<()>,
                         }
 
@@ -265,6 +465,9 @@ pub fn fetch_schema(input: TokenStream) -> TokenStream {
                             fn eq(&self, other: &Self) -> bool {
                                 self.data == other.data
                                     && self.loading == other.loading
+                                    #fetching_eq
+                                    #done_eq
+                                    #freshness_eq
                             }
                         }
 
@@ -272,6 +475,9 @@ pub fn fetch_schema(input: TokenStream) -> TokenStream {
                         pub struct #hook_async_handle_name {
                             pub data: UseStateHandle<Option<#res>>,
                             pub loading: UseStateHandle<bool>,
+                            #fetching_field
+                            #done_field
+                            #freshness_field
                             pub error: UseStateHandle<Option<FetchError>>,
                             pub trigger: Callback<#params_struct_name>,
                             pub cancel: Callback<()>,
@@ -281,6 +487,9 @@ pub fn fetch_schema(input: TokenStream) -> TokenStream {
                             fn eq(&self, other: &Self) -> bool {
                                 self.data == other.data
                                     && self.loading == other.loading
+                                    #fetching_eq
+                                    #done_eq
+                                    #freshness_eq
                             }
                         }
 
@@ -291,14 +500,59 @@ pub fn fetch_schema(input: TokenStream) -> TokenStream {
                             pub incoming: #res,
                         }
 
-                        #[derive(Clone, PartialEq, Default)]
+                        #[derive(Clone, PartialEq)]
                         pub struct #hook_options_name {
+                            /// Sends the request on mount and whenever the params change, `true` by default.
+                            /// Set it to `false` to only send it through the handle's `trigger`
+                            pub enabled: bool,
                             pub cache_options: Option<CacheOptions>,
                             #[deprecated(note = "Use `on_data` instead")]
                             pub on_success: Option<Callback<#res>>,
                             pub on_data: Option<Callback<#res>>,
+                            /// Maps the response, cached or not, before it's stored in the hook's data, e.g. to
+                            /// keep a slice of it or normalize it. It runs first, `on_update`, `on_data` and
+                            /// `on_success` receive the selected data. The cache keeps the response as it was sent
+                            pub select: Option<Callback<#res, #res>>,
                             pub on_update: Option<Callback<#on_update_struct_name, Option<#res>>>,
                             pub on_error: Option<Callback<FetchError>>,
+                            /// Aborts the request along with this signal, e.g. when a parent operation is cancelled
+                            pub signal: Option<Rc<web_sys::AbortSignal>>,
+                            /// Sends the request to this base URL instead of the client's one
+                            pub base_url_override: Option<String>,
+                            /// Extra headers for the request, set after the client's middlewares
+                            pub headers: Vec<(String, String)>,
+                            /// Replaces the client's credentials mode for this request
+                            pub credentials: Option<web_sys::RequestCredentials>,
+                            /// Replaces the client's CORS mode for this request
+                            pub mode: Option<web_sys::RequestMode>,
+                            /// Receives the download progress of the response body. Uploads report nothing, `fetch`
+                            /// doesn't expose their progress. A deduplicated request reports to the hook that sent it
+                            pub on_progress: Option<Callback<Progress>>,
+                            /// Queries whose cached responses are removed once the request succeeds, their mounted
+                            /// hooks send them again. Meant for mutations, e.g. to refresh a list after a `POST`
+                            pub invalidate: Vec<#cache_target_name>,
+                        }
+
+                        impl Default for #hook_options_name {
+                            #[allow(deprecated)]
+                            fn default() -> Self {
+                                Self {
+                                    enabled: true,
+                                    cache_options: None,
+                                    on_success: None,
+                                    on_data: None,
+                                    select: None,
+                                    on_update: None,
+                                    on_error: None,
+                                    signal: None,
+                                    base_url_override: None,
+                                    headers: Vec::new(),
+                                    credentials: None,
+                                    mode: None,
+                                    on_progress: None,
+                                    invalidate: Vec::new(),
+                                }
+                            }
                         }
                     });
                 }
@@ -340,22 +594,142 @@ pub fn fetch_schema(input: TokenStream) -> TokenStream {
 
                     state_enum_variant_names.push(variant_name);
 
-                    methods.push(quote! {
-                        pub async fn #fetch_method_name(&self, url: String, abort_signal: Rc<web_sys::AbortSignal>, params: #params_struct_name) -> Result<String, FetchError> {
-                            let fetch_options = FetchOptions {
-                                slugs: params.slugs,
-                                query: params.query,
-                                body: params.body,
-                                middlewares: self.middlewares.as_slice(),
-                                abort_signal: abort_signal.clone(),
-                            };
+                    // With the `mock` feature a client holding a mock answers from its presets
+                    let mock_response = (mock && !stream).then(|| {
+                        let set_mock_method_name = format_ident!("set_{}", variant_snake_case);
+
+                        mock_setters.push(quote! {
+                            pub fn #set_mock_method_name(&self, response: Result<#res, FetchError>) -> &Self {
+                                let response = response.and_then(|data| {
+                                    serde_json::to_string(&data).map_err(|error| FetchError::JsonError(error.to_string()))
+                                });
 
-                            fetch::<#slugs, #query, #body>(
-                                HttpMethod::from(#verb),
-                                url.as_str(),
-                                fetch_options,
-                            ).await
+                                (*self.responses).borrow_mut().insert(#variant_snake_case, response);
+                                self
+                            }
+                        });
+
+                        quote! {
+                            if let Some(mock) = &self.mock {
+                                return mock.response(#variant_snake_case);
+                            }
                         }
+                    });
+
+                    let fetch_method = if stream {
+                        quote! {
+                            pub async fn #fetch_method_name(&self, url: String, abort_signal: Rc<web_sys::AbortSignal>, params: #params_struct_name) -> Result<web_sys::Response, FetchError> {
+                                let middlewares = self.request_middlewares();
+
+                                let fetch_options = FetchOptions {
+                                    slugs: params.slugs,
+                                    query: params.query,
+                                    body: params.body,
+                                    middlewares: middlewares.as_slice(),
+                                    abort_signal: abort_signal.clone(),
+                                    credentials: self.credentials,
+                                    mode: self.mode,
+                                    codec: self.codec.as_ref(),
+                                    on_progress: self.on_progress.clone(),
+                                };
+
+                                fetch_response::<#slugs, #query, #body>(
+                                    HttpMethod::from(#verb),
+                                    url.as_str(),
+                                    fetch_options,
+                                ).await
+                            }
+                        }
+                    } else if verb == "GET" {
+                        // Revalidates the cached body with its `ETag` and serves it again on `304 Not Modified`
+                        quote! {
+                            pub async fn #fetch_method_name(&self, url: String, abort_signal: Rc<web_sys::AbortSignal>, params: #params_struct_name) -> Result<String, FetchError> {
+                                #mock_response
+
+                                let cache_key = self.#get_cache_key_method_name(&params)?;
+
+                                let etag = self.cache.borrow().peek(&cache_key).and_then(|entry| entry.etag.clone());
+
+                                let middlewares = self.request_middlewares();
+
+                                let fetch_options = FetchOptions {
+                                    slugs: params.slugs,
+                                    query: params.query,
+                                    body: params.body,
+                                    middlewares: middlewares.as_slice(),
+                                    abort_signal: abort_signal.clone(),
+                                    credentials: self.credentials,
+                                    mode: self.mode,
+                                    codec: self.codec.as_ref(),
+                                    on_progress: self.on_progress.clone(),
+                                };
+
+                                let response = fetch_conditional::<#slugs, #query, #body>(
+                                    HttpMethod::from(#verb),
+                                    url.as_str(),
+                                    fetch_options,
+                                    etag.as_deref(),
+                                ).await?;
+
+                                match response {
+                                    ConditionalResponse::NotModified => {
+                                        let mut cache = (*self.cache).borrow_mut();
+                                        cache.refresh(&cache_key, None);
+
+                                        let Some(entry) = cache.peek(&cache_key) else {
+                                            return Err(FetchError::InvalidResponse("304 Not Modified without a cached body".to_string()));
+                                        };
+
+                                        self.codec.encode(&entry.data).map_err(FetchError::JsonError)
+                                    }
+                                    ConditionalResponse::Modified { body, etag } => {
+                                        if let Some(etag) = etag {
+                                            let value = if body.trim().is_empty() {
+                                                Ok(serde_json::Value::Null)
+                                            } else {
+                                                self.codec.decode(&body)
+                                            };
+
+                                            if let Ok(value) = value {
+                                                (*self.cache).borrow_mut().set_with_etag(&cache_key, &value, None, Some(&etag));
+                                            }
+                                        }
+
+                                        Ok(body)
+                                    }
+                                }
+                            }
+                        }
+                    } else {
+                        quote! {
+                            pub async fn #fetch_method_name(&self, url: String, abort_signal: Rc<web_sys::AbortSignal>, params: #params_struct_name) -> Result<String, FetchError> {
+                                #mock_response
+
+                                let middlewares = self.request_middlewares();
+
+                                let fetch_options = FetchOptions {
+                                    slugs: params.slugs,
+                                    query: params.query,
+                                    body: params.body,
+                                    middlewares: middlewares.as_slice(),
+                                    abort_signal: abort_signal.clone(),
+                                    credentials: self.credentials,
+                                    mode: self.mode,
+                                    codec: self.codec.as_ref(),
+                                    on_progress: self.on_progress.clone(),
+                                };
+
+                                fetch::<#slugs, #query, #body>(
+                                    HttpMethod::from(#verb),
+                                    url.as_str(),
+                                    fetch_options,
+                                ).await
+                            }
+                        }
+                    };
+
+                    methods.push(quote! {
+                        #fetch_method
 
                         pub fn #get_cache_key_method_name(&self, params: &#params_struct_name) -> Result<String, FetchError> {
                             let url = self.#prepare_url_method_name();
@@ -367,7 +741,15 @@ pub fn fetch_schema(input: TokenStream) -> TokenStream {
                                 return Err(FetchError::UnknownError("Failed to generate cache key".to_string()));
                             };
 
-                            Ok(format!("{}:{cache_key}", #variant_snake_case))
+                            // Responses in one locale, or fetched with other headers, are never served for another
+                            let mut key = #variant_snake_case.to_string();
+
+                            for part in [self.locale.as_deref(), self.overrides_key.as_deref()].into_iter().flatten() {
+                                key.push(':');
+                                key.push_str(part);
+                            }
+
+                            Ok(format!("{key}:{cache_key}"))
                         }
 
                         pub fn #update_queries_method_name(&self, cb: impl Fn(Option<#res>) -> Option<#res>) {
@@ -382,6 +764,60 @@ pub fn fetch_schema(input: TokenStream) -> TokenStream {
                             }
                         }
                     });
+
+                    // Only cached queries can be invalidated
+                    if freshness {
+                        cache_target_variants.push(quote! {
+                            #variant_name(Option<#params_struct_name>),
+                        });
+
+                        invalidate_arms.push(quote! {
+                            #cache_target_name::#variant_name(params) => self.#invalidate_method_name(params.as_ref()),
+                        });
+
+                        methods.push(quote! {
+                            /// Removes the cached responses of this query, only the one for `params` if given, and
+                            /// sends the mounted queries that used them again
+                            pub fn #invalidate_method_name(&self, params: Option<&#params_struct_name>) {
+                                let cache_key = match params.map(|params| self.#get_cache_key_method_name(params)) {
+                                    Some(Ok(cache_key)) => Some(cache_key),
+                                    Some(Err(_)) => return,
+                                    None => None,
+                                };
+
+                                {
+                                    let prefix = format!("{}:", #variant_snake_case);
+                                    let mut cache = (*self.cache).borrow_mut();
+
+                                    let keys = cache
+                                        .iter()
+                                        .map(|(key, _)| key.clone())
+                                        .filter(|key| cache_key.as_ref().map_or_else(|| key.starts_with(&prefix), |cache_key| key == cache_key))
+                                        .collect::<Vec<_>>();
+
+                                    for key in keys {
+                                        cache.remove(&key);
+                                    }
+                                }
+
+                                // Collected first, a refetch served synchronously would borrow the queries again
+                                let refetches = self.queries.borrow().get(#variant_snake_case).map(|slotmap| {
+                                    slotmap.iter().filter_map(|(_, value)| match value {
+                                        #state_enum_name::#variant_name(state)
+                                            if cache_key.is_none() || *state.cache_key.borrow() == cache_key =>
+                                        {
+                                            state.refetch.borrow().clone()
+                                        }
+                                        _ => None,
+                                    }).collect::<Vec<_>>()
+                                }).unwrap_or_default();
+
+                                for refetch in refetches {
+                                    refetch.emit(());
+                                }
+                            }
+                        });
+                    }
                 }
 
                 // Hooks
@@ -409,6 +845,7 @@ pub fn fetch_schema(input: TokenStream) -> TokenStream {
                             let client = #fetch_client_hook_name();
                             let signal = use_mut_ref(|| Signal::new(None::<#res>));
                             let data = use_signal_state(signal.clone());
+                            let messages = use_reducer(WsMessages::<#res>::default);
                             let status = use_state(|| WsStatus::Closed);
                             let error = use_state(|| None::<FetchError>);
                             let state_key_ref = use_mut_ref(|| None::<String>);
@@ -436,6 +873,7 @@ pub fn fetch_schema(input: TokenStream) -> TokenStream {
 
                             let onmessage = use_callback(options.clone(), {
                                 let signal = signal.clone();
+                                let messages = messages.dispatcher();
                                 let error = error.clone();
 
                                 move |(event, res): (web_sys::MessageEvent, #ws_data_enum_name), options| {
@@ -444,6 +882,13 @@ pub fn fetch_schema(input: TokenStream) -> TokenStream {
                                     }
 
                                     if let #ws_data_enum_name::#variant_name(res) = res {
+                                        if let Some(options) = options.as_ref().filter(|o| o.accumulate) {
+                                            messages.dispatch(WsMessagesPush {
+                                                message: res.clone(),
+                                                max_messages: options.max_messages,
+                                            });
+                                        }
+
                                         if let Some(on_update) = options.as_ref().and_then(|o| o.on_update.clone()) {
                                             let res = on_update.emit(#on_update_struct_name {
                                                 existing: signal.borrow().get().clone(),
@@ -458,14 +903,18 @@ pub fn fetch_schema(input: TokenStream) -> TokenStream {
                                 }
                             });
 
-                            let onerror = use_callback(options.clone(), {
+                            let onerror = use_callback((options.clone(), client.clone()), {
                                 let error = error.clone();
 
-                                move |err: FetchError, options| {
+                                move |err: FetchError, (options, client)| {
                                     if let Some(on_error) = options.as_ref().and_then(|o| o.on_error.clone()) {
                                         on_error.emit(err.clone());
                                     }
 
+                                    if let Some(on_global_error) = client.on_global_error.as_ref() {
+                                        on_global_error.emit(err.clone());
+                                    }
+
                                     error.set(Some(err));
                                 }
                             });
@@ -657,6 +1106,7 @@ pub fn fetch_schema(input: TokenStream) -> TokenStream {
 
                             #hook_async_handle_name {
                                 data,
+                                messages: (*messages).clone(),
                                 status,
                                 error,
                                 send,
@@ -685,6 +1135,7 @@ pub fn fetch_schema(input: TokenStream) -> TokenStream {
 
                             #hook_handle_name {
                                 data: hook.data,
+                                messages: hook.messages,
                                 status: hook.status,
                                 error: hook.error,
                                 send: hook.send.clone(),
@@ -712,6 +1163,7 @@ pub fn fetch_schema(input: TokenStream) -> TokenStream {
 
                             #hook_handle_name {
                                 data: hook.data,
+                                messages: hook.messages,
                                 status: hook.status,
                                 error: hook.error,
                                 send: hook.send.clone(),
@@ -720,96 +1172,150 @@ pub fn fetch_schema(input: TokenStream) -> TokenStream {
                         }
                     });
                 } else {
-                    hooks.push(quote! {
-                        #[hook]
-                        fn #common_hook_name(options: Option<#hook_options_name>) -> #hook_async_handle_name {
-                            let client = #fetch_client_hook_name();
-                            let signal = use_mut_ref(|| Signal::new(None::<#res>));
-                            let data = use_signal_state(signal.clone());
-                            let loading = use_state(|| false);
-                            let error = use_state(|| None::<FetchError>);
-                            let state_key_ref = use_mut_ref(|| #variant_snake_case);
-                            let slot_key_ref = use_mut_ref(|| None::<usize>);
+                    // Shares a pending request with every hook that asks for the same cache key,
+                    // a hook that cancels stops waiting while the others still get the response
+                    let dedupe_fetch = quote! {
+                        {
+                            let request_client = client.clone();
+                            let params = params.clone();
+
+                            client.in_flight.dedupe(&cache_key, &abort_signal, move |shared_signal| async move {
+                                request_client.#fetch_method_name(url, shared_signal, params).await
+                            }).await
+                        }
+                    };
 
-                            use_effect_with(client.clone(), {
-                                let state_key_ref = state_key_ref.clone();
-                                let slot_key_ref = slot_key_ref.clone();
-                                let signal = signal.clone();
+                    // A single call may target another origin or add headers without a separate client
+                    let override_client = quote! {
+                        let client = match options.as_ref() {
+                            Some(options) if options.base_url_override.is_some()
+                                || !options.headers.is_empty()
+                                || options.credentials.is_some()
+                                || options.mode.is_some()
+                                || options.on_progress.is_some() => {
+                                let mut request_client = client.with_request_overrides(options.base_url_override.as_deref(), &options.headers);
+
+                                if options.credentials.is_some() {
+                                    request_client.credentials = options.credentials;
+                                }
 
-                                move |client| {
-                                    let state_key = state_key_ref.borrow().to_string();
+                                if options.mode.is_some() {
+                                    request_client.mode = options.mode;
+                                }
 
-                                    if slot_key_ref.borrow().is_none() {
-                                        let mut queries = (*client.queries).borrow_mut();
+                                if options.on_progress.is_some() {
+                                    request_client.on_progress = options.on_progress.clone();
+                                }
 
-                                        if let Some(mut slotmap) = queries.get_mut(&state_key) {
-                                            let slot_key = slotmap.insert(#state_enum_name::#variant_name(#state_struct_name {
-                                                data: signal.clone(),
-                                            }));
+                                request_client.overrides_key = generate_overrides_key(&options.headers, options.credentials, options.mode);
 
-                                            slot_key_ref.replace(Some(slot_key));
-                                        } else {
-                                            let mut slotmap = SlotMap::<#state_enum_name>::new();
+                                Rc::new(request_client)
+                            }
+                            _ => client.clone(),
+                        };
+                    };
 
-                                            let slot_key = slotmap.insert(#state_enum_name::#variant_name(#state_struct_name {
-                                                data: signal.clone(),
-                                            }));
+                    // Every request gets its own controller so `cancel`/`cancel_all` only abort live requests,
+                    // the follower of the external signal is dropped once the request settles
+                    let start_request = quote! {
+                        let abort_controller = match web_sys::AbortController::new() {
+                            Ok(controller) => Rc::new(controller),
+                            Err(abort_controller_error) => {
+                                error.set(Some(FetchError::UnknownError(format!("{abort_controller_error:?}"))));
+                                return;
+                            }
+                        };
 
-                                            slot_key_ref.replace(Some(slot_key));
-                                            queries.insert(state_key.clone(), slotmap);
-                                        }
-                                    }
+                        let abort_follower = options
+                            .as_ref()
+                            .and_then(|o| o.signal.as_ref())
+                            .map(|external_signal| follow_abort_signal(&abort_controller, external_signal));
 
-                                    {
-                                        let client = client.clone();
+                        let abort_signal = Rc::new(abort_controller.signal());
+                        abort_controller_ref.replace(Some(abort_controller.clone()));
+                        client.abort_controllers.register(&abort_controller);
+                    };
 
-                                        move || {
-                                            if let Some(slot_key) = slot_key_ref.borrow().as_ref() {
-                                                let mut queries = (*client.queries).borrow_mut();
+                    let trigger = if stream {
+                        quote! {
+                            use_callback((client.clone(), options.clone()), {
+                                let loading = loading.clone();
+                                let error = error.clone();
+                                let signal = signal.clone();
+                                let done = done.clone();
+                                let abort_controller_ref = abort_controller_ref.clone();
 
-                                                if let Some(mut slotmap) = queries.get_mut(&state_key) {
-                                                    slotmap.remove(*slot_key);
+                                move |params: #params_struct_name, (client, options)| {
+                                    let loading = loading.clone();
+                                    let error = error.clone();
+                                    #override_client
+                                    let options = options.clone();
+                                    let signal = signal.clone();
+                                    let done = done.clone();
+                                    let url = client.#prepare_url_method_name();
 
-                                                    if slotmap.is_empty() {
-                                                        queries.remove(&state_key);
+                                    #start_request
+
+                                    spawn_local(async move {
+                                        loading.set(true);
+                                        done.set(false);
+                                        signal.borrow().set(Some(Vec::new()));
+
+                                        match client.#fetch_method_name(url, abort_signal, params.clone()).await {
+                                            Ok(response) => {
+                                                let result = read_response_stream(&response, |chunk| {
+                                                    let existing = signal.borrow().get();
+
+                                                    if let Some(on_update) = options.as_ref().and_then(|o| o.on_update.clone()) {
+                                                        let res = on_update.emit(#on_update_struct_name {
+                                                            params: params.clone(),
+                                                            existing,
+                                                            incoming: vec![chunk],
+                                                        });
+
+                                                        signal.borrow().set(res);
+                                                    } else {
+                                                        let mut chunks = existing.unwrap_or_default();
+                                                        chunks.push(chunk);
+                                                        signal.borrow().set(Some(chunks));
                                                     }
+                                                }).await;
+
+                                                if let Err(err) = result {
+                                                    error.set(Some(err));
                                                 }
                                             }
+                                            Err(err) => {
+                                                error.set(Some(err));
+                                            }
                                         }
-                                    }
-                                }
-                            });
 
-                            let abort_controller = match web_sys::AbortController::new() {
-                                Ok(controller) => Rc::new(controller),
-                                Err(abort_controller_error) => {
-                                    error.set(Some(FetchError::UnknownError(format!("{abort_controller_error:?}"))));
-
-                                    return #hook_async_handle_name {
-                                        data,
-                                        loading,
-                                        error,
-                                        trigger: Callback::noop(),
-                                        cancel: Callback::noop(),
-                                    };
+                                        client.abort_controllers.unregister(&abort_controller);
+                                        drop(abort_follower);
+                                        done.set(true);
+                                        loading.set(false);
+                                    });
                                 }
-                            };
-
-                            let abort_signal = Rc::new(abort_controller.signal());
-
-                            let trigger = use_callback((client.clone(), options.clone()), {
+                            })
+                        }
+                    } else {
+                        quote! {
+                            use_callback((client.clone(), options.clone()), {
                                 let loading = loading.clone();
+                                #fetching_clone
                                 let error = error.clone();
                                 let signal = signal.clone();
-                                let abort_signal = abort_signal.clone();
+                                let abort_controller_ref = abort_controller_ref.clone();
+                                #freshness_clone
 
                                 move |params: #params_struct_name, (client, options)| {
                                     let loading = loading.clone();
+                                    #fetching_clone
                                     let error = error.clone();
-                                    let client = client.clone();
+                                    #freshness_clone
+                                    #override_client
                                     let options = options.clone();
                                     let signal = signal.clone();
-                                    let abort_signal = abort_signal.clone();
 
                                     let cache_policy = {
                                         let custom_cache_policy = options.as_ref().and_then(
@@ -823,31 +1329,31 @@ pub fn fetch_schema(input: TokenStream) -> TokenStream {
                                     };
 
                                     let url = client.#prepare_url_method_name();
-                                    let method = HttpMethod::from(#verb);
 
-                                    let Ok(cache_key) = generate_cache_key(
-                                        &method, url.as_str(), &params.slugs, &params.query, &params.body
-                                    ) else {
+                                    let Ok(cache_key) = client.#get_cache_key_method_name(&params) else {
                                         error.set(Some(FetchError::UnknownError("Failed to generate cache key".to_string())));
                                         return;
                                     };
 
-                                    let cache_key = format!("{}:{cache_key}", #variant_snake_case);
-
                                     let cache_entry = {
                                         let cache_ref = client.cache.borrow();
                                         cache_ref.get(&cache_key).cloned()
                                     };
 
+                                    #start_request
+
                                     spawn_local(async move {
-                                        loading.set(true);
+                                        #start_loading
 
                                         match cache_policy {
                                             CachePolicy::StaleWhileRevalidate => {
                                                 if let Some(entry) = cache_entry {
                                                     match deserialize_cached_data::<#res>(&entry.data) {
                                                         Ok(res) => {
+                                                            #select_res
+
                                                             signal.borrow().set(Some(res));
+                                                            #cached_loaded
                                                         }
                                                         Err(err) => {
                                                             error.set(Some(err));
@@ -855,10 +1361,11 @@ pub fn fetch_schema(input: TokenStream) -> TokenStream {
                                                     }
                                                 }
 
-                                                match client.#fetch_method_name(url, abort_signal, params.clone()).await {
+                                                match #dedupe_fetch {
                                                     Ok(res) => {
                                                         match deserialize_response_and_store_cache::<#res>(
                                                             &res,
+                                                            client.codec.as_ref(),
                                                             &client.cache,
                                                             &cache_key,
                                                             options.as_ref().and_then(
@@ -866,6 +1373,11 @@ pub fn fetch_schema(input: TokenStream) -> TokenStream {
                                                             )
                                                         ) {
                                                             Ok(res) => {
+                                                                #select_res
+
+                                                                #mark_updated
+                                                                #invalidate_targets
+
                                                                 if let Some(on_update) = options.as_ref().and_then(|o| o.on_update.clone()) {
                                                                     let res = on_update.emit(#on_update_struct_name {
                                                                         params,
@@ -892,6 +1404,8 @@ pub fn fetch_schema(input: TokenStream) -> TokenStream {
                                                 if let Some(entry) = cache_entry {
                                                     match deserialize_cached_data::<#res>(&entry.data) {
                                                         Ok(res) => {
+                                                            #select_res
+
                                                             signal.borrow().set(Some(res));
                                                         }
                                                         Err(err) => {
@@ -899,10 +1413,11 @@ pub fn fetch_schema(input: TokenStream) -> TokenStream {
                                                         }
                                                     }
                                                 } else {
-                                                    match client.#fetch_method_name(url, abort_signal, params.clone()).await {
+                                                    match #dedupe_fetch {
                                                         Ok(res) => {
                                                             match deserialize_response_and_store_cache::<#res>(
                                                                 &res,
+                                                                client.codec.as_ref(),
                                                                 &client.cache,
                                                                 &cache_key,
                                                                 options.as_ref().and_then(
@@ -910,6 +1425,11 @@ pub fn fetch_schema(input: TokenStream) -> TokenStream {
                                                                 )
                                                             ) {
                                                                 Ok(res) => {
+                                                                    #select_res
+
+                                                                    #mark_updated
+                                                                    #invalidate_targets
+
                                                                     if let Some(on_update) = options.as_ref().and_then(|o| o.on_update.clone()) {
                                                                         let res = on_update.emit(#on_update_struct_name {
                                                                             params,
@@ -938,6 +1458,7 @@ pub fn fetch_schema(input: TokenStream) -> TokenStream {
                                                     Ok(res) => {
                                                         match deserialize_response_and_store_cache::<#res>(
                                                             &res,
+                                                            client.codec.as_ref(),
                                                             &client.cache,
                                                             &cache_key,
                                                             options.as_ref().and_then(
@@ -945,6 +1466,11 @@ pub fn fetch_schema(input: TokenStream) -> TokenStream {
                                                             )
                                                         ) {
                                                             Ok(res) => {
+                                                                #select_res
+
+                                                                #mark_updated
+                                                                #invalidate_targets
+
                                                                 if let Some(on_update) = options.as_ref().and_then(|o| o.on_update.clone()) {
                                                                     let res = on_update.emit(#on_update_struct_name {
                                                                         params,
@@ -971,6 +1497,8 @@ pub fn fetch_schema(input: TokenStream) -> TokenStream {
                                                 if let Some(entry) = cache_entry {
                                                     match deserialize_cached_data::<#res>(&entry.data) {
                                                         Ok(res) => {
+                                                            #select_res
+
                                                             if let Some(on_update) = options.as_ref().and_then(|o| o.on_update.clone()) {
                                                                 let res = on_update.emit(#on_update_struct_name {
                                                                     params,
@@ -991,35 +1519,244 @@ pub fn fetch_schema(input: TokenStream) -> TokenStream {
                                             }
                                         }
 
+                                        client.abort_controllers.unregister(&abort_controller);
+                                        drop(abort_follower);
                                         loading.set(false);
+                                        #stop_fetching
                                     });
                                 }
+                            })
+                        }
+                    };
+
+                    // Suspends the component until the data is available, GET requests only
+                    let suspense_hook = (verb == "GET" && !stream).then(|| {
+                        quote! {
+                            #[hook]
+                            pub fn #hook_name_suspense(params: #params_struct_name) -> yew::suspense::SuspensionResult<Result<#res, FetchError>> {
+                                let client = #fetch_client_hook_name();
+
+                                let result = yew::suspense::use_future_with(params, move |params| async move {
+                                    let params = (*params).clone();
+                                    let url = client.#prepare_url_method_name();
+
+                                    let result: Result<#res, FetchError> = async {
+                                        let cache_key = client.#get_cache_key_method_name(&params)?;
+
+                                        let cache_entry = {
+                                            let cache_ref = client.cache.borrow();
+
+                                            if *cache_ref.policy() == CachePolicy::NetworkOnly {
+                                                None
+                                            } else {
+                                                cache_ref.get(&cache_key).cloned()
+                                            }
+                                        };
+
+                                        if let Some(entry) = cache_entry {
+                                            return deserialize_cached_data::<#res>(&entry.data);
+                                        }
+
+                                        let abort_controller = web_sys::AbortController::new().map_err(
+                                            |err| FetchError::UnknownError(format!("{err:?}"))
+                                        )?;
+
+                                        let abort_controller = Rc::new(abort_controller);
+                                        let abort_signal = Rc::new(abort_controller.signal());
+
+                                        client.abort_controllers.register(&abort_controller);
+                                        let res = #dedupe_fetch;
+                                        client.abort_controllers.unregister(&abort_controller);
+
+                                        deserialize_response_and_store_cache::<#res>(&res?, client.codec.as_ref(), &client.cache, &cache_key, None)
+                                    }.await;
+
+                                    match &result {
+                                        Ok(_) => {
+                                            if let Some(on_global_success) = client.on_global_success.as_ref() {
+                                                on_global_success.emit(());
+                                            }
+                                        }
+                                        Err(err) => {
+                                            if let Some(on_global_error) = client.on_global_error.as_ref() {
+                                                on_global_error.emit(err.clone());
+                                            }
+                                        }
+                                    }
+
+                                    result
+                                })?;
+
+                                Ok((*result).clone())
+                            }
+                        }
+                    });
+
+                    hooks.push(quote! {
+                        #suspense_hook
+
+                        #[hook]
+                        fn #common_hook_name(options: Option<#hook_options_name>) -> #hook_async_handle_name {
+                            let client = #fetch_client_hook_name();
+                            let signal = use_mut_ref(|| Signal::new(None::<#res>));
+                            let data = use_signal_state(signal.clone());
+                            let loading = use_state(|| false);
+                            #fetching_decl
+                            #done_decl
+                            #freshness_decl
+                            let error = use_state(|| None::<FetchError>);
+                            let state_key_ref = use_mut_ref(|| #variant_snake_case);
+                            let slot_key_ref = use_mut_ref(|| None::<usize>);
+                            let cache_key_ref = use_mut_ref(|| None::<String>);
+                            let refetch_ref = use_mut_ref(|| None::<Callback<()>>);
+
+                            use_effect_with(client.clone(), {
+                                let state_key_ref = state_key_ref.clone();
+                                let slot_key_ref = slot_key_ref.clone();
+                                let signal = signal.clone();
+                                let cache_key_ref = cache_key_ref.clone();
+                                let refetch_ref = refetch_ref.clone();
+
+                                move |client| {
+                                    let state_key = state_key_ref.borrow().to_string();
+
+                                    if slot_key_ref.borrow().is_none() {
+                                        let mut queries = (*client.queries).borrow_mut();
+
+                                        if let Some(mut slotmap) = queries.get_mut(&state_key) {
+                                            let slot_key = slotmap.insert(#state_enum_name::#variant_name(#state_struct_name {
+                                                data: signal.clone(),
+                                                cache_key: cache_key_ref.clone(),
+                                                refetch: refetch_ref.clone(),
+                                            }));
+
+                                            slot_key_ref.replace(Some(slot_key));
+                                        } else {
+                                            let mut slotmap = SlotMap::<#state_enum_name>::new();
+
+                                            let slot_key = slotmap.insert(#state_enum_name::#variant_name(#state_struct_name {
+                                                data: signal.clone(),
+                                                cache_key: cache_key_ref.clone(),
+                                                refetch: refetch_ref.clone(),
+                                            }));
+
+                                            slot_key_ref.replace(Some(slot_key));
+                                            queries.insert(state_key.clone(), slotmap);
+                                        }
+                                    }
+
+                                    {
+                                        let client = client.clone();
+
+                                        // Forgets the slot so the hook registers again when the client changes
+                                        move || {
+                                            let slot_key = slot_key_ref.take();
+
+                                            if let Some(slot_key) = slot_key {
+                                                let mut queries = (*client.queries).borrow_mut();
+
+                                                if let Some(mut slotmap) = queries.get_mut(&state_key) {
+                                                    slotmap.remove(slot_key);
+
+                                                    if slotmap.is_empty() {
+                                                        queries.remove(&state_key);
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
                             });
 
-                            use_effect_with((error.clone(), options.clone()), |(error, options)| {
+                            let abort_controller_ref = use_mut_ref(|| None::<Rc<web_sys::AbortController>>);
+
+                            // Stop tracking the last request once the hook unmounts
+                            use_effect_with((client.clone(), abort_controller_ref.clone()), |(client, abort_controller_ref)| {
+                                let client = client.clone();
+                                let abort_controller_ref = abort_controller_ref.clone();
+
+                                move || {
+                                    if let Some(abort_controller) = abort_controller_ref.borrow().as_ref() {
+                                        client.abort_controllers.unregister(abort_controller);
+                                    }
+                                }
+                            });
+
+                            let trigger = #trigger;
+
+                            // Remembers the params so that `invalidate` can send the query again
+                            let params_ref = use_mut_ref(|| None::<#params_struct_name>);
+
+                            let trigger = use_callback(
+                                (client.clone(), trigger, cache_key_ref, params_ref.clone()),
+                                |params: #params_struct_name, (client, trigger, cache_key_ref, params_ref)| {
+                                    cache_key_ref.replace(client.#get_cache_key_method_name(&params).ok());
+                                    params_ref.replace(Some(params.clone()));
+                                    trigger.emit(params);
+                                },
+                            );
+
+                            // Replaced on every render, so `invalidate` never sends the query through a stale trigger
+                            refetch_ref.replace(Some(Callback::from({
+                                let trigger = trigger.clone();
+
+                                move |()| {
+                                    let params = params_ref.borrow().clone();
+
+                                    if let Some(params) = params {
+                                        trigger.emit(params);
+                                    }
+                                }
+                            })));
+
+                            use_effect_with((error.clone(), options.clone(), client.clone()), |(error, options, client)| {
                                 if let Some(error) = (**error).as_ref() {
                                     let on_error = options.as_ref().and_then(|o| o.on_error.clone()).unwrap_or_else(Callback::noop);
                                     on_error.emit(error.clone());
+
+                                    if let Some(on_global_error) = client.on_global_error.as_ref() {
+                                        on_global_error.emit(error.clone());
+                                    }
                                 }
                             });
 
-                            use_effect_with((data.clone(), options.clone()), |(data, options)| {
+                            use_effect_with((data.clone(), options.clone(), client.clone()), |(data, options, client)| {
                                 if let Some(data) = (**data).as_ref() {
+                                    // Shares the loaded data with the lazy readers of this query
+                                    if let Some(slotmap) = client.queries.borrow().get(#lazy_state_key) {
+                                        for (_, value) in slotmap.iter() {
+                                            if let #state_enum_name::#variant_name(state) = value {
+                                                state.data.borrow().set(Some(data.clone()));
+                                            }
+                                        }
+                                    }
+
                                     if let Some(on_data) = options.as_ref().and_then(|o| o.on_data.clone()) {
                                         on_data.emit(data.clone());
                                     } else if let Some(on_success) = options.as_ref().and_then(|o| o.on_success.clone()) {
                                         on_success.emit(data.clone());
                                     }
+
+                                    if let Some(on_global_success) = client.on_global_success.as_ref() {
+                                        on_global_success.emit(());
+                                    }
                                 }
                             });
 
-                            let cancel = use_callback(abort_controller.clone(), |(), controller| {
-                                controller.abort();
+                            let cancel = use_callback(abort_controller_ref.clone(), |(), abort_controller_ref| {
+                                if let Some(abort_controller) = abort_controller_ref.borrow().as_ref() {
+                                    abort_controller.abort();
+                                }
                             });
 
+                            #freshness_stale
+
                             #hook_async_handle_name {
                                 data,
                                 loading,
+                                #fetching_init
+                                #done_init
+                                #freshness_init
                                 error,
                                 trigger,
                                 cancel,
@@ -1038,34 +1775,83 @@ pub fn fetch_schema(input: TokenStream) -> TokenStream {
 
                         #[hook]
                         pub fn #hook_name(params: #params_struct_name) -> #hook_handle_name {
-                            let hook = #common_hook_name(None);
+                            #hook_with_options_name(params, #hook_options_name::default())
+                        }
 
-                            use_effect_with((params.clone(), hook.trigger.clone()), |(params, trigger)| {
+                        #[hook]
+                        pub fn #hook_with_options_name(params: #params_struct_name, options: #hook_options_name) -> #hook_handle_name {
+                            let enabled = options.enabled;
+                            let hook = #common_hook_name(Some(options));
+
+                            use_effect_with((params.clone(), hook.trigger.clone(), enabled), |(params, trigger, enabled)| {
+                                if *enabled {
+                                    trigger.emit(params.clone());
+                                }
+                            });
+
+                            let trigger = use_callback((params, hook.trigger.clone()), |(), (params, trigger)| {
                                 trigger.emit(params.clone());
                             });
 
                             #hook_handle_name {
                                 data: hook.data,
                                 loading: hook.loading,
+                                #fetching_from_hook
+                                #done_from_hook
+                                #freshness_from_hook
                                 error: hook.error,
+                                trigger,
                                 cancel: hook.cancel,
                             }
                         }
 
+                        /// Reads the data already loaded by the other hooks of this query without ever sending it,
+                        /// `None` until one of them loads
                         #[hook]
-                        pub fn #hook_with_options_name(params: #params_struct_name, options: #hook_options_name) -> #hook_handle_name {
-                            let hook = #common_hook_name(Some(options));
+                        pub fn #hook_name_lazy() -> Option<#res> {
+                            let client = #fetch_client_hook_name();
 
-                            use_effect_with((params.clone(), hook.trigger.clone()), |(params, trigger)| {
-                                trigger.emit(params.clone());
+                            let signal = use_mut_ref(|| {
+                                let loaded = client.queries.borrow().get(#variant_snake_case).and_then(|slotmap| {
+                                    slotmap.iter().find_map(|(_, value)| match value {
+                                        #state_enum_name::#variant_name(state) => state.data.borrow().get(),
+                                        _ => None,
+                                    })
+                                });
+
+                                Signal::new(loaded)
                             });
 
-                            #hook_handle_name {
-                                data: hook.data,
-                                loading: hook.loading,
-                                error: hook.error,
-                                cancel: hook.cancel,
-                            }
+                            let data = use_signal_state(signal.clone());
+
+                            // Registered apart from the queries so invalidation and the states leave it out
+                            use_effect_with(client.clone(), move |client| {
+                                let slot_key = (*client.queries)
+                                    .borrow_mut()
+                                    .entry(#lazy_state_key.to_string())
+                                    .or_insert_with(SlotMap::new)
+                                    .insert(#state_enum_name::#variant_name(#state_struct_name {
+                                        data: signal,
+                                        cache_key: Rc::new(RefCell::new(None)),
+                                        refetch: Rc::new(RefCell::new(None)),
+                                    }));
+
+                                let client = client.clone();
+
+                                move || {
+                                    let mut queries = (*client.queries).borrow_mut();
+
+                                    if let Some(slotmap) = queries.get_mut(#lazy_state_key) {
+                                        slotmap.remove(slot_key);
+
+                                        if slotmap.is_empty() {
+                                            queries.remove(#lazy_state_key);
+                                        }
+                                    }
+                                }
+                            });
+
+                            (*data).clone()
                         }
 
                         #[hook]
@@ -1114,6 +1900,81 @@ pub fn fetch_schema(input: TokenStream) -> TokenStream {
     let fetch_debug_name = format_ident!("{}FetchClientDebug", enum_name);
     let fetch_debug_snake_case_name =
         format_ident!("{}", fetch_debug_name.to_string().to_snake_case());
+    let fetch_debug_props_name = format_ident!("{}Props", fetch_debug_name);
+
+    let mock_field = mock.then(|| {
+        quote! {
+            pub mock: Option<#fetch_client_mock_name>,
+        }
+    });
+
+    let mock_init = mock.then(|| quote! { mock: None, });
+
+    let mock_client = mock.then(|| {
+        quote! {
+            /// Pre-set responses for the endpoints of a client, used to test components without a network.
+            ///
+            /// A client holding the mock resolves every non-streaming HTTP endpoint from it and fails with
+            /// `FetchError::UnknownError` for those without a preset. Streams and web sockets are not mocked.
+            #[derive(Clone, Default)]
+            pub struct #fetch_client_mock_name {
+                responses: Rc<RefCell<HashMap<&'static str, Result<String, FetchError>>>>,
+            }
+
+            impl PartialEq for #fetch_client_mock_name {
+                fn eq(&self, other: &Self) -> bool {
+                    Rc::ptr_eq(&self.responses, &other.responses)
+                }
+            }
+
+            impl #fetch_client_mock_name {
+                pub fn new() -> Self {
+                    Self::default()
+                }
+
+                /// A client that resolves every request from this mock
+                pub fn client(&self) -> #fetch_client_name {
+                    #fetch_client_name::new("").with_mock(self.clone())
+                }
+
+                /// Drops every preset response
+                pub fn clear(&self) {
+                    (*self.responses).borrow_mut().clear();
+                }
+
+                fn response(&self, endpoint: &str) -> Result<String, FetchError> {
+                    self.responses
+                        .borrow()
+                        .get(endpoint)
+                        .cloned()
+                        .unwrap_or_else(|| Err(FetchError::UnknownError(format!("No mock response set for {endpoint}"))))
+                }
+
+                #(#mock_setters)*
+            }
+        }
+    });
+
+    let with_mock = mock.then(|| {
+        quote! {
+            /// Resolves requests from the mock's preset responses instead of the network
+            pub fn with_mock(mut self, mock: #fetch_client_mock_name) -> Self {
+                self.mock = Some(mock);
+                self
+            }
+        }
+    });
+
+    // A schema without cached queries has nothing to invalidate
+    let invalidate_match = if invalidate_arms.is_empty() {
+        quote! { match *target {} }
+    } else {
+        quote! {
+            match target {
+                #(#invalidate_arms)*
+            }
+        }
+    };
 
     let expanded = quote! {
         mod #module_name {
@@ -1148,6 +2009,13 @@ pub fn fetch_schema(input: TokenStream) -> TokenStream {
                 pub web_socket_watcher: Rc<RefCell<WebSocketWatcher<#ws_data_enum_name>>>,
             }
 
+            /// A cached query to invalidate, all of its responses or only the one for the given params
+            #[derive(Clone, PartialEq)]
+            #[allow(clippy::enum_variant_names)]
+            pub enum #cache_target_name {
+                #(#cache_target_variants)*
+            }
+
             #[derive(Clone, Debug, PartialEq)]
             enum #state_enum_name {
                 Ws(#ws_state_struct_name),
@@ -1177,13 +2045,46 @@ pub fn fetch_schema(input: TokenStream) -> TokenStream {
                 pub middlewares: Vec<Middleware>,
                 pub cache: Rc<RefCell<dyn Cacheable>>,
                 pub queries: Rc<RefCell<HashMap<String, SlotMap<#state_enum_name>>>>,
+                pub in_flight: InFlightRequests,
+                pub abort_controllers: AbortRegistry,
+                pub on_global_error: Option<Callback<FetchError>>,
+                pub on_global_success: Option<Callback<()>>,
+                /// Whether requests send cookies and HTTP auth, `None` keeps the browser default (`same-origin`)
+                pub credentials: Option<web_sys::RequestCredentials>,
+                /// The CORS mode of requests, `None` keeps the browser default (`cors`)
+                pub mode: Option<web_sys::RequestMode>,
+                /// Encodes request bodies and decodes responses, `serde_json` by default
+                pub codec: Codec,
+                /// Sent as `Accept-Language` and partitions the cache, see `with_locale`
+                pub locale: Option<String>,
+                /// Partitions the cache and the in-flight requests of a call sent with its own headers,
+                /// credentials or mode, see `generate_overrides_key`
+                pub overrides_key: Option<String>,
+                /// Receives the download progress of every response body, hooks set it with their `on_progress`
+                pub on_progress: Option<Callback<Progress>>,
+                #mock_field
                 _marker: std::marker::PhantomData<#enum_name>,
             }
 
+            #mock_client
+
+            // The shared state and the middlewares can't be compared by value, a client only equals
+            // a copy of itself that holds the same ones
             impl PartialEq for #fetch_client_name {
                 fn eq(&self, other: &Self) -> bool {
                     self.base_url == other.base_url
                     && self.middlewares.len() == other.middlewares.len()
+                    && self.middlewares.iter().zip(&other.middlewares).all(|(a, b)| Rc::ptr_eq(a, b))
+                    && Rc::ptr_eq(&self.cache, &other.cache)
+                    && Rc::ptr_eq(&self.queries, &other.queries)
+                    && Rc::ptr_eq(&self.codec, &other.codec)
+                    && self.on_global_error == other.on_global_error
+                    && self.on_global_success == other.on_global_success
+                    && self.credentials == other.credentials
+                    && self.mode == other.mode
+                    && self.on_progress == other.on_progress
+                    && self.locale == other.locale
+                    && self.overrides_key == other.overrides_key
                 }
             }
 
@@ -1194,6 +2095,17 @@ pub fn fetch_schema(input: TokenStream) -> TokenStream {
                         middlewares: Vec::new(),
                         cache: Rc::new(RefCell::new(Cache::default())),
                         queries: Rc::new(RefCell::new(HashMap::new())),
+                        in_flight: InFlightRequests::new(),
+                        abort_controllers: AbortRegistry::new(),
+                        on_global_error: None,
+                        on_global_success: None,
+                        credentials: None,
+                        mode: None,
+                        codec: Rc::new(SerdeJsonCodec),
+                        on_progress: None,
+                        locale: None,
+                        overrides_key: None,
+                        #mock_init
                         _marker: std::marker::PhantomData
                     }
                 }
@@ -1208,6 +2120,111 @@ pub fn fetch_schema(input: TokenStream) -> TokenStream {
                     self
                 }
 
+                /// Sets the credentials mode of every request, e.g. `Include` to send cookies cross-origin.
+                ///
+                /// It's applied before the middlewares run, so a middleware can still replace it per request.
+                pub fn with_credentials(mut self, credentials: web_sys::RequestCredentials) -> Self {
+                    self.credentials = Some(credentials);
+                    self
+                }
+
+                /// Sets the CORS mode of every request.
+                ///
+                /// Like the credentials it's applied before the middlewares. Note that in `NoCors` mode the
+                /// browser drops the headers that aren't CORS-safelisted, including the ones middlewares set.
+                pub fn with_mode(mut self, mode: web_sys::RequestMode) -> Self {
+                    self.mode = Some(mode);
+                    self
+                }
+
+                /// Removes the cached responses of `target` and sends the mounted queries that used them again
+                pub fn invalidate(&self, target: &#cache_target_name) {
+                    #invalidate_match
+                }
+
+                /// Replaces `serde_json` as the wire format of request and response bodies.
+                ///
+                /// The cache stores the decoded values, so cached data never goes through `decode` twice.
+                pub fn with_codec(mut self, codec: Codec) -> Self {
+                    self.codec = codec;
+                    self
+                }
+
+                /// Sends `locale`, e.g. `"fr-CH"`, as the `Accept-Language` of every request.
+                ///
+                /// Cached responses are kept per locale, so switching it never serves a response in the
+                /// previous language. The header is set before the middlewares run, they can still replace it.
+                /// To switch the locale of a mounted client, pass it to the provider's `locale` instead.
+                pub fn with_locale(mut self, locale: &str) -> Self {
+                    self.locale = Some(locale.to_string());
+                    self
+                }
+
+                /// The middlewares every request runs, starting with the one setting the locale
+                pub fn request_middlewares(&self) -> Vec<Middleware> {
+                    self.locale
+                        .as_deref()
+                        .map(accept_language_middleware)
+                        .into_iter()
+                        .chain(self.middlewares.iter().cloned())
+                        .collect()
+                }
+
+                #with_mock
+
+                /// A copy of the client that sends requests to `base_url` and with the extra `headers`.
+                ///
+                /// The copy shares the cache, the queries and the in-flight requests with this client, the
+                /// headers partition the entries it stores and the requests it shares.
+                #[must_use]
+                pub fn with_request_overrides(&self, base_url: Option<&str>, headers: &[(String, String)]) -> Self {
+                    let mut client = self.clone();
+
+                    if let Some(base_url) = base_url {
+                        client.base_url = base_url.to_string();
+                    }
+
+                    if !headers.is_empty() {
+                        client.overrides_key = generate_overrides_key(headers, None, None);
+
+                        let headers = Rc::new(headers.to_vec());
+
+                        client.middlewares.push(Rc::new(move |_request_init, request_headers| {
+                            let headers = headers.clone();
+
+                            Box::pin(async move {
+                                for (name, value) in headers.iter() {
+                                    // The browser rejects invalid header names, skip them like it would
+                                    let _ = (*request_headers).borrow_mut().set(name, value);
+                                }
+                            })
+                        }));
+                    }
+
+                    client
+                }
+
+                /// Called for every error of every hook, after the hook's own `on_error`
+                pub fn with_on_global_error(mut self, on_global_error: Callback<FetchError>) -> Self {
+                    self.on_global_error = Some(on_global_error);
+                    self
+                }
+
+                /// Called whenever any hook receives data, after the hook's own `on_success`/`on_data`
+                pub fn with_on_global_success(mut self, on_global_success: Callback<()>) -> Self {
+                    self.on_global_success = Some(on_global_success);
+                    self
+                }
+
+                /// Aborts every request started by the generated hooks that is still running.
+                ///
+                /// This is best-effort: requests that already settled aren't affected, neither are
+                /// calls made directly through the client's fetch methods. Hooks sharing a deduplicated
+                /// request all receive the abort error.
+                pub fn cancel_all(&self) {
+                    self.abort_controllers.abort_all();
+                }
+
                 #(#methods)*
             }
 
@@ -1238,20 +2255,46 @@ pub fn fetch_schema(input: TokenStream) -> TokenStream {
             #[derive(Clone, PartialEq, Properties)]
             pub struct #fetch_client_context_props_name {
                 pub client: #fetch_client_name,
+                /// Replaces the locale of `client`, switching it sends the mounted queries again in the new one
+                #[prop_or_default]
+                pub locale: Option<AttrValue>,
                 pub children: Children,
             }
 
             #[function_component(#fetch_client_context_provider_name)]
             pub fn #fetch_client_context_snake_case_provider_name(props: &#fetch_client_context_props_name) -> Html {
+                // Keeps handing out the same `Rc` while the parent passes copies of the same client
+                let client = use_memo((props.client.clone(), props.locale.clone()), |(client, locale)| {
+                    match locale {
+                        Some(locale) => client.clone().with_locale(locale),
+                        None => client.clone(),
+                    }
+                });
+
                 html! {
-                    <ContextProvider<Rc<#fetch_client_name>> context={Rc::new(props.client.clone())}>
+                    <ContextProvider<Rc<#fetch_client_name>> context={client}>
                         {for props.children.iter()}
                     </ContextProvider<Rc<#fetch_client_name>>>
                 }
             }
 
+            #[derive(Clone, PartialEq, Properties)]
+            pub struct #fetch_debug_props_name {
+                /// Extra class for the panel root, use it to override the `--fetch-debug-*` CSS variables
+                #[prop_or_default]
+                pub class: Option<AttrValue>,
+                #[prop_or_default]
+                pub position: FetchDebugPosition,
+                /// The z-index of the toggle button, the sheet is placed right above it
+                #[prop_or(9998)]
+                pub z_index: i32,
+                /// Skip the built-in styles entirely
+                #[prop_or_default]
+                pub unstyled: bool,
+            }
+
             #[function_component(#fetch_debug_name)]
-            pub fn #fetch_debug_snake_case_name() -> Html {
+            pub fn #fetch_debug_snake_case_name(props: &#fetch_debug_props_name) -> Html {
                 #[derive(Clone, PartialEq)]
                 enum Tab {
                     Cache,
@@ -1286,7 +2329,12 @@ pub fn fetch_schema(input: TokenStream) -> TokenStream {
                 });
 
                 html! {
-                    <>
+                    <div
+                        class={classes!("fetch-debug-root", props.class.clone())}
+                        style={format!("--fetch-debug-z-index: {};", props.z_index)}
+                        data-position={props.position.as_str().to_string()}
+                        data-side={props.position.side().to_string()}
+                    >
                         <button class="fetch-debug" onclick={&toggle_sheet}>
                             <svg
                                 xmlns="http://www.w3.org/2000/svg"
@@ -1359,7 +2407,7 @@ pub fn fetch_schema(input: TokenStream) -> TokenStream {
                                                                 <strong>{ key }</strong>
 
                                                                 <span>{"Expires at: "}{
-                                                                    Date::new(&JsValue::from_f64(entry.timestamp))
+                                                                    Date::new(&JsValue::from_f64(entry.expires_at()))
                                                                         .to_locale_string("en-US", &JsValue::from_str("full"))
                                                                         .as_string()
                                                                 }</span>
@@ -1414,128 +2462,163 @@ pub fn fetch_schema(input: TokenStream) -> TokenStream {
                             } }
                         </aside>
 
-                        <style>
-                            {r"
-                                .fetch-debug {
-                                    position: fixed;
-                                    bottom: 0;
-                                    border: none;
-                                    right: 0;
-                                    color: white;
-                                    padding: 0.5rem;
-                                    margin: 1rem;
-                                    display: flex;
-                                    justify-content: center;
-                                    align-items: center;
-                                    width: 3rem;
-                                    height: 3rem;
-                                    background-color: #1c1c1c;
-                                    border-radius: 100%;
-                                    z-index: 9998;
-                                    transition: box-shadow 0.3s ease-in-out;
-                                }
+                        if !props.unstyled {
+                            <style>
+                                {r"
+                                    .fetch-debug-root {
+                                        --fetch-debug-background: #1c1c1c;
+                                        --fetch-debug-item-background: #2c2c2c;
+                                        --fetch-debug-color: white;
+                                        --fetch-debug-shadow: rgba(0, 0, 0, 0.3);
+                                        --fetch-debug-sheet-width: 600px;
+                                    }
 
-                                .fetch-debug:hover {
-                                    box-shadow: 0 0 10px rgba(0, 0, 0, 0.3);
-                                }
+                                    .fetch-debug-root .fetch-debug {
+                                        position: fixed;
+                                        border: none;
+                                        color: var(--fetch-debug-color);
+                                        padding: 0.5rem;
+                                        margin: 1rem;
+                                        display: flex;
+                                        justify-content: center;
+                                        align-items: center;
+                                        width: 3rem;
+                                        height: 3rem;
+                                        background-color: var(--fetch-debug-background);
+                                        border-radius: 100%;
+                                        z-index: var(--fetch-debug-z-index);
+                                        transition: box-shadow 0.3s ease-in-out;
+                                    }
 
-                                .fetch-debug-header {
-                                    position: sticky;
-                                    top: 0;
-                                    display: flex;
-                                    flex-direction: column;
-                                    padding: 1rem;
-                                    background-color: #1c1c1c;
-                                }
+                                    .fetch-debug-root[data-position='bottom-right'] .fetch-debug {
+                                        bottom: 0;
+                                        right: 0;
+                                    }
 
-                                .fetch-debug-title {
-                                    display: flex;
-                                    color: white;
-                                    justify-content: space-between;
-                                    align-items: center;
-                                }
+                                    .fetch-debug-root[data-position='bottom-left'] .fetch-debug {
+                                        bottom: 0;
+                                        left: 0;
+                                    }
 
-                                .fetch-debug-title h2 {
-                                    margin: 0;
-                                    color: white;
-                                }
+                                    .fetch-debug-root[data-position='top-right'] .fetch-debug {
+                                        top: 0;
+                                        right: 0;
+                                    }
 
-                                .fetch-debug-nav {
-                                    display: flex;
-                                    margin-top: 1rem;
-                                    justify-content: space-between;
-                                    align-items: center;
-                                }
+                                    .fetch-debug-root[data-position='top-left'] .fetch-debug {
+                                        top: 0;
+                                        left: 0;
+                                    }
 
-                                .fetch-debug-nav-item {
-                                    flex: 1;
-                                    justify-content: center;
-                                    background-color: transparent;
-                                    border: none;
-                                    color: white;
-                                    cursor: pointer;
-                                }
+                                    .fetch-debug-root .fetch-debug:hover {
+                                        box-shadow: 0 0 10px var(--fetch-debug-shadow);
+                                    }
 
-                                .fetch-debug-nav-item-active {
-                                    text-decoration: underline;
-                                }
+                                    .fetch-debug-root .fetch-debug-header {
+                                        position: sticky;
+                                        top: 0;
+                                        display: flex;
+                                        flex-direction: column;
+                                        padding: 1rem;
+                                        background-color: var(--fetch-debug-background);
+                                    }
 
-                                .fetch-debug-sheet {
-                                    position: fixed;
-                                    top: 0;
-                                    right: 0;
-                                    min-width: 600px;
-                                    max-width: 50vw;
-                                    height: 100%;
-                                    background-color: #1c1c1c;
-                                    color: white;
-                                    box-shadow: -2px 0 5px rgba(0, 0, 0, 0.3);
-                                    transform: translateX(100%);
-                                    transition: transform 0.3s ease-in-out;
-                                    overflow-y: auto;
-                                    z-index: 9999;
-                                }
+                                    .fetch-debug-root .fetch-debug-title {
+                                        display: flex;
+                                        color: var(--fetch-debug-color);
+                                        justify-content: space-between;
+                                        align-items: center;
+                                    }
 
-                                .fetch-debug-sheet button {
-                                    background-color: transparent;
-                                    border: none;
-                                    color: white;
-                                    cursor: pointer;
-                                }
+                                    .fetch-debug-root .fetch-debug-title h2 {
+                                        margin: 0;
+                                        color: var(--fetch-debug-color);
+                                    }
 
-                                .fetch-debug-sheet-open {
-                                    transform: translateX(0);
-                                }
+                                    .fetch-debug-root .fetch-debug-nav {
+                                        display: flex;
+                                        margin-top: 1rem;
+                                        justify-content: space-between;
+                                        align-items: center;
+                                    }
 
-                                .fetch-debug-item {
-                                    display: flex;
-                                    flex-direction: column;
-                                    gap: 6px;
-                                    margin-bottom: 10px;
-                                    margin-left: 8px;
-                                    margin-right: 8px;
-                                    padding: 10px;
-                                    border-radius: 5px;
-                                    font-size: 0.8rem;
-                                    background-color: #2c2c2c;
-                                }
+                                    .fetch-debug-root .fetch-debug-nav-item {
+                                        flex: 1;
+                                        justify-content: center;
+                                        background-color: transparent;
+                                        border: none;
+                                        color: var(--fetch-debug-color);
+                                        cursor: pointer;
+                                    }
 
-                                 .fetch-debug-item-title {
-                                    display: flex;
-                                    flex-direction: column;
-                                    gap: 6px;
-                                    cursor: pointer;
-                                }
+                                    .fetch-debug-root .fetch-debug-nav-item-active {
+                                        text-decoration: underline;
+                                    }
 
-                                .fetch-debug-data {
-                                    background-color: #1c1c1c;
-                                    padding: 10px;
-                                    border-radius: 3px;
-                                    overflow-x: auto;
-                                }
-                            "}
-                        </style>
-                    </>
+                                    .fetch-debug-root .fetch-debug-sheet {
+                                        position: fixed;
+                                        top: 0;
+                                        right: 0;
+                                        min-width: var(--fetch-debug-sheet-width);
+                                        max-width: 50vw;
+                                        height: 100%;
+                                        background-color: var(--fetch-debug-background);
+                                        color: var(--fetch-debug-color);
+                                        box-shadow: -2px 0 5px var(--fetch-debug-shadow);
+                                        transform: translateX(100%);
+                                        transition: transform 0.3s ease-in-out;
+                                        overflow-y: auto;
+                                        z-index: calc(var(--fetch-debug-z-index) + 1);
+                                    }
+
+                                    .fetch-debug-root[data-side='left'] .fetch-debug-sheet {
+                                        right: auto;
+                                        left: 0;
+                                        box-shadow: 2px 0 5px var(--fetch-debug-shadow);
+                                        transform: translateX(-100%);
+                                    }
+
+                                    .fetch-debug-root .fetch-debug-sheet button {
+                                        background-color: transparent;
+                                        border: none;
+                                        color: var(--fetch-debug-color);
+                                        cursor: pointer;
+                                    }
+
+                                    .fetch-debug-root .fetch-debug-sheet.fetch-debug-sheet-open {
+                                        transform: translateX(0);
+                                    }
+
+                                    .fetch-debug-root .fetch-debug-item {
+                                        display: flex;
+                                        flex-direction: column;
+                                        gap: 6px;
+                                        margin-bottom: 10px;
+                                        margin-left: 8px;
+                                        margin-right: 8px;
+                                        padding: 10px;
+                                        border-radius: 5px;
+                                        font-size: 0.8rem;
+                                        background-color: var(--fetch-debug-item-background);
+                                    }
+
+                                    .fetch-debug-root .fetch-debug-item-title {
+                                        display: flex;
+                                        flex-direction: column;
+                                        gap: 6px;
+                                        cursor: pointer;
+                                    }
+
+                                    .fetch-debug-root .fetch-debug-data {
+                                        background-color: var(--fetch-debug-background);
+                                        padding: 10px;
+                                        border-radius: 3px;
+                                        overflow-x: auto;
+                                    }
+                                "}
+                            </style>
+                        }
+                    </div>
                 }
             }
 
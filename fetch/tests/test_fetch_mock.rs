@@ -0,0 +1,206 @@
+#![cfg(feature = "mock")]
+
+use schema::*;
+use serde::{Deserialize, Serialize};
+use std::rc::Rc;
+use yew::prelude::*;
+use yewlish_fetch_utils::*;
+use yewlish_testing_tools::*;
+
+#[derive(Default, Serialize, PartialEq, Clone)]
+struct PostSlugs {
+    id: u32,
+}
+
+#[derive(Default, Deserialize, Debug, Serialize, PartialEq, Clone)]
+struct PostBody {
+    id: u32,
+    title: String,
+}
+
+mod schema {
+    use yewlish_fetch::FetchSchema;
+
+    #[derive(FetchSchema)]
+    pub enum Api {
+        #[get("/posts", res = Vec<PostBody>)]
+        GetPosts,
+        #[get("/posts/{id}", slugs = PostSlugs, res = PostBody)]
+        GetPost,
+        #[delete("/posts/{id}", slugs = PostSlugs)]
+        DeletePost,
+    }
+}
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+fn mock() -> ApiFetchClientMock {
+    let mock = ApiFetchClientMock::new();
+
+    mock.set_get_posts(Ok(vec![
+        PostBody {
+            id: 1,
+            title: "First".to_string(),
+        },
+        PostBody {
+            id: 2,
+            title: "Second".to_string(),
+        },
+    ]))
+    .set_delete_post(Err(FetchError::HttpError("403".to_string())));
+
+    mock
+}
+
+#[derive(Properties, Clone, PartialEq)]
+struct MockRootProps {
+    children: Children,
+}
+
+#[function_component(MockRoot)]
+fn mock_root(props: &MockRootProps) -> Html {
+    html! {
+        <ApiFetchClientProvider client={mock().client()}>
+            {for props.children.iter()}
+        </ApiFetchClientProvider>
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    #[wasm_bindgen_test]
+    async fn test_mock_resolves_preset_responses() {
+        let mock = mock();
+        let client = mock.client();
+        let signal = Rc::new(web_sys::AbortController::new().unwrap().signal());
+
+        let posts = client
+            .get_posts(
+                client.prepare_get_posts_url(),
+                signal.clone(),
+                GetPostsParams::default(),
+            )
+            .await
+            .unwrap();
+
+        let posts: Vec<PostBody> = deserialize_response(&posts).unwrap();
+        assert_eq!(posts.len(), 2);
+
+        let deleted = client
+            .delete_post(
+                client.prepare_delete_post_url(),
+                signal.clone(),
+                DeletePostParams::new().slugs(PostSlugs { id: 1 }),
+            )
+            .await;
+
+        assert_eq!(deleted, Err(FetchError::HttpError("403".to_string())));
+
+        // Endpoints without a preset never reach the network
+        let post = client
+            .get_post(
+                client.prepare_get_post_url(),
+                signal,
+                GetPostParams::new().slugs(PostSlugs { id: 1 }),
+            )
+            .await;
+
+        assert!(matches!(post, Err(FetchError::UnknownError(_))));
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_mock_resolves_hooks() {
+        let t = render!(
+            {
+                let posts = use_get_posts(GetPostsParams::default());
+
+                html! {
+                    <ul>
+                        {for (*posts.data).clone().unwrap_or_default().iter().map(|post| html! {
+                            <li key={post.id}>{&post.title}</li>
+                        })}
+                    </ul>
+                }
+            },
+            MockRoot
+        )
+        .await;
+
+        t.wait_for(1000.0, || t.query_all_by_role("listitem").len() == 2)
+            .await;
+
+        assert!(t.query_by_text("First").exists());
+        assert!(t.query_by_text("Second").exists());
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_disabled_hook_waits_for_trigger() {
+        let t = render!(
+            {
+                let posts = use_get_posts_with_options(
+                    GetPostsParams::default(),
+                    GetPostsOptions {
+                        enabled: false,
+                        ..Default::default()
+                    },
+                );
+
+                html! {
+                    <>
+                        <button onclick={posts.trigger.reform(|_: MouseEvent| ())}>{"Load"}</button>
+                        <ul>
+                            {for (*posts.data).clone().unwrap_or_default().iter().map(|post| html! {
+                                <li key={post.id}>{&post.title}</li>
+                            })}
+                        </ul>
+                    </>
+                }
+            },
+            MockRoot
+        )
+        .await;
+
+        assert!(
+            !t.wait_for(200.0, || !t.query_all_by_role("listitem").is_empty())
+                .await
+        );
+
+        t.query_by_role("button").click().await;
+
+        t.wait_for(1000.0, || t.query_all_by_role("listitem").len() == 2)
+            .await;
+
+        assert!(t.query_by_text("First").exists());
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_updated_at_tracks_network_data() {
+        let t = render!(
+            {
+                let posts = use_get_posts(GetPostsParams::default());
+                use_remember_value((*posts.updated_at, posts.is_stale));
+
+                html! {
+                    <ul>
+                        {for (*posts.data).clone().unwrap_or_default().iter().map(|post| html! {
+                            <li key={post.id}>{&post.title}</li>
+                        })}
+                    </ul>
+                }
+            },
+            MockRoot
+        )
+        .await;
+
+        t.wait_for(1000.0, || t.query_all_by_role("listitem").len() == 2)
+            .await;
+
+        let (updated_at, is_stale) = t.get_remembered_value::<(Option<f64>, bool)>();
+
+        assert!(updated_at.is_some_and(|updated_at| updated_at <= js_sys::Date::now()));
+        assert!(!is_stale);
+    }
+}
@@ -47,6 +47,8 @@ mod schema {
         GetPostComments,
         #[get("/comments", query = GetPostCommentsQuery, res = Vec<CommentBody>)]
         GetComments,
+        #[get("/posts", stream)]
+        StreamPosts,
         #[post("/posts", body = PostBody)]
         CreatePost,
         #[put("/posts/{id}", slugs = PostSlugs, body = PostBody)]
@@ -99,6 +101,179 @@ fn test_root(props: &TestRootProps) -> Html {
     }
 }
 
+thread_local! {
+    static GLOBAL_ERRORS: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+}
+
+#[function_component(GlobalInterceptorsRoot)]
+fn global_interceptors_root(props: &TestRootProps) -> Html {
+    let client = ApiFetchClient::new("https://jsonplaceholder.typicode.com").with_on_global_error(
+        Callback::from(|_error: FetchError| {
+            GLOBAL_ERRORS.with(|errors| errors.set(errors.get() + 1));
+        }),
+    );
+
+    html! {
+        <ApiFetchClientProvider client={client}>
+            {for props.children.iter()}
+        </ApiFetchClientProvider>
+    }
+}
+
+/// Only reachable through a per-call base URL override
+#[function_component(UnreachableRoot)]
+fn unreachable_root(props: &TestRootProps) -> Html {
+    let client = ApiFetchClient::new("https://unreachable.invalid");
+
+    html! {
+        <ApiFetchClientProvider client={client}>
+            {for props.children.iter()}
+        </ApiFetchClientProvider>
+    }
+}
+
+thread_local! {
+    static CLIENT_POINTERS: std::cell::RefCell<Vec<usize>> = const { std::cell::RefCell::new(Vec::new()) };
+}
+
+#[derive(Properties, Clone, PartialEq)]
+struct ClientPointerProps {
+    renders: u32,
+}
+
+/// Records the address of the client it gets from the context on every render
+#[function_component(ClientPointer)]
+fn client_pointer(props: &ClientPointerProps) -> Html {
+    let client = use_api_fetch_client();
+
+    CLIENT_POINTERS.with(|pointers| pointers.borrow_mut().push(Rc::as_ptr(&client) as usize));
+
+    html! {
+        <p>{format!("Renders: {}", props.renders)}</p>
+    }
+}
+
+thread_local! {
+    static REQUEST_LOCALES: std::cell::RefCell<Vec<String>> = const { std::cell::RefCell::new(Vec::new()) };
+}
+
+/// Shows a post and invalidates it on click, the requests record their `Accept-Language`
+#[function_component(LocalizedPost)]
+fn localized_post() -> Html {
+    let client = use_api_fetch_client();
+    let post = use_get_post(GetPostParams::new().slugs(PostSlugs { id: 3 }));
+
+    let onclick = use_callback(client, |_: MouseEvent, client| {
+        client.invalidate(&ApiCacheTarget::GetPost(None));
+    });
+
+    html! {
+        <>
+            <button {onclick}>{"Invalidate"}</button>
+            <p>{(*post.data).clone().map(|post| post.title).unwrap_or_default()}</p>
+        </>
+    }
+}
+
+/// Shows two posts with buttons that invalidate the first one or both
+#[function_component(InvalidatedPosts)]
+fn invalidated_posts() -> Html {
+    let client = use_api_fetch_client();
+    let first = use_get_post(GetPostParams::new().slugs(PostSlugs { id: 1 }));
+    let second = use_get_post(GetPostParams::new().slugs(PostSlugs { id: 2 }));
+
+    let invalidate_first = use_callback(client.clone(), |_: MouseEvent, client| {
+        client.invalidate(&ApiCacheTarget::GetPost(Some(
+            GetPostParams::new().slugs(PostSlugs { id: 1 }),
+        )));
+    });
+
+    let invalidate_all = use_callback(client, |_: MouseEvent, client| {
+        client.invalidate(&ApiCacheTarget::GetPost(None));
+    });
+
+    html! {
+        <>
+            <button onclick={invalidate_first}>{"Invalidate first"}</button>
+            <button onclick={invalidate_all}>{"Invalidate all"}</button>
+            <p>{(*first.data).clone().map(|post| post.title).unwrap_or_default()}</p>
+            <p>{(*second.data).clone().map(|post| post.title).unwrap_or_default()}</p>
+        </>
+    }
+}
+
+/// Lists the posts and creates one on click, which invalidates the list
+#[function_component(CreatePostForm)]
+fn create_post_form() -> Html {
+    let posts = use_get_posts(GetPostsParams::default());
+
+    let create_post = use_create_post_with_options_async(CreatePostOptions {
+        invalidate: vec![ApiCacheTarget::GetPosts(None)],
+        ..Default::default()
+    });
+
+    let onclick = use_callback(create_post.trigger.clone(), |_: MouseEvent, trigger| {
+        trigger.emit(CreatePostParams {
+            body: PostBody {
+                id: 101,
+                title: "Test".to_string(),
+                body: "Test".to_string(),
+                user_id: 1,
+            },
+            ..Default::default()
+        });
+    });
+
+    html! {
+        <>
+            <button {onclick}>{"Create"}</button>
+            <p>{format!("Posts: {}", (*posts.data).as_ref().map_or(0, Vec::len))}</p>
+            if (*create_post.data).is_some() {
+                <p>{"Created"}</p>
+            }
+        </>
+    }
+}
+
+/// Loads two posts and cancels every live request of the client on click
+#[function_component(CancelledPosts)]
+fn cancelled_posts() -> Html {
+    let client = use_api_fetch_client();
+    let first = use_get_post(GetPostParams::new().slugs(PostSlugs { id: 4 }));
+    let second = use_get_post(GetPostParams::new().slugs(PostSlugs { id: 5 }));
+
+    let onclick = use_callback(client, |_: MouseEvent, client| {
+        client.cancel_all();
+    });
+
+    let status = |error: bool, loading: bool| match (error, loading) {
+        (true, _) => "Cancelled",
+        (false, true) => "Loading",
+        (false, false) => "Loaded",
+    };
+
+    html! {
+        <>
+            <button {onclick}>{"Cancel all"}</button>
+            <p data-testid="first">{status(first.error.is_some(), *first.loading)}</p>
+            <p data-testid="second">{status(second.error.is_some(), *second.loading)}</p>
+        </>
+    }
+}
+
+#[function_component(SuspensePosts)]
+fn suspense_posts() -> HtmlResult {
+    let posts = use_get_posts_suspense(GetPostsParams::default())?;
+
+    Ok(html! {
+        <ul>
+            {for posts.unwrap_or_default().iter().map(|post| html! {
+                <li key={post.id}>{&post.title}</li>
+            })}
+        </ul>
+    })
+}
+
 #[cfg(test)]
 mod test {
     use crate::*;
@@ -294,6 +469,246 @@ mod test {
         assert!(result.is_ok());
     }
 
+    #[wasm_bindgen_test]
+    fn test_empty_body_deserialization() {
+        let client = ApiFetchClient::new("https://jsonplaceholder.typicode.com");
+        let cache: Rc<std::cell::RefCell<dyn Cacheable>> =
+            Rc::new(std::cell::RefCell::new(Cache::default()));
+
+        // `DeletePost` has `res = ()`, and a `204 No Content` response has an empty body
+        let cache_key = client
+            .get_delete_post_cache_entry(&DeletePostParams::new().slugs(PostSlugs { id: 1 }))
+            .unwrap();
+
+        let result = deserialize_response_and_store_cache::<()>(
+            "",
+            &SerdeJsonCodec,
+            &cache,
+            &cache_key,
+            None,
+        );
+        assert!(result.is_ok());
+
+        let cached = cache.borrow().get(&cache_key).cloned().unwrap();
+        assert!(deserialize_cached_data::<()>(&cached.data).is_ok());
+
+        assert_eq!(deserialize_response::<Option<PostBody>>("").unwrap(), None);
+        assert_eq!(deserialize_response::<String>(" ").unwrap(), String::new());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_invalidate() {
+        let client = ApiFetchClient::new("https://jsonplaceholder.typicode.com");
+        let first = GetPostParams::new().slugs(PostSlugs { id: 1 });
+        let second = GetPostParams::new().slugs(PostSlugs { id: 2 });
+
+        let keys = [
+            client.get_get_post_cache_entry(&first).unwrap(),
+            client.get_get_post_cache_entry(&second).unwrap(),
+            client
+                .get_get_posts_cache_entry(&GetPostsParams::default())
+                .unwrap(),
+        ];
+
+        for key in &keys {
+            client
+                .cache
+                .borrow_mut()
+                .set(key, &serde_json::Value::Null, None);
+        }
+
+        client.invalidate(&ApiCacheTarget::GetPost(Some(first)));
+
+        assert!(client.cache.borrow().get(&keys[0]).is_none());
+        assert!(client.cache.borrow().get(&keys[1]).is_some());
+
+        client.invalidate(&ApiCacheTarget::GetPost(None));
+
+        assert!(client.cache.borrow().get(&keys[1]).is_none());
+        assert!(client.cache.borrow().get(&keys[2]).is_some());
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_locale_partitions_the_cache() {
+        let client = ApiFetchClient::new("https://jsonplaceholder.typicode.com");
+        let params = GetPostParams::new().slugs(PostSlugs { id: 1 });
+
+        let english = client.clone().with_locale("en");
+        let french = client.clone().with_locale("fr");
+
+        let keys = [
+            english.get_get_post_cache_entry(&params).unwrap(),
+            french.get_get_post_cache_entry(&params).unwrap(),
+        ];
+
+        assert_ne!(keys[0], keys[1]);
+
+        // The clients share the cache, invalidating the query drops every locale
+        for key in &keys {
+            client
+                .cache
+                .borrow_mut()
+                .set(key, &serde_json::Value::Null, None);
+        }
+
+        french.invalidate(&ApiCacheTarget::GetPost(None));
+
+        assert!(client.cache.borrow().get(&keys[0]).is_none());
+        assert!(client.cache.borrow().get(&keys[1]).is_none());
+
+        let url = web_sys::Url::new(&english.prepare_get_post_url()).unwrap();
+        let signal = Rc::new(web_sys::AbortController::new().unwrap().signal());
+
+        let request = build_request(
+            &url,
+            &HttpMethod::GET,
+            &FetchOptions {
+                slugs: (),
+                query: (),
+                body: (),
+                middlewares: &english.request_middlewares(),
+                abort_signal: signal.clone(),
+                credentials: None,
+                mode: None,
+                codec: english.codec.as_ref(),
+                on_progress: None,
+            },
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            request.headers().get("Accept-Language").unwrap().as_deref(),
+            Some("en")
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_provider_locale_refetches() {
+        let t = render!({
+            let locale = use_state(|| AttrValue::from("en"));
+
+            let client = use_memo((), |()| {
+                let record_locale: Middleware = Rc::new(|_request_init, headers| {
+                    if let Ok(Some(locale)) = headers.borrow().get("Accept-Language") {
+                        REQUEST_LOCALES.with(|locales| locales.borrow_mut().push(locale));
+                    }
+
+                    Box::pin(async {})
+                });
+
+                ApiFetchClient::new("https://jsonplaceholder.typicode.com")
+                    .with_middlewares(vec![record_locale])
+            });
+
+            let onclick = use_callback(locale.clone(), |_: MouseEvent, locale| {
+                locale.set(AttrValue::from("fr"));
+            });
+
+            html! {
+                <>
+                    <button {onclick}>{"French"}</button>
+                    <ApiFetchClientProvider client={(*client).clone()} locale={(*locale).clone()}>
+                        <LocalizedPost />
+                    </ApiFetchClientProvider>
+                </>
+            }
+        })
+        .await;
+
+        let requests = || REQUEST_LOCALES.with(|locales| locales.borrow().clone());
+
+        t.wait_for(1000.0, || requests().len() == 1).await;
+
+        t.query_by_text("French").click().await;
+        t.wait_for(1000.0, || requests().len() == 2).await;
+
+        // The hook registered again with the new client, so invalidating still reaches it
+        t.query_by_text("Invalidate").click().await;
+        t.wait_for(1000.0, || requests().len() == 3).await;
+
+        assert_eq!(requests(), vec!["en", "fr", "fr"]);
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_invalidate_refetches_the_mounted_queries() {
+        thread_local! {
+            static REQUESTS: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+        }
+
+        let t = render!({
+            let client = use_memo((), |()| {
+                let count_requests: Middleware = Rc::new(|_request_init, _headers| {
+                    REQUESTS.with(|requests| requests.set(requests.get() + 1));
+                    Box::pin(async {})
+                });
+
+                ApiFetchClient::new("https://jsonplaceholder.typicode.com")
+                    .with_middlewares(vec![count_requests])
+            });
+
+            html! {
+                <ApiFetchClientProvider client={(*client).clone()}>
+                    <InvalidatedPosts />
+                </ApiFetchClientProvider>
+            }
+        })
+        .await;
+
+        let requests = || REQUESTS.with(std::cell::Cell::get);
+
+        assert!(t.wait_for(2000.0, || requests() == 2).await);
+
+        // Only the hook that used the invalidated params sends its query again
+        t.query_by_text("Invalidate first").click().await;
+        assert!(t.wait_for(2000.0, || requests() == 3).await);
+
+        t.query_by_text("Invalidate all").click().await;
+        assert!(t.wait_for(2000.0, || requests() == 5).await);
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_mutation_invalidates_the_queries_on_success() {
+        thread_local! {
+            static REQUESTS: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+        }
+
+        let t = render!({
+            let client = use_memo((), |()| {
+                let count_requests: Middleware = Rc::new(|_request_init, _headers| {
+                    REQUESTS.with(|requests| requests.set(requests.get() + 1));
+                    Box::pin(async {})
+                });
+
+                ApiFetchClient::new("https://jsonplaceholder.typicode.com")
+                    .with_middlewares(vec![count_requests])
+            });
+
+            html! {
+                <ApiFetchClientProvider client={(*client).clone()}>
+                    <CreatePostForm />
+                </ApiFetchClientProvider>
+            }
+        })
+        .await;
+
+        let requests = || REQUESTS.with(std::cell::Cell::get);
+
+        assert!(t.wait_for(2000.0, || requests() == 1).await);
+        assert!(
+            t.wait_for(2000.0, || t.query_by_text("Posts: 100").exists())
+                .await
+        );
+
+        // The list is cached, only the invalidation makes it go to the network again
+        t.query_by_text("Create").click().await;
+        assert!(
+            t.wait_for(2000.0, || t.query_by_text("Created").exists())
+                .await
+        );
+        assert!(t.wait_for(2000.0, || requests() == 3).await);
+    }
+
     #[wasm_bindgen_test]
     async fn test_middleware() {
         let client =
@@ -371,6 +786,148 @@ mod test {
         );
     }
 
+    #[wasm_bindgen_test]
+    async fn test_hook_select() {
+        let t = render!(
+            {
+                let options = use_memo((), |()| GetPostsOptions {
+                    select: Some(Callback::from(|posts: Vec<PostBody>| {
+                        posts.into_iter().take(10).collect()
+                    })),
+                    ..Default::default()
+                });
+
+                let posts =
+                    use_get_posts_with_options(GetPostsParams::default(), (*options).clone());
+
+                html! {
+                    <ul>
+                        {for (*posts.data).clone().unwrap_or_default().iter().map(|post| html! {
+                            <li key={post.id}>{&post.title}</li>
+                        })}
+                    </ul>
+                }
+            },
+            TestRoot
+        )
+        .await;
+
+        t.wait_for(1000.0, || !t.query_all_by_role("listitem").is_empty())
+            .await;
+
+        assert_eq!(t.query_all_by_role("listitem").len(), 10);
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_hook_is_fetching() {
+        let t = render!(
+            {
+                let posts = use_get_posts(GetPostsParams::default());
+                let renders = use_mut_ref(Vec::<(bool, bool, bool)>::new);
+
+                renders.borrow_mut().push((
+                    posts.data.is_some(),
+                    *posts.loading,
+                    *posts.is_fetching,
+                ));
+
+                use_remember_value(renders.borrow().clone());
+
+                html! {
+                    <>
+                        <button onclick={posts.trigger.reform(|_: MouseEvent| ())}>{"Refetch"}</button>
+                        if posts.data.is_some() && !*posts.is_fetching {
+                            <p>{"Loaded"}</p>
+                        }
+                    </>
+                }
+            },
+            TestRoot
+        )
+        .await;
+
+        t.wait_for(1000.0, || t.query_by_text("Loaded").exists())
+            .await;
+
+        t.query_by_text("Refetch").click().await;
+
+        t.wait_for(1000.0, || t.query_by_text("Loaded").exists())
+            .await;
+
+        let renders = t.get_remembered_value::<Vec<(bool, bool, bool)>>();
+
+        // The first load is `loading`, the refresh of the shown data only `is_fetching`
+        assert!(renders.contains(&(false, true, true)));
+        assert!(renders.contains(&(true, false, true)));
+        assert!(!renders.contains(&(true, true, true)));
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_lazy_hook_reads_without_fetching() {
+        let t = render!(
+            {
+                let enabled = use_state(|| false);
+                let lazy_posts = use_get_posts_lazy();
+
+                let posts = use_get_posts_with_options(
+                    GetPostsParams::default(),
+                    GetPostsOptions {
+                        enabled: *enabled,
+                        ..Default::default()
+                    },
+                );
+
+                use_remember_value(*posts.loading);
+
+                html! {
+                    <>
+                        <button onclick={Callback::from(move |_: MouseEvent| enabled.set(true))}>{"Load"}</button>
+                        if let Some(lazy_posts) = lazy_posts {
+                            <p>{format!("Lazy {}", lazy_posts.len())}</p>
+                        }
+                    </>
+                }
+            },
+            TestRoot
+        )
+        .await;
+
+        // The lazy hook alone never sends the query
+        assert!(!t.get_remembered_value::<bool>());
+        assert!(!t.query_by_text("Lazy 100").exists());
+
+        t.query_by_text("Load").click().await;
+
+        t.wait_for(1000.0, || t.query_by_text("Lazy 100").exists())
+            .await;
+
+        assert!(t.query_by_text("Lazy 100").exists());
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_web_socket_accumulate_starts_empty() {
+        let t = render!(
+            {
+                let options = use_memo((), |()| WebSocketOptions {
+                    accumulate: true,
+                    max_messages: Some(50),
+                    ..Default::default()
+                });
+
+                let socket =
+                    use_web_socket_with_options(WebSocketParams::default(), (*options).clone());
+
+                use_remember_value(socket.messages.borrow().clone());
+
+                html! {}
+            },
+            TestRoot
+        )
+        .await;
+
+        assert!(t.get_remembered_value::<Vec<f64>>().is_empty());
+    }
+
     #[wasm_bindgen_test]
     async fn test_hook_async() {
         let t = render!(
@@ -438,4 +995,688 @@ mod test {
             100
         );
     }
+
+    #[wasm_bindgen_test]
+    fn test_params_builder() {
+        let params = UpdatePostParams::new()
+            .slugs(PostSlugs { id: 1 })
+            .body(PostBody {
+                id: 1,
+                title: "title".to_string(),
+                ..Default::default()
+            });
+
+        assert!(
+            params
+                == UpdatePostParams {
+                    slugs: PostSlugs { id: 1 },
+                    body: PostBody {
+                        id: 1,
+                        title: "title".to_string(),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                }
+        );
+
+        assert!(WebSocketParams::new() == WebSocketParams::default());
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_get_request_stream() {
+        let client = ApiFetchClient::new("https://jsonplaceholder.typicode.com");
+
+        let abort_controller = web_sys::AbortController::new().unwrap();
+        let signal = Rc::new(abort_controller.signal());
+
+        let response = client
+            .stream_posts(
+                client.prepare_stream_posts_url(),
+                signal.clone(),
+                StreamPostsParams::default(),
+            )
+            .await
+            .unwrap();
+
+        let chunks = std::cell::RefCell::new(Vec::<String>::new());
+
+        read_response_stream(&response, |chunk| chunks.borrow_mut().push(chunk))
+            .await
+            .unwrap();
+
+        assert!(!chunks.borrow().is_empty());
+
+        let result: Vec<PostBody> = deserialize_response(&chunks.borrow().concat()).unwrap();
+        assert_eq!(result.len(), 100);
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_in_flight_dedupe() {
+        let client = Rc::new(ApiFetchClient::new("https://jsonplaceholder.typicode.com"));
+        let params = GetPostsParams::default();
+        let cache_key = client.get_get_posts_cache_entry(&params).unwrap();
+        let calls = Rc::new(std::cell::Cell::new(0));
+
+        let request = || {
+            let client = client.clone();
+            let params = params.clone();
+            let calls = calls.clone();
+
+            move |signal| async move {
+                calls.set(calls.get() + 1);
+
+                client
+                    .get_posts(client.prepare_get_posts_url(), signal, params)
+                    .await
+            }
+        };
+
+        let first_signal = web_sys::AbortController::new().unwrap().signal();
+        let second_signal = web_sys::AbortController::new().unwrap().signal();
+
+        let (first, second) = futures::join!(
+            client
+                .in_flight
+                .dedupe(&cache_key, &first_signal, request()),
+            client
+                .in_flight
+                .dedupe(&cache_key, &second_signal, request()),
+        );
+
+        assert_eq!(calls.get(), 1);
+        assert_eq!(first, second);
+        assert!(client.in_flight.is_empty());
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_in_flight_dedupe_survives_a_cancelled_caller() {
+        let t = render!(
+            {
+                let first = use_get_post(GetPostParams::new().slugs(PostSlugs { id: 2 }));
+                let second = use_get_post(GetPostParams::new().slugs(PostSlugs { id: 2 }));
+
+                html! {
+                    <>
+                        <button onclick={first.cancel.reform(|_: MouseEvent| ())}>{"Cancel"}</button>
+                        <p data-testid="first">{if first.error.is_some() { "Cancelled" } else { "" }}</p>
+                        <p data-testid="second">{(*second.data).clone().map(|post| post.title).unwrap_or_default()}</p>
+                    </>
+                }
+            },
+            TestRoot
+        )
+        .await;
+
+        // Both hooks wait for the same request, only the first one stops waiting
+        t.query_by_text("Cancel").click().await;
+
+        t.wait_for(1000.0, || !t.query_by_testid("second").text().is_empty())
+            .await;
+
+        assert_eq!(t.query_by_testid("first").text(), "Cancelled");
+        assert!(!t.query_by_testid("second").text().is_empty());
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_on_global_error() {
+        let t = render!(
+            {
+                let post = use_get_post(GetPostParams::new().slugs(PostSlugs { id: 0 }));
+
+                html! {
+                    if post.error.is_some() {
+                        <p>{"Error"}</p>
+                    }
+                }
+            },
+            GlobalInterceptorsRoot
+        )
+        .await;
+
+        t.wait_for(1000.0, || GLOBAL_ERRORS.with(std::cell::Cell::get) > 0)
+            .await;
+
+        assert_eq!(GLOBAL_ERRORS.with(std::cell::Cell::get), 1);
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_provider_keeps_the_client_identity() {
+        let t = render!({
+            let renders = use_state(|| 0);
+
+            let onclick = use_callback(renders.clone(), |_: MouseEvent, renders| {
+                renders.set(**renders + 1);
+            });
+
+            let client = use_memo((), |()| {
+                ApiFetchClient::new("https://jsonplaceholder.typicode.com")
+            });
+
+            // A copy of the same client on every render, the third one gets its own cache
+            let client = if *renders < 2 {
+                (*client).clone()
+            } else {
+                (*client)
+                    .clone()
+                    .with_cache(Rc::new(std::cell::RefCell::new(Cache::default())))
+            };
+
+            html! {
+                <>
+                    <button {onclick}>{"Rerender"}</button>
+                    <ApiFetchClientProvider {client}>
+                        <ClientPointer renders={*renders} />
+                    </ApiFetchClientProvider>
+                </>
+            }
+        })
+        .await;
+
+        t.query_by_role("button").click().await;
+        t.query_by_role("button").click().await;
+
+        let pointers = CLIENT_POINTERS.with(|pointers| pointers.borrow().clone());
+
+        assert_eq!(pointers.len(), 3);
+        assert_eq!(pointers[0], pointers[1]);
+        assert_ne!(pointers[1], pointers[2]);
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_suspense_hook() {
+        let t = render!(
+            {
+                html! {
+                    <Suspense fallback={html! { <p>{"Loading"}</p> }}>
+                        <SuspensePosts />
+                    </Suspense>
+                }
+            },
+            TestRoot
+        )
+        .await;
+
+        assert!(t.query_by_text("Loading").exists());
+
+        t.wait_for(1000.0, || {
+            let post_items = t.query_all_by_role("listitem");
+            post_items.len() == 100
+        })
+        .await;
+
+        assert!(!t.query_by_text("Loading").exists());
+        assert_eq!(t.query_all_by_role("listitem").len(), 100);
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_signal_batch() {
+        let signal = Signal::new(0);
+        let notifications = Rc::new(std::cell::RefCell::new(Vec::new()));
+
+        signal.subscribe(Callback::from({
+            let notifications = notifications.clone();
+            move |value: i32| notifications.borrow_mut().push(value)
+        }));
+
+        signal.batch(|signal| {
+            signal.set(1);
+            signal.set(2);
+            signal.batch(|signal| signal.set(3));
+        });
+
+        signal.batch(|_signal| {});
+
+        assert_eq!(signal.get(), 3);
+        assert_eq!(*notifications.borrow(), vec![0, 3]);
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_cancel_all() {
+        let client = ApiFetchClient::new("https://jsonplaceholder.typicode.com");
+
+        let running = Rc::new(web_sys::AbortController::new().unwrap());
+        let settled = Rc::new(web_sys::AbortController::new().unwrap());
+
+        client.abort_controllers.register(&running);
+        client.abort_controllers.register(&settled);
+        client.abort_controllers.unregister(&settled);
+        assert_eq!(client.abort_controllers.len(), 1);
+
+        client.cancel_all();
+
+        assert!(running.signal().aborted());
+        assert!(!settled.signal().aborted());
+        assert!(client.abort_controllers.is_empty());
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_cancel_all_aborts_the_mounted_queries() {
+        let t = render!({
+            let client = use_memo((), |()| {
+                // Holds the requests back long enough to cancel them
+                let delay: Middleware = Rc::new(|_request_init, _headers| {
+                    Box::pin(yew::platform::time::sleep(
+                        std::time::Duration::from_millis(300),
+                    ))
+                });
+
+                ApiFetchClient::new("https://jsonplaceholder.typicode.com")
+                    .with_middlewares(vec![delay])
+            });
+
+            use_remember_value((*client).clone());
+
+            html! {
+                <ApiFetchClientProvider client={(*client).clone()}>
+                    <CancelledPosts />
+                </ApiFetchClientProvider>
+            }
+        })
+        .await;
+
+        let client = t.get_remembered_value::<ApiFetchClient>();
+        assert_eq!(client.abort_controllers.len(), 2);
+
+        t.query_by_text("Cancel all").click().await;
+
+        assert!(
+            t.wait_for(1000.0, || {
+                t.query_by_testid("first").text() == "Cancelled"
+                    && t.query_by_testid("second").text() == "Cancelled"
+            })
+            .await
+        );
+        assert!(client.abort_controllers.is_empty());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_cache_entry_staleness() {
+        let mut cache = Cache::default();
+        cache.set("fresh", &serde_json::Value::Null, Some(60_000.0));
+        cache.set("expired", &serde_json::Value::Null, Some(-1.0));
+
+        let entry = cache.get("fresh").cloned().unwrap();
+        assert_eq!(entry.max_age(), 60_000.0);
+        assert_eq!(entry.expires_at(), entry.stored_at + 60_000.0);
+        assert_eq!(entry.timestamp, entry.expires_at());
+        assert!(!entry.is_expired());
+        assert!(!entry.is_stale(60_000.0));
+        assert!(entry.is_stale(-1.0));
+
+        assert!(cache.get("expired").is_none());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_cache_entry_etag() {
+        let mut cache = Cache::default();
+        let data = serde_json::json!({ "id": 1 });
+
+        cache.set_with_etag("post", &data, None, Some("\"v1\""));
+        assert_eq!(cache.get("post").unwrap().etag.as_deref(), Some("\"v1\""));
+
+        // Storing the same data again keeps the `ETag`
+        cache.set("post", &data, Some(60_000.0));
+        assert_eq!(cache.get("post").unwrap().etag.as_deref(), Some("\"v1\""));
+
+        // Revalidation restarts the entry and keeps its max age
+        cache.set_with_etag("post", &data, Some(-1.0), Some("\"v1\""));
+        assert!(cache.get("post").is_none());
+        assert!(cache.peek("post").is_some());
+
+        cache.refresh("post", Some(60_000.0));
+        assert_eq!(cache.get("post").unwrap().etag.as_deref(), Some("\"v1\""));
+
+        // New data drops the `ETag` that described the old one
+        cache.set("post", &serde_json::json!({ "id": 2 }), None);
+        assert_eq!(cache.get("post").unwrap().etag, None);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_follow_abort_signal() {
+        let parent = web_sys::AbortController::new().unwrap();
+        let request = Rc::new(web_sys::AbortController::new().unwrap());
+
+        let _follower = follow_abort_signal(&request, &parent.signal());
+        assert!(!request.signal().aborted());
+
+        parent.abort();
+        assert!(request.signal().aborted());
+
+        // A signal that already aborted cancels the request right away
+        let late_request = Rc::new(web_sys::AbortController::new().unwrap());
+        let _late_follower = follow_abort_signal(&late_request, &parent.signal());
+        assert!(late_request.signal().aborted());
+
+        // A settled request stops following the signal
+        let other_parent = web_sys::AbortController::new().unwrap();
+        let settled_request = Rc::new(web_sys::AbortController::new().unwrap());
+        drop(follow_abort_signal(
+            &settled_request,
+            &other_parent.signal(),
+        ));
+
+        other_parent.abort();
+        assert!(!settled_request.signal().aborted());
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_hook_with_base_url_override() {
+        let t = render!(
+            {
+                let post = use_get_post_with_options(
+                    GetPostParams::new().slugs(PostSlugs { id: 1 }),
+                    GetPostOptions {
+                        base_url_override: Some("https://jsonplaceholder.typicode.com".to_string()),
+                        headers: vec![("X-Tenant".to_string(), "yewlish".to_string())],
+                        ..Default::default()
+                    },
+                );
+
+                html! {
+                    <p>{(*post.data).clone().map(|post| post.title).unwrap_or_default()}</p>
+                }
+            },
+            UnreachableRoot
+        )
+        .await;
+
+        t.wait_for(1000.0, || !t.query_by_role("paragraph").text().is_empty())
+            .await;
+
+        assert!(!t.query_by_role("paragraph").text().is_empty());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_request_overrides_share_the_client_state() {
+        let client = ApiFetchClient::new("https://jsonplaceholder.typicode.com");
+        let tenant_client = client.with_request_overrides(
+            Some("https://tenant.example.com/"),
+            &[("X-Tenant".to_string(), "yewlish".to_string())],
+        );
+
+        assert_eq!(
+            tenant_client.prepare_get_posts_url(),
+            "https://tenant.example.com/posts"
+        );
+        assert_eq!(
+            tenant_client.middlewares.len(),
+            client.middlewares.len() + 1
+        );
+        assert!(Rc::ptr_eq(&tenant_client.cache, &client.cache));
+
+        // Responses fetched with other headers are cached and shared apart from the plain ones
+        let same_origin_client =
+            client.with_request_overrides(None, &[("X-Tenant".to_string(), "yewlish".to_string())]);
+
+        let params = GetPostsParams::default();
+
+        assert_ne!(
+            same_origin_client
+                .get_get_posts_cache_entry(&params)
+                .unwrap(),
+            client.get_get_posts_cache_entry(&params).unwrap()
+        );
+        assert_eq!(
+            same_origin_client
+                .with_request_overrides(None, &[("x-tenant".to_string(), "yewlish".to_string())])
+                .get_get_posts_cache_entry(&params)
+                .unwrap(),
+            same_origin_client
+                .get_get_posts_cache_entry(&params)
+                .unwrap()
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_unexpected_content_type() {
+        // A `data:` URL answers with an HTML body, like an error page of a proxy would
+        let client = ApiFetchClient::new("data:text/html,oops");
+        let signal = Rc::new(web_sys::AbortController::new().unwrap().signal());
+
+        let result = client
+            .get_posts(
+                client.prepare_get_posts_url(),
+                signal,
+                GetPostsParams::default(),
+            )
+            .await;
+
+        let Err(FetchError::UnexpectedContentType {
+            expected,
+            got,
+            body_snippet,
+        }) = result
+        else {
+            panic!("Expected an unexpected content type error, got {result:?}");
+        };
+
+        assert_eq!(expected, DEFAULT_ACCEPT);
+        assert!(got.starts_with("text/html"));
+        assert!(body_snippet.starts_with("oops"));
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_download_progress() {
+        let progress = Rc::new(std::cell::RefCell::new(Vec::<Progress>::new()));
+
+        let mut client = ApiFetchClient::new("data:application/json,[1,2,3]");
+
+        client.on_progress = Some(Callback::from({
+            let progress = progress.clone();
+            move |event| progress.borrow_mut().push(event)
+        }));
+
+        let signal = Rc::new(web_sys::AbortController::new().unwrap().signal());
+
+        let body = client
+            .get_posts(
+                client.prepare_get_posts_url(),
+                signal,
+                GetPostsParams::default(),
+            )
+            .await
+            .unwrap();
+
+        let progress = progress.borrow();
+
+        assert_eq!(progress.first().map(|event| event.loaded), Some(0.0));
+        assert_eq!(
+            progress.last().map(|event| event.loaded),
+            Some(body.len() as f64)
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_download_progress_counts_the_received_bytes() {
+        let progress = Rc::new(std::cell::RefCell::new(Vec::<Progress>::new()));
+
+        // The decoder drops the byte order mark, the progress still counts its 3 bytes
+        let mut client = ApiFetchClient::new("data:application/json,%EF%BB%BF[1,2,3]");
+
+        client.on_progress = Some(Callback::from({
+            let progress = progress.clone();
+            move |event| progress.borrow_mut().push(event)
+        }));
+
+        let signal = Rc::new(web_sys::AbortController::new().unwrap().signal());
+
+        let body = client
+            .get_posts(
+                client.prepare_get_posts_url(),
+                signal,
+                GetPostsParams::default(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(body.len(), 7);
+        assert_eq!(
+            progress.borrow().last().map(|event| event.loaded),
+            Some(10.0)
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_build_request_credentials_and_mode() {
+        let url = web_sys::Url::new("https://jsonplaceholder.typicode.com/posts").unwrap();
+        let signal = Rc::new(web_sys::AbortController::new().unwrap().signal());
+
+        let request = build_request(
+            &url,
+            &HttpMethod::GET,
+            &FetchOptions {
+                slugs: (),
+                query: (),
+                body: (),
+                middlewares: &[],
+                abort_signal: signal.clone(),
+                credentials: None,
+                mode: None,
+                codec: &SerdeJsonCodec,
+                on_progress: None,
+            },
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            request.credentials(),
+            web_sys::RequestCredentials::SameOrigin
+        );
+        assert_eq!(request.mode(), web_sys::RequestMode::Cors);
+
+        let client = ApiFetchClient::new("https://jsonplaceholder.typicode.com")
+            .with_credentials(web_sys::RequestCredentials::Include)
+            .with_mode(web_sys::RequestMode::SameOrigin);
+
+        let request = build_request(
+            &url,
+            &HttpMethod::GET,
+            &FetchOptions {
+                slugs: (),
+                query: (),
+                body: (),
+                middlewares: &[],
+                abort_signal: signal.clone(),
+                credentials: client.credentials,
+                mode: client.mode,
+                codec: client.codec.as_ref(),
+                on_progress: None,
+            },
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(request.credentials(), web_sys::RequestCredentials::Include);
+        assert_eq!(request.mode(), web_sys::RequestMode::SameOrigin);
+
+        // Middlewares run afterwards and have the last word
+        let omit: Middleware = Rc::new(|request_init, _headers| {
+            request_init
+                .borrow()
+                .set_credentials(web_sys::RequestCredentials::Omit);
+
+            Box::pin(async {})
+        });
+
+        let request = build_request(
+            &url,
+            &HttpMethod::GET,
+            &FetchOptions {
+                slugs: (),
+                query: (),
+                body: (),
+                middlewares: &[omit],
+                abort_signal: signal.clone(),
+                credentials: client.credentials,
+                mode: client.mode,
+                codec: client.codec.as_ref(),
+                on_progress: None,
+            },
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(request.credentials(), web_sys::RequestCredentials::Omit);
+    }
+
+    /// Wraps bodies into a `{ "data": .. }` envelope, like some APIs expect
+    struct EnvelopeCodec;
+
+    impl JsonCodec for EnvelopeCodec {
+        fn encode(&self, value: &serde_json::Value) -> Result<String, String> {
+            SerdeJsonCodec.encode(&serde_json::json!({ "data": value }))
+        }
+
+        fn decode(&self, text: &str) -> Result<serde_json::Value, String> {
+            let mut envelope = SerdeJsonCodec.decode(text)?;
+
+            envelope
+                .get_mut("data")
+                .map(serde_json::Value::take)
+                .ok_or_else(|| "missing data".to_string())
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_custom_codec() {
+        let client = ApiFetchClient::new("https://jsonplaceholder.typicode.com")
+            .with_codec(Rc::new(EnvelopeCodec));
+
+        let url = web_sys::Url::new("https://jsonplaceholder.typicode.com/posts").unwrap();
+        let signal = Rc::new(web_sys::AbortController::new().unwrap().signal());
+
+        let post = PostBody {
+            id: 1,
+            title: "Title".to_string(),
+            body: "Body".to_string(),
+            user_id: 1,
+        };
+
+        let request = build_request(
+            &url,
+            &HttpMethod::POST,
+            &FetchOptions {
+                slugs: (),
+                query: (),
+                body: post.clone(),
+                middlewares: &[],
+                abort_signal: signal.clone(),
+                credentials: None,
+                mode: None,
+                codec: client.codec.as_ref(),
+                on_progress: None,
+            },
+        )
+        .await
+        .unwrap();
+
+        let body = wasm_bindgen_futures::JsFuture::from(request.text().unwrap())
+            .await
+            .unwrap()
+            .as_string()
+            .unwrap();
+
+        assert!(body.starts_with(r#"{"data":{"#));
+
+        let cache: Rc<std::cell::RefCell<dyn Cacheable>> =
+            Rc::new(std::cell::RefCell::new(Cache::default()));
+
+        let result = deserialize_response_and_store_cache::<PostBody>(
+            &body,
+            client.codec.as_ref(),
+            &cache,
+            "post",
+            None,
+        );
+
+        assert_eq!(result.unwrap(), post);
+
+        // The cache keeps the decoded value, without the envelope
+        let cached = cache.borrow().get("post").cloned().unwrap();
+        assert_eq!(
+            deserialize_cached_data::<PostBody>(&cached.data).unwrap(),
+            post
+        );
+    }
 }
@@ -0,0 +1,122 @@
+use std::{
+    cell::RefCell,
+    rc::{Rc, Weak},
+};
+use wasm_bindgen::{closure::Closure, JsCast};
+use web_sys::{AbortController, AbortSignal, AddEventListenerOptions};
+
+/// Abort controllers of the requests that are currently running.
+///
+/// The controllers are held weakly, so a hook that goes away without deregistering
+/// doesn't keep its controller alive.
+#[derive(Default, Clone)]
+pub struct AbortRegistry {
+    controllers: Rc<RefCell<Vec<Weak<AbortController>>>>,
+}
+
+impl AbortRegistry {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.controllers
+            .borrow()
+            .iter()
+            .filter(|controller| controller.strong_count() > 0)
+            .count()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn register(&self, controller: &Rc<AbortController>) {
+        let mut controllers = self.controllers.borrow_mut();
+        controllers.retain(|controller| controller.strong_count() > 0);
+        controllers.push(Rc::downgrade(controller));
+    }
+
+    pub fn unregister(&self, controller: &Rc<AbortController>) {
+        self.controllers.borrow_mut().retain(|registered| {
+            registered.strong_count() > 0 && !Weak::ptr_eq(registered, &Rc::downgrade(controller))
+        });
+    }
+
+    /// Aborts every registered controller that is still alive and clears the registry.
+    pub fn abort_all(&self) {
+        let controllers = self.controllers.take();
+
+        for controller in controllers.iter().filter_map(Weak::upgrade) {
+            controller.abort();
+        }
+    }
+}
+
+/// Keeps a controller following an external abort signal, the listener is removed on drop.
+#[must_use = "the controller stops following the signal once this is dropped"]
+pub struct AbortSignalFollower {
+    signal: AbortSignal,
+    on_abort: Option<Closure<dyn FnMut()>>,
+}
+
+impl Drop for AbortSignalFollower {
+    fn drop(&mut self) {
+        let Some(on_abort) = self.on_abort.take() else {
+            return;
+        };
+
+        if let Err(error) = self
+            .signal
+            .remove_event_listener_with_callback("abort", on_abort.as_ref().unchecked_ref())
+        {
+            log::error!("Failed to stop following the abort signal: {error:?}");
+        }
+    }
+}
+
+/// Aborts `controller` together with an external `signal`, right away if it already aborted.
+///
+/// Lets a request follow a cancellation source it doesn't own, e.g. a parent operation.
+/// Keep the returned follower until the request settles, so a long-lived signal
+/// doesn't collect a listener per request.
+pub fn follow_abort_signal(
+    controller: &Rc<AbortController>,
+    signal: &AbortSignal,
+) -> AbortSignalFollower {
+    if signal.aborted() {
+        controller.abort();
+
+        return AbortSignalFollower {
+            signal: signal.clone(),
+            on_abort: None,
+        };
+    }
+
+    let controller = Rc::downgrade(controller);
+
+    let on_abort = Closure::<dyn FnMut()>::new(move || {
+        if let Some(controller) = controller.upgrade() {
+            controller.abort();
+        }
+    });
+
+    let options = AddEventListenerOptions::new();
+    options.set_once(true);
+
+    if let Err(error) = signal.add_event_listener_with_callback_and_add_event_listener_options(
+        "abort",
+        on_abort.as_ref().unchecked_ref(),
+        &options,
+    ) {
+        log::error!("Failed to follow the abort signal: {error:?}");
+    }
+
+    AbortSignalFollower {
+        signal: signal.clone(),
+        on_abort: Some(on_abort),
+    }
+}
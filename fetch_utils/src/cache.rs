@@ -18,8 +18,64 @@ pub struct CacheOptions {
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct CacheEntry {
+    /// When the entry expires, in milliseconds since the Unix epoch
     pub timestamp: f64,
     pub data: serde_json::Value,
+    /// When the entry was stored, in milliseconds since the Unix epoch
+    pub stored_at: f64,
+    /// The `ETag` the server sent with `data`, sent back as `If-None-Match` to revalidate it
+    pub etag: Option<String>,
+}
+
+impl CacheEntry {
+    /// An entry for `data` stored now that stays valid for `max_age` milliseconds
+    #[must_use]
+    pub fn new(data: serde_json::Value, max_age: f64) -> Self {
+        let stored_at = Date::now();
+
+        Self {
+            timestamp: stored_at + max_age,
+            data,
+            stored_at,
+            etag: None,
+        }
+    }
+
+    /// How long the entry stays valid after `stored_at`, in milliseconds
+    #[must_use]
+    pub fn max_age(&self) -> f64 {
+        self.timestamp - self.stored_at
+    }
+
+    /// Milliseconds elapsed since the entry was stored
+    #[must_use]
+    pub fn age(&self) -> f64 {
+        Date::now() - self.stored_at
+    }
+
+    /// When the entry expires, in milliseconds since the Unix epoch
+    #[must_use]
+    pub fn expires_at(&self) -> f64 {
+        self.timestamp
+    }
+
+    /// Whether the entry is older than `max_age` milliseconds
+    #[must_use]
+    pub fn is_stale(&self, max_age: f64) -> bool {
+        Self::is_stale_since(self.stored_at, max_age)
+    }
+
+    /// Whether data received at `stored_at` is older than `max_age` milliseconds
+    #[must_use]
+    pub fn is_stale_since(stored_at: f64, max_age: f64) -> bool {
+        Date::now() - stored_at >= max_age
+    }
+
+    /// Whether the entry outlived the max age it was stored with
+    #[must_use]
+    pub fn is_expired(&self) -> bool {
+        self.timestamp <= Date::now()
+    }
 }
 
 pub struct Cache {
@@ -42,6 +98,39 @@ pub trait Cacheable {
     fn iter(&self) -> Iter<String, CacheEntry>;
     fn remove(&mut self, key: &str);
     fn clear(&mut self);
+
+    /// Returns the entry stored under `key` even if it already expired
+    fn peek(&self, key: &str) -> Option<&CacheEntry> {
+        self.iter()
+            .find(|(entry_key, _)| entry_key.as_str() == key)
+            .map(|(_, entry)| entry)
+    }
+
+    /// Stores `value` along with the `ETag` the server sent for it
+    fn set_with_etag(
+        &mut self,
+        key: &str,
+        value: &serde_json::Value,
+        max_age: Option<f64>,
+        _etag: Option<&str>,
+    ) {
+        self.set(key, value, max_age);
+    }
+
+    /// Restarts the max age of an entry, e.g. after the server answered `304 Not Modified`.
+    /// Keeps the max age the entry was stored with unless `max_age` is given.
+    fn refresh(&mut self, key: &str, max_age: Option<f64>) {
+        let Some(entry) = self.peek(key).cloned() else {
+            return;
+        };
+
+        self.set_with_etag(
+            key,
+            &entry.data,
+            max_age.or(Some(entry.max_age())),
+            entry.etag.as_deref(),
+        );
+    }
 }
 
 const CACHE_MAX_AGE: f64 = 10.0 * 60.0 * 1000.0; // Ten minutes
@@ -66,23 +155,40 @@ impl Cacheable for Cache {
     }
 
     fn set(&mut self, key: &str, value: &serde_json::Value, max_age: Option<f64>) {
+        // The same data is still described by the `ETag` it was stored with
+        let etag = self
+            .entries
+            .get(key)
+            .filter(|entry| entry.data == *value)
+            .and_then(|entry| entry.etag.clone());
+
+        self.set_with_etag(key, value, max_age, etag.as_deref());
+    }
+
+    fn set_with_etag(
+        &mut self,
+        key: &str,
+        value: &serde_json::Value,
+        max_age: Option<f64>,
+        etag: Option<&str>,
+    ) {
         self.entries.insert(
             key.to_string(),
             CacheEntry {
-                timestamp: Date::now() + max_age.unwrap_or(self.max_age),
-                data: value.clone(),
+                etag: etag.map(ToString::to_string),
+                ..CacheEntry::new(value.clone(), max_age.unwrap_or(self.max_age))
             },
         );
     }
 
-    fn get(&self, key: &str) -> Option<&CacheEntry> {
-        if let Some(cache_entry) = self.entries.get(key) {
-            if cache_entry.timestamp > js_sys::Date::now() {
-                return Some(cache_entry);
-            }
-        }
+    fn peek(&self, key: &str) -> Option<&CacheEntry> {
+        self.entries.get(key)
+    }
 
-        None
+    fn get(&self, key: &str) -> Option<&CacheEntry> {
+        self.entries
+            .get(key)
+            .filter(|cache_entry| !cache_entry.is_expired())
     }
 
     #[must_use]
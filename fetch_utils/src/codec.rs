@@ -0,0 +1,53 @@
+use std::rc::Rc;
+
+/// Turns the JSON values of request and response bodies into text and back.
+///
+/// The typed (de)serialization still goes through `serde`, a codec only controls the wire
+/// format, e.g. to rename keys between `camelCase` and `snake_case` or to parse numbers
+/// another way. `decode` must undo `encode`: bodies revalidated with `304 Not Modified` are
+/// served from the cache by encoding the cached value again.
+pub trait JsonCodec {
+    /// Serializes a request body
+    ///
+    /// # Errors
+    ///
+    /// Returns a message describing why the value can't be serialized.
+    fn encode(&self, value: &serde_json::Value) -> Result<String, String>;
+
+    /// Parses a non-empty response body
+    ///
+    /// # Errors
+    ///
+    /// Returns a message describing why the text can't be parsed.
+    fn decode(&self, text: &str) -> Result<serde_json::Value, String>;
+}
+
+pub type Codec = Rc<dyn JsonCodec>;
+
+/// The default codec, plain `serde_json`
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct SerdeJsonCodec;
+
+impl JsonCodec for SerdeJsonCodec {
+    fn encode(&self, value: &serde_json::Value) -> Result<String, String> {
+        serde_json::to_string(value).map_err(|error| error.to_string())
+    }
+
+    fn decode(&self, text: &str) -> Result<serde_json::Value, String> {
+        serde_json::from_str(text).map_err(|error| error.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serde_json_codec_round_trip() {
+        let value = serde_json::json!({ "id": 1, "tags": ["a", "b"], "draft": null });
+        let text = SerdeJsonCodec.encode(&value).unwrap();
+
+        assert_eq!(SerdeJsonCodec.decode(&text).unwrap(), value);
+        assert!(SerdeJsonCodec.decode("{").is_err());
+    }
+}
@@ -77,3 +77,33 @@ pub fn accordion_content(props: &AccordionContentProps) -> Html {
         </div>
     }
 }
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub enum FetchDebugPosition {
+    #[default]
+    BottomRight,
+    BottomLeft,
+    TopRight,
+    TopLeft,
+}
+
+impl FetchDebugPosition {
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        match self {
+            FetchDebugPosition::BottomRight => "bottom-right",
+            FetchDebugPosition::BottomLeft => "bottom-left",
+            FetchDebugPosition::TopRight => "top-right",
+            FetchDebugPosition::TopLeft => "top-left",
+        }
+    }
+
+    /// The side of the viewport the debug sheet slides in from.
+    #[must_use]
+    pub fn side(&self) -> &str {
+        match self {
+            FetchDebugPosition::BottomRight | FetchDebugPosition::TopRight => "right",
+            FetchDebugPosition::BottomLeft | FetchDebugPosition::TopLeft => "left",
+        }
+    }
+}
@@ -26,6 +26,13 @@ pub enum FetchError {
     JsonError(String),
     #[error("Response deserialization error: {0}")]
     ResponseDeserializationError(String),
+    #[error("Unexpected content type: expected {expected}, got {got}: {body_snippet:?}")]
+    UnexpectedContentType {
+        expected: String,
+        got: String,
+        /// The start of the body, e.g. the title of an HTML error page
+        body_snippet: String,
+    },
     #[error("Unknown error: {0}")]
     UnknownError(String),
 }
@@ -1,9 +1,12 @@
 use crate::{
-    helpers::{build_request, build_url, send_request},
-    FetchError, Middleware,
+    helpers::{
+        build_request, build_url, send_conditional_request, send_request, send_request_for_response,
+    },
+    FetchError, JsonCodec, Middleware,
 };
 use serde::Serialize;
 use std::rc::Rc;
+use yew::Callback;
 
 /// Enum representing supported HTTP methods.
 #[derive(Debug)]
@@ -46,12 +49,30 @@ impl From<&str> for HttpMethod {
     }
 }
 
+/// How much of a response body has been received.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Progress {
+    /// Bytes of the body received so far, as they came off the stream before decoding
+    pub loaded: f64,
+    /// The `Content-Length` of the response, `None` when it's missing or not exposed to CORS
+    pub total: Option<f64>,
+}
+
 pub struct FetchOptions<'a, S, Q, B> {
     pub slugs: S,
     pub query: Q,
     pub body: B,
     pub middlewares: &'a [Middleware],
     pub abort_signal: Rc<web_sys::AbortSignal>,
+    /// Whether cookies and HTTP auth are sent, `None` keeps the browser default (`same-origin`)
+    pub credentials: Option<web_sys::RequestCredentials>,
+    /// The CORS mode of the request, `None` keeps the browser default (`cors`)
+    pub mode: Option<web_sys::RequestMode>,
+    /// Serializes the body, see [`crate::SerdeJsonCodec`] for the default
+    pub codec: &'a dyn JsonCodec,
+    /// Receives the download progress of the response body. `fetch` has no upload progress,
+    /// and [`fetch_response`] leaves the body to the caller, so it never reports any
+    pub on_progress: Option<Callback<Progress>>,
 }
 
 #[allow(clippy::too_many_lines)]
@@ -64,6 +85,8 @@ pub struct FetchOptions<'a, S, Q, B> {
 /// - `query`: Optional query parameters that implement `Serialize`.
 /// - `body`: Optional request body that implements `Serialize`.
 /// - `middlewares`: A vector of middleware functions that can modify the request.
+/// - `credentials`/`mode`: Applied to the request before the middlewares run, so they can still change them.
+/// - `codec`: Serializes the body.
 ///
 /// # Returns
 /// A `Result` containing the deserialized response or a `FetchError` error.
@@ -79,16 +102,69 @@ where
 {
     let url = build_url(url, &options.slugs, &options.query)?;
 
-    let request = build_request(
-        &url,
-        &method,
-        &options.body,
-        options.middlewares,
-        &options.abort_signal,
-    )
-    .await?;
+    let request = build_request(&url, &method, &options).await?;
 
-    let response_text = send_request(&request).await?;
+    let response_text = send_request(&request, options.on_progress.as_ref()).await?;
 
     Ok(response_text)
 }
+
+/// Same as [`fetch`], but resolves to the raw `web_sys::Response` instead of buffering the body.
+///
+/// Useful for streaming responses, see [`crate::read_response_stream`].
+pub async fn fetch_response<'a, S, Q, B>(
+    method: HttpMethod,
+    url: &str,
+    options: FetchOptions<'a, S, Q, B>,
+) -> Result<web_sys::Response, FetchError>
+where
+    S: Serialize + Default + PartialEq,
+    Q: Serialize + Default + PartialEq,
+    B: Serialize + Default + PartialEq,
+{
+    let url = build_url(url, &options.slugs, &options.query)?;
+
+    let request = build_request(&url, &method, &options).await?;
+
+    send_request_for_response(&request).await
+}
+
+/// The outcome of a request sent with `If-None-Match`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConditionalResponse {
+    /// The server answered `304 Not Modified`, the cached body is still current
+    NotModified,
+    /// The server sent a new body, along with its `ETag` if it has one
+    Modified { body: String, etag: Option<String> },
+}
+
+/// Same as [`fetch`], but revalidates a cached body by sending `etag` as `If-None-Match`.
+///
+/// # Errors
+///
+/// Returns a `FetchError` if the request can't be built or sent, or the server answers
+/// with an error status.
+pub async fn fetch_conditional<'a, S, Q, B>(
+    method: HttpMethod,
+    url: &str,
+    options: FetchOptions<'a, S, Q, B>,
+    etag: Option<&str>,
+) -> Result<ConditionalResponse, FetchError>
+where
+    S: Serialize + Default + PartialEq,
+    Q: Serialize + Default + PartialEq,
+    B: Serialize + Default + PartialEq,
+{
+    let url = build_url(url, &options.slugs, &options.query)?;
+
+    let request = build_request(&url, &method, &options).await?;
+
+    if let Some(etag) = etag {
+        request
+            .headers()
+            .set("If-None-Match", etag)
+            .map_err(|error| FetchError::HeaderMutationError(format!("{error:?}")))?;
+    }
+
+    send_conditional_request(&request, options.on_progress.as_ref()).await
+}
@@ -1,12 +1,90 @@
-use crate::{Cacheable, FetchError, HttpMethod, Middleware};
+use crate::{
+    encode_query, Cacheable, ConditionalResponse, FetchError, FetchOptions, HttpMethod, JsonCodec,
+    Progress,
+};
+use js_sys::Uint8Array;
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
 use serde::Serialize;
 use sha1::{Digest, Sha1};
-use std::{any::TypeId, cell::RefCell, rc::Rc};
+use std::{
+    any::TypeId,
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
 use wasm_bindgen::JsCast;
 use wasm_bindgen::JsValue;
 use wasm_bindgen_futures::JsFuture;
-use web_sys::{window, Headers, Request, RequestInit, Response, Url};
+use web_sys::{
+    window, Headers, ReadableStreamDefaultReader, ReadableStreamReadResult, Request,
+    RequestCredentials, RequestInit, RequestMode, Response, TextDecodeOptions, TextDecoder, Url,
+};
+use yew::Callback;
+
+/// The `Accept` header of every request, middlewares can replace it to negotiate another type
+pub const DEFAULT_ACCEPT: &str = "application/json";
+
+/// How much of an unexpected body `FetchError::UnexpectedContentType` keeps
+const BODY_SNIPPET_LENGTH: usize = 200;
+
+/// What slug values get percent-encoded, the same as `encodeURIComponent`
+const SLUG_ENCODE_SET: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'_')
+    .remove(b'.')
+    .remove(b'!')
+    .remove(b'~')
+    .remove(b'*')
+    .remove(b'\'')
+    .remove(b'(')
+    .remove(b')');
+
+/// Serializes `value` into canonical JSON, so equal params always hash the same.
+///
+/// Object keys are sorted, whatever the order of the struct fields or of a `HashMap`, and
+/// floats without a fractional part are written as integers, e.g. `1.0` as `1` and `-0.0` as `0`.
+fn canonical_json<T>(value: &T) -> Result<Vec<u8>, serde_json::Error>
+where
+    T: Serialize + ?Sized,
+{
+    serde_json::to_vec(&canonicalize(serde_json::to_value(value)?))
+}
+
+fn canonicalize(value: serde_json::Value) -> serde_json::Value {
+    match value {
+        serde_json::Value::Object(map) => {
+            let mut entries = map.into_iter().collect::<Vec<_>>();
+            entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+            serde_json::Value::Object(
+                entries
+                    .into_iter()
+                    .map(|(key, value)| (key, canonicalize(value)))
+                    .collect(),
+            )
+        }
+        serde_json::Value::Array(values) => {
+            serde_json::Value::Array(values.into_iter().map(canonicalize).collect())
+        }
+        // Only integers below 2^53 are exact as floats, larger ones are kept as they are
+        serde_json::Value::Number(number) => match number.as_f64() {
+            #[allow(clippy::cast_possible_truncation, clippy::float_cmp)]
+            Some(float)
+                if number.is_f64()
+                    && float.fract() == 0.0
+                    && float.abs() < 9_007_199_254_740_992.0 =>
+            {
+                serde_json::Value::from(float as i64)
+            }
+            _ => serde_json::Value::Number(number),
+        },
+        value => value,
+    }
+}
 
+/// Hashes the request into the key of its cache entry.
+///
+/// The params are serialized canonically (see `canonical_json`), so two equal params yield the
+/// same key regardless of field or map order.
 pub fn generate_cache_key<S, Q, B>(
     method: &HttpMethod,
     url: &str,
@@ -23,21 +101,22 @@ where
     hasher.update(method.as_str().as_bytes());
     hasher.update(url.as_bytes());
 
-    let slugs_bytes = serde_json::to_vec(slugs)
+    let slugs_bytes = canonical_json(slugs)
         .map_err(|error| FetchError::SlugsSerializationError(error.to_string()))?;
     hasher.update(&slugs_bytes);
 
-    let query_bytes = serde_json::to_vec(query)
+    let query_bytes = canonical_json(query)
         .map_err(|error| FetchError::QuerySerializationError(error.to_string()))?;
     hasher.update(&query_bytes);
 
-    let body_bytes = serde_json::to_vec(body)
+    let body_bytes = canonical_json(body)
         .map_err(|error| FetchError::BodySerializationError(error.to_string()))?;
     hasher.update(&body_bytes);
 
     Ok(format!("{:x}", hasher.finalize()))
 }
 
+/// Like `generate_cache_key`, for the state shared by the hooks of a socket, which has no body.
 pub fn generate_state_key<S, Q>(
     prefix: &str,
     url: &str,
@@ -52,72 +131,126 @@ where
     hasher.update(prefix.as_bytes());
     hasher.update(url.as_bytes());
 
-    let slugs_bytes = serde_json::to_vec(slugs)
+    let slugs_bytes = canonical_json(slugs)
         .map_err(|error| FetchError::SlugsSerializationError(error.to_string()))?;
     hasher.update(&slugs_bytes);
 
-    let query_bytes = serde_json::to_vec(query)
+    let query_bytes = canonical_json(query)
         .map_err(|error| FetchError::QuerySerializationError(error.to_string()))?;
     hasher.update(&query_bytes);
 
     Ok(format!("{:x}", hasher.finalize()))
 }
 
-pub fn build_url<S, Q>(url: &str, slugs: &S, query: &Q) -> Result<Url, FetchError>
+/// Hashes the headers, credentials and mode set for a single call into a part of its cache key.
+///
+/// Header names are compared case-insensitively and their order doesn't matter. Returns `None`
+/// without any override, so the key of a plain call doesn't change.
+#[must_use]
+pub fn generate_overrides_key(
+    headers: &[(String, String)],
+    credentials: Option<RequestCredentials>,
+    mode: Option<RequestMode>,
+) -> Option<String> {
+    if headers.is_empty() && credentials.is_none() && mode.is_none() {
+        return None;
+    }
+
+    let mut headers = headers
+        .iter()
+        .map(|(name, value)| (name.to_ascii_lowercase(), value.as_str()))
+        .collect::<Vec<_>>();
+
+    headers.sort_unstable();
+
+    let mut hasher = Sha1::new();
+
+    for (name, value) in headers {
+        hasher.update(name.as_bytes());
+        hasher.update(b":");
+        hasher.update(value.as_bytes());
+        hasher.update(b"\n");
+    }
+
+    hasher.update(format!("{credentials:?}:{mode:?}").as_bytes());
+
+    Some(format!("{:x}", hasher.finalize()))
+}
+
+/// Replaces the `{key}` placeholders of `url` with the slug values, percent-encoded so a value
+/// holding e.g. a `/` or a space stays a single path segment.
+fn substitute_slugs<S>(url: &str, slugs: &S) -> Result<String, FetchError>
 where
-    S: Serialize + Default + PartialEq,
-    Q: Serialize + Default + PartialEq,
+    S: Serialize,
 {
-    let url = {
-        let mut url = url.to_string();
+    let mut url = url.to_string();
 
-        // Serialize path parameters to a map
-        let path_params_map = serde_json::to_value(slugs)
-            .map_err(|error| FetchError::SlugsSerializationError(error.to_string()))?;
+    // Serialize path parameters to a map
+    let path_params_map = serde_json::to_value(slugs)
+        .map_err(|error| FetchError::SlugsSerializationError(error.to_string()))?;
 
-        if let serde_json::Value::Object(map) = path_params_map {
-            for (key, value) in &map {
-                let placeholder = format!("{{{key}}}");
+    if let serde_json::Value::Object(map) = path_params_map {
+        for (key, value) in &map {
+            let placeholder = format!("{{{key}}}");
 
-                let value_str = match value {
-                    serde_json::Value::String(s) => s.clone(),
-                    _ => value.to_string(),
-                };
+            let value_str = match value {
+                serde_json::Value::String(s) => s.clone(),
+                _ => value.to_string(),
+            };
 
-                url = url.replace(&placeholder, &value_str);
-            }
+            let value_str = utf8_percent_encode(&value_str, SLUG_ENCODE_SET).to_string();
+            url = url.replace(&placeholder, &value_str);
         }
+    }
 
-        url
-    };
+    Ok(url)
+}
+
+pub fn build_url<S, Q>(url: &str, slugs: &S, query: &Q) -> Result<Url, FetchError>
+where
+    S: Serialize + Default + PartialEq,
+    Q: Serialize + Default + PartialEq,
+{
+    let url = substitute_slugs(url, slugs)?;
 
     let url = Url::new(&url).map_err(|_| FetchError::UrlParsingError("Invalid URL".to_string()))?;
 
     if *query != Q::default() {
-        let query_string = serde_urlencoded::to_string(query)
-            .map_err(|error| FetchError::QuerySerializationError(error.to_string()))?;
-
-        url.set_search(&query_string);
+        url.set_search(&encode_query(query)?);
     }
 
     Ok(url)
 }
 
-pub async fn build_request<T>(
+/// Builds the request for `url`, applying the `credentials` and `mode` of `options` before the
+/// middlewares run.
+///
+/// The body is serialized with the `codec` of `options`, its `slugs` and `query` are expected to
+/// be part of `url` already, see [`build_url`]. In `no-cors` mode the browser silently drops every
+/// header that isn't CORS-safelisted, including the JSON `Content-Type` and whatever the
+/// middlewares set, e.g. `Authorization`.
+pub async fn build_request<S, Q, B>(
     url: &Url,
     method: &HttpMethod,
-    body: &T,
-    middlewares: &[Middleware],
-    abort_signal: &web_sys::AbortSignal,
+    options: &FetchOptions<'_, S, Q, B>,
 ) -> Result<Request, FetchError>
 where
-    T: Serialize + Default + PartialEq,
+    B: Serialize + Default + PartialEq,
 {
     let request_init = Rc::new(RefCell::new(RequestInit::new()));
 
     {
         let request_init = request_init.borrow_mut();
         request_init.set_method(method.as_str());
+
+        // Unset values keep the browser defaults, middlewares see these and may replace them
+        if let Some(credentials) = options.credentials {
+            request_init.set_credentials(credentials);
+        }
+
+        if let Some(mode) = options.mode {
+            request_init.set_mode(mode);
+        }
     }
 
     // Initialize headers
@@ -126,10 +259,18 @@ where
 
     let headers = Rc::new(RefCell::new(headers));
 
+    // Middlewares may negotiate another type, the response is only checked against JSON
+    headers
+        .borrow_mut()
+        .set("Accept", DEFAULT_ACCEPT)
+        .map_err(|error| FetchError::HeaderMutationError(format!("{error:?}")))?;
+
     // Set the request body and headers
-    if *body != T::default() {
-        let body_str = serde_json::to_string(&body)
-            .map_err(|error| FetchError::BodySerializationError(error.to_string()))?;
+    if options.body != B::default() {
+        let body_str = serde_json::to_value(&options.body)
+            .map_err(|error| error.to_string())
+            .and_then(|value| options.codec.encode(&value))
+            .map_err(FetchError::BodySerializationError)?;
 
         {
             let request_init = request_init.borrow_mut();
@@ -144,7 +285,8 @@ where
     }
 
     // Apply middlewares
-    let middleware_futures: Vec<_> = middlewares
+    let middleware_futures: Vec<_> = options
+        .middlewares
         .iter()
         .map(|middleware| middleware(request_init.clone(), headers.clone()))
         .collect();
@@ -157,7 +299,7 @@ where
     {
         let request_init = request_init.borrow_mut();
         request_init.set_headers(&headers.borrow());
-        request_init.set_signal(Some(abort_signal));
+        request_init.set_signal(Some(&options.abort_signal));
     }
 
     let request = Request::new_with_str_and_init(
@@ -169,7 +311,7 @@ where
     Ok(request)
 }
 
-pub async fn send_request(request: &Request) -> Result<String, FetchError> {
+async fn dispatch_request(request: &Request) -> Result<Response, FetchError> {
     // Perform the fetch operation
     let window = window().ok_or_else(FetchError::WindowNotFound)?;
 
@@ -178,10 +320,12 @@ pub async fn send_request(request: &Request) -> Result<String, FetchError> {
         .map_err(|error| FetchError::NetworkError(format!("Failed to fetch: {error:?}")))?;
 
     // Convert the response to a `Response` object
-    let response: Response = response_js_value
+    response_js_value
         .dyn_into()
-        .map_err(|error| FetchError::InvalidResponse(format!("Response error: {error:?}")))?;
+        .map_err(|error| FetchError::InvalidResponse(format!("Response error: {error:?}")))
+}
 
+fn ensure_ok(response: &Response) -> Result<(), FetchError> {
     // Check if the response isn't OK (OK is a status in the range 200-299)
     if !response.ok() {
         return Err(FetchError::HttpError(format!(
@@ -191,6 +335,57 @@ pub async fn send_request(request: &Request) -> Result<String, FetchError> {
         )));
     }
 
+    Ok(())
+}
+
+fn is_json(content_type: &str) -> bool {
+    let mime = content_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase();
+
+    mime == "application/json" || mime.ends_with("+json")
+}
+
+/// Fails when a request that accepts JSON receives a body of another type, e.g. an HTML error page.
+///
+/// Empty bodies and responses without a `Content-Type` pass, the deserializer deals with them.
+fn ensure_expected_content_type(
+    request: &Request,
+    response: &Response,
+    body: &str,
+) -> Result<(), FetchError> {
+    let expected = request
+        .headers()
+        .get("Accept")
+        .ok()
+        .flatten()
+        .unwrap_or_default();
+
+    if !is_json(&expected) || body.trim().is_empty() {
+        return Ok(());
+    }
+
+    match response.headers().get("Content-Type").ok().flatten() {
+        Some(got) if !is_json(&got) => Err(FetchError::UnexpectedContentType {
+            expected,
+            got,
+            body_snippet: body.chars().take(BODY_SNIPPET_LENGTH).collect(),
+        }),
+        _ => Ok(()),
+    }
+}
+
+async fn read_response_text(
+    response: &Response,
+    on_progress: Option<&Callback<Progress>>,
+) -> Result<String, FetchError> {
+    if let Some(on_progress) = on_progress {
+        return read_response_text_with_progress(response, on_progress).await;
+    }
+
     // Parse the response body
     let response_text = JsFuture::from(
         response
@@ -203,10 +398,173 @@ pub async fn send_request(request: &Request) -> Result<String, FetchError> {
     Ok(response_text.as_string().unwrap_or_default())
 }
 
+/// Reads the body through its stream, reporting the received bytes after every chunk.
+///
+/// Browsers decompress the stream, so with a compressed response `loaded` ends up above the
+/// `Content-Length` it's compared to.
+async fn read_response_text_with_progress(
+    response: &Response,
+    on_progress: &Callback<Progress>,
+) -> Result<String, FetchError> {
+    let total = response
+        .headers()
+        .get("Content-Length")
+        .ok()
+        .flatten()
+        .and_then(|length| length.parse::<f64>().ok());
+
+    let body = RefCell::new(String::new());
+    let loaded = Cell::new(0.0);
+
+    on_progress.emit(Progress { loaded: 0.0, total });
+
+    // Counts the bytes as received, the decoded text may be shorter or longer
+    read_response_chunks(response, |chunk, length| {
+        body.borrow_mut().push_str(&chunk);
+        loaded.set(loaded.get() + length as f64);
+
+        on_progress.emit(Progress {
+            loaded: loaded.get(),
+            total,
+        });
+    })
+    .await?;
+
+    Ok(body.into_inner())
+}
+
+pub async fn send_request_for_response(request: &Request) -> Result<Response, FetchError> {
+    let response = dispatch_request(request).await?;
+    ensure_ok(&response)?;
+
+    Ok(response)
+}
+
+pub async fn send_request(
+    request: &Request,
+    on_progress: Option<&Callback<Progress>>,
+) -> Result<String, FetchError> {
+    let response = send_request_for_response(request).await?;
+    let body = read_response_text(&response, on_progress).await?;
+
+    ensure_expected_content_type(request, &response, &body)?;
+    Ok(body)
+}
+
+/// Like [`send_request`], but treats `304 Not Modified` as a success and keeps the `ETag`.
+///
+/// Cross-origin responses only expose the `ETag` when the server lists it in
+/// `Access-Control-Expose-Headers`.
+pub async fn send_conditional_request(
+    request: &Request,
+    on_progress: Option<&Callback<Progress>>,
+) -> Result<ConditionalResponse, FetchError> {
+    let response = dispatch_request(request).await?;
+
+    if response.status() == 304 {
+        return Ok(ConditionalResponse::NotModified);
+    }
+
+    ensure_ok(&response)?;
+
+    let etag = response.headers().get("ETag").ok().flatten();
+    let body = read_response_text(&response, on_progress).await?;
+
+    ensure_expected_content_type(request, &response, &body)?;
+    Ok(ConditionalResponse::Modified { body, etag })
+}
+
+/// Reads the response body chunk by chunk, decoding each one as UTF-8 text.
+///
+/// Multi-byte characters split between chunks are kept by the decoder until the next chunk arrives.
+pub async fn read_response_stream(
+    response: &Response,
+    on_chunk: impl Fn(String),
+) -> Result<(), FetchError> {
+    read_response_chunks(response, |text, _length| {
+        if !text.is_empty() {
+            on_chunk(text);
+        }
+    })
+    .await
+}
+
+/// Same as [`read_response_stream`], but passes the byte length of every chunk along with its
+/// decoded text, which is empty when the chunk only holds part of a character.
+async fn read_response_chunks(
+    response: &Response,
+    on_chunk: impl Fn(String, usize),
+) -> Result<(), FetchError> {
+    let Some(body) = response.body() else {
+        return Ok(());
+    };
+
+    let reader: ReadableStreamDefaultReader = body
+        .get_reader()
+        .dyn_into()
+        .map_err(|error| FetchError::InvalidResponse(format!("Stream reader error: {error:?}")))?;
+
+    let decoder = TextDecoder::new()
+        .map_err(|error| FetchError::UnknownError(format!("Text decoder error: {error:?}")))?;
+
+    let decode_options = TextDecodeOptions::new();
+    decode_options.set_stream(true);
+
+    loop {
+        let result: ReadableStreamReadResult = JsFuture::from(reader.read())
+            .await
+            .map_err(|error| FetchError::NetworkError(format!("Failed to read: {error:?}")))?
+            .unchecked_into();
+
+        if result.get_done().unwrap_or(true) {
+            break;
+        }
+
+        let chunk = Uint8Array::new(&result.get_value()).to_vec();
+
+        let text = decoder
+            .decode_with_u8_array_and_options(&chunk, &decode_options)
+            .map_err(|error| FetchError::InvalidResponse(format!("Decode error: {error:?}")))?;
+
+        on_chunk(text, chunk.len());
+    }
+
+    let rest = decoder
+        .decode()
+        .map_err(|error| FetchError::InvalidResponse(format!("Decode error: {error:?}")))?;
+
+    if !rest.is_empty() {
+        on_chunk(rest, 0);
+    }
+
+    Ok(())
+}
+
+/// Deserializes an empty body (e.g. `204 No Content`) as if it was `null`,
+/// so `()` and `Option<T>` succeed. `String` gets an empty string.
+fn deserialize_empty_body<R>() -> Result<R, FetchError>
+where
+    R: for<'de> serde::Deserialize<'de> + 'static,
+{
+    let value = if TypeId::of::<R>() == TypeId::of::<String>() {
+        serde_json::Value::String(String::new())
+    } else {
+        serde_json::Value::Null
+    };
+
+    serde_json::from_value::<R>(value).map_err(|error| {
+        FetchError::ResponseDeserializationError(format!("empty body --- {error:?}"))
+    })
+}
+
 pub fn deserialize_cached_data<R>(data: &serde_json::Value) -> Result<R, FetchError>
 where
-    R: for<'de> serde::Deserialize<'de>,
+    R: for<'de> serde::Deserialize<'de> + 'static,
 {
+    if data.is_null() {
+        return deserialize_empty_body();
+    }
+
     let result = serde_json::from_value::<R>(data.clone()).map_err(|error| {
         FetchError::ResponseDeserializationError(format!("{data:?} --- {error:?}"))
     })?;
@@ -218,12 +576,8 @@ pub fn deserialize_response<R>(response_text: &str) -> Result<R, FetchError>
 where
     R: for<'de> serde::Deserialize<'de> + 'static,
 {
-    if response_text.trim().is_empty()
-        && std::any::TypeId::of::<R>() == std::any::TypeId::of::<String>()
-    {
-        return serde_json::from_str("\"\"").map_err(|error| {
-            FetchError::ResponseDeserializationError(format!("{response_text:?} --- {error:?}"))
-        });
+    if response_text.trim().is_empty() {
+        return deserialize_empty_body();
     }
 
     let value = serde_json::from_str(response_text).map_err(|error| {
@@ -233,8 +587,10 @@ where
     Ok(value)
 }
 
+/// Decodes the response with `codec` and caches the decoded value under `cache_key`.
 pub fn deserialize_response_and_store_cache<R>(
     response_text: &str,
+    codec: &dyn JsonCodec,
     cache: &Rc<RefCell<dyn Cacheable>>,
     cache_key: &str,
     max_age: Option<f64>,
@@ -242,13 +598,16 @@ pub fn deserialize_response_and_store_cache<R>(
 where
     R: for<'de> serde::Deserialize<'de> + Default + 'static,
 {
-    if response_text.trim().is_empty()
-        && (TypeId::of::<R>() == TypeId::of::<String>() || TypeId::of::<R>() == TypeId::of::<()>())
-    {
-        return Ok(R::default());
+    if response_text.trim().is_empty() {
+        let result = deserialize_empty_body()?;
+        cache
+            .borrow_mut()
+            .set(cache_key, &serde_json::Value::Null, max_age);
+
+        return Ok(result);
     }
 
-    let value = serde_json::from_str(response_text).map_err(|error| {
+    let value = codec.decode(response_text).map_err(|error| {
         FetchError::ResponseDeserializationError(format!("{response_text:?} --- {error:?}"))
     })?;
 
@@ -261,3 +620,134 @@ where
 
     Ok(result)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::{BTreeMap, HashMap};
+
+    #[derive(Serialize)]
+    struct Filters {
+        page: f64,
+        tags: HashMap<String, u32>,
+    }
+
+    #[derive(Serialize)]
+    struct ReorderedFilters {
+        tags: BTreeMap<String, u32>,
+        page: u32,
+    }
+
+    #[test]
+    fn test_cache_key_ignores_field_and_map_order() {
+        let mut tags = HashMap::new();
+
+        for (index, tag) in ["rust", "yew", "wasm", "web", "ui", "fetch"]
+            .iter()
+            .enumerate()
+        {
+            tags.insert((*tag).to_string(), index as u32);
+        }
+
+        let filters = Filters {
+            page: 2.0,
+            tags: tags.clone(),
+        };
+
+        let reordered = ReorderedFilters {
+            tags: tags.into_iter().collect(),
+            page: 2,
+        };
+
+        let key = generate_cache_key(&HttpMethod::GET, "/posts", &(), &filters, &()).unwrap();
+
+        assert_eq!(
+            key,
+            generate_cache_key(&HttpMethod::GET, "/posts", &(), &reordered, &()).unwrap()
+        );
+        assert_eq!(
+            generate_state_key("ws", "/posts", &filters, &()).unwrap(),
+            generate_state_key("ws", "/posts", &reordered, &()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_cache_key_tells_different_params_apart() {
+        let first = generate_cache_key(&HttpMethod::GET, "/posts", &(), &[1.5], &()).unwrap();
+        let second = generate_cache_key(&HttpMethod::GET, "/posts", &(), &[1], &()).unwrap();
+
+        assert_ne!(first, second);
+
+        // The same params sent with another method are another request
+        assert_ne!(
+            generate_cache_key(&HttpMethod::GET, "/posts", &(), &(), &()).unwrap(),
+            generate_cache_key(&HttpMethod::POST, "/posts", &(), &(), &()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_overrides_key_ignores_header_order_and_case() {
+        let headers = vec![
+            ("X-Tenant".to_string(), "yewlish".to_string()),
+            ("Authorization".to_string(), "Bearer token".to_string()),
+        ];
+
+        let reordered = vec![
+            ("authorization".to_string(), "Bearer token".to_string()),
+            ("x-tenant".to_string(), "yewlish".to_string()),
+        ];
+
+        assert_eq!(generate_overrides_key(&[], None, None), None);
+        assert_eq!(
+            generate_overrides_key(&headers, None, None),
+            generate_overrides_key(&reordered, None, None)
+        );
+
+        // Another value, credentials or mode is another request
+        assert_ne!(
+            generate_overrides_key(&headers, None, None),
+            generate_overrides_key(&headers[..1], None, None)
+        );
+        assert_ne!(
+            generate_overrides_key(&[], Some(RequestCredentials::Include), None),
+            generate_overrides_key(&[], None, Some(RequestMode::Cors))
+        );
+    }
+
+    #[test]
+    fn test_substitute_slugs_percent_encodes_values() {
+        #[derive(Serialize)]
+        struct Slugs {
+            id: u32,
+            path: String,
+        }
+
+        let slugs = Slugs {
+            id: 1,
+            path: "docs/my file.txt".to_string(),
+        };
+
+        assert_eq!(
+            substitute_slugs("https://example.com/posts/{id}/files/{path}", &slugs).unwrap(),
+            "https://example.com/posts/1/files/docs%2Fmy%20file.txt"
+        );
+
+        // Characters `encodeURIComponent` keeps are left as they are
+        let slugs = Slugs {
+            id: 2,
+            path: "a-b_c.d~(e)!".to_string(),
+        };
+
+        assert_eq!(
+            substitute_slugs("/posts/{id}/{path}", &slugs).unwrap(),
+            "/posts/2/a-b_c.d~(e)!"
+        );
+    }
+
+    #[test]
+    fn test_canonical_json_normalizes_numbers() {
+        assert_eq!(canonical_json(&1.0).unwrap(), b"1");
+        assert_eq!(canonical_json(&-0.0).unwrap(), b"0");
+        assert_eq!(canonical_json(&0.25).unwrap(), b"0.25");
+    }
+}
@@ -0,0 +1,204 @@
+use crate::FetchError;
+use futures::{
+    channel::oneshot,
+    future::{select, Either, FutureExt, LocalBoxFuture, Shared},
+};
+use std::{
+    cell::{Cell, RefCell},
+    collections::HashMap,
+    future::Future,
+    rc::Rc,
+};
+use wasm_bindgen::{closure::Closure, JsCast};
+use web_sys::{AbortController, AbortSignal};
+
+pub type InFlightRequest = Shared<LocalBoxFuture<'static, Result<String, FetchError>>>;
+
+/// A pending request together with the controller it owns and the number of callers awaiting it.
+#[derive(Clone)]
+struct InFlightEntry {
+    request: InFlightRequest,
+    controller: Rc<AbortController>,
+    subscribers: Rc<Cell<usize>>,
+}
+
+/// Requests that are currently pending, keyed by their cache key.
+///
+/// Used by the generated hooks to share a single network request between hooks
+/// that ask for the same endpoint with the same params at the same time.
+#[derive(Default, Clone)]
+pub struct InFlightRequests {
+    requests: Rc<RefCell<HashMap<String, InFlightEntry>>>,
+}
+
+/// Keeps a caller subscribed to a shared request, the last one to leave aborts it.
+struct Subscription {
+    requests: Rc<RefCell<HashMap<String, InFlightEntry>>>,
+    key: String,
+    entry: InFlightEntry,
+}
+
+impl Drop for Subscription {
+    fn drop(&mut self) {
+        let subscribers = self.entry.subscribers.get() - 1;
+        self.entry.subscribers.set(subscribers);
+
+        if subscribers > 0 {
+            return;
+        }
+
+        let mut requests = self.requests.borrow_mut();
+
+        if requests
+            .get(&self.key)
+            .is_some_and(|entry| Rc::ptr_eq(&entry.subscribers, &self.entry.subscribers))
+        {
+            requests.remove(&self.key);
+        }
+
+        // A no-op once the request settled
+        self.entry.controller.abort();
+    }
+}
+
+/// Wakes the caller when its abort signal fires, the listener goes away with it.
+struct AbortListener {
+    signal: AbortSignal,
+    on_abort: Closure<dyn FnMut()>,
+}
+
+impl AbortListener {
+    fn new(signal: &AbortSignal, sender: oneshot::Sender<()>) -> Self {
+        let sender = RefCell::new(Some(sender));
+
+        let on_abort = Closure::<dyn FnMut()>::new(move || {
+            if let Some(sender) = sender.borrow_mut().take() {
+                let _ = sender.send(());
+            }
+        });
+
+        if let Err(error) =
+            signal.add_event_listener_with_callback("abort", on_abort.as_ref().unchecked_ref())
+        {
+            log::error!("Failed to follow the abort signal: {error:?}");
+        }
+
+        Self {
+            signal: signal.clone(),
+            on_abort,
+        }
+    }
+}
+
+impl Drop for AbortListener {
+    fn drop(&mut self) {
+        if let Err(error) = self
+            .signal
+            .remove_event_listener_with_callback("abort", self.on_abort.as_ref().unchecked_ref())
+        {
+            log::error!("Failed to stop following the abort signal: {error:?}");
+        }
+    }
+}
+
+impl InFlightRequests {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn contains(&self, key: &str) -> bool {
+        self.requests.borrow().contains_key(key)
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.requests.borrow().len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.requests.borrow().is_empty()
+    }
+
+    /// Awaits the pending request stored under `key`, or starts a new one with `request`
+    /// and shares it with every caller that arrives before it settles.
+    ///
+    /// The shared request runs with its own abort signal, passed to `request`. Aborting
+    /// `abort_signal` only stops this caller from waiting, the request itself is aborted
+    /// once every caller has left.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error produced by the shared request, or a network error when
+    /// `abort_signal` aborts first.
+    pub async fn dedupe<F>(
+        &self,
+        key: &str,
+        abort_signal: &AbortSignal,
+        request: impl FnOnce(Rc<AbortSignal>) -> F,
+    ) -> Result<String, FetchError>
+    where
+        F: Future<Output = Result<String, FetchError>> + 'static,
+    {
+        if abort_signal.aborted() {
+            return Err(aborted_error());
+        }
+
+        let pending = self.requests.borrow().get(key).cloned();
+
+        let entry = if let Some(pending) = pending {
+            pending
+        } else {
+            let controller = AbortController::new()
+                .map_err(|error| FetchError::UnknownError(format!("{error:?}")))?;
+
+            let controller = Rc::new(controller);
+
+            let entry = InFlightEntry {
+                request: request(Rc::new(controller.signal())).boxed_local().shared(),
+                controller,
+                subscribers: Rc::new(Cell::new(0)),
+            };
+
+            self.requests
+                .borrow_mut()
+                .insert(key.to_string(), entry.clone());
+
+            entry
+        };
+
+        entry.subscribers.set(entry.subscribers.get() + 1);
+
+        let _subscription = Subscription {
+            requests: self.requests.clone(),
+            key: key.to_string(),
+            entry: entry.clone(),
+        };
+
+        let (sender, receiver) = oneshot::channel::<()>();
+        let _listener = AbortListener::new(abort_signal, sender);
+
+        match select(entry.request.clone(), receiver).await {
+            Either::Left((result, _)) => {
+                let mut requests = self.requests.borrow_mut();
+
+                // Callers that arrive after this point start a new request
+                if requests
+                    .get(key)
+                    .is_some_and(|pending| Rc::ptr_eq(&pending.subscribers, &entry.subscribers))
+                {
+                    requests.remove(key);
+                }
+
+                result
+            }
+            Either::Right(_) => Err(aborted_error()),
+        }
+    }
+}
+
+fn aborted_error() -> FetchError {
+    FetchError::NetworkError("The request was aborted".to_string())
+}
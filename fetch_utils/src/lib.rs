@@ -1,9 +1,13 @@
+mod abort_registry;
 mod cache;
+mod codec;
 mod components;
 mod error;
 mod fetch;
 mod helpers;
+mod in_flight;
 mod middleware;
+mod query;
 mod signal;
 mod slotmap;
 mod web_socket;
@@ -14,12 +18,16 @@ pub extern crate serde_json;
 pub extern crate wasm_bindgen;
 pub extern crate web_sys;
 
+pub use abort_registry::*;
 pub use cache::*;
+pub use codec::*;
 pub use components::*;
 pub use error::*;
 pub use fetch::*;
 pub use helpers::*;
+pub use in_flight::*;
 pub use middleware::*;
+pub use query::*;
 pub use signal::*;
 pub use slotmap::*;
 pub use web_socket::*;
@@ -4,3 +4,18 @@ use web_sys::{Headers, RequestInit};
 pub type MiddlewareFuture = Pin<Box<dyn Future<Output = ()>>>;
 pub type Middleware =
     Rc<dyn Fn(Rc<RefCell<RequestInit>>, Rc<RefCell<Headers>>) -> MiddlewareFuture>;
+
+/// Sets the `Accept-Language` of the request to `locale`, e.g. `"fr-CH"` or `"en;q=0.8"`.
+///
+/// An invalid header value is skipped, the way the browser would reject it.
+pub fn accept_language_middleware(locale: &str) -> Middleware {
+    let locale = Rc::new(locale.to_string());
+
+    Rc::new(move |_request_init, headers| {
+        let locale = locale.clone();
+
+        Box::pin(async move {
+            let _ = (*headers).borrow_mut().set("Accept-Language", &locale);
+        })
+    })
+}
@@ -0,0 +1,708 @@
+use crate::FetchError;
+use serde::ser::{self, Impossible, Serialize};
+use std::fmt::{Display, Formatter};
+use url::form_urlencoded;
+
+type Pairs = form_urlencoded::Serializer<'static, String>;
+
+/// Encodes a query struct or map into an `application/x-www-form-urlencoded` string.
+///
+/// Fields are written in declaration order with the following rules:
+/// - `None` and unit values are omitted
+/// - `Vec`s, slices and tuples repeat the key, `ids: vec![1, 2]` becomes `ids=1&ids=2`, an empty one is omitted
+/// - booleans become `true`/`false`, unit enum variants their (serde-renamed) name
+/// - nested structs, maps and sequences of sequences are rejected, as there is no agreed encoding for them
+///
+/// # Errors
+///
+/// Returns `FetchError::QuerySerializationError` when the query isn't a struct or a map, or holds a nested value.
+pub fn encode_query<Q>(query: &Q) -> Result<String, FetchError>
+where
+    Q: Serialize + ?Sized,
+{
+    let mut pairs = Pairs::new(String::new());
+
+    query
+        .serialize(QuerySerializer { pairs: &mut pairs })
+        .map_err(|error| FetchError::QuerySerializationError(error.to_string()))?;
+
+    Ok(pairs.finish())
+}
+
+#[derive(Debug)]
+struct QueryError(String);
+
+impl Display for QueryError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for QueryError {}
+
+impl ser::Error for QueryError {
+    fn custom<T: Display>(msg: T) -> Self {
+        QueryError(msg.to_string())
+    }
+}
+
+fn unsupported<T>(what: &str) -> Result<T, QueryError> {
+    Err(QueryError(format!("{what} can't be encoded in a query")))
+}
+
+/// Serializes the top level of the query, which has to be a struct or a map
+struct QuerySerializer<'a> {
+    pairs: &'a mut Pairs,
+}
+
+macro_rules! reject {
+    ($($method:ident($($ty:ty),*)),* $(,)?) => {
+        $(
+            fn $method(self, $(_: $ty),*) -> Result<Self::Ok, Self::Error> {
+                unsupported(stringify!($method))
+            }
+        )*
+    };
+}
+
+impl<'a> ser::Serializer for QuerySerializer<'a> {
+    type Ok = ();
+    type Error = QueryError;
+    type SerializeSeq = Impossible<(), QueryError>;
+    type SerializeTuple = Impossible<(), QueryError>;
+    type SerializeTupleStruct = Impossible<(), QueryError>;
+    type SerializeTupleVariant = Impossible<(), QueryError>;
+    type SerializeMap = MapSerializer<'a>;
+    type SerializeStruct = MapSerializer<'a>;
+    type SerializeStructVariant = Impossible<(), QueryError>;
+
+    reject! {
+        serialize_bool(bool),
+        serialize_i8(i8),
+        serialize_i16(i16),
+        serialize_i32(i32),
+        serialize_i64(i64),
+        serialize_u8(u8),
+        serialize_u16(u16),
+        serialize_u32(u32),
+        serialize_u64(u64),
+        serialize_f32(f32),
+        serialize_f64(f64),
+        serialize_char(char),
+        serialize_str(&str),
+        serialize_bytes(&[u8]),
+        serialize_unit_variant(&'static str, u32, &'static str),
+    }
+
+    fn serialize_none(self) -> Result<(), QueryError> {
+        Ok(())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<(), QueryError> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<(), QueryError> {
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<(), QueryError> {
+        Ok(())
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<(), QueryError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<(), QueryError> {
+        unsupported("an enum variant")
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, QueryError> {
+        unsupported("a top-level sequence")
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, QueryError> {
+        unsupported("a top-level tuple")
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, QueryError> {
+        unsupported("a top-level tuple struct")
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, QueryError> {
+        unsupported("an enum variant")
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, QueryError> {
+        Ok(MapSerializer {
+            pairs: self.pairs,
+            key: None,
+        })
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, QueryError> {
+        Ok(MapSerializer {
+            pairs: self.pairs,
+            key: None,
+        })
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, QueryError> {
+        unsupported("an enum variant")
+    }
+}
+
+struct MapSerializer<'a> {
+    pairs: &'a mut Pairs,
+    key: Option<String>,
+}
+
+impl ser::SerializeMap for MapSerializer<'_> {
+    type Ok = ();
+    type Error = QueryError;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), QueryError> {
+        match key.serialize(ScalarSerializer)? {
+            Some(key) => {
+                self.key = Some(key);
+                Ok(())
+            }
+            None => unsupported("an empty key"),
+        }
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), QueryError> {
+        let key = self
+            .key
+            .take()
+            .ok_or_else(|| QueryError("a value was serialized before its key".to_string()))?;
+
+        value.serialize(FieldSerializer {
+            pairs: self.pairs,
+            key: &key,
+        })
+    }
+
+    fn end(self) -> Result<(), QueryError> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeStruct for MapSerializer<'_> {
+    type Ok = ();
+    type Error = QueryError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), QueryError> {
+        value.serialize(FieldSerializer {
+            pairs: self.pairs,
+            key,
+        })
+    }
+
+    fn end(self) -> Result<(), QueryError> {
+        Ok(())
+    }
+}
+
+/// Serializes the value of a single field, repeating the key for sequences
+struct FieldSerializer<'a> {
+    pairs: &'a mut Pairs,
+    key: &'a str,
+}
+
+impl FieldSerializer<'_> {
+    fn append<T: Serialize + ?Sized>(self, value: &T) -> Result<(), QueryError> {
+        if let Some(value) = value.serialize(ScalarSerializer)? {
+            self.pairs.append_pair(self.key, &value);
+        }
+
+        Ok(())
+    }
+}
+
+macro_rules! forward_to_scalar {
+    ($($method:ident($ty:ty)),* $(,)?) => {
+        $(
+            fn $method(self, value: $ty) -> Result<Self::Ok, Self::Error> {
+                self.append(&value)
+            }
+        )*
+    };
+}
+
+impl<'a> ser::Serializer for FieldSerializer<'a> {
+    type Ok = ();
+    type Error = QueryError;
+    type SerializeSeq = SeqSerializer<'a>;
+    type SerializeTuple = SeqSerializer<'a>;
+    type SerializeTupleStruct = SeqSerializer<'a>;
+    type SerializeTupleVariant = Impossible<(), QueryError>;
+    type SerializeMap = Impossible<(), QueryError>;
+    type SerializeStruct = Impossible<(), QueryError>;
+    type SerializeStructVariant = Impossible<(), QueryError>;
+
+    forward_to_scalar! {
+        serialize_bool(bool),
+        serialize_i8(i8),
+        serialize_i16(i16),
+        serialize_i32(i32),
+        serialize_i64(i64),
+        serialize_i128(i128),
+        serialize_u8(u8),
+        serialize_u16(u16),
+        serialize_u32(u32),
+        serialize_u64(u64),
+        serialize_u128(u128),
+        serialize_f32(f32),
+        serialize_f64(f64),
+        serialize_char(char),
+        serialize_str(&str),
+    }
+
+    fn serialize_bytes(self, _value: &[u8]) -> Result<(), QueryError> {
+        unsupported("a byte array")
+    }
+
+    fn serialize_none(self) -> Result<(), QueryError> {
+        Ok(())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<(), QueryError> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<(), QueryError> {
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<(), QueryError> {
+        Ok(())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<(), QueryError> {
+        self.append(variant)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<(), QueryError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<(), QueryError> {
+        unsupported("an enum variant with data")
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, QueryError> {
+        Ok(SeqSerializer {
+            pairs: self.pairs,
+            key: self.key,
+        })
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, QueryError> {
+        Ok(SeqSerializer {
+            pairs: self.pairs,
+            key: self.key,
+        })
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, QueryError> {
+        Ok(SeqSerializer {
+            pairs: self.pairs,
+            key: self.key,
+        })
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, QueryError> {
+        unsupported("an enum variant with data")
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, QueryError> {
+        unsupported("a nested map")
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, QueryError> {
+        unsupported("a nested struct")
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, QueryError> {
+        unsupported("an enum variant with data")
+    }
+}
+
+struct SeqSerializer<'a> {
+    pairs: &'a mut Pairs,
+    key: &'a str,
+}
+
+impl SeqSerializer<'_> {
+    fn append<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), QueryError> {
+        FieldSerializer {
+            pairs: self.pairs,
+            key: self.key,
+        }
+        .append(value)
+    }
+}
+
+impl ser::SerializeSeq for SeqSerializer<'_> {
+    type Ok = ();
+    type Error = QueryError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), QueryError> {
+        self.append(value)
+    }
+
+    fn end(self) -> Result<(), QueryError> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeTuple for SeqSerializer<'_> {
+    type Ok = ();
+    type Error = QueryError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), QueryError> {
+        self.append(value)
+    }
+
+    fn end(self) -> Result<(), QueryError> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeTupleStruct for SeqSerializer<'_> {
+    type Ok = ();
+    type Error = QueryError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), QueryError> {
+        self.append(value)
+    }
+
+    fn end(self) -> Result<(), QueryError> {
+        Ok(())
+    }
+}
+
+/// Turns a single value into its query representation, `None` for values that are omitted
+struct ScalarSerializer;
+
+macro_rules! to_string {
+    ($($method:ident($ty:ty)),* $(,)?) => {
+        $(
+            fn $method(self, value: $ty) -> Result<Self::Ok, Self::Error> {
+                Ok(Some(value.to_string()))
+            }
+        )*
+    };
+}
+
+impl ser::Serializer for ScalarSerializer {
+    type Ok = Option<String>;
+    type Error = QueryError;
+    type SerializeSeq = Impossible<Option<String>, QueryError>;
+    type SerializeTuple = Impossible<Option<String>, QueryError>;
+    type SerializeTupleStruct = Impossible<Option<String>, QueryError>;
+    type SerializeTupleVariant = Impossible<Option<String>, QueryError>;
+    type SerializeMap = Impossible<Option<String>, QueryError>;
+    type SerializeStruct = Impossible<Option<String>, QueryError>;
+    type SerializeStructVariant = Impossible<Option<String>, QueryError>;
+
+    to_string! {
+        serialize_bool(bool),
+        serialize_i8(i8),
+        serialize_i16(i16),
+        serialize_i32(i32),
+        serialize_i64(i64),
+        serialize_i128(i128),
+        serialize_u8(u8),
+        serialize_u16(u16),
+        serialize_u32(u32),
+        serialize_u64(u64),
+        serialize_u128(u128),
+        serialize_f32(f32),
+        serialize_f64(f64),
+        serialize_char(char),
+        serialize_str(&str),
+    }
+
+    fn serialize_bytes(self, _value: &[u8]) -> Result<Option<String>, QueryError> {
+        unsupported("a byte array")
+    }
+
+    fn serialize_none(self) -> Result<Option<String>, QueryError> {
+        Ok(None)
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(
+        self,
+        value: &T,
+    ) -> Result<Option<String>, QueryError> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Option<String>, QueryError> {
+        Ok(None)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Option<String>, QueryError> {
+        Ok(None)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Option<String>, QueryError> {
+        Ok(Some(variant.to_string()))
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Option<String>, QueryError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<Option<String>, QueryError> {
+        unsupported("an enum variant with data")
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, QueryError> {
+        unsupported("a nested sequence")
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, QueryError> {
+        unsupported("a nested tuple")
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, QueryError> {
+        unsupported("a nested tuple struct")
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, QueryError> {
+        unsupported("an enum variant with data")
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, QueryError> {
+        unsupported("a nested map")
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, QueryError> {
+        unsupported("a nested struct")
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, QueryError> {
+        unsupported("an enum variant with data")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Serialize;
+    use std::collections::BTreeMap;
+
+    #[derive(Serialize, Default)]
+    struct SearchQuery {
+        q: String,
+        page: Option<u32>,
+        tags: Vec<String>,
+        exact: bool,
+        sort: Option<Sort>,
+    }
+
+    #[derive(Serialize)]
+    #[serde(rename_all = "snake_case")]
+    enum Sort {
+        NewestFirst,
+    }
+
+    #[test]
+    fn test_encode_query_scalars_keep_declaration_order() {
+        let query = SearchQuery {
+            q: "yew & rust".to_string(),
+            page: Some(2),
+            exact: true,
+            sort: Some(Sort::NewestFirst),
+            ..SearchQuery::default()
+        };
+
+        assert_eq!(
+            encode_query(&query).unwrap(),
+            "q=yew+%26+rust&page=2&exact=true&sort=newest_first"
+        );
+    }
+
+    #[test]
+    fn test_encode_query_omits_none() {
+        assert_eq!(
+            encode_query(&SearchQuery::default()).unwrap(),
+            "q=&exact=false"
+        );
+    }
+
+    #[test]
+    fn test_encode_query_repeats_sequence_keys() {
+        let query = SearchQuery {
+            tags: vec!["a".to_string(), "b".to_string()],
+            ..SearchQuery::default()
+        };
+
+        assert_eq!(
+            encode_query(&query).unwrap(),
+            "q=&tags=a&tags=b&exact=false"
+        );
+
+        #[derive(Serialize)]
+        struct Ids {
+            ids: Vec<Option<u32>>,
+            pair: (u8, u8),
+        }
+
+        assert_eq!(
+            encode_query(&Ids {
+                ids: vec![Some(1), None, Some(3)],
+                pair: (4, 5),
+            })
+            .unwrap(),
+            "ids=1&ids=3&pair=4&pair=5"
+        );
+    }
+
+    #[test]
+    fn test_encode_query_accepts_maps() {
+        let query = BTreeMap::from([("b", vec![1, 2]), ("a", vec![])]);
+
+        assert_eq!(encode_query(&query).unwrap(), "b=1&b=2");
+    }
+
+    #[test]
+    fn test_encode_query_rejects_nested_values() {
+        #[derive(Serialize)]
+        struct Nested {
+            inner: BTreeMap<String, u32>,
+        }
+
+        #[derive(Serialize)]
+        struct Matrix {
+            rows: Vec<Vec<u32>>,
+        }
+
+        assert!(matches!(
+            encode_query(&Nested {
+                inner: BTreeMap::new()
+            }),
+            Err(FetchError::QuerySerializationError(_))
+        ));
+
+        assert!(matches!(
+            encode_query(&Matrix {
+                rows: vec![vec![1]]
+            }),
+            Err(FetchError::QuerySerializationError(_))
+        ));
+
+        assert!(matches!(
+            encode_query(&vec![1, 2]),
+            Err(FetchError::QuerySerializationError(_))
+        ));
+    }
+}
@@ -1,5 +1,5 @@
 use serde::Serialize;
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::rc::Rc;
 use yew::prelude::*;
 
@@ -7,6 +7,8 @@ use yew::prelude::*;
 pub struct Signal<T> {
     value: Rc<RefCell<T>>,
     subscribers: Rc<RefCell<Vec<Callback<T>>>>,
+    batch_depth: Rc<Cell<usize>>,
+    pending: Rc<Cell<bool>>,
 }
 
 impl<T: 'static + Clone> Signal<T> {
@@ -14,6 +16,8 @@ impl<T: 'static + Clone> Signal<T> {
         Self {
             value: Rc::new(RefCell::new(initial)),
             subscribers: Rc::new(RefCell::new(Vec::new())),
+            batch_depth: Rc::new(Cell::new(0)),
+            pending: Rc::new(Cell::new(false)),
         }
     }
 
@@ -23,10 +27,34 @@ impl<T: 'static + Clone> Signal<T> {
     }
 
     pub fn set(&self, new_value: T) {
-        *self.value.borrow_mut() = new_value.clone();
+        *self.value.borrow_mut() = new_value;
+
+        if self.batch_depth.get() > 0 {
+            self.pending.set(true);
+            return;
+        }
+
+        self.notify();
+    }
+
+    /// Holds back notifications while `f` runs, then notifies subscribers once with the final value.
+    ///
+    /// Nested batches are merged into the outermost one. Subscribers aren't notified if nothing was set.
+    pub fn batch(&self, f: impl FnOnce(&Self)) {
+        self.batch_depth.set(self.batch_depth.get() + 1);
+        f(self);
+        self.batch_depth.set(self.batch_depth.get() - 1);
+
+        if self.batch_depth.get() == 0 && self.pending.replace(false) {
+            self.notify();
+        }
+    }
+
+    fn notify(&self) {
+        let value = self.get();
 
         for callback in self.subscribers.borrow().iter() {
-            callback.emit(new_value.clone());
+            callback.emit(value.clone());
         }
     }
 
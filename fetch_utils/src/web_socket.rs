@@ -1,10 +1,13 @@
-use std::{cell::RefCell, rc::Rc};
+use std::{
+    cell::{Ref, RefCell},
+    rc::Rc,
+};
 
 use serde::Serialize;
 use wasm_bindgen::prelude::Closure;
 use wasm_bindgen::JsCast;
 use web_sys::{CloseEvent, ErrorEvent, Event, MessageEvent, WebSocket};
-use yew::Callback;
+use yew::{Callback, Reducible};
 
 use crate::{deserialize_response, FetchError};
 
@@ -176,3 +179,116 @@ pub enum WsStatus {
     #[default]
     Closed,
 }
+
+/// Messages accumulated by a web socket hook, oldest first.
+///
+/// Incoming messages are appended to a shared buffer in place, so handles are compared
+/// by the buffer and its version instead of message by message.
+#[derive(Debug)]
+pub struct WsMessages<T> {
+    messages: Rc<RefCell<Vec<T>>>,
+    version: usize,
+}
+
+impl<T> WsMessages<T> {
+    #[must_use]
+    pub fn borrow(&self) -> Ref<'_, Vec<T>> {
+        self.messages.borrow()
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.messages.borrow().len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.messages.borrow().is_empty()
+    }
+
+    /// Bumped on every appended message.
+    #[must_use]
+    pub fn version(&self) -> usize {
+        self.version
+    }
+}
+
+impl<T> Default for WsMessages<T> {
+    fn default() -> Self {
+        Self {
+            messages: Rc::default(),
+            version: 0,
+        }
+    }
+}
+
+impl<T> Clone for WsMessages<T> {
+    fn clone(&self) -> Self {
+        Self {
+            messages: self.messages.clone(),
+            version: self.version,
+        }
+    }
+}
+
+impl<T> PartialEq for WsMessages<T> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.messages, &other.messages) && self.version == other.version
+    }
+}
+
+/// Appends `message` to the accumulated `WsMessages`.
+pub struct WsMessagesPush<T> {
+    pub message: T,
+    /// Drops the oldest messages past this count
+    pub max_messages: Option<usize>,
+}
+
+impl<T> Reducible for WsMessages<T> {
+    type Action = WsMessagesPush<T>;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        {
+            let mut messages = self.messages.borrow_mut();
+            messages.push(action.message);
+
+            if let Some(max_messages) = action.max_messages {
+                let overflow = messages.len().saturating_sub(max_messages);
+                messages.drain(..overflow);
+            }
+        }
+
+        Rc::new(Self {
+            messages: self.messages.clone(),
+            version: self.version + 1,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn push(messages: Rc<WsMessages<u32>>, message: u32) -> Rc<WsMessages<u32>> {
+        messages.reduce(WsMessagesPush {
+            message,
+            max_messages: Some(2),
+        })
+    }
+
+    #[test]
+    fn test_ws_messages_append_in_place() {
+        let empty = Rc::new(WsMessages::<u32>::default());
+        let first = push(empty.clone(), 1);
+        let second = push(first.clone(), 2);
+        let third = push(second.clone(), 3);
+
+        assert_eq!(*third.borrow(), vec![2, 3]);
+        assert_eq!(third.version(), 3);
+
+        // Every version shares the same buffer, so they only differ by version
+        assert!(Rc::ptr_eq(&empty.messages, &third.messages));
+        assert_ne!(*second, *third);
+        assert_eq!(*third, (*third).clone());
+    }
+}
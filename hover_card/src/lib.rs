@@ -0,0 +1,388 @@
+use std::{cell::RefCell, rc::Rc};
+use web_sys::wasm_bindgen::{closure::Closure, JsCast};
+use yew::prelude::*;
+use yewlish_popover::{
+    Popover, PopoverAlign, PopoverChangeReason, PopoverContent, PopoverContext, PopoverOpenChange,
+    PopoverOpenReason, PopoverSide, PopoverTrigger, PopoverTriggerRenderAsProps,
+    ReduciblePopoverContext,
+};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct HoverCardContext {
+    pub open_delay: u32,
+    pub close_delay: u32,
+    timeout_id: Rc<RefCell<Option<i32>>>,
+}
+
+impl HoverCardContext {
+    /// Opens the popover after `open_delay`, or right away when the delay is zero.
+    pub fn open(&self, popover: &PopoverContext) {
+        if popover.is_open {
+            self.cancel();
+            return;
+        }
+
+        if self.open_delay == 0 {
+            self.open_instantly(popover);
+            return;
+        }
+
+        let on_open_with_reason = popover.on_open_with_reason.clone();
+
+        self.schedule(self.open_delay, move || {
+            on_open_with_reason.emit(PopoverOpenReason::Delayed);
+        });
+    }
+
+    /// Opens the popover without waiting, e.g. when the trigger receives keyboard focus.
+    pub fn open_instantly(&self, popover: &PopoverContext) {
+        self.cancel();
+
+        if !popover.is_open {
+            popover.on_open_with_reason.emit(PopoverOpenReason::Instant);
+        }
+    }
+
+    /// Closes the popover after `close_delay` unless the pointer comes back in the meantime.
+    pub fn close(&self, popover: &PopoverContext) {
+        if !popover.is_open {
+            self.cancel();
+            return;
+        }
+
+        let on_change = popover.on_change.clone();
+
+        self.schedule(self.close_delay, move || {
+            on_change.emit(PopoverOpenChange {
+                open: false,
+                reason: PopoverChangeReason::Hover,
+            });
+        });
+    }
+
+    /// Drops the pending open or close, if any.
+    pub fn cancel(&self) {
+        let Some(timeout_id) = self.timeout_id.borrow_mut().take() else {
+            return;
+        };
+
+        if let Some(window) = web_sys::window() {
+            window.clear_timeout_with_handle(timeout_id);
+        }
+    }
+
+    fn schedule(&self, delay: u32, callback: impl FnOnce() + 'static) {
+        self.cancel();
+
+        let Some(window) = web_sys::window() else {
+            return;
+        };
+
+        let timeout_id = self.timeout_id.clone();
+
+        let callback = Closure::once_into_js(move || {
+            timeout_id.borrow_mut().take();
+            callback();
+        });
+
+        match window.set_timeout_with_callback_and_timeout_and_arguments_0(
+            callback.unchecked_ref(),
+            delay as i32,
+        ) {
+            Ok(timeout_id) => {
+                *self.timeout_id.borrow_mut() = Some(timeout_id);
+            }
+            Err(err) => {
+                log::error!("Failed to set timeout {err:?}");
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct HoverCardProps {
+    pub children: Children,
+    #[prop_or_default]
+    pub class: Option<AttrValue>,
+    #[prop_or_default]
+    pub open: Option<bool>,
+    #[prop_or_default]
+    pub default_open: bool,
+    #[prop_or_default]
+    pub on_open_change: Callback<bool>,
+    /// Milliseconds the pointer has to rest on the trigger before the card opens
+    #[prop_or(700)]
+    pub open_delay: u32,
+    /// Milliseconds the card stays open after the pointer leaves the trigger or the content
+    #[prop_or(300)]
+    pub close_delay: u32,
+}
+
+/// Shows rich content next to a trigger while it is hovered or focused.
+///
+/// Unlike a tooltip, the card stays open while the pointer moves onto its content, so it
+/// can hold links and other interactive elements.
+///
+/// # Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use hover_card::{HoverCard, HoverCardContent, HoverCardTrigger};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <HoverCard>
+///             <HoverCardTrigger href="https://github.com/patchwork-body">{"@patchwork-body"}</HoverCardTrigger>
+///             <HoverCardContent>
+///                 <p>{"Components for Yew"}</p>
+///             </HoverCardContent>
+///         </HoverCard>
+///     }
+/// }
+/// ```
+#[function_component(HoverCard)]
+pub fn hover_card(props: &HoverCardProps) -> Html {
+    let timeout_id = use_mut_ref(|| None::<i32>);
+
+    let context = HoverCardContext {
+        open_delay: props.open_delay,
+        close_delay: props.close_delay,
+        timeout_id,
+    };
+
+    // Drop a pending open or close when the card goes away
+    use_effect_with(context.clone(), |context| {
+        let context = context.clone();
+        move || context.cancel()
+    });
+
+    html! {
+        <ContextProvider<HoverCardContext> {context}>
+            <Popover
+                class={&props.class}
+                open={props.open}
+                default_open={props.default_open}
+                on_open_change={&props.on_open_change}
+            >
+                {for props.children.iter()}
+            </Popover>
+        </ContextProvider<HoverCardContext>>
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct HoverCardTriggerProps {
+    #[prop_or_default]
+    pub children: Children,
+    #[prop_or_default]
+    pub class: Option<AttrValue>,
+    #[prop_or_default]
+    pub href: Option<AttrValue>,
+}
+
+#[function_component(HoverCardTrigger)]
+pub fn hover_card_trigger(props: &HoverCardTriggerProps) -> Html {
+    let hover_card =
+        use_context::<HoverCardContext>().expect("HoverCardTrigger must be a child of HoverCard");
+    let popover = use_context::<ReduciblePopoverContext>()
+        .expect("HoverCardTrigger must be a child of HoverCard");
+
+    let open = use_callback(
+        (hover_card.clone(), popover.clone()),
+        |_event: PointerEvent, (hover_card, popover)| {
+            hover_card.open(popover);
+        },
+    );
+
+    let close = use_callback(
+        (hover_card.clone(), popover.clone()),
+        |_event: PointerEvent, (hover_card, popover)| {
+            hover_card.close(popover);
+        },
+    );
+
+    let open_on_focus = use_callback(
+        (hover_card.clone(), popover.clone()),
+        |_event: FocusEvent, (hover_card, popover)| {
+            hover_card.open_instantly(popover);
+        },
+    );
+
+    let close_on_blur = use_callback(
+        (hover_card.clone(), popover.clone()),
+        |_event: FocusEvent, (hover_card, popover)| {
+            hover_card.close(popover);
+        },
+    );
+
+    let render_as = {
+        let href = props.href.clone();
+
+        Callback::from(move |trigger: PopoverTriggerRenderAsProps| {
+            html! {
+                <a
+                    ref={trigger.r#ref.clone()}
+                    href={href.clone()}
+                    tabindex="0"
+                    aria-expanded={trigger.is_open.to_string()}
+                    data-side={trigger.side.as_ref().map(ToString::to_string)}
+                    data-align={trigger.align.as_ref().map(ToString::to_string)}
+                    class={&trigger.class}
+                    onpointerenter={&open}
+                    onpointerleave={&close}
+                    onfocus={&open_on_focus}
+                    onblur={&close_on_blur}
+                >
+                    {trigger.children}
+                </a>
+            }
+        })
+    };
+
+    html! {
+        <PopoverTrigger class={&props.class} {render_as}>
+            {for props.children.iter()}
+        </PopoverTrigger>
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct HoverCardContentProps {
+    #[prop_or_default]
+    pub children: Children,
+    #[prop_or_default]
+    pub class: Option<AttrValue>,
+    #[prop_or_default]
+    pub side: PopoverSide,
+    #[prop_or_default]
+    pub align: PopoverAlign,
+}
+
+#[function_component(HoverCardContent)]
+pub fn hover_card_content(props: &HoverCardContentProps) -> Html {
+    let hover_card =
+        use_context::<HoverCardContext>().expect("HoverCardContent must be a child of HoverCard");
+    let popover = use_context::<ReduciblePopoverContext>()
+        .expect("HoverCardContent must be a child of HoverCard");
+
+    // Keep the card open while the pointer or focus is inside it
+    let stay_open = use_callback(hover_card.clone(), |(), hover_card| {
+        hover_card.cancel();
+    });
+
+    let close = use_callback(
+        (hover_card.clone(), popover.clone()),
+        |(), (hover_card, popover)| {
+            hover_card.close(popover);
+        },
+    );
+
+    // The card only previews, the focus stays on the trigger that opened it
+    let keep_focus = use_callback((), |event: Event, ()| {
+        event.prevent_default();
+    });
+
+    html! {
+        <PopoverContent
+            class={&props.class}
+            side={props.side.clone()}
+            align={props.align.clone()}
+            role={None::<AttrValue>}
+            on_open_auto_focus={keep_focus}
+        >
+            <div
+                onpointerenter={stay_open.reform(|_: PointerEvent| ())}
+                onpointerleave={close.reform(|_: PointerEvent| ())}
+                onfocusin={stay_open.reform(|_: FocusEvent| ())}
+                onfocusout={close.reform(|_: FocusEvent| ())}
+            >
+                {for props.children.iter()}
+            </div>
+        </PopoverContent>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+    use yewlish_testing_tools::*;
+
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    const TRIGGER: &str = "a[href='#profile']";
+
+    fn dispatch_pointer_event(name: &str) {
+        let element = web_sys::window()
+            .and_then(|window| window.document())
+            .and_then(|document| document.query_selector(TRIGGER).ok())
+            .flatten()
+            .expect("Failed to find the element");
+
+        let event = web_sys::PointerEvent::new(name).expect("Failed to create a pointer event");
+
+        element
+            .dispatch_event(&event)
+            .expect("Failed to dispatch the pointer event");
+    }
+
+    fn active_href() -> Option<String> {
+        web_sys::window()
+            .and_then(|window| window.document())
+            .and_then(|document| document.active_element())
+            .and_then(|element| element.get_attribute("href"))
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_hover_card_opens_on_hover_and_closes_on_leave() {
+        let t = render!({
+            html! {
+                <HoverCard open_delay={0} close_delay={0}>
+                    <HoverCardTrigger href="#profile">{"@profile"}</HoverCardTrigger>
+                    <HoverCardContent>
+                        <p>{"Card"}</p>
+                    </HoverCardContent>
+                </HoverCard>
+            }
+        })
+        .await;
+
+        assert!(!t.query_by_text("Card").exists());
+
+        dispatch_pointer_event("pointerenter");
+        t.wait_for(100.0, || t.query_by_text("Card").exists()).await;
+        assert!(t.query_by_text("Card").exists());
+
+        // The card only previews the trigger, it isn't a dialog
+        assert!(!t.query_by_role("dialog").exists());
+
+        dispatch_pointer_event("pointerleave");
+        t.wait_for(100.0, || !t.query_by_text("Card").exists())
+            .await;
+        assert!(!t.query_by_text("Card").exists());
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_hover_card_keeps_the_focus_on_the_trigger() {
+        let t = render!({
+            html! {
+                <HoverCard>
+                    <HoverCardTrigger href="#profile">{"@profile"}</HoverCardTrigger>
+                    <HoverCardContent>
+                        <a href="#follow">{"Follow"}</a>
+                    </HoverCardContent>
+                </HoverCard>
+            }
+        })
+        .await;
+
+        // Focus opens the card right away
+        t.query_by_selector(TRIGGER).focus().await;
+        t.wait_for(100.0, || t.query_by_text("Follow").exists())
+            .await;
+
+        assert!(t.query_by_text("Follow").exists());
+        assert_eq!(active_href().as_deref(), Some("#profile"));
+    }
+}
@@ -1,8 +1,13 @@
+use std::cell::RefCell;
 use std::rc::Rc;
 
+use virtual_list::VirtualList;
+use web_sys::{wasm_bindgen::JsCast, HtmlOptionElement, HtmlSelectElement};
 use yew::prelude::*;
 use yewlish_presence::*;
-use yewlish_utils::hooks::{use_children_as_html_collection, use_controllable_state, use_keydown};
+use yewlish_utils::hooks::{
+    use_children_as_html_collection, use_controllable_multiple, use_keydown,
+};
 
 #[derive(Clone, PartialEq, Debug)]
 pub enum ListboxSelected {
@@ -16,67 +21,57 @@ impl Default for ListboxSelected {
     }
 }
 
-#[derive(Clone, PartialEq, Debug)]
-pub struct ListboxContext {
-    pub active: AttrValue,
-    pub selected: ListboxSelected,
-    pub on_selected_change: Callback<ListboxSelected>,
+impl ListboxSelected {
+    fn values(&self) -> Vec<AttrValue> {
+        match self {
+            ListboxSelected::Single(selected) => selected.iter().cloned().collect(),
+            ListboxSelected::Multiple(selected) => selected.clone(),
+        }
+    }
+
+    fn from_values(values: Vec<AttrValue>, multiple: bool) -> Self {
+        if multiple {
+            ListboxSelected::Multiple(values)
+        } else {
+            ListboxSelected::Single(values.into_iter().next())
+        }
+    }
 }
 
 pub enum ListboxAction {
     ToggleSelection(AttrValue),
     SetActive(AttrValue),
+    /// Deselects every option
+    Clear,
+    /// Selects the given options, ignored by a single selection listbox
+    SelectAll(Vec<AttrValue>),
+    /// Replaces the selection, a single selection listbox keeps the first option
+    SetSelection(Vec<AttrValue>),
 }
 
-impl Reducible for ListboxContext {
-    type Action = ListboxAction;
-
-    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
-        match action {
-            ListboxAction::ToggleSelection(id) => {
-                let selected = match self.selected.clone() {
-                    ListboxSelected::Single(prev) => match prev {
-                        Some(prev) => {
-                            if prev == id {
-                                ListboxSelected::Single(None)
-                            } else {
-                                ListboxSelected::Single(Some(id.clone()))
-                            }
-                        }
-                        None => ListboxSelected::Single(Some(id.clone())),
-                    },
-                    ListboxSelected::Multiple(prev) => {
-                        if prev.contains(&id) {
-                            let mut next = prev.clone();
-                            next.retain(|v| v != &id);
-                            ListboxSelected::Multiple(next)
-                        } else {
-                            let mut next = prev.clone();
-                            next.push(id.clone());
-                            ListboxSelected::Multiple(next)
-                        }
-                    }
-                };
-
-                self.on_selected_change.emit(selected.clone());
+#[derive(Clone, PartialEq, Debug)]
+pub struct ListboxContext {
+    pub active: AttrValue,
+    pub selected: ListboxSelected,
+    on_action: Callback<ListboxAction>,
+}
 
-                ListboxContext {
-                    selected,
-                    active: id,
-                    ..(*self).clone()
-                }
-                .into()
-            }
-            ListboxAction::SetActive(id) => ListboxContext {
-                active: id,
-                ..(*self).clone()
-            }
-            .into(),
-        }
+impl ListboxContext {
+    pub fn dispatch(&self, action: ListboxAction) {
+        self.on_action.emit(action);
     }
 }
 
-pub type MutableListboxContext = UseReducerHandle<ListboxContext>;
+/// Changes the selection of a listbox from outside of it, e.g. from "Clear" and "Select all" buttons
+#[derive(Clone, PartialEq, Debug)]
+pub struct ListboxController {
+    pub clear: Callback<()>,
+    /// Selects every option of a `multiple` listbox
+    pub select_all: Callback<()>,
+}
+
+/// Filled with the listbox's controller once it's mounted, create it with `use_mut_ref(|| None)`
+pub type ListboxControllerRef = Rc<RefCell<Option<ListboxController>>>;
 
 #[derive(Clone, PartialEq, Properties)]
 pub struct ListboxProps {
@@ -91,51 +86,143 @@ pub struct ListboxProps {
     pub multiple: bool,
     #[prop_or_default]
     pub class: Option<AttrValue>,
+    /// Renders a native `<select>` with an `<option>` per child instead of the custom listbox
+    #[prop_or_default]
+    pub native: bool,
+    #[prop_or_default]
+    pub controller_ref: Option<ListboxControllerRef>,
 }
 
-#[function_component(Listbox)]
-pub fn listbox(props: &ListboxProps) -> Html {
-    let default_selected = use_memo(
-        (props.default_selected.clone(), props.multiple),
-        |(default_selected, multiple)| {
-            if default_selected.is_some() {
-                default_selected.clone()
-            } else if *multiple {
-                Some(ListboxSelected::Multiple(Vec::new()))
-            } else {
-                Some(ListboxSelected::Single(None))
+fn is_option_selected(selected: &ListboxSelected, id: &AttrValue) -> bool {
+    match selected {
+        ListboxSelected::Single(selected) => selected.as_ref() == Some(id),
+        ListboxSelected::Multiple(selected) => selected.contains(id),
+    }
+}
+
+/// The selection and active option model of `Listbox`, for components that render their own
+/// options on top of it, e.g. a combobox. Provide the returned context to `ListboxOption`s.
+#[hook]
+pub fn use_listbox_context(
+    selected: Option<ListboxSelected>,
+    default_selected: Option<ListboxSelected>,
+    on_selected_change: Callback<ListboxSelected>,
+    multiple: bool,
+) -> ListboxContext {
+    // A `Multiple` selection makes the listbox multiple even without the prop
+    let multiple = multiple
+        || matches!(
+            selected.as_ref().or(default_selected.as_ref()),
+            Some(ListboxSelected::Multiple(_))
+        );
+
+    let on_change = use_callback(
+        (on_selected_change, multiple),
+        |values: Vec<AttrValue>, (on_selected_change, multiple)| {
+            on_selected_change.emit(ListboxSelected::from_values(values, *multiple));
+        },
+    );
+
+    let (values, toggle, set, clear) = use_controllable_multiple(
+        default_selected.as_ref().map(ListboxSelected::values),
+        selected.as_ref().map(ListboxSelected::values),
+        on_change,
+        multiple,
+    );
+
+    let active = use_state_eq(|| AttrValue::from(""));
+
+    let on_action = use_callback(
+        (active.setter(), toggle, set, clear, multiple),
+        |action: ListboxAction, (set_active, toggle, set, clear, multiple)| match action {
+            ListboxAction::ToggleSelection(id) => {
+                toggle.emit(id.clone());
+                set_active.set(id);
+            }
+            ListboxAction::SetActive(id) => set_active.set(id),
+            ListboxAction::Clear => clear.emit(()),
+            ListboxAction::SelectAll(ids) => {
+                if *multiple {
+                    set.emit(ids);
+                }
             }
+            ListboxAction::SetSelection(ids) => set.emit(ids),
         },
     );
 
-    let selected = use_memo(
-        (props.selected.clone(), default_selected.clone()),
-        |(selected, default_selected)| {
-            if selected.is_some() {
-                selected.clone()
-            } else {
-                (**default_selected).clone()
+    ListboxContext {
+        active: (*active).clone(),
+        selected: ListboxSelected::from_values(values, multiple),
+        on_action,
+    }
+}
+
+/// The index of the option a navigation key moves to among `total` options, starting from the
+/// active option at `current`. Arrow keys wrap around, `None` for any other key.
+#[must_use]
+pub fn next_active_index(key: &str, current: Option<usize>, total: usize) -> Option<usize> {
+    if total == 0 {
+        return None;
+    }
+
+    match (key, current) {
+        ("ArrowDown", Some(index)) => Some((index + 1) % total),
+        ("ArrowDown", None) | ("Home", _) => Some(0),
+        ("ArrowUp", Some(0) | None) | ("End", _) => Some(total - 1),
+        ("ArrowUp", Some(index)) => Some(index - 1),
+        _ => None,
+    }
+}
+
+#[hook]
+fn use_listbox_controller(
+    controller_ref: Option<ListboxControllerRef>,
+    on_action: Callback<ListboxAction>,
+    option_ids: Callback<(), Vec<AttrValue>>,
+) {
+    let clear = use_callback(on_action.clone(), |(), on_action| {
+        on_action.emit(ListboxAction::Clear);
+    });
+
+    let select_all = use_callback((on_action, option_ids), |(), (on_action, option_ids)| {
+        on_action.emit(ListboxAction::SelectAll(option_ids.emit(())));
+    });
+
+    use_effect_with(
+        (controller_ref, clear, select_all),
+        |(controller_ref, clear, select_all)| {
+            if let Some(controller_ref) = controller_ref {
+                controller_ref.replace(Some(ListboxController {
+                    clear: clear.clone(),
+                    select_all: select_all.clone(),
+                }));
             }
         },
     );
+}
 
-    let (selected, dispatch) = use_controllable_state(
-        (*default_selected).clone(),
-        (*selected).clone(),
+#[function_component(Listbox)]
+pub fn listbox(props: &ListboxProps) -> Html {
+    let context_value = use_listbox_context(
+        props.selected.clone(),
+        props.default_selected.clone(),
         props.on_selected_change.clone(),
+        props.multiple,
     );
 
-    let on_selected_change =
-        use_callback(dispatch.clone(), |selected: ListboxSelected, dispatch| {
-            dispatch.emit(Box::new(move |_| selected.clone()));
-        });
-
-    let context_value = use_reducer(|| ListboxContext {
-        active: AttrValue::from(""),
-        selected: (*selected).clone().borrow().clone(),
-        on_selected_change,
+    let option_ids = use_callback(props.children.clone(), |(), children| {
+        children
+            .iter()
+            .map(|option| option.props.id.clone())
+            .collect::<Vec<_>>()
     });
 
+    use_listbox_controller(
+        props.controller_ref.clone(),
+        context_value.on_action.clone(),
+        option_ids,
+    );
+
     let node_ref = use_node_ref();
     let options = use_children_as_html_collection(node_ref.clone());
 
@@ -149,7 +236,11 @@ pub fn listbox(props: &ListboxProps) -> Html {
             let active = context_value.active.clone();
 
             if event.key() == " " {
-                context_value.dispatch(ListboxAction::ToggleSelection(AttrValue::from(active)));
+                // Nothing to toggle until an option has been made active
+                if !active.is_empty() {
+                    context_value.dispatch(ListboxAction::ToggleSelection(active));
+                }
+
                 return;
             }
 
@@ -176,38 +267,18 @@ pub fn listbox(props: &ListboxProps) -> Html {
                 index
             };
 
-            let next_index = {
-                if let Some(index) = current_option_index {
-                    match event.key().as_str() {
-                        "ArrowDown" => {
-                            if index + 1 >= options.length() {
-                                Some(0)
-                            } else {
-                                Some(index + 1)
-                            }
-                        }
-                        "ArrowUp" => {
-                            if index == 0 {
-                                Some(options.length() - 1)
-                            } else {
-                                Some(index - 1)
-                            }
-                        }
-                        _ => None,
-                    }
-                } else {
-                    match event.key().as_str() {
-                        "ArrowDown" => Some(0),
-                        "ArrowUp" => Some(options.length() - 1),
-                        _ => None,
-                    }
-                }
-            };
+            let next_index = next_active_index(
+                &event.key(),
+                current_option_index.map(|index| index as usize),
+                options.length() as usize,
+            );
 
-            if let Some(index) = next_index {
-                if let Some(option) = options.item(index) {
-                    context_value.dispatch(ListboxAction::SetActive(AttrValue::from(option.id())));
-                }
+            let next_option = next_index
+                .and_then(|index| u32::try_from(index).ok())
+                .and_then(|index| options.item(index));
+
+            if let Some(option) = next_option {
+                context_value.dispatch(ListboxAction::SetActive(AttrValue::from(option.id())));
             }
         }
     };
@@ -217,12 +288,247 @@ pub fn listbox(props: &ListboxProps) -> Html {
         navigation_handler,
     );
 
+    // The native select already applied the change, take its selection over as a whole
+    let on_native_change = use_callback(context_value.clone(), |event: Event, context_value| {
+        let Some(select) = event.target_dyn_into::<HtmlSelectElement>() else {
+            return;
+        };
+
+        let selected_options = select.selected_options();
+
+        let ids = (0..selected_options.length())
+            .filter_map(|index| selected_options.item(index))
+            .filter_map(|option| option.dyn_into::<HtmlOptionElement>().ok())
+            .map(|option| AttrValue::from(option.value()))
+            .collect();
+
+        context_value.dispatch(ListboxAction::SetSelection(ids));
+    });
+
+    let select_ref = use_node_ref();
+
+    // The `selected` attribute only sets the initial selection, so keep the live one in sync
+    use_effect_with(
+        (select_ref.clone(), context_value.selected.clone()),
+        |(select_ref, selected)| {
+            let Some(select) = select_ref.cast::<HtmlSelectElement>() else {
+                return;
+            };
+
+            let options = select.options();
+
+            for index in 0..options.length() {
+                if let Some(option) = options
+                    .item(index)
+                    .and_then(|option| option.dyn_into::<HtmlOptionElement>().ok())
+                {
+                    option.set_selected(is_option_selected(selected, &option.value().into()));
+                }
+            }
+        },
+    );
+
+    if props.native {
+        let selected = context_value.selected.clone();
+
+        let options = props.children.iter().map(|option| {
+            let id = option.props.id.clone();
+            let is_selected = is_option_selected(&selected, &id);
+
+            html! {
+                <option key={id.to_string()} value={id} class={option.props.class.clone()} selected={is_selected}>
+                    {for option.props.children.iter()}
+                </option>
+            }
+        });
+
+        return html! {
+            <ContextProvider<ListboxContext> context={context_value.clone()}>
+                <select ref={select_ref} class={props.class.clone()} multiple={props.multiple} onchange={on_native_change}>
+                    {for options}
+                </select>
+            </ContextProvider<ListboxContext>>
+        };
+    }
+
+    let set_size = props.children.len();
+
+    let options = props
+        .children
+        .iter()
+        .enumerate()
+        .map(|(index, mut option)| {
+            let option_props = Rc::make_mut(&mut option.props);
+            option_props.posinset = option_props.posinset.or(Some(index + 1));
+            option_props.setsize = option_props.setsize.or(Some(set_size));
+            option
+        });
+
     html! {
-        <ContextProvider<MutableListboxContext> context={context_value.clone()}>
+        <ContextProvider<ListboxContext> context={context_value.clone()}>
             <ul ref={node_ref} role="listbox" tabindex="0" class={props.class.clone()} aria-multiselectable={if props.multiple { "true" } else { "false" }} aria-activedescendant={context_value.active.clone()} onkeydown={navigate_through_options}>
-                {for props.children.iter()}
+                {for options}
             </ul>
-        </ContextProvider<MutableListboxContext>>
+        </ContextProvider<ListboxContext>>
+    }
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct VirtualListboxProps {
+    pub total_items: usize,
+    pub item_height: f64,
+    pub viewport_height: f64,
+    /// Returns the id of the option at the given index, it must match the `id` of the rendered `ListboxOption`
+    pub option_id: Callback<usize, AttrValue>,
+    /// Renders a `ListboxOption` for the given index
+    pub render_option: Callback<usize, Html>,
+    #[prop_or_default]
+    pub selected: Option<ListboxSelected>,
+    #[prop_or_default]
+    pub default_selected: Option<ListboxSelected>,
+    #[prop_or_default]
+    pub on_selected_change: Callback<ListboxSelected>,
+    #[prop_or_default]
+    pub multiple: bool,
+    #[prop_or_default]
+    pub class: Option<AttrValue>,
+    #[prop_or_default]
+    pub controller_ref: Option<ListboxControllerRef>,
+}
+
+/// A `Listbox` that only renders the options visible in its viewport.
+///
+/// Keyboard navigation works over the logical indexes of all options instead of the rendered DOM,
+/// the active option is scrolled into view whenever it leaves the rendered window.
+///
+/// # Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use listbox::{ListboxOption, VirtualListbox};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <VirtualListbox
+///             total_items={10_000}
+///             item_height={32.0}
+///             viewport_height={320.0}
+///             option_id={Callback::from(|index: usize| AttrValue::from(format!("option-{index}")))}
+///             render_option={Callback::from(|index: usize| html! {
+///                 <ListboxOption id={format!("option-{index}")}>{format!("Option {index}")}</ListboxOption>
+///             })}
+///         />
+///     }
+/// }
+/// ```
+#[function_component(VirtualListbox)]
+pub fn virtual_listbox(props: &VirtualListboxProps) -> Html {
+    let context_value = use_listbox_context(
+        props.selected.clone(),
+        props.default_selected.clone(),
+        props.on_selected_change.clone(),
+        props.multiple,
+    );
+
+    // Only resolved when `select_all` runs, since it needs the ids of the options that aren't rendered
+    let option_ids = use_callback(
+        (props.total_items, props.option_id.clone()),
+        |(), (total_items, option_id)| {
+            (0..*total_items)
+                .map(|index| option_id.emit(index))
+                .collect::<Vec<_>>()
+        },
+    );
+
+    use_listbox_controller(
+        props.controller_ref.clone(),
+        context_value.on_action.clone(),
+        option_ids,
+    );
+
+    let active_index = use_state(|| None::<usize>);
+
+    let navigation_handler = {
+        let context_value = context_value.clone();
+        let active_index = active_index.clone();
+        let option_id = props.option_id.clone();
+        let total_items = props.total_items;
+
+        move |event: KeyboardEvent| {
+            let active = context_value.active.clone();
+
+            if event.key() == " " {
+                if !active.is_empty() {
+                    context_value.dispatch(ListboxAction::ToggleSelection(active));
+                }
+
+                return;
+            }
+
+            if total_items == 0 {
+                return;
+            }
+
+            // The active option might have been changed by a click, so resolve its index again
+            let current_index = match *active_index {
+                Some(index) if option_id.emit(index) == active => Some(index),
+                _ if active.is_empty() => None,
+                _ => (0..total_items).find(|index| option_id.emit(*index) == active),
+            };
+
+            if let Some(index) = next_active_index(&event.key(), current_index, total_items) {
+                active_index.set(Some(index));
+                context_value.dispatch(ListboxAction::SetActive(option_id.emit(index)));
+            }
+        }
+    };
+
+    // Only a window of options is mounted, so each one is told where it sits in the whole set
+    let render_option = use_callback(
+        (props.render_option.clone(), props.total_items),
+        |index: usize, (render_option, total_items)| {
+            html! {
+                <ContextProvider<ListboxOptionPosition> context={ListboxOptionPosition {
+                    posinset: index + 1,
+                    setsize: *total_items,
+                }}>
+                    {render_option.emit(index)}
+                </ContextProvider<ListboxOptionPosition>>
+            }
+        },
+    );
+
+    let navigate_through_options = use_keydown(
+        vec![
+            "ArrowDown".into(),
+            "ArrowUp".into(),
+            "Home".into(),
+            "End".into(),
+            " ".into(),
+        ],
+        navigation_handler,
+    );
+
+    html! {
+        <ContextProvider<ListboxContext> context={context_value.clone()}>
+            <div
+                role="listbox"
+                tabindex="0"
+                class={props.class.clone()}
+                aria-multiselectable={if props.multiple { "true" } else { "false" }}
+                aria-activedescendant={context_value.active.clone()}
+                onkeydown={navigate_through_options}
+            >
+                <VirtualList
+                    total_items={props.total_items}
+                    item_height={props.item_height}
+                    viewport_height={props.viewport_height}
+                    render_item={render_option}
+                    scroll_to_index={*active_index}
+                />
+            </div>
+        </ContextProvider<ListboxContext>>
     }
 }
 
@@ -231,25 +537,35 @@ pub struct ListboxOptionContext {
     pub is_selected: Rc<bool>,
 }
 
+/// The position of a virtualized option within the whole set of options
+#[derive(Clone, PartialEq)]
+struct ListboxOptionPosition {
+    posinset: usize,
+    setsize: usize,
+}
+
 #[derive(Clone, PartialEq, Properties)]
 pub struct ListboxOptionProps {
-    pub id: &'static str,
+    pub id: AttrValue,
     pub children: Children,
     #[prop_or_default]
     pub class: Option<AttrValue>,
+    /// 1-based position used for `aria-posinset`, filled in by the parent listbox when omitted
+    #[prop_or_default]
+    pub posinset: Option<usize>,
+    /// Total number of options used for `aria-setsize`, filled in by the parent listbox when omitted
+    #[prop_or_default]
+    pub setsize: Option<usize>,
 }
 
 #[function_component(ListboxOption)]
 pub fn listbox_option(props: &ListboxOptionProps) -> Html {
     let context =
-        use_context::<MutableListboxContext>().expect("ListboxOption must be a child of Listbox");
+        use_context::<ListboxContext>().expect("ListboxOption must be a child of Listbox");
 
     let is_selected = use_memo(
-        (props.id, context.selected.clone()),
-        |(id, selected)| match selected.clone() {
-            ListboxSelected::Single(selected) => selected == AttrValue::from(*id).into(),
-            ListboxSelected::Multiple(selected) => selected.contains(&AttrValue::from(*id)),
-        },
+        (props.id.clone(), context.selected.clone()),
+        |(id, selected)| is_option_selected(selected, id),
     );
 
     let context_value = ListboxOptionContext {
@@ -269,49 +585,44 @@ pub fn listbox_option(props: &ListboxOptionProps) -> Html {
     });
 
     let select_on_click = use_callback(
-        (props.id, context.clone()),
+        (props.id.clone(), context.clone()),
         |_event: MouseEvent, (id, context)| {
-            context.dispatch(ListboxAction::ToggleSelection(AttrValue::from(*id)));
+            context.dispatch(ListboxAction::ToggleSelection(id.clone()));
         },
     );
 
-    use_effect_with(
-        (context.clone(), is_selected.clone(), props.id),
-        |(context, is_selected, id)| {
-            if let ListboxSelected::Single(selected) = &context.selected {
-                match selected {
-                    Some(selected) => {
-                        if selected == id && !**is_selected {
-                            context.dispatch(ListboxAction::ToggleSelection(AttrValue::from(*id)));
-                        }
-                    }
-                    None => {
-                        if !**is_selected {
-                            context.dispatch(ListboxAction::ToggleSelection(AttrValue::from(*id)));
-                        }
-                    }
-                }
+    let data_active = use_memo(
+        (context.active.clone(), props.id.clone()),
+        |(active, id)| {
+            if active == id {
+                "true"
+            } else {
+                "false"
             }
         },
     );
 
-    let data_active = use_memo(context.active.clone(), |active| {
-        if *active == props.id {
-            "true"
-        } else {
-            "false"
-        }
-    });
+    let position = use_context::<ListboxOptionPosition>();
+
+    let aria_posinset = props
+        .posinset
+        .or(position.as_ref().map(|position| position.posinset))
+        .map(|posinset| posinset.to_string());
+
+    let aria_setsize = props
+        .setsize
+        .or(position.as_ref().map(|position| position.setsize))
+        .map(|setsize| setsize.to_string());
 
     let element = if *is_multiple {
         html! {
-            <li id={props.id} role="option" class={props.class.clone()} aria-checked={*aria_selected} data-active={*data_active} onclick={select_on_click}>
+            <li id={props.id.clone()} role="option" class={props.class.clone()} aria-checked={*aria_selected} aria-posinset={aria_posinset} aria-setsize={aria_setsize} data-active={*data_active} onclick={select_on_click}>
                 {for props.children.iter()}
             </li>
         }
     } else {
         html! {
-            <li id={props.id} role="option" class={props.class.clone()} aria-selected={*aria_selected} data-active={*data_active} onclick={select_on_click}>
+            <li id={props.id.clone()} role="option" class={props.class.clone()} aria-selected={*aria_selected} aria-posinset={aria_posinset} aria-setsize={aria_setsize} data-active={*data_active} onclick={select_on_click}>
                 {for props.children.iter()}
             </li>
         }
@@ -357,3 +668,398 @@ pub fn listbox_option_indicator(props: &ListboxOptionIndicatorProps) -> Html {
         </Presence>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+    use yewlish_testing_tools::*;
+
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    #[test]
+    fn test_next_active_index() {
+        assert_eq!(next_active_index("ArrowDown", None, 3), Some(0));
+        assert_eq!(next_active_index("ArrowDown", Some(0), 3), Some(1));
+        assert_eq!(next_active_index("ArrowDown", Some(2), 3), Some(0));
+        assert_eq!(next_active_index("ArrowUp", None, 3), Some(2));
+        assert_eq!(next_active_index("ArrowUp", Some(0), 3), Some(2));
+        assert_eq!(next_active_index("ArrowUp", Some(2), 3), Some(1));
+        assert_eq!(next_active_index("Home", Some(2), 3), Some(0));
+        assert_eq!(next_active_index("End", None, 3), Some(2));
+        assert_eq!(next_active_index("Enter", Some(1), 3), None);
+        assert_eq!(next_active_index("ArrowDown", None, 0), None);
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_listbox_should_set_option_position() {
+        let t = render!({
+            html! {
+                <Listbox>
+                    <ListboxOption id="option-1">{"Option 1"}</ListboxOption>
+                    <ListboxOption id="option-2">{"Option 2"}</ListboxOption>
+                    <ListboxOption id="option-3">{"Option 3"}</ListboxOption>
+                </Listbox>
+            }
+        })
+        .await;
+
+        let options = t.query_all_by_role("option");
+        assert_eq!(options.len(), 3);
+
+        for (index, option) in options.iter().enumerate() {
+            assert_eq!(
+                option.attribute("aria-posinset"),
+                (index + 1).to_string().into()
+            );
+            assert_eq!(option.attribute("aria-setsize"), "3".to_string().into());
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_listbox_native_should_render_select() {
+        let t = render!({
+            html! {
+                <Listbox native={true} multiple={true} default_selected={ListboxSelected::Multiple(vec!["option-2".into()])}>
+                    <ListboxOption id="option-1">{"Option 1"}</ListboxOption>
+                    <ListboxOption id="option-2">{"Option 2"}</ListboxOption>
+                </Listbox>
+            }
+        })
+        .await;
+
+        let options = t.query_all_by_role("option");
+        assert_eq!(options.len(), 2);
+
+        assert_eq!(options[0].text(), "Option 1");
+        assert_eq!(options[1].text(), "Option 2");
+    }
+
+    /// Selects the options with the given values the way a user would, then fires `change`
+    async fn change_native_select(values: &[&str]) {
+        let select = gloo_utils::document()
+            .query_selector("select")
+            .ok()
+            .flatten()
+            .and_then(|select| select.dyn_into::<HtmlSelectElement>().ok())
+            .expect("Failed to find the select");
+
+        let options = select.options();
+
+        for index in 0..options.length() {
+            if let Some(option) = options
+                .item(index)
+                .and_then(|option| option.dyn_into::<HtmlOptionElement>().ok())
+            {
+                option.set_selected(values.contains(&option.value().as_str()));
+            }
+        }
+
+        let init = web_sys::EventInit::new();
+        init.set_bubbles(true);
+
+        let change = Event::new_with_event_init_dict("change", &init).unwrap();
+        select.dispatch_event(&change).unwrap();
+
+        yew::platform::time::sleep(std::time::Duration::ZERO).await;
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_listbox_native_single_change() {
+        let t = render!({
+            let selected = use_state(|| ListboxSelected::Single(Some("option-2".into())));
+
+            let on_selected_change = use_callback(selected.clone(), |next, selected| {
+                selected.set(next);
+            });
+
+            use_remember_value((*selected).clone());
+
+            html! {
+                <Listbox native={true} {on_selected_change} default_selected={(*selected).clone()}>
+                    <ListboxOption id="option-1">{"Option 1"}</ListboxOption>
+                    <ListboxOption id="option-2">{"Option 2"}</ListboxOption>
+                </Listbox>
+            }
+        })
+        .await;
+
+        // Choosing an option above the current one doesn't bring the old one back
+        change_native_select(&["option-1"]).await;
+
+        assert_eq!(
+            t.get_remembered_value::<ListboxSelected>(),
+            ListboxSelected::Single(Some("option-1".into()))
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_listbox_native_multiple_change() {
+        let t = render!({
+            let changes = use_state(Vec::<ListboxSelected>::new);
+
+            let on_selected_change = use_callback(changes.clone(), |selected, changes| {
+                let mut next = (**changes).clone();
+                next.push(selected);
+                changes.set(next);
+            });
+
+            use_remember_value((*changes).clone());
+
+            html! {
+                <Listbox native={true} multiple={true} {on_selected_change}>
+                    <ListboxOption id="option-1">{"Option 1"}</ListboxOption>
+                    <ListboxOption id="option-2">{"Option 2"}</ListboxOption>
+                    <ListboxOption id="option-3">{"Option 3"}</ListboxOption>
+                </Listbox>
+            }
+        })
+        .await;
+
+        change_native_select(&["option-1", "option-3"]).await;
+
+        // One change carrying the whole selection
+        assert_eq!(
+            t.get_remembered_value::<Vec<ListboxSelected>>(),
+            vec![ListboxSelected::Multiple(vec![
+                "option-1".into(),
+                "option-3".into()
+            ])]
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_listbox_single_should_only_report_user_changes() {
+        let t = render!({
+            let changes = use_state(Vec::<ListboxSelected>::new);
+
+            let on_selected_change = use_callback(changes.clone(), |selected, changes| {
+                let mut next = (**changes).clone();
+                next.push(selected);
+                changes.set(next);
+            });
+
+            use_remember_value((*changes).clone());
+
+            html! {
+                <Listbox {on_selected_change}>
+                    <ListboxOption id="option-1">{"Option 1"}</ListboxOption>
+                    <ListboxOption id="option-2">{"Option 2"}</ListboxOption>
+                </Listbox>
+            }
+        })
+        .await;
+
+        assert_eq!(t.get_remembered_value::<Vec<ListboxSelected>>(), vec![]);
+
+        t.query_by_text("Option 2").click().await;
+        t.query_by_text("Option 1").click().await;
+
+        let options = t.query_all_by_role("option");
+        assert_eq!(
+            options[0].attribute("aria-selected"),
+            "true".to_string().into()
+        );
+        assert_eq!(
+            options[1].attribute("aria-selected"),
+            "false".to_string().into()
+        );
+
+        assert_eq!(
+            t.get_remembered_value::<Vec<ListboxSelected>>(),
+            vec![
+                ListboxSelected::Single(Some("option-2".into())),
+                ListboxSelected::Single(Some("option-1".into())),
+            ]
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_listbox_controller_should_select_all_and_clear() {
+        let t = render!({
+            let controller_ref = use_mut_ref(|| None::<ListboxController>);
+            let selected = use_state(|| None::<ListboxSelected>);
+
+            let on_selected_change = use_callback(selected.clone(), |next, selected| {
+                selected.set(Some(next));
+            });
+
+            let on_select_all =
+                use_callback(controller_ref.clone(), |_: MouseEvent, controller_ref| {
+                    if let Some(controller) = controller_ref.borrow().as_ref() {
+                        controller.select_all.emit(());
+                    }
+                });
+
+            let on_clear = use_callback(controller_ref.clone(), |_: MouseEvent, controller_ref| {
+                if let Some(controller) = controller_ref.borrow().as_ref() {
+                    controller.clear.emit(());
+                }
+            });
+
+            use_remember_value((*selected).clone());
+
+            html! {
+                <>
+                    <Listbox multiple={true} controller_ref={controller_ref} {on_selected_change}>
+                        <ListboxOption id="option-1">{"Option 1"}</ListboxOption>
+                        <ListboxOption id="option-2">{"Option 2"}</ListboxOption>
+                    </Listbox>
+                    <button onclick={on_select_all}>{"Select all"}</button>
+                    <button onclick={on_clear}>{"Clear"}</button>
+                </>
+            }
+        })
+        .await;
+
+        t.query_by_text("Select all").click().await;
+
+        for option in t.query_all_by_role("option") {
+            assert_eq!(option.attribute("aria-checked"), "true".to_string().into());
+        }
+
+        assert_eq!(
+            t.get_remembered_value::<Option<ListboxSelected>>(),
+            Some(ListboxSelected::Multiple(vec![
+                "option-1".into(),
+                "option-2".into()
+            ]))
+        );
+
+        t.query_by_text("Clear").click().await;
+
+        for option in t.query_all_by_role("option") {
+            assert_eq!(option.attribute("aria-checked"), "false".to_string().into());
+        }
+
+        assert_eq!(
+            t.get_remembered_value::<Option<ListboxSelected>>(),
+            Some(ListboxSelected::Multiple(Vec::new()))
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_listbox_space_without_an_active_option_does_nothing() {
+        let t = render!({
+            let changes = use_state(Vec::<ListboxSelected>::new);
+
+            let on_selected_change = use_callback(changes.clone(), |selected, changes| {
+                let mut next = (**changes).clone();
+                next.push(selected);
+                changes.set(next);
+            });
+
+            use_remember_value((*changes).clone());
+
+            html! {
+                <Listbox {on_selected_change}>
+                    <ListboxOption id="option-1">{"Option 1"}</ListboxOption>
+                    <ListboxOption id="option-2">{"Option 2"}</ListboxOption>
+                </Listbox>
+            }
+        })
+        .await;
+
+        let t = t.query_by_role("listbox").keydown(" ").await;
+        assert_eq!(t.get_remembered_value::<Vec<ListboxSelected>>(), vec![]);
+
+        let t = t.query_by_role("listbox").keydown("ArrowDown").await;
+        let t = t.query_by_role("listbox").keydown(" ").await;
+
+        assert_eq!(
+            t.get_remembered_value::<Vec<ListboxSelected>>(),
+            vec![ListboxSelected::Single(Some("option-1".into()))]
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_virtual_listbox_space_without_an_active_option_does_nothing() {
+        let t = render!({
+            let changes = use_state(Vec::<ListboxSelected>::new);
+
+            let on_selected_change = use_callback(changes.clone(), |selected, changes| {
+                let mut next = (**changes).clone();
+                next.push(selected);
+                changes.set(next);
+            });
+
+            use_remember_value((*changes).clone());
+
+            html! {
+                <VirtualListbox
+                    total_items={100}
+                    item_height={20.0}
+                    viewport_height={100.0}
+                    option_id={Callback::from(|index: usize| AttrValue::from(format!("option-{index}")))}
+                    render_option={Callback::from(|index: usize| html! {
+                        <ListboxOption id={format!("option-{index}")}>{format!("Option {index}")}</ListboxOption>
+                    })}
+                    {on_selected_change}
+                />
+            }
+        })
+        .await;
+
+        let t = t.query_by_role("listbox").keydown(" ").await;
+        assert_eq!(t.get_remembered_value::<Vec<ListboxSelected>>(), vec![]);
+
+        let t = t.query_by_role("listbox").keydown("ArrowDown").await;
+        let t = t.query_by_role("listbox").keydown(" ").await;
+
+        assert_eq!(
+            t.get_remembered_value::<Vec<ListboxSelected>>(),
+            vec![ListboxSelected::Single(Some("option-0".into()))]
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_virtual_listbox_controller_selects_the_options_that_are_not_rendered() {
+        let t = render!({
+            let controller_ref = use_mut_ref(|| None::<ListboxController>);
+            let selected = use_state(|| None::<ListboxSelected>);
+
+            let on_selected_change = use_callback(selected.clone(), |next, selected| {
+                selected.set(Some(next));
+            });
+
+            let on_select_all =
+                use_callback(controller_ref.clone(), |_: MouseEvent, controller_ref| {
+                    if let Some(controller) = controller_ref.borrow().as_ref() {
+                        controller.select_all.emit(());
+                    }
+                });
+
+            use_remember_value((*selected).clone());
+
+            html! {
+                <>
+                    <VirtualListbox
+                        multiple={true}
+                        total_items={20}
+                        item_height={20.0}
+                        viewport_height={60.0}
+                        option_id={Callback::from(|index: usize| AttrValue::from(format!("option-{index}")))}
+                        render_option={Callback::from(|index: usize| html! {
+                            <ListboxOption id={format!("option-{index}")}>{format!("Option {index}")}</ListboxOption>
+                        })}
+                        controller_ref={controller_ref}
+                        {on_selected_change}
+                    />
+                    <button onclick={on_select_all}>{"Select all"}</button>
+                </>
+            }
+        })
+        .await;
+
+        t.query_by_text("Select all").click().await;
+
+        assert_eq!(
+            t.get_remembered_value::<Option<ListboxSelected>>(),
+            Some(ListboxSelected::Multiple(
+                (0..20)
+                    .map(|index| AttrValue::from(format!("option-{index}")))
+                    .collect()
+            ))
+        );
+    }
+}
@@ -0,0 +1,425 @@
+use web_sys::wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement};
+use yew::prelude::*;
+use yewlish_popover::{
+    Popover, PopoverAlign, PopoverContent, PopoverSide, PopoverTrigger, PopoverTriggerRenderAsProps,
+};
+use yewlish_roving_focus::RovingFocus;
+use yewlish_utils::{enums::Orientation, hooks::use_controllable_state};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct MenubarContext {
+    /// The value of the open menu, empty when every menu is closed
+    pub value: AttrValue,
+    pub orientation: Orientation,
+    pub r#loop: bool,
+    pub node_ref: NodeRef,
+    pub on_value_change: Callback<AttrValue>,
+}
+
+impl MenubarContext {
+    /// Focuses the trigger `offset` menus away from the `from` menu and opens its menu.
+    pub fn move_to(&self, from: &str, offset: isize) {
+        let Some(menubar) = self.node_ref.cast::<Element>() else {
+            return;
+        };
+
+        let Ok(triggers) = menubar.query_selector_all("[data-menubar-trigger]:not([disabled])")
+        else {
+            return;
+        };
+
+        let triggers = (0..triggers.length())
+            .filter_map(|index| triggers.item(index))
+            .filter_map(|node| node.dyn_into::<HtmlElement>().ok())
+            .collect::<Vec<_>>();
+
+        let Some(current) = triggers
+            .iter()
+            .position(|trigger| trigger.get_attribute("data-value").as_deref() == Some(from))
+        else {
+            return;
+        };
+
+        let len = triggers.len() as isize;
+        let next = current as isize + offset;
+
+        let next = if self.r#loop {
+            next.rem_euclid(len)
+        } else if (0..len).contains(&next) {
+            next
+        } else {
+            return;
+        };
+
+        let trigger = &triggers[next as usize];
+
+        if let Err(error) = trigger.focus() {
+            log::error!("Failed to focus the menubar trigger: {error:?}");
+        }
+
+        if let Some(value) = trigger.get_attribute("data-value") {
+            self.on_value_change.emit(value.into());
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct MenubarMenuContext {
+    pub value: AttrValue,
+}
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct MenubarProps {
+    #[prop_or_default]
+    pub children: Children,
+    #[prop_or_default]
+    pub class: Option<AttrValue>,
+    #[prop_or_default]
+    pub value: Option<AttrValue>,
+    #[prop_or_default]
+    pub default_value: Option<AttrValue>,
+    #[prop_or_default]
+    pub on_value_change: Callback<AttrValue>,
+    #[prop_or_default]
+    pub orientation: Orientation,
+    #[prop_or(true)]
+    pub r#loop: bool,
+}
+
+/// A desktop-style bar of menus built on top of `Popover` and `RovingFocus`.
+///
+/// Arrow keys move between the top-level triggers. While a menu is open, ArrowLeft/ArrowRight
+/// inside it (or hovering/focusing another trigger) switch to the neighbouring menu.
+///
+/// # Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use menubar::{Menubar, MenubarContent, MenubarItem, MenubarMenu, MenubarTrigger};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Menubar>
+///             <MenubarMenu value="file">
+///                 <MenubarTrigger>{"File"}</MenubarTrigger>
+///                 <MenubarContent>
+///                     <MenubarItem>{"New"}</MenubarItem>
+///                     <MenubarItem>{"Open"}</MenubarItem>
+///                 </MenubarContent>
+///             </MenubarMenu>
+///             <MenubarMenu value="edit">
+///                 <MenubarTrigger>{"Edit"}</MenubarTrigger>
+///                 <MenubarContent>
+///                     <MenubarItem>{"Undo"}</MenubarItem>
+///                 </MenubarContent>
+///             </MenubarMenu>
+///         </Menubar>
+///     }
+/// }
+/// ```
+#[function_component(Menubar)]
+pub fn menubar(props: &MenubarProps) -> Html {
+    let (value, dispatch) = use_controllable_state(
+        props.default_value.clone(),
+        props.value.clone(),
+        props.on_value_change.clone(),
+    );
+
+    let node_ref = use_node_ref();
+
+    let on_value_change = use_callback(dispatch.clone(), |next: AttrValue, dispatch| {
+        dispatch.emit(Box::new(move |_| next.clone()));
+    });
+
+    let context = MenubarContext {
+        value: value.borrow().clone(),
+        orientation: props.orientation.clone(),
+        r#loop: props.r#loop,
+        node_ref: node_ref.clone(),
+        on_value_change,
+    };
+
+    html! {
+        <ContextProvider<MenubarContext> {context}>
+            <div ref={node_ref} style="display: contents;">
+                <RovingFocus
+                    role="menubar"
+                    class={&props.class}
+                    orientation={props.orientation.clone()}
+                    r#loop={props.r#loop}
+                >
+                    {for props.children.iter()}
+                </RovingFocus>
+            </div>
+        </ContextProvider<MenubarContext>>
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct MenubarMenuProps {
+    #[prop_or_default]
+    pub children: Children,
+    #[prop_or_default]
+    pub class: Option<AttrValue>,
+    pub value: AttrValue,
+}
+
+#[function_component(MenubarMenu)]
+pub fn menubar_menu(props: &MenubarMenuProps) -> Html {
+    let context = use_context::<MenubarContext>().expect("MenubarMenu must be a child of Menubar");
+
+    let on_open_change = use_callback(
+        (context.clone(), props.value.clone()),
+        |open: bool, (context, value)| {
+            if open {
+                context.on_value_change.emit(value.clone());
+            } else if context.value == *value {
+                context.on_value_change.emit(AttrValue::default());
+            }
+        },
+    );
+
+    html! {
+        <ContextProvider<MenubarMenuContext> context={MenubarMenuContext { value: props.value.clone() }}>
+            <Popover
+                open={context.value == props.value}
+                {on_open_change}
+                class={&props.class}
+            >
+                {for props.children.iter()}
+            </Popover>
+        </ContextProvider<MenubarMenuContext>>
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct MenubarTriggerProps {
+    #[prop_or_default]
+    pub children: Children,
+    #[prop_or_default]
+    pub class: Option<AttrValue>,
+    #[prop_or_default]
+    pub disabled: bool,
+}
+
+#[function_component(MenubarTrigger)]
+pub fn menubar_trigger(props: &MenubarTriggerProps) -> Html {
+    let context =
+        use_context::<MenubarContext>().expect("MenubarTrigger must be a child of Menubar");
+    let menu =
+        use_context::<MenubarMenuContext>().expect("MenubarTrigger must be a child of MenubarMenu");
+
+    let open_on_keydown = use_callback(
+        (context.clone(), menu.value.clone()),
+        |event: KeyboardEvent, (context, value)| {
+            let open_key = match context.orientation {
+                Orientation::Horizontal => "ArrowDown",
+                Orientation::Vertical => "ArrowRight",
+            };
+
+            if event.key() != open_key {
+                return;
+            }
+
+            // Keep the menubar's roving focus from handling the same key
+            event.prevent_default();
+            event.stop_propagation();
+            context.on_value_change.emit(value.clone());
+        },
+    );
+
+    // Follow the user to another trigger while a menu is open
+    let switch_open_menu = use_callback(
+        (context.clone(), menu.value.clone()),
+        |(), (context, value)| {
+            if !context.value.is_empty() && context.value != *value {
+                context.on_value_change.emit(value.clone());
+            }
+        },
+    );
+
+    let render_as = {
+        let value = menu.value.clone();
+        let disabled = props.disabled;
+
+        Callback::from(move |trigger: PopoverTriggerRenderAsProps| {
+            let switch_on_focus = switch_open_menu.reform(|_: FocusEvent| ());
+            let switch_on_pointer_enter = switch_open_menu.reform(|_: PointerEvent| ());
+
+            html! {
+                <button
+                    ref={trigger.r#ref.clone()}
+                    type="button"
+                    role="menuitem"
+                    aria-haspopup="menu"
+                    aria-expanded={trigger.is_open.to_string()}
+                    data-state={if trigger.is_open { "open" } else { "closed" }}
+                    data-menubar-trigger=""
+                    data-value={value.clone()}
+                    class={&trigger.class}
+                    {disabled}
+                    onclick={trigger.toggle}
+                    onkeydown={&open_on_keydown}
+                    onfocus={switch_on_focus}
+                    onpointerenter={switch_on_pointer_enter}
+                >
+                    {trigger.children}
+                </button>
+            }
+        })
+    };
+
+    html! {
+        <PopoverTrigger class={&props.class} {render_as}>
+            {for props.children.iter()}
+        </PopoverTrigger>
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct MenubarContentProps {
+    #[prop_or_default]
+    pub children: Children,
+    #[prop_or_default]
+    pub class: Option<AttrValue>,
+    /// Defaults to the bottom of a horizontal menubar and the right of a vertical one
+    #[prop_or_default]
+    pub side: Option<PopoverSide>,
+    #[prop_or(PopoverAlign::Start)]
+    pub align: PopoverAlign,
+}
+
+#[function_component(MenubarContent)]
+pub fn menubar_content(props: &MenubarContentProps) -> Html {
+    let context =
+        use_context::<MenubarContext>().expect("MenubarContent must be a child of Menubar");
+    let menu =
+        use_context::<MenubarMenuContext>().expect("MenubarContent must be a child of MenubarMenu");
+
+    let move_between_menus = use_callback(
+        (context.clone(), menu.value.clone()),
+        |event: KeyboardEvent, (context, value)| {
+            if context.orientation != Orientation::Horizontal {
+                return;
+            }
+
+            let offset = match event.key().as_str() {
+                "ArrowLeft" => -1,
+                "ArrowRight" => 1,
+                _ => return,
+            };
+
+            event.prevent_default();
+            event.stop_propagation();
+            context.move_to(value, offset);
+        },
+    );
+
+    let side = props.side.clone().unwrap_or(match context.orientation {
+        Orientation::Horizontal => PopoverSide::Bottom,
+        Orientation::Vertical => PopoverSide::Right,
+    });
+
+    html! {
+        <PopoverContent class={&props.class} {side} align={props.align.clone()}>
+            <div role="menu" aria-orientation="vertical">
+                <RovingFocus
+                    orientation={Orientation::Vertical}
+                    r#loop={context.r#loop}
+                    on_cross_axis_key={move_between_menus}
+                >
+                    {for props.children.iter()}
+                </RovingFocus>
+            </div>
+        </PopoverContent>
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct MenubarItemProps {
+    #[prop_or_default]
+    pub children: Children,
+    #[prop_or_default]
+    pub class: Option<AttrValue>,
+    #[prop_or_default]
+    pub disabled: bool,
+    #[prop_or_default]
+    pub on_select: Callback<()>,
+}
+
+#[function_component(MenubarItem)]
+pub fn menubar_item(props: &MenubarItemProps) -> Html {
+    let context = use_context::<MenubarContext>().expect("MenubarItem must be a child of Menubar");
+
+    let select = use_callback(
+        (context.clone(), props.on_select.clone(), props.disabled),
+        |_event: MouseEvent, (context, on_select, disabled)| {
+            if *disabled {
+                return;
+            }
+
+            on_select.emit(());
+            context.on_value_change.emit(AttrValue::default());
+        },
+    );
+
+    html! {
+        <button
+            type="button"
+            role="menuitem"
+            class={&props.class}
+            disabled={props.disabled}
+            data-disabled={props.disabled.then_some("")}
+            onclick={select}
+        >
+            {for props.children.iter()}
+        </button>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+    use yewlish_testing_tools::*;
+
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    async fn test_arrow_right_opens_the_next_menu() {
+        let t = render!({
+            html! {
+                <Menubar>
+                    <MenubarMenu value="file">
+                        <MenubarTrigger>{"File"}</MenubarTrigger>
+                        <MenubarContent>
+                            <MenubarItem>{"New"}</MenubarItem>
+                        </MenubarContent>
+                    </MenubarMenu>
+                    <MenubarMenu value="edit">
+                        <MenubarTrigger>{"Edit"}</MenubarTrigger>
+                        <MenubarContent>
+                            <MenubarItem>{"Undo"}</MenubarItem>
+                        </MenubarContent>
+                    </MenubarMenu>
+                </Menubar>
+            }
+        })
+        .await;
+
+        t.query_by_text("File").click().await;
+        assert!(t.query_by_text("New").exists());
+
+        // The key goes through the roving focus of the open menu first
+        t.query_by_text("New").keydown("ArrowRight").await;
+
+        assert!(!t.query_by_text("New").exists());
+        assert!(t.query_by_text("Undo").exists());
+        assert_eq!(
+            t.query_by_text("Edit").attribute("aria-expanded"),
+            Some("true".to_string())
+        );
+    }
+}
@@ -0,0 +1,499 @@
+use web_sys::wasm_bindgen::JsCast;
+use web_sys::{ClipboardEvent, HtmlInputElement};
+use yew::prelude::*;
+use yewlish_roving_focus::hooks::use_roving_iterator::{IndexRoving, RovingIterator};
+use yewlish_utils::enums::Dir;
+
+/// Which characters the boxes of an `OtpInput` accept
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum OtpInputType {
+    /// Digits only, touch devices show a numeric keyboard
+    #[default]
+    Number,
+    /// Any character but whitespace
+    Text,
+}
+
+impl OtpInputType {
+    pub fn accepts(&self, character: char) -> bool {
+        match self {
+            OtpInputType::Number => character.is_ascii_digit(),
+            OtpInputType::Text => !character.is_whitespace(),
+        }
+    }
+
+    fn input_mode(&self) -> &'static str {
+        match self {
+            OtpInputType::Number => "numeric",
+            OtpInputType::Text => "text",
+        }
+    }
+}
+
+/// Writes the accepted characters of `text` into the boxes from `start` on, the ones that
+/// don't fit are dropped.
+///
+/// Returns the box to focus next: the one after the last written character, or the last box
+/// once the code is filled up to the end.
+pub fn distribute(
+    values: &mut [Option<char>],
+    start: usize,
+    text: &str,
+    r#type: &OtpInputType,
+) -> usize {
+    let mut index = start;
+
+    for character in text.chars().filter(|character| r#type.accepts(*character)) {
+        let Some(value) = values.get_mut(index) else {
+            break;
+        };
+
+        *value = Some(character);
+        index += 1;
+    }
+
+    index.min(values.len().saturating_sub(1))
+}
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct OtpInputProps {
+    /// How many characters the code has, one box each
+    #[prop_or(6)]
+    pub length: usize,
+    #[prop_or_default]
+    pub r#type: OtpInputType,
+    /// Hides the entered characters like a password field
+    #[prop_or_default]
+    pub mask: bool,
+    #[prop_or_default]
+    pub disabled: bool,
+    #[prop_or(Dir::Ltr)]
+    pub dir: Dir,
+    /// Submits the whole code with forms through a hidden input of this name
+    #[prop_or_default]
+    pub name: Option<AttrValue>,
+    #[prop_or_default]
+    pub class: Option<AttrValue>,
+    #[prop_or_default]
+    pub input_class: Option<AttrValue>,
+    /// Called with the characters entered so far whenever a box changes
+    #[prop_or_default]
+    pub on_change: Callback<String>,
+    /// Called with the code once every box is filled
+    #[prop_or_default]
+    pub on_complete: Callback<String>,
+}
+
+/// A one-time code input rendering a box per character.
+///
+/// Typing a character moves to the next box and Backspace in an empty box clears the previous
+/// one. A pasted or autofilled code is spread over the boxes, from the first one when it's long
+/// enough to fill them all. Arrow keys, Home and End move between the boxes.
+///
+/// # Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use otp_input::OtpInput;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let code = use_state(String::new);
+///
+///     let on_complete = {
+///         let code = code.clone();
+///         Callback::from(move |value: String| code.set(value))
+///     };
+///
+///     html! {
+///         <>
+///             <OtpInput length={6} {on_complete} />
+///             <p>{(*code).clone()}</p>
+///         </>
+///     }
+/// }
+/// ```
+#[function_component(OtpInput)]
+pub fn otp_input(props: &OtpInputProps) -> Html {
+    let values = use_state_eq(|| vec![None; props.length]);
+
+    let refs = use_memo(props.length, |length| {
+        (0..*length).map(|_| NodeRef::default()).collect::<Vec<_>>()
+    });
+
+    use_effect_with(props.length, {
+        let values = values.clone();
+
+        move |length| {
+            if values.len() != *length {
+                let mut next = (*values).clone();
+                next.resize(*length, None);
+                values.set(next);
+            }
+        }
+    });
+
+    let focus_box = use_callback(refs.clone(), |index: usize, refs| {
+        if let Some(input) = refs.get(index).and_then(NodeRef::cast::<HtmlInputElement>) {
+            let _ = input.focus();
+            input.select();
+        }
+    });
+
+    let commit = use_callback(
+        (
+            values.clone(),
+            focus_box.clone(),
+            props.on_change.clone(),
+            props.on_complete.clone(),
+        ),
+        |(next, focus): (Vec<Option<char>>, Option<usize>),
+         (values, focus_box, on_change, on_complete)| {
+            if **values != next {
+                let code: String = next.iter().flatten().collect();
+
+                on_change.emit(code.clone());
+
+                if next.iter().all(Option::is_some) {
+                    on_complete.emit(code);
+                }
+
+                values.set(next);
+            }
+
+            if let Some(index) = focus {
+                focus_box.emit(index);
+            }
+        },
+    );
+
+    let on_input = use_callback(
+        (values.clone(), props.r#type.clone(), commit.clone()),
+        |(index, event): (usize, InputEvent), (values, r#type, commit)| {
+            let Some(input) = event.target_dyn_into::<HtmlInputElement>() else {
+                return;
+            };
+
+            let mut next = (**values).clone();
+
+            let Some(previous) = next.get(index).copied() else {
+                return;
+            };
+
+            let mut text = input.value();
+
+            if text.is_empty() {
+                next[index] = None;
+                commit.emit((next, None));
+                return;
+            }
+
+            // Typing into a filled box without selecting it appends, keep the new character only
+            if let Some(previous) = previous {
+                if text.chars().count() == 2 {
+                    if let Some(position) = text.find(previous) {
+                        text.remove(position);
+                    }
+                }
+            }
+
+            let typed = text.chars().any(|character| r#type.accepts(character));
+            let focus = distribute(&mut next, index, &text, r#type);
+
+            // Rejected characters don't change the state, so Yew wouldn't restore the box
+            input.set_value(&next[index].map(String::from).unwrap_or_default());
+
+            commit.emit((next, typed.then_some(focus)));
+        },
+    );
+
+    let on_keydown = use_callback(
+        (
+            values.clone(),
+            props.dir.clone(),
+            focus_box.clone(),
+            commit.clone(),
+        ),
+        |(index, event): (usize, KeyboardEvent), (values, dir, focus_box, commit)| {
+            let mut next = (**values).clone();
+
+            if index >= next.len() {
+                return;
+            }
+
+            let mut roving = IndexRoving {
+                current: index as u32,
+                length: next.len() as u32,
+                r#loop: false,
+            };
+
+            let target = match event.key().as_str() {
+                "Backspace" => {
+                    event.prevent_default();
+
+                    if next[index].is_some() {
+                        next[index] = None;
+                        commit.emit((next, Some(index)));
+                    } else if index > 0 {
+                        next[index - 1] = None;
+                        commit.emit((next, Some(index - 1)));
+                    }
+
+                    return;
+                }
+                "Delete" => {
+                    event.prevent_default();
+                    next[index] = None;
+                    commit.emit((next, Some(index)));
+                    return;
+                }
+                "ArrowLeft" => roving.prev(dir),
+                "ArrowRight" => roving.next(dir),
+                "Home" => roving.first(&Dir::Ltr),
+                "End" => roving.last(&Dir::Ltr),
+                _ => return,
+            };
+
+            event.prevent_default();
+
+            if let Some(target) = target {
+                focus_box.emit(target as usize);
+            }
+        },
+    );
+
+    let on_paste = use_callback(
+        (values.clone(), props.r#type.clone(), commit.clone()),
+        |(index, event): (usize, Event), (values, r#type, commit)| {
+            let Some(text) = event
+                .dyn_ref::<ClipboardEvent>()
+                .and_then(ClipboardEvent::clipboard_data)
+                .and_then(|data| data.get_data("text").ok())
+            else {
+                return;
+            };
+
+            event.prevent_default();
+
+            let mut next = (**values).clone();
+
+            let accepted = text
+                .chars()
+                .filter(|character| r#type.accepts(*character))
+                .count();
+
+            // A whole code replaces the current one, whichever box it was pasted into
+            let start = if accepted >= next.len() { 0 } else { index };
+            let focus = distribute(&mut next, start, &text, r#type);
+
+            commit.emit((next, (accepted > 0).then_some(focus)));
+        },
+    );
+
+    let select_on_focus = use_callback((), |event: FocusEvent, ()| {
+        if let Some(input) = event.target_dyn_into::<HtmlInputElement>() {
+            input.select();
+        }
+    });
+
+    let code: String = values.iter().flatten().collect();
+    let complete = !values.is_empty() && values.iter().all(Option::is_some);
+
+    html! {
+        <div
+            role="group"
+            class={&props.class}
+            dir={props.dir.clone()}
+            data-complete={complete.then_some("")}
+            data-disabled={props.disabled.then_some("")}
+        >
+            {for refs.iter().enumerate().map(|(index, node_ref)| {
+                let value = values.get(index).copied().flatten();
+
+                html! {
+                    <input
+                        key={index}
+                        ref={node_ref.clone()}
+                        type={if props.mask { "password" } else { "text" }}
+                        inputmode={props.r#type.input_mode()}
+                        pattern={(props.r#type == OtpInputType::Number).then_some("[0-9]*")}
+                        autocomplete="one-time-code"
+                        aria-label={format!("Character {} of {}", index + 1, props.length)}
+                        class={&props.input_class}
+                        disabled={props.disabled}
+                        value={value.map(String::from).unwrap_or_default()}
+                        data-filled={value.is_some().then_some("")}
+                        oninput={on_input.reform(move |event| (index, event))}
+                        onkeydown={on_keydown.reform(move |event| (index, event))}
+                        onpaste={on_paste.reform(move |event| (index, event))}
+                        onfocus={&select_on_focus}
+                    />
+                }
+            })}
+            if let Some(name) = props.name.clone() {
+                <input type="hidden" {name} value={code} />
+            }
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+    use yewlish_testing_tools::*;
+
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    fn boxes() -> Vec<HtmlInputElement> {
+        let inputs = web_sys::window()
+            .and_then(|window| window.document())
+            .and_then(|document| document.query_selector_all("input[inputmode]").ok())
+            .expect("Failed to query the boxes");
+
+        (0..inputs.length())
+            .filter_map(|index| inputs.item(index))
+            .filter_map(|node| node.dyn_into().ok())
+            .collect()
+    }
+
+    async fn type_into(input: &HtmlInputElement, text: &str) {
+        input.set_value(text);
+
+        let init = web_sys::InputEventInit::new();
+        init.set_bubbles(true);
+
+        let event = web_sys::InputEvent::new_with_event_init_dict("input", &init)
+            .expect("Failed to create the input event");
+
+        let _ = input.dispatch_event(&event);
+        yew::platform::time::sleep(std::time::Duration::ZERO).await;
+    }
+
+    async fn paste_into(input: &HtmlInputElement, text: &str) {
+        let data = web_sys::DataTransfer::new().expect("Failed to create the data transfer");
+        let _ = data.set_data("text", text);
+
+        let init = web_sys::ClipboardEventInit::new();
+        init.set_bubbles(true);
+        init.set_cancelable(true);
+        init.set_clipboard_data(Some(&data));
+
+        let event = ClipboardEvent::new_with_event_init_dict("paste", &init)
+            .expect("Failed to create the paste event");
+
+        let _ = input.dispatch_event(&event);
+        yew::platform::time::sleep(std::time::Duration::ZERO).await;
+    }
+
+    fn values() -> String {
+        boxes().iter().map(HtmlInputElement::value).collect()
+    }
+
+    #[test]
+    fn test_distribute() {
+        let mut values = vec![None; 4];
+
+        assert_eq!(distribute(&mut values, 1, "7", &OtpInputType::Number), 2);
+        assert_eq!(values, vec![None, Some('7'), None, None]);
+
+        // Non-digits are skipped and the overflow is dropped
+        assert_eq!(
+            distribute(&mut values, 0, "1-2 3a45", &OtpInputType::Number),
+            3
+        );
+        assert_eq!(values, vec![Some('1'), Some('2'), Some('3'), Some('4')]);
+
+        assert_eq!(distribute(&mut values, 2, "x", &OtpInputType::Number), 2);
+        assert_eq!(distribute(&mut values, 2, "x", &OtpInputType::Text), 3);
+        assert_eq!(values[2], Some('x'));
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_otp_input_auto_advance_and_backspace() {
+        let t = render!({
+            let changes = use_state(Vec::<String>::new);
+
+            let on_change = use_callback(changes.clone(), |code, changes| {
+                let mut next = (**changes).clone();
+                next.push(code);
+                changes.set(next);
+            });
+
+            use_remember_value((*changes).clone());
+
+            html! {
+                <OtpInput length={4} {on_change} />
+            }
+        })
+        .await;
+
+        let inputs = boxes();
+        assert_eq!(inputs.len(), 4);
+        assert_eq!(
+            inputs[0].get_attribute("autocomplete").as_deref(),
+            Some("one-time-code")
+        );
+
+        type_into(&inputs[0], "1").await;
+        type_into(&inputs[1], "a").await;
+
+        assert_eq!(values(), "1");
+        assert_eq!(t.get_remembered_value::<Vec<String>>(), vec!["1"]);
+
+        type_into(&inputs[1], "2").await;
+        assert_eq!(values(), "12");
+
+        // The cursor moved to the third box, which is empty, so Backspace clears the second one
+        t.query_by_selector("input[aria-label='Character 3 of 4']")
+            .keydown("Backspace")
+            .await;
+
+        assert_eq!(values(), "1");
+        assert_eq!(
+            t.get_remembered_value::<Vec<String>>(),
+            vec!["1", "12", "1"]
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_otp_input_paste_completes_the_code() {
+        let t = render!({
+            let completed = use_state(|| None::<String>);
+
+            let on_complete = use_callback(completed.clone(), |code, completed| {
+                completed.set(Some(code));
+            });
+
+            use_remember_value((*completed).clone());
+
+            html! {
+                <OtpInput length={4} name="code" {on_complete} />
+            }
+        })
+        .await;
+
+        // A whole code fills the boxes from the first one, wherever it's pasted
+        paste_into(&boxes()[2], "12-34").await;
+
+        assert_eq!(values(), "1234");
+        assert_eq!(
+            t.get_remembered_value::<Option<String>>(),
+            Some("1234".to_string())
+        );
+
+        let hidden: HtmlInputElement = web_sys::window()
+            .and_then(|window| window.document())
+            .and_then(|document| document.query_selector("input[name='code']").ok())
+            .flatten()
+            .and_then(|element| element.dyn_into().ok())
+            .expect("Failed to find the hidden input");
+
+        assert_eq!(hidden.value(), "1234");
+        assert_eq!(
+            t.query_by_selector("div[role='group']")
+                .attribute("data-complete"),
+            Some(String::new())
+        );
+    }
+}
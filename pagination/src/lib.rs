@@ -0,0 +1,318 @@
+use yew::prelude::*;
+use yewlish_utils::hooks::use_controllable_state;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum PaginationItem {
+    Page(usize),
+    /// Stands for the pages hidden between the leading boundary and the current page's siblings
+    StartEllipsis,
+    /// Stands for the pages hidden between the current page's siblings and the trailing boundary
+    EndEllipsis,
+}
+
+/// Lays out the pages of a pagination, starting from page 1.
+///
+/// Keeps `boundary_count` pages at both ends and `sibling_count` pages around `current_page`,
+/// replacing every other gap with an ellipsis. A gap of a single page shows that page instead,
+/// so the number of items stays the same wherever the current page is.
+#[must_use]
+pub fn pagination_items(
+    total_pages: usize,
+    current_page: usize,
+    sibling_count: usize,
+    boundary_count: usize,
+) -> Vec<PaginationItem> {
+    let total = total_pages as isize;
+    let page = current_page.clamp(1, total_pages.max(1)) as isize;
+    let siblings = sibling_count as isize;
+    let boundary = boundary_count as isize;
+
+    let start_pages = 1..=boundary.min(total);
+    let end_pages = (total - boundary + 1).max(boundary + 1)..=total;
+
+    let siblings_start = (page - siblings)
+        .min(total - boundary - siblings * 2 - 1)
+        .max(boundary + 2);
+
+    let siblings_end =
+        (page + siblings)
+            .max(boundary + siblings * 2 + 2)
+            .min(if end_pages.is_empty() {
+                total - 1
+            } else {
+                end_pages.start() - 2
+            });
+
+    let mut items = start_pages.map(page_item).collect::<Vec<_>>();
+
+    if siblings_start > boundary + 2 {
+        items.push(PaginationItem::StartEllipsis);
+    } else if boundary + 1 < total - boundary {
+        items.push(page_item(boundary + 1));
+    }
+
+    items.extend((siblings_start..=siblings_end).map(page_item));
+
+    if siblings_end < total - boundary - 1 {
+        items.push(PaginationItem::EndEllipsis);
+    } else if total - boundary > boundary {
+        items.push(page_item(total - boundary));
+    }
+
+    items.extend(end_pages.map(page_item));
+    items
+}
+
+fn page_item(page: isize) -> PaginationItem {
+    PaginationItem::Page(page as usize)
+}
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct PaginationProps {
+    pub total_pages: usize,
+    #[prop_or_default]
+    pub current_page: Option<usize>,
+    #[prop_or(1)]
+    pub default_page: usize,
+    #[prop_or_default]
+    pub on_page_change: Callback<usize>,
+    /// Pages shown on each side of the current page
+    #[prop_or(1)]
+    pub sibling_count: usize,
+    /// Pages always shown at the start and the end
+    #[prop_or(1)]
+    pub boundary_count: usize,
+    #[prop_or_default]
+    pub class: Option<AttrValue>,
+    #[prop_or(AttrValue::Static("Previous"))]
+    pub prev_label: AttrValue,
+    #[prop_or(AttrValue::Static("Next"))]
+    pub next_label: AttrValue,
+}
+
+/// A navigation landmark that lets the user move between the pages of a list.
+///
+/// # Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use pagination::Pagination;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let page = use_state(|| 1);
+///
+///     let on_page_change = use_callback(page.clone(), |next_page, page| {
+///         page.set(next_page);
+///     });
+///
+///     html! {
+///         <Pagination total_pages={20} current_page={*page} {on_page_change} />
+///     }
+/// }
+/// ```
+#[function_component(Pagination)]
+pub fn pagination(props: &PaginationProps) -> Html {
+    let (page, dispatch) = use_controllable_state(
+        Some(props.default_page),
+        props.current_page,
+        props.on_page_change.clone(),
+    );
+
+    let go_to = use_callback(
+        (dispatch, props.total_pages),
+        |next_page: usize, (dispatch, total_pages)| {
+            let next_page = next_page.clamp(1, (*total_pages).max(1));
+            dispatch.emit(Box::new(move |_| next_page));
+        },
+    );
+
+    let page = *page.borrow();
+    let items = pagination_items(
+        props.total_pages,
+        page,
+        props.sibling_count,
+        props.boundary_count,
+    );
+
+    let is_first = page <= 1;
+    let is_last = page >= props.total_pages;
+
+    html! {
+        <nav aria-label="pagination" class={&props.class}>
+            <ul>
+                <li>
+                    <button
+                        type="button"
+                        aria-label="Go to previous page"
+                        data-pagination-prev=""
+                        disabled={is_first}
+                        onclick={go_to.reform(move |_: MouseEvent| page.saturating_sub(1))}
+                    >
+                        {props.prev_label.clone()}
+                    </button>
+                </li>
+
+                {for items.into_iter().map(|item| match item {
+                    PaginationItem::Page(item_page) => {
+                        let is_current = item_page == page;
+
+                        html! {
+                            <li key={item_page}>
+                                <button
+                                    type="button"
+                                    aria-label={format!("Go to page {item_page}")}
+                                    aria-current={is_current.then_some("page")}
+                                    data-state={if is_current { "active" } else { "inactive" }}
+                                    data-page={item_page.to_string()}
+                                    onclick={go_to.reform(move |_: MouseEvent| item_page)}
+                                >
+                                    {item_page}
+                                </button>
+                            </li>
+                        }
+                    }
+                    PaginationItem::StartEllipsis | PaginationItem::EndEllipsis => html! {
+                        <li key={format!("{item:?}")}>
+                            <span aria-hidden="true" data-pagination-ellipsis="">{"…"}</span>
+                        </li>
+                    },
+                })}
+
+                <li>
+                    <button
+                        type="button"
+                        aria-label="Go to next page"
+                        data-pagination-next=""
+                        disabled={is_last}
+                        onclick={go_to.reform(move |_: MouseEvent| page + 1)}
+                    >
+                        {props.next_label.clone()}
+                    </button>
+                </li>
+            </ul>
+        </nav>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use PaginationItem::{EndEllipsis, Page, StartEllipsis};
+
+    #[test]
+    fn test_pagination_items_without_gaps() {
+        assert_eq!(
+            pagination_items(5, 3, 1, 1),
+            vec![Page(1), Page(2), Page(3), Page(4), Page(5)]
+        );
+    }
+
+    #[test]
+    fn test_pagination_items_at_the_start() {
+        assert_eq!(
+            pagination_items(10, 1, 1, 1),
+            vec![
+                Page(1),
+                Page(2),
+                Page(3),
+                Page(4),
+                Page(5),
+                EndEllipsis,
+                Page(10)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_pagination_items_in_the_middle() {
+        assert_eq!(
+            pagination_items(10, 5, 1, 1),
+            vec![
+                Page(1),
+                StartEllipsis,
+                Page(4),
+                Page(5),
+                Page(6),
+                EndEllipsis,
+                Page(10)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_pagination_items_at_the_end() {
+        assert_eq!(
+            pagination_items(10, 10, 1, 1),
+            vec![
+                Page(1),
+                StartEllipsis,
+                Page(6),
+                Page(7),
+                Page(8),
+                Page(9),
+                Page(10)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_pagination_items_show_a_single_hidden_page() {
+        // Page 2 would be the only page behind the ellipsis
+        assert_eq!(
+            pagination_items(10, 4, 1, 1),
+            vec![
+                Page(1),
+                Page(2),
+                Page(3),
+                Page(4),
+                Page(5),
+                EndEllipsis,
+                Page(10)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_pagination_items_with_wider_siblings_and_boundaries() {
+        assert_eq!(
+            pagination_items(20, 10, 2, 2),
+            vec![
+                Page(1),
+                Page(2),
+                StartEllipsis,
+                Page(8),
+                Page(9),
+                Page(10),
+                Page(11),
+                Page(12),
+                EndEllipsis,
+                Page(19),
+                Page(20)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_pagination_items_without_boundaries() {
+        assert_eq!(
+            pagination_items(10, 5, 1, 0),
+            vec![StartEllipsis, Page(4), Page(5), Page(6), EndEllipsis]
+        );
+    }
+
+    #[test]
+    fn test_pagination_items_with_few_pages() {
+        assert_eq!(pagination_items(0, 1, 1, 1), vec![]);
+        assert_eq!(pagination_items(1, 1, 1, 1), vec![Page(1)]);
+        assert_eq!(pagination_items(2, 2, 1, 1), vec![Page(1), Page(2)]);
+    }
+
+    #[test]
+    fn test_pagination_items_clamp_the_current_page() {
+        assert_eq!(
+            pagination_items(10, 42, 1, 1),
+            pagination_items(10, 10, 1, 1)
+        );
+    }
+}
@@ -1,26 +1,140 @@
 use std::{
+    cell::RefCell,
     fmt::{Display, Formatter},
     rc::Rc,
 };
 use web_sys::wasm_bindgen::JsCast;
-use web_sys::{wasm_bindgen::prelude::Closure, Element};
+use web_sys::{Element, HtmlElement};
 use yew::prelude::*;
 use yewlish_attr_passer::*;
 use yewlish_presence::*;
 use yewlish_roving_focus::helpers::get_focusable_element;
-use yewlish_utils::hooks::{use_controllable_state, use_interaction_outside, use_viewport_move};
+use yewlish_utils::hooks::{
+    use_controllable_open, use_escape_key, use_interaction_outside, use_viewport_move,
+    InteractionOutsideLayer,
+};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct PopoverContext {
     pub host: NodeRef,
+    /// The element rendered by `PopoverTrigger`, interactions with it never close the popover as outside ones
+    pub trigger: NodeRef,
     pub is_open: bool,
     pub on_toggle: Callback<bool>,
+    /// Opens or closes the popover and reports why through `on_open_change_with_reason`
+    pub on_change: Callback<PopoverOpenChange>,
+    /// The side `PopoverContent` was positioned on, `None` until it has been rendered
+    pub resolved_side: Option<PopoverSide>,
+    /// The alignment `PopoverContent` was positioned with, `None` until it has been rendered
+    pub resolved_align: Option<PopoverAlign>,
+    /// How the popover was opened, `None` when it was toggled directly
+    pub open_reason: Option<PopoverOpenReason>,
+    /// Opens the popover on behalf of a debounced trigger, e.g. after a hover delay
+    pub on_open_with_reason: Callback<PopoverOpenReason>,
+    /// Every mounted `PopoverContent`, a popover may render several driven by the same open state
+    pub contents: PopoverContents,
+}
+
+impl PopoverContext {
+    /// `open`, `instant-open` or `delayed-open` depending on the open reason, `closed` otherwise
+    #[must_use]
+    pub fn data_state(&self) -> &'static str {
+        match (self.is_open, &self.open_reason) {
+            (false, _) => "closed",
+            (true, None) => "open",
+            (true, Some(PopoverOpenReason::Instant)) => "instant-open",
+            (true, Some(PopoverOpenReason::Delayed)) => "delayed-open",
+        }
+    }
+}
+
+/// The `PopoverContent`s mounted within a popover.
+///
+/// Each content treats interactions with the others as inside ones, and the listeners they
+/// share, e.g. the Escape key one of the host, close the popover only once per event.
+#[derive(Clone, Debug, Default)]
+pub struct PopoverContents {
+    refs: Rc<RefCell<Vec<NodeRef>>>,
+    dismissed_by: Rc<RefCell<Option<Event>>>,
+}
+
+impl PopoverContents {
+    fn register(&self, content_ref: &NodeRef) {
+        self.refs.borrow_mut().push(content_ref.clone());
+    }
+
+    fn unregister(&self, content_ref: &NodeRef) {
+        self.refs
+            .borrow_mut()
+            .retain(|node_ref| node_ref != content_ref);
+    }
+
+    /// The first mounted content, it publishes its placement to the trigger
+    fn is_primary(&self, content_ref: &NodeRef) -> bool {
+        self.refs.borrow().first() == Some(content_ref)
+    }
+
+    /// Whether `target` is within any of the mounted contents
+    #[must_use]
+    pub fn contains(&self, target: &Element) -> bool {
+        self.refs.borrow().iter().any(|node_ref| {
+            node_ref
+                .cast::<Element>()
+                .is_some_and(|content| content.contains(Some(target)))
+        })
+    }
+
+    /// Returns `false` when another content already closed the popover for this `event`
+    fn dismiss(&self, event: &Event) -> bool {
+        let mut dismissed_by = self.dismissed_by.borrow_mut();
+
+        if dismissed_by.as_ref() == Some(event) {
+            return false;
+        }
+
+        *dismissed_by = Some(event.clone());
+        true
+    }
+}
+
+impl PartialEq for PopoverContents {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.refs, &other.refs)
+    }
+}
+
+/// What opened or closed the popover
+#[derive(Clone, Debug, PartialEq)]
+pub enum PopoverChangeReason {
+    /// The trigger was clicked
+    Trigger,
+    EscapeKey,
+    InteractionOutside,
+    /// The pointer rested on or left a debounced trigger, e.g. the one of a hover card
+    Hover,
+    /// The controlled `open` prop changed or `on_toggle` was called directly
+    Programmatic,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct PopoverOpenChange {
+    pub open: bool,
+    pub reason: PopoverChangeReason,
+}
+
+/// Distinguishes a popover that skipped its open delay from one that waited for it
+#[derive(Clone, Debug, PartialEq)]
+pub enum PopoverOpenReason {
+    Instant,
+    Delayed,
 }
 
 pub enum PopoverAction {
     Open,
     Close,
     Toggle,
+    Resolve(PopoverSide, PopoverAlign),
+    SetOpenReason(Option<PopoverOpenReason>),
 }
 
 impl Reducible for PopoverContext {
@@ -43,6 +157,17 @@ impl Reducible for PopoverContext {
                 ..(*self).clone()
             }
             .into(),
+            PopoverAction::Resolve(side, align) => PopoverContext {
+                resolved_side: Some(side),
+                resolved_align: Some(align),
+                ..(*self).clone()
+            }
+            .into(),
+            PopoverAction::SetOpenReason(open_reason) => PopoverContext {
+                open_reason,
+                ..(*self).clone()
+            }
+            .into(),
         }
     }
 }
@@ -56,6 +181,9 @@ pub struct PopoverProps {
     pub open: Option<bool>,
     #[prop_or_default]
     pub on_open_change: Callback<bool>,
+    /// Like `on_open_change`, but also tells what opened or closed the popover
+    #[prop_or_default]
+    pub on_open_change_with_reason: Callback<PopoverOpenChange>,
     #[prop_or_default]
     pub default_open: bool,
     #[prop_or_default]
@@ -65,27 +193,88 @@ pub struct PopoverProps {
 #[function_component(Popover)]
 pub fn popover(props: &PopoverProps) -> Html {
     let node_ref = use_node_ref();
+    let trigger_ref = use_node_ref();
+
+    // Set right before dispatching, anything else that changes the state is a controlled update
+    let change_reason = use_mut_ref(|| PopoverChangeReason::Programmatic);
 
-    let (is_open, dispatch) = use_controllable_state(
-        props.default_open.into(),
-        props.open,
+    // The context keeps the callbacks it was created with, so read the props' latest ones from here
+    let on_open_change_props =
+        use_mut_ref(<(Callback<bool>, Callback<PopoverOpenChange>)>::default);
+    *on_open_change_props.borrow_mut() = (
         props.on_open_change.clone(),
+        props.on_open_change_with_reason.clone(),
     );
 
-    let on_toggle = use_callback(dispatch.clone(), {
-        move |new_state, dispatch| {
-            dispatch.emit(Box::new(move |_| new_state));
-        }
+    let on_open_change = use_callback(
+        (change_reason.clone(), on_open_change_props),
+        |open: bool, (change_reason, on_open_change_props)| {
+            let reason = change_reason.replace(PopoverChangeReason::Programmatic);
+            let (on_open_change, on_open_change_with_reason) =
+                on_open_change_props.borrow().clone();
+
+            on_open_change.emit(open);
+            on_open_change_with_reason.emit(PopoverOpenChange { open, reason });
+        },
+    );
+
+    let (is_open, set_open) = use_controllable_open(props.default_open, props.open, on_open_change);
+
+    let open_reason = use_state_eq(|| None::<PopoverOpenReason>);
+
+    let on_change = use_callback(
+        (
+            set_open.clone(),
+            open_reason.setter(),
+            change_reason.clone(),
+        ),
+        move |change: PopoverOpenChange, (set_open, set_open_reason, change_reason)| {
+            set_open_reason.set(None);
+            *change_reason.borrow_mut() = change.reason;
+            set_open.emit(change.open);
+        },
+    );
+
+    let on_toggle = use_callback(on_change.clone(), |open, on_change| {
+        on_change.emit(PopoverOpenChange {
+            open,
+            reason: PopoverChangeReason::Programmatic,
+        });
     });
 
+    let on_open_with_reason = use_callback(
+        (set_open, open_reason.setter(), change_reason),
+        |reason: PopoverOpenReason, (set_open, set_open_reason, change_reason)| {
+            set_open_reason.set(Some(reason));
+            *change_reason.borrow_mut() = PopoverChangeReason::Hover;
+            set_open.emit(true);
+        },
+    );
+
     let context_value = use_reducer(|| PopoverContext {
         host: node_ref.clone(),
-        is_open: *is_open.borrow(),
+        trigger: trigger_ref,
+        is_open,
         on_toggle,
+        on_change,
+        resolved_side: None,
+        resolved_align: None,
+        open_reason: (*open_reason).clone(),
+        on_open_with_reason,
+        contents: PopoverContents::default(),
     });
 
     use_effect_with(
-        (*(*is_open).borrow(), context_value.clone()),
+        ((*open_reason).clone(), context_value.clone()),
+        |(open_reason, context_value)| {
+            if *open_reason != context_value.open_reason {
+                context_value.dispatch(PopoverAction::SetOpenReason(open_reason.clone()));
+            }
+        },
+    );
+
+    use_effect_with(
+        (is_open, context_value.clone()),
         |(is_open, context_value)| {
             if *is_open != context_value.is_open {
                 context_value.dispatch(PopoverAction::Toggle);
@@ -104,9 +293,16 @@ pub fn popover(props: &PopoverProps) -> Html {
 
 #[derive(Clone, Debug, PartialEq, Properties)]
 pub struct PopoverTriggerRenderAsProps {
+    /// Attach it to the rendered trigger so that toggling doesn't also count as an outside interaction
+    #[prop_or_default]
+    pub r#ref: NodeRef,
     pub toggle: Callback<MouseEvent>,
     pub is_open: bool,
     #[prop_or_default]
+    pub side: Option<PopoverSide>,
+    #[prop_or_default]
+    pub align: Option<PopoverAlign>,
+    #[prop_or_default]
     pub children: Children,
     #[prop_or_default]
     pub class: Option<AttrValue>,
@@ -131,33 +327,36 @@ pub fn popover_trigger(props: &PopoverTriggerProps) -> Html {
         let context = context.clone();
 
         move |_event: MouseEvent, is_open| {
-            context.on_toggle.emit(!is_open);
+            context.on_change.emit(PopoverOpenChange {
+                open: !is_open,
+                reason: PopoverChangeReason::Trigger,
+            });
         }
     });
 
-    let data_state = use_memo(
-        context.is_open,
-        |is_open| {
-            if *is_open {
-                "open"
-            } else {
-                "closed"
-            }
-        },
-    );
+    let data_state = use_memo(context.clone(), |context| context.data_state());
 
     let element = if let Some(render_as) = &props.render_as {
         html! {{
             render_as.emit(PopoverTriggerRenderAsProps {
+                r#ref: context.trigger.clone(),
                 children: props.children.clone(),
                 class: props.class.clone(),
                 toggle,
                 is_open: context.is_open,
+                side: context.resolved_side.clone(),
+                align: context.resolved_align.clone(),
             })
         }}
     } else {
         html! {
-            <button class={&props.class} onclick={&toggle}>
+            <button
+                ref={context.trigger.clone()}
+                class={&props.class}
+                onclick={&toggle}
+                data-side={context.resolved_side.as_ref().map(ToString::to_string)}
+                data-align={context.resolved_align.as_ref().map(ToString::to_string)}
+            >
                 {props.children.clone()}
             </button>
         }
@@ -211,6 +410,56 @@ impl Display for PopoverAlign {
     }
 }
 
+/// How `PopoverContent` stays within the viewport along its alignment axis, e.g. horizontally
+/// for the `Top` and `Bottom` sides
+#[derive(Clone, Debug, PartialEq)]
+pub enum PopoverSticky {
+    /// Stays in view while part of the trigger is visible, then leaves along with it
+    Partial,
+    /// Stays in view even once the trigger scrolled away
+    Always,
+}
+
+/// Clamps the start of the content, `size` long, within `[padding, viewport - padding]`.
+///
+/// With `Partial` the content keeps touching the anchor, which spans `anchor_size` from `anchor_start`.
+fn sticky_offset(
+    offset: f64,
+    size: f64,
+    anchor_start: f64,
+    anchor_size: f64,
+    viewport: f64,
+    padding: f64,
+    sticky: &PopoverSticky,
+) -> f64 {
+    // The start edge wins when the content is larger than the viewport
+    let offset = offset.min(viewport - padding - size).max(padding);
+
+    match sticky {
+        PopoverSticky::Partial => offset
+            .min(anchor_start + anchor_size)
+            .max(anchor_start - size),
+        PopoverSticky::Always => offset,
+    }
+}
+
+/// Lets `callback` cancel an automatic focus, returns whether it should still happen
+fn auto_focus(name: &str, callback: &Callback<Event>) -> bool {
+    let init = web_sys::EventInit::new();
+    init.set_cancelable(true);
+
+    let event = match Event::new_with_event_init_dict(name, &init) {
+        Ok(event) => event,
+        Err(error) => {
+            log::error!("Failed to create the {name} event: {error:?}");
+            return true;
+        }
+    };
+
+    callback.emit(event.clone());
+    !event.default_prevented()
+}
+
 #[derive(Clone, Debug, PartialEq, Properties)]
 pub struct PopoverContentProps {
     #[prop_or_default]
@@ -229,6 +478,60 @@ pub struct PopoverContentProps {
     pub on_esc_key_down: Callback<KeyboardEvent>,
     #[prop_or_default]
     pub on_interaction_outside: Callback<Event>,
+    /// Called before the content focuses its first focusable element once opened,
+    /// `prevent_default` skips it, e.g. to focus a specific field instead
+    #[prop_or_default]
+    pub on_open_auto_focus: Callback<Event>,
+    /// Called before the focus returns to the trigger once the content closed,
+    /// `prevent_default` leaves the focus where it is
+    #[prop_or_default]
+    pub on_close_auto_focus: Callback<Event>,
+    /// Renders the content inline within the `Popover` instead of portaling it into `viewport`,
+    /// it keeps the fixed positioning either way
+    #[prop_or(true)]
+    pub portal: bool,
+    /// Keeps the content within the viewport while the trigger scrolls, `None` moves it along
+    /// with the trigger
+    #[prop_or_default]
+    pub sticky: Option<PopoverSticky>,
+    /// The distance kept between a sticky content and the edges of the viewport, in pixels
+    #[prop_or_default]
+    pub collision_padding: f64,
+    /// The z-index of the content. Without one, a nested content is stacked right above its
+    /// parent and a root content is left to the page unless another root content is already open
+    #[prop_or_default]
+    pub z_index: Option<i32>,
+    /// The role of the content, `None` for one that only supplements the trigger, e.g. a hover card
+    #[prop_or(Some(AttrValue::Static("dialog")))]
+    pub role: Option<AttrValue>,
+}
+
+/// The z-index root contents without one are stacked from, once several of them are open
+pub const DEFAULT_Z_INDEX: i32 = 50;
+
+/// The z-index of the content that the nested popovers are rendered within
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct PopoverLayer {
+    z_index: i32,
+}
+
+thread_local! {
+    /// The stacking orders of the open root contents, in the order they were opened
+    static OPEN_LAYERS: RefCell<Vec<i32>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Stacks a root content above the ones that are already open
+fn open_layer() -> i32 {
+    OPEN_LAYERS.with(|layers| {
+        let mut layers = layers.borrow_mut();
+        let order = layers.last().map_or(0, |order| order + 1);
+        layers.push(order);
+        order
+    })
+}
+
+fn close_layer(order: i32) {
+    OPEN_LAYERS.with(|layers| layers.borrow_mut().retain(|layer| *layer != order));
 }
 
 #[function_component(PopoverContent)]
@@ -243,42 +546,59 @@ pub fn popover_content(props: &PopoverContentProps) -> Html {
             .expect("PopoverContent must be a child of Popover")
     });
 
-    {
-        let context = context.clone();
+    let content_ref = use_node_ref();
 
-        use_effect_with(
-            (host.clone(), props.on_esc_key_down.clone()),
-            |(host, on_esc_key_down)| {
-                let on_esc_key_down = on_esc_key_down.clone();
+    use_effect_with(
+        (content_ref.clone(), context.contents.clone()),
+        |(content_ref, contents)| {
+            contents.register(content_ref);
 
-                let listener = Closure::wrap(Box::new(move |event: KeyboardEvent| {
-                    if event.key() != "Escape" {
-                        return;
-                    }
+            let content_ref = content_ref.clone();
+            let contents = contents.clone();
 
-                    on_esc_key_down.emit(event.clone());
+            move || contents.unregister(&content_ref)
+        },
+    );
 
-                    if event.default_prevented() {
-                        return;
-                    }
+    let close_on_esc_key_down = use_callback(
+        (context.clone(), props.on_esc_key_down.clone()),
+        |event: KeyboardEvent, (context, on_esc_key_down)| {
+            on_esc_key_down.emit(event.clone());
 
-                    context.on_toggle.emit(false);
-                }) as Box<dyn FnMut(_)>);
+            if event.default_prevented() || !context.contents.dismiss(&event) {
+                return;
+            }
 
-                let _ = host
-                    .add_event_listener_with_callback("keydown", listener.as_ref().unchecked_ref());
+            context.on_change.emit(PopoverOpenChange {
+                open: false,
+                reason: PopoverChangeReason::EscapeKey,
+            });
+        },
+    );
 
-                let host = host.clone();
+    use_escape_key((&host).into(), close_on_esc_key_down);
 
-                move || {
-                    let _ = host.remove_event_listener_with_callback(
-                        "keydown",
-                        listener.as_ref().unchecked_ref(),
-                    );
-                }
-            },
-        );
-    }
+    // Publish the placement so the trigger can follow it, only the first content does so they don't take turns
+    use_effect_with(
+        (
+            props.side.clone(),
+            props.align.clone(),
+            context.resolved_side.clone(),
+            context.resolved_align.clone(),
+            context.contents.clone(),
+            context.dispatcher(),
+            content_ref.clone(),
+        ),
+        |(side, align, resolved_side, resolved_align, contents, dispatcher, content_ref)| {
+            if !contents.is_primary(content_ref) {
+                return;
+            }
+
+            if resolved_side.as_ref() != Some(side) || resolved_align.as_ref() != Some(align) {
+                dispatcher.dispatch(PopoverAction::Resolve(side.clone(), align.clone()));
+            }
+        },
+    );
 
     let dom_rect = host.get_bounding_client_rect();
     let adjusted_height = use_state(|| None::<f64>);
@@ -302,9 +622,93 @@ pub fn popover_content(props: &PopoverContentProps) -> Html {
 
     use_viewport_move(&context.host, auto_update_handler);
 
+    // Clamping needs the size of the content in pixels, `100%` only works within the transform
+    let content_size = use_state_eq(|| None::<(f64, f64)>);
+
+    use_effect({
+        let content_ref = content_ref.clone();
+        let content_size = content_size.clone();
+        let sticky = props.sticky.is_some();
+
+        move || {
+            if let Some(content) = content_ref.cast::<Element>().filter(|_| sticky) {
+                let rect = content.get_bounding_client_rect();
+                content_size.set(Some((rect.width(), rect.height())));
+            }
+        }
+    });
+
+    let viewport_size = host.owner_document().and_then(|document| {
+        let root = document.document_element()?;
+        Some((
+            f64::from(root.client_width()),
+            f64::from(root.client_height()),
+        ))
+    });
+
+    let sticky = props
+        .sticky
+        .as_ref()
+        .zip(*content_size)
+        .zip(viewport_size)
+        .map(|((sticky, size), viewport)| (sticky, size, viewport));
+
+    // Replaces the offset along the alignment axis once the content has been measured
+    let sticky_x = sticky
+        .as_ref()
+        .and_then(|(sticky, (width, _), (viewport, _))| {
+            let offset = match props.side {
+                PopoverSide::Top | PopoverSide::Bottom => match props.align {
+                    PopoverAlign::Start => dom_rect.x(),
+                    PopoverAlign::Center => dom_rect.x() - (width - dom_rect.width()) / 2.0,
+                    PopoverAlign::End => dom_rect.x() - width + dom_rect.width(),
+                },
+                PopoverSide::Left | PopoverSide::Right => return None,
+            };
+
+            let offset = sticky_offset(
+                offset,
+                *width,
+                dom_rect.x(),
+                dom_rect.width(),
+                *viewport,
+                props.collision_padding,
+                sticky,
+            );
+
+            Some(format!("{offset}px"))
+        });
+
+    let sticky_y = sticky
+        .as_ref()
+        .and_then(|(sticky, (_, height), (_, viewport))| {
+            let anchor_height = adjusted_height.unwrap_or_else(|| dom_rect.height());
+
+            let offset = match props.side {
+                PopoverSide::Left | PopoverSide::Right => match props.align {
+                    PopoverAlign::Start => dom_rect.y(),
+                    PopoverAlign::Center => dom_rect.y() - anchor_height,
+                    PopoverAlign::End => dom_rect.y() + anchor_height - height,
+                },
+                PopoverSide::Top | PopoverSide::Bottom => return None,
+            };
+
+            let offset = sticky_offset(
+                offset,
+                *height,
+                dom_rect.y(),
+                anchor_height,
+                *viewport,
+                props.collision_padding,
+                sticky,
+            );
+
+            Some(format!("{offset}px"))
+        });
+
     let transform = format!(
         "transform: translate({}, {});",
-        match props.side {
+        sticky_x.unwrap_or_else(|| match props.side {
             PopoverSide::Right => format!("calc({}px + {}px)", dom_rect.x(), dom_rect.width()),
             PopoverSide::Top | PopoverSide::Bottom => match props.align {
                 PopoverAlign::Start => format!("calc({}px)", dom_rect.x()),
@@ -317,8 +721,8 @@ pub fn popover_content(props: &PopoverContentProps) -> Html {
                     format!("calc({}px - 100% + {}px)", dom_rect.x(), dom_rect.width()),
             },
             PopoverSide::Left => format!("calc({}px - 100%)", dom_rect.x()),
-        },
-        match props.side {
+        }),
+        sticky_y.unwrap_or_else(|| match props.side {
             PopoverSide::Top => format!("calc({}px - 100%)", dom_rect.y()),
             PopoverSide::Bottom => format!(
                 "calc({}px + {}px)",
@@ -338,17 +742,50 @@ pub fn popover_content(props: &PopoverContentProps) -> Html {
                     adjusted_height.unwrap_or_else(|| dom_rect.height())
                 ),
             },
+        }),
+    );
+
+    let parent_layer = use_context::<PopoverLayer>();
+    let layer_order = use_state_eq(|| None::<i32>);
+
+    // Sibling root contents stack in the order they were opened
+    use_effect_with(
+        (
+            context.is_open && parent_layer.is_none(),
+            layer_order.setter(),
+        ),
+        |(is_open_root, layer_order)| {
+            let order = is_open_root.then(open_layer);
+            layer_order.set(order);
+
+            move || {
+                if let Some(order) = order {
+                    close_layer(order);
+                }
+            }
         },
     );
 
-    let style = format!("{style} {transform}");
-    let content_ref = use_node_ref();
+    let z_index = match (props.z_index, parent_layer) {
+        (Some(z_index), _) => Some(z_index + layer_order.unwrap_or_default()),
+        (None, Some(layer)) => Some(layer.z_index + 1),
+        (None, None) => layer_order
+            .filter(|order| *order > 0)
+            .map(|order| DEFAULT_Z_INDEX + order),
+    };
 
-    use_interaction_outside(
+    let style = match z_index {
+        Some(z_index) => format!("{style} {transform} z-index: {z_index};"),
+        None => format!("{style} {transform}"),
+    };
+
+    // Nested popovers portal their content out of this one, the layer keeps them inside it
+    let interaction_outside_layer = use_interaction_outside(
         {
             let mut nodes = vec![];
             nodes.push((&host).into());
             nodes.push((&content_ref).into());
+            nodes.push((&context.trigger).into());
 
             if props.container.is_some() {
                 nodes.push((&context.host.clone()).into());
@@ -361,29 +798,113 @@ pub fn popover_content(props: &PopoverContentProps) -> Html {
             let on_interaction_outside = props.on_interaction_outside.clone();
 
             move |event: Event| {
+                // Within another content of the same popover
+                if context
+                    .contents
+                    .contains(&event.target_unchecked_into::<Element>())
+                {
+                    return;
+                }
+
                 on_interaction_outside.emit(event.clone());
 
-                if event.default_prevented() {
+                if event.default_prevented() || !context.contents.dismiss(&event) {
                     return;
                 }
 
-                context.on_toggle.emit(false);
+                context.on_change.emit(PopoverOpenChange {
+                    open: false,
+                    reason: PopoverChangeReason::InteractionOutside,
+                });
             }
         },
     );
 
-    let focus_on_present = use_callback(content_ref.clone(), |(), content_ref| {
-        if let Some(content) = content_ref.cast::<Element>() {
-            if let Some(element) = get_focusable_element(&content) {
-                match element.focus() {
-                    Ok(()) => {}
-                    Err(error) => {
-                        log::error!("Failed to focus the popover content: {error:?}");
+    let focus_on_present = use_callback(
+        (content_ref.clone(), props.on_open_auto_focus.clone()),
+        |(), (content_ref, on_open_auto_focus)| {
+            if !auto_focus("popover.open_auto_focus", on_open_auto_focus) {
+                return;
+            }
+
+            if let Some(content) = content_ref.cast::<Element>() {
+                if let Some(element) = get_focusable_element(&content) {
+                    match element.focus() {
+                        Ok(()) => {}
+                        Err(error) => {
+                            log::error!("Failed to focus the popover content: {error:?}");
+                        }
                     }
                 }
             }
-        }
-    });
+        },
+    );
+
+    let focus_on_exit = use_callback(
+        (context.trigger.clone(), props.on_close_auto_focus.clone()),
+        |(), (trigger, on_close_auto_focus)| {
+            // The focus already moved on, e.g. to the field pressed outside of the content
+            let focus_moved = web_sys::window()
+                .and_then(|window| window.document())
+                .and_then(|document| Some((document.active_element()?, document.body()?)))
+                .is_some_and(|(active, body)| active != Element::from(body));
+
+            if focus_moved || !auto_focus("popover.close_auto_focus", on_close_auto_focus) {
+                return;
+            }
+
+            if let Some(trigger) = trigger.cast::<HtmlElement>() {
+                if let Err(error) = trigger.focus() {
+                    log::error!("Failed to focus the popover trigger: {error:?}");
+                }
+            }
+        },
+    );
+
+    let side = props.side.clone();
+    let align = props.align.clone();
+    let role = props.role.clone();
+
+    let content = html! {
+        <Presence
+            r#ref={content_ref.clone()}
+            class={&props.class}
+            name="popover-content"
+            present={context.is_open}
+            class={&props.class}
+            on_present={focus_on_present}
+            on_exit={focus_on_exit}
+            render_as={Callback::from(move |presence_props: PresenceRenderAsProps| {
+                if !presence_props.presence {
+                    return html! {};
+                }
+
+                html! {
+                    <div
+                        ref={presence_props.r#ref.clone()}
+                        data-state={context.data_state()}
+                        data-side={side.to_string()}
+                        data-align={align.to_string()}
+                        role={role.clone()}
+                        style={style.clone()}
+                        class={&presence_props.class}
+                    >
+                        {presence_props.children.clone()}
+                    </div>
+                }
+            })}
+        >
+            <ContextProvider<InteractionOutsideLayer> context={interaction_outside_layer}>
+                <ContextProvider<PopoverLayer> context={PopoverLayer { z_index: z_index.unwrap_or(DEFAULT_Z_INDEX) }}>
+                    {props.children.clone()}
+                </ContextProvider<PopoverLayer>>
+            </ContextProvider<InteractionOutsideLayer>>
+        </Presence>
+    };
+
+    if !props.portal {
+        return content;
+    }
 
     let viewport = props.viewport.clone().unwrap_or_else(|| {
         host.owner_document()
@@ -392,41 +913,454 @@ pub fn popover_content(props: &PopoverContentProps) -> Html {
             .expect("Failed to get viewport")
     });
 
-    let side = props.side.clone();
-    let align = props.align.clone();
+    create_portal(content, viewport)
+}
 
-    create_portal(
-        html! {
-            <Presence
-                r#ref={content_ref.clone()}
-                class={&props.class}
-                name="popover-content"
-                present={context.is_open}
-                class={&props.class}
-                on_present={focus_on_present}
-                render_as={Callback::from(move |presence_props: PresenceRenderAsProps| {
-                    if !presence_props.presence {
-                        return html! {};
-                    }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+    use wasm_bindgen_test::*;
+    use yew::platform::time::sleep;
+    use yewlish_testing_tools::*;
 
-                    html! {
-                        <div
-                            ref={presence_props.r#ref.clone()}
-                            data-state={if context.is_open { "open" } else { "closed" }}
-                            data-side={side.to_string()}
-                            data-align={align.to_string()}
-                            role="dialog"
-                            style={style.clone()}
-                            class={&presence_props.class}
-                        >
-                            {presence_props.children.clone()}
-                        </div>
-                    }
-                })}
-            >
-                {props.children.clone()}
-            </Presence>
-        },
-        viewport,
-    )
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    #[test]
+    fn test_sticky_offset() {
+        // Fits in the viewport, nothing to clamp
+        assert_eq!(
+            sticky_offset(
+                100.0,
+                50.0,
+                100.0,
+                20.0,
+                800.0,
+                8.0,
+                &PopoverSticky::Partial
+            ),
+            100.0
+        );
+
+        // The trigger is partly out on the left, the content stays within the padding
+        assert_eq!(
+            sticky_offset(
+                -30.0,
+                100.0,
+                -10.0,
+                40.0,
+                800.0,
+                8.0,
+                &PopoverSticky::Partial
+            ),
+            8.0
+        );
+
+        // The trigger left the viewport, only `Always` keeps the content in view
+        assert_eq!(
+            sticky_offset(
+                -300.0,
+                100.0,
+                -300.0,
+                40.0,
+                800.0,
+                8.0,
+                &PopoverSticky::Partial
+            ),
+            -260.0
+        );
+        assert_eq!(
+            sticky_offset(
+                -300.0,
+                100.0,
+                -300.0,
+                40.0,
+                800.0,
+                8.0,
+                &PopoverSticky::Always
+            ),
+            8.0
+        );
+
+        // Wider than the viewport, the start edge stays in view
+        assert_eq!(
+            sticky_offset(
+                100.0,
+                900.0,
+                100.0,
+                40.0,
+                800.0,
+                8.0,
+                &PopoverSticky::Always
+            ),
+            8.0
+        );
+    }
+
+    /// The outside interaction listens to `mousedown`, not to `click`
+    fn mousedown(testid: &str) {
+        let element = web_sys::window()
+            .and_then(|window| window.document())
+            .and_then(|document| {
+                document
+                    .query_selector(&format!("[data-testid='{testid}']"))
+                    .ok()
+            })
+            .flatten()
+            .expect("Failed to find the element");
+
+        let init = web_sys::MouseEventInit::new();
+        init.set_bubbles(true);
+
+        let mousedown = web_sys::MouseEvent::new_with_mouse_event_init_dict("mousedown", &init)
+            .expect("Failed to create a mousedown event");
+
+        element
+            .dispatch_event(&mousedown)
+            .expect("Failed to dispatch the mousedown event");
+    }
+
+    /// A real click starts with a `mousedown`, which is what the outside interaction listens to
+    async fn press_trigger(t: &Tester) {
+        mousedown("trigger");
+        t.query_by_testid("trigger").click().await;
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_trigger_click_is_not_an_outside_interaction() {
+        let t = render!({
+            html! {
+                <Popover>
+                    <PopoverTrigger>
+                        <span data-testid="trigger">{"Trigger"}</span>
+                    </PopoverTrigger>
+                    <PopoverContent>{"Content"}</PopoverContent>
+                </Popover>
+            }
+        })
+        .await;
+
+        press_trigger(&t).await;
+        assert!(t.query_by_role("dialog").exists());
+
+        press_trigger(&t).await;
+        assert!(!t.query_by_role("dialog").exists());
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_on_open_change_with_reason() {
+        let t = render!({
+            let changes = use_mut_ref(Vec::<PopoverOpenChange>::new);
+
+            let on_open_change_with_reason = use_callback(changes.clone(), |change, changes| {
+                changes.borrow_mut().push(change);
+            });
+
+            // The shared `Rc` keeps every change, also the ones made after the last render
+            use_remember_value(changes.clone());
+
+            html! {
+                <Popover {on_open_change_with_reason}>
+                    <PopoverTrigger>
+                        <span data-testid="trigger">{"Trigger"}</span>
+                    </PopoverTrigger>
+                    <PopoverContent>{"Content"}</PopoverContent>
+                </Popover>
+            }
+        })
+        .await;
+
+        press_trigger(&t).await;
+        press_trigger(&t).await;
+
+        let changes = t.get_remembered_value::<Rc<RefCell<Vec<PopoverOpenChange>>>>();
+
+        assert_eq!(
+            *changes.borrow(),
+            vec![
+                PopoverOpenChange {
+                    open: true,
+                    reason: PopoverChangeReason::Trigger,
+                },
+                PopoverOpenChange {
+                    open: false,
+                    reason: PopoverChangeReason::Trigger,
+                },
+            ]
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_multiple_contents_share_the_open_state() {
+        let t = render!({
+            let changes = use_mut_ref(Vec::<PopoverOpenChange>::new);
+
+            let on_open_change_with_reason = use_callback(changes.clone(), |change, changes| {
+                changes.borrow_mut().push(change);
+            });
+
+            use_remember_value(changes.clone());
+
+            html! {
+                <Popover {on_open_change_with_reason}>
+                    <PopoverTrigger>
+                        <span data-testid="trigger">{"Trigger"}</span>
+                    </PopoverTrigger>
+                    <PopoverContent>
+                        <span data-testid="preview">{"Preview"}</span>
+                    </PopoverContent>
+                    <PopoverContent side={PopoverSide::Right}>
+                        <span data-testid="menu">{"Menu"}</span>
+                    </PopoverContent>
+                </Popover>
+            }
+        })
+        .await;
+
+        press_trigger(&t).await;
+        assert!(t.query_by_testid("preview").exists());
+        assert!(t.query_by_testid("menu").exists());
+
+        // Pressing within one content isn't an outside interaction for the other
+        mousedown("menu");
+        sleep(Duration::ZERO).await;
+        assert!(t.query_by_testid("menu").exists());
+        assert!(t.query_by_testid("preview").exists());
+
+        // Both contents listen to the Escape key of the popover, it still closes it once
+        t.query_by_testid("trigger").keydown("Escape").await;
+        assert!(!t.query_by_testid("preview").exists());
+        assert!(!t.query_by_testid("menu").exists());
+
+        assert_eq!(
+            *t.get_remembered_value::<Rc<RefCell<Vec<PopoverOpenChange>>>>()
+                .borrow(),
+            vec![
+                PopoverOpenChange {
+                    open: true,
+                    reason: PopoverChangeReason::Trigger,
+                },
+                PopoverOpenChange {
+                    open: false,
+                    reason: PopoverChangeReason::EscapeKey,
+                },
+            ]
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_nested_content_stacks_above_its_parent() {
+        close_open_layers();
+
+        let t = render!({
+            html! {
+                <Popover default_open={true}>
+                    <PopoverContent z_index={50}>
+                        <Popover default_open={true}>
+                            <PopoverContent>{"Nested"}</PopoverContent>
+                        </Popover>
+                    </PopoverContent>
+                </Popover>
+            }
+        })
+        .await;
+
+        let z_indexes = t
+            .query_all_by_role("dialog")
+            .iter()
+            .map(|dialog| {
+                dialog
+                    .attribute("style")
+                    .and_then(|style| style.split("z-index:").nth(1).map(str::to_string))
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            z_indexes,
+            vec![Some(" 50;".to_string()), Some(" 51;".to_string())]
+        );
+    }
+
+    // Contents rendered by earlier tests are never unmounted and keep their layers open
+    fn close_open_layers() {
+        OPEN_LAYERS.with(|layers| layers.borrow_mut().clear());
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_nested_content_stacks_above_the_default_base() {
+        close_open_layers();
+
+        let t = render!({
+            html! {
+                <Popover default_open={true}>
+                    <PopoverContent>
+                        <Popover default_open={true}>
+                            <PopoverContent>{"Nested"}</PopoverContent>
+                        </Popover>
+                    </PopoverContent>
+                </Popover>
+            }
+        })
+        .await;
+
+        let z_indexes = t
+            .query_all_by_role("dialog")
+            .iter()
+            .map(|dialog| {
+                dialog
+                    .attribute("style")
+                    .and_then(|style| style.split("z-index:").nth(1).map(str::to_string))
+            })
+            .collect::<Vec<_>>();
+
+        // The root content is left to the page, its nested content stacks above the base
+        assert_eq!(
+            z_indexes,
+            vec![None, Some(format!(" {};", DEFAULT_Z_INDEX + 1))]
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_sibling_contents_stack_in_the_order_they_were_opened() {
+        close_open_layers();
+
+        let t = render!({
+            let first = use_state(|| false);
+            let second = use_state(|| false);
+
+            let open_first = use_callback(first.clone(), |_: MouseEvent, first| first.set(true));
+            let open_second =
+                use_callback(second.clone(), |_: MouseEvent, second| second.set(true));
+
+            html! {
+                <>
+                    <button onclick={open_first}>{"Open first"}</button>
+                    <button onclick={open_second}>{"Open second"}</button>
+                    <Popover open={*first}>
+                        <PopoverContent>{"First"}</PopoverContent>
+                    </Popover>
+                    <Popover open={*second}>
+                        <PopoverContent>{"Second"}</PopoverContent>
+                    </Popover>
+                </>
+            }
+        })
+        .await;
+
+        let z_index = |text: &str| {
+            t.query_all_by_role("dialog")
+                .into_iter()
+                .find(|dialog| dialog.text() == text)
+                .and_then(|dialog| dialog.attribute("style"))
+                .and_then(|style| {
+                    style.split("z-index:").nth(1).and_then(|z_index| {
+                        z_index.trim().trim_end_matches(';').parse::<i32>().ok()
+                    })
+                })
+        };
+
+        t.query_by_text("Open first").click().await;
+        assert_eq!(z_index("First"), None);
+
+        // The second content opens above the first one, which is still left to the page
+        t.query_by_text("Open second").click().await;
+        assert_eq!(z_index("First"), None);
+        assert_eq!(z_index("Second"), Some(DEFAULT_Z_INDEX + 1));
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_nested_content_is_inside_its_parent() {
+        let t = render!({
+            html! {
+                <Popover default_open={true}>
+                    <PopoverContent>
+                        <span data-testid="parent">{"Parent"}</span>
+                        <Popover default_open={true}>
+                            <PopoverContent>
+                                <span data-testid="nested">{"Nested"}</span>
+                            </PopoverContent>
+                        </Popover>
+                    </PopoverContent>
+                </Popover>
+            }
+        })
+        .await;
+
+        // The nested content is portaled out of its parent, pressing it keeps both open
+        mousedown("nested");
+        sleep(Duration::ZERO).await;
+        assert!(t.query_by_testid("parent").exists());
+        assert!(t.query_by_testid("nested").exists());
+
+        // Pressing the parent is still outside of the nested content
+        mousedown("parent");
+        sleep(Duration::ZERO).await;
+        assert!(t.query_by_testid("parent").exists());
+        assert!(!t.query_by_testid("nested").exists());
+    }
+
+    fn active_element_text() -> Option<String> {
+        web_sys::window()
+            .and_then(|window| window.document())
+            .and_then(|document| document.active_element())
+            .and_then(|element| element.text_content())
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_auto_focus_on_open_and_close() {
+        let t = render!({
+            html! {
+                <Popover>
+                    <PopoverTrigger>{"Trigger"}</PopoverTrigger>
+                    <PopoverContent>
+                        <button>{"Inside"}</button>
+                    </PopoverContent>
+                </Popover>
+            }
+        })
+        .await;
+
+        t.query_by_text("Trigger").click().await;
+        t.wait_for(100.0, || active_element_text().as_deref() == Some("Inside"))
+            .await;
+
+        assert_eq!(active_element_text().as_deref(), Some("Inside"));
+
+        // Closing returns the focus to the trigger
+        t.query_by_text("Trigger").keydown("Escape").await;
+        t.wait_for(100.0, || !t.query_by_role("dialog").exists())
+            .await;
+
+        assert_eq!(active_element_text().as_deref(), Some("Trigger"));
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_auto_focus_can_be_prevented() {
+        let t = render!({
+            let prevent = Callback::from(|event: Event| event.prevent_default());
+
+            html! {
+                <Popover>
+                    <PopoverTrigger>{"Trigger"}</PopoverTrigger>
+                    <PopoverContent
+                        on_open_auto_focus={prevent.clone()}
+                        on_close_auto_focus={prevent}
+                    >
+                        <button>{"Inside"}</button>
+                    </PopoverContent>
+                </Popover>
+            }
+        })
+        .await;
+
+        t.query_by_text("Trigger").click().await;
+        assert!(t.query_by_role("dialog").exists());
+        assert_ne!(active_element_text().as_deref(), Some("Inside"));
+
+        t.query_by_text("Trigger").keydown("Escape").await;
+        t.wait_for(100.0, || !t.query_by_role("dialog").exists())
+            .await;
+
+        assert_ne!(active_element_text().as_deref(), Some("Trigger"));
+    }
 }
@@ -1,4 +1,4 @@
-use hooks::use_presence::use_presence;
+pub use hooks::use_presence::use_presence;
 use yew::prelude::*;
 use yewlish_attr_passer::AttrReceiver;
 
@@ -28,13 +28,46 @@ pub struct PresenceProps {
     pub class: Option<AttrValue>,
     #[prop_or_default]
     pub render_as: Option<Callback<PresenceRenderAsProps, Html>>,
+    /// Called once the element is mounted, when `present` turns `true`
     #[prop_or_default]
     pub on_present: Option<Callback<()>>,
+    /// Called once the element is unmounted, i.e. after its exit animation
+    #[prop_or_default]
+    pub on_exit: Option<Callback<()>>,
+    /// The tag of the mounted element when `render_as` isn't set, extra attributes can be
+    /// passed to it through an `AttrPasser` with the same `name`
+    #[prop_or("div")]
+    pub element: &'static str,
 }
 
 #[function_component(Presence)]
 pub fn presence(props: &PresenceProps) -> Html {
     let presence = use_presence(props.present, props.r#ref.clone());
+    let was_present = use_mut_ref(|| false);
+
+    // Runs after the render, so the element is already mounted for `on_present`
+    use_effect_with(*presence, {
+        let on_present = props.on_present.clone();
+        let on_exit = props.on_exit.clone();
+
+        move |presence| {
+            let was_present = was_present.replace(*presence);
+
+            match (was_present, *presence) {
+                (false, true) => {
+                    if let Some(on_present) = on_present {
+                        on_present.emit(());
+                    }
+                }
+                (true, false) => {
+                    if let Some(on_exit) = on_exit {
+                        on_exit.emit(());
+                    }
+                }
+                _ => {}
+            }
+        }
+    });
 
     let element = if let Some(render_as) = &props.render_as {
         html! {{
@@ -50,14 +83,10 @@ pub fn presence(props: &PresenceProps) -> Html {
             return html! {};
         }
 
-        if let Some(on_present) = &props.on_present {
-            on_present.emit(());
-        }
-
         html! {
-            <div ref={props.r#ref.clone()} class={props.class.clone()}>
+            <@{props.element} ref={props.r#ref.clone()} class={props.class.clone()}>
                 {props.children.clone()}
-            </div>
+            </@>
         }
     };
 
@@ -67,3 +96,107 @@ pub fn presence(props: &PresenceProps) -> Html {
         </AttrReceiver>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    use wasm_bindgen_test::*;
+    use yewlish_testing_tools::*;
+
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    async fn test_on_present_fires_once_across_rerenders() {
+        thread_local! {
+            static PRESENTS: Cell<usize> = const { Cell::new(0) };
+        }
+
+        let t = render!({
+            let present = use_state(|| false);
+            let renders = use_state(|| 0);
+
+            let on_present = use_callback((), |(), ()| {
+                PRESENTS.with(|presents| presents.set(presents.get() + 1));
+            });
+
+            let toggle = use_callback(present.clone(), |_event: MouseEvent, present| {
+                present.set(!**present);
+            });
+
+            let rerender = use_callback(renders.clone(), |_event: MouseEvent, renders| {
+                renders.set(**renders + 1);
+            });
+
+            html! {
+                <>
+                    <button onclick={toggle}>{"Toggle"}</button>
+                    <button onclick={rerender}>{format!("Rerender {}", *renders)}</button>
+                    <Presence present={*present} on_present={on_present}>
+                        {"Content"}
+                    </Presence>
+                </>
+            }
+        })
+        .await;
+
+        assert!(!t.query_by_text("Content").exists());
+        assert_eq!(PRESENTS.with(Cell::get), 0);
+
+        t.query_by_text("Toggle").click().await;
+        assert!(t.query_by_text("Content").exists());
+        assert_eq!(PRESENTS.with(Cell::get), 1);
+
+        t.query_by_text("Rerender").click().await;
+        t.query_by_text("Rerender").click().await;
+        assert!(t.query_by_text("Rerender 2").exists());
+        assert!(t.query_by_text("Content").exists());
+        assert_eq!(PRESENTS.with(Cell::get), 1);
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_on_exit_fires_once_the_element_is_unmounted() {
+        thread_local! {
+            static EXITS: Cell<usize> = const { Cell::new(0) };
+        }
+
+        let t = render!({
+            let present = use_state(|| true);
+            let renders = use_state(|| 0);
+
+            let on_exit = use_callback((), |(), ()| {
+                EXITS.with(|exits| exits.set(exits.get() + 1));
+            });
+
+            let toggle = use_callback(present.clone(), |_event: MouseEvent, present| {
+                present.set(!**present);
+            });
+
+            let rerender = use_callback(renders.clone(), |_event: MouseEvent, renders| {
+                renders.set(**renders + 1);
+            });
+
+            html! {
+                <>
+                    <button onclick={toggle}>{"Toggle"}</button>
+                    <button onclick={rerender}>{format!("Rerender {}", *renders)}</button>
+                    <Presence present={*present} on_exit={on_exit}>
+                        {"Content"}
+                    </Presence>
+                </>
+            }
+        })
+        .await;
+
+        assert!(t.query_by_text("Content").exists());
+        assert_eq!(EXITS.with(Cell::get), 0);
+
+        t.query_by_text("Toggle").click().await;
+        assert!(!t.query_by_text("Content").exists());
+        assert_eq!(EXITS.with(Cell::get), 1);
+
+        t.query_by_text("Rerender").click().await;
+        assert!(t.query_by_text("Rerender 1").exists());
+        assert_eq!(EXITS.with(Cell::get), 1);
+    }
+}
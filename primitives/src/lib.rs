@@ -1,5 +1,9 @@
 use html::IntoPropValue;
 use yew::prelude::*;
+use yewlish_utils::hooks::use_long_press;
+
+/// Milliseconds a button has to be held when `long_press_duration` isn't set
+pub const DEFAULT_LONG_PRESS_DURATION: u32 = 500;
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum DataState {
@@ -48,15 +52,57 @@ pub struct PrimitiveButtonProps {
     pub aria_disabled: Option<AttrValue>,
     #[prop_or_default]
     pub data_orientation: Option<AttrValue>,
+    /// Called when the button is held down, the click that ends a long press is swallowed
+    #[prop_or_default]
+    pub on_long_press: Option<Callback<PointerEvent>>,
+    /// Milliseconds the button has to be held, `DEFAULT_LONG_PRESS_DURATION` when omitted
+    #[prop_or_default]
+    pub long_press_duration: Option<u32>,
 }
 
 #[function_component(PrimitiveButton)]
 pub fn primitive_button(props: &PrimitiveButtonProps) -> Html {
+    let long_pressed = use_mut_ref(|| false);
+
+    let on_long_press = props.on_long_press.clone().map(|on_long_press| {
+        let long_pressed = long_pressed.clone();
+
+        Callback::from(move |event: PointerEvent| {
+            *long_pressed.borrow_mut() = true;
+            on_long_press.emit(event);
+        })
+    });
+
+    use_long_press(
+        props.node_ref.clone(),
+        props
+            .long_press_duration
+            .unwrap_or(DEFAULT_LONG_PRESS_DURATION),
+        on_long_press,
+    );
+
+    // A new press starts over, the previous one may have ended outside of the button
+    let reset_long_pressed = use_callback(long_pressed.clone(), |_: PointerEvent, long_pressed| {
+        *long_pressed.borrow_mut() = false;
+    });
+
+    let on_click = use_callback(
+        (props.on_click.clone(), long_pressed),
+        |event: MouseEvent, (on_click, long_pressed)| {
+            if long_pressed.replace(false) {
+                return;
+            }
+
+            on_click.emit(event);
+        },
+    );
+
     html! {
         <button
             ref={&props.node_ref}
             type={&props.r#type}
-            onclick={&props.on_click}
+            onclick={on_click}
+            onpointerdown={reset_long_pressed}
             disabled={props.disabled}
             class={&props.class}
             aria-pressed={&props.aria_pressed}
@@ -5,7 +5,10 @@ use yewlish_attr_passer::{attributify, AttrPasser, AttrReceiver};
 use yewlish_roving_focus::*;
 use yewlish_utils::{
     enums::{DataState, Dir, Orientation},
-    hooks::use_conditional_attr,
+    hooks::{
+        use_conditional_attr, use_controllable_reducer, use_form_reset,
+        UseControllableReducerHandle,
+    },
 };
 
 #[derive(Debug, Clone, PartialEq)]
@@ -15,6 +18,9 @@ pub struct RadioGroupContext {
     pub required: bool,
     pub disabled: bool,
     pub readonly: bool,
+    pub select_on_focus: bool,
+    /// Requests another value, a controlled group only reports it through `on_value_change`
+    pub(crate) select: Callback<AttrValue>,
 }
 
 pub enum RadioGroupAction {
@@ -35,8 +41,6 @@ impl Reducible for RadioGroupContext {
     }
 }
 
-type ReducibleRadioGroupContext = UseReducerHandle<RadioGroupContext>;
-
 #[derive(Debug, Clone, PartialEq, Properties)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct RadioGroupProps {
@@ -63,42 +67,104 @@ pub struct RadioGroupProps {
     pub orientation: Orientation,
     #[prop_or(true)]
     pub r#loop: bool,
+    /// Renders a plain container without arrow-key navigation when `false`
+    #[prop_or(true)]
+    pub roving_focus: bool,
+    /// Marks the group as failing validation with `aria-invalid`
+    #[prop_or_default]
+    pub invalid: bool,
+    /// Id of the element holding the validation message, referenced by `aria-describedby`
+    #[prop_or_default]
+    pub error_id: Option<AttrValue>,
+    /// Selects items as arrow keys move focus to them. When `false`, arrow keys only move
+    /// focus and Space or Enter selects the focused item
+    #[prop_or(true)]
+    pub select_on_focus: bool,
     #[prop_or_default]
     pub on_value_change: Callback<AttrValue>,
 }
 
 #[function_component(RadioGroup)]
 pub fn radio_group(props: &RadioGroupProps) -> Html {
-    let context_value = use_reducer(|| RadioGroupContext {
+    // The reducer follows the controllable value, so a controlled group reconciles the value of
+    // its parent while a selection is only reported through `on_value_change`
+    let UseControllableReducerHandle {
+        value,
+        dispatch,
+        reducer,
+        reset,
+    } = use_controllable_reducer(
+        props.default_value.clone(),
+        props.value.clone(),
+        props.on_value_change.clone(),
+        |value| RadioGroupContext {
+            name: props.name.clone(),
+            value,
+            required: props.required,
+            readonly: props.readonly,
+            disabled: props.disabled,
+            select_on_focus: props.select_on_focus,
+            select: Callback::default(),
+        },
+        |context| context.value.clone(),
+        RadioGroupAction::SetValue,
+    );
+
+    let node_ref = use_node_ref();
+    use_form_reset(&node_ref, reset);
+
+    let select = use_callback(
+        (value.clone(), dispatch),
+        |next: AttrValue, (value, dispatch)| {
+            if *value.borrow() != next {
+                dispatch.emit(Box::new(move |_| next.clone()));
+            }
+        },
+    );
+
+    let context_value = RadioGroupContext {
         name: props.name.clone(),
-        value: props
-            .value
-            .clone()
-            .unwrap_or(props.default_value.clone().unwrap_or_default()),
+        value: reducer.value.clone(),
         required: props.required,
         readonly: props.readonly,
         disabled: props.disabled,
-    });
+        select_on_focus: props.select_on_focus,
+        select,
+    };
 
-    use_effect_with(
-        (context_value.value.clone(), props.on_value_change.clone()),
-        |(value, on_value_change)| {
-            on_value_change.emit(value.clone());
-        },
-    );
+    if props.roving_focus {
+        return html! {
+            <ContextProvider<RadioGroupContext> context={context_value}>
+                <RovingFocus
+                    r#ref={node_ref}
+                    role="radiogroup"
+                    aria_invalid={props.invalid.then_some(AttrValue::Static("true"))}
+                    aria_describedby={props.error_id.clone()}
+                    class={&props.class}
+                    orientation={props.orientation.clone()}
+                    dir={props.dir.clone().unwrap_or(Dir::Ltr)}
+                    r#loop={props.r#loop}
+                >
+                    {for props.children.iter()}
+                </RovingFocus>
+            </ContextProvider<RadioGroupContext>>
+        };
+    }
 
     html! {
-        <ContextProvider<ReducibleRadioGroupContext> context={context_value}>
-            <RovingFocus
+        <ContextProvider<RadioGroupContext> context={context_value}>
+            <div
+                ref={node_ref}
                 role="radiogroup"
+                aria-invalid={props.invalid.then_some("true")}
+                aria-describedby={props.error_id.clone()}
                 class={&props.class}
-                orientation={props.orientation.clone()}
+                data-orientation={props.orientation.clone()}
                 dir={props.dir.clone().unwrap_or(Dir::Ltr)}
-                r#loop={props.r#loop}
             >
                 {for props.children.iter()}
-            </RovingFocus>
-        </ContextProvider<ReducibleRadioGroupContext>>
+            </div>
+        </ContextProvider<RadioGroupContext>>
     }
 }
 
@@ -176,8 +242,8 @@ pub struct RadioGroupItemProps {
 
 #[function_component(RadioGroupItem)]
 pub fn radio_group_item(props: &RadioGroupItemProps) -> Html {
-    let group_context = use_context::<ReducibleRadioGroupContext>()
-        .expect("RadioGroupItem must be a child of RadioGroup");
+    let group_context =
+        use_context::<RadioGroupContext>().expect("RadioGroupItem must be a child of RadioGroup");
 
     let checked = use_memo(
         (group_context.clone(), props.value.clone(), props.checked),
@@ -219,9 +285,7 @@ pub fn radio_group_item(props: &RadioGroupItemProps) -> Html {
                 return;
             }
 
-            group_context.dispatch(RadioGroupAction::SetValue(
-                value.clone().unwrap_or_default(),
-            ));
+            group_context.select.emit(value.clone().unwrap_or_default());
         },
     );
 
@@ -229,9 +293,29 @@ pub fn radio_group_item(props: &RadioGroupItemProps) -> Html {
         toggle.emit(());
     });
 
-    let toggle_by_focus = use_callback(toggle.clone(), move |_: FocusEvent, toggle| {
-        toggle.emit(());
-    });
+    let toggle_by_focus = use_callback(
+        (toggle.clone(), group_context.select_on_focus),
+        move |_: FocusEvent, (toggle, select_on_focus)| {
+            if *select_on_focus {
+                toggle.emit(());
+            }
+        },
+    );
+
+    let toggle_by_keydown = use_callback(
+        (toggle.clone(), group_context.select_on_focus),
+        move |event: KeyboardEvent, (toggle, select_on_focus)| {
+            if *select_on_focus {
+                return;
+            }
+
+            if matches!(event.key().as_str(), " " | "Enter") {
+                // Keeps the button from selecting a second time through its synthetic click
+                event.prevent_default();
+                toggle.emit(());
+            }
+        },
+    );
 
     let disabled = props.disabled || group_context.disabled;
 
@@ -265,6 +349,7 @@ pub fn radio_group_item(props: &RadioGroupItemProps) -> Html {
                     value={props.value.clone()}
                     onclick={&toggle_by_click}
                     onfocus={&toggle_by_focus}
+                    onkeydown={&toggle_by_keydown}
                 >
                     {for props.children.iter()}
                 </button>
@@ -656,4 +741,295 @@ mod tests {
             "true".to_string().into()
         );
     }
+
+    #[wasm_bindgen_test]
+    async fn test_radio_group_select_on_focus_disabled() {
+        let t = render!({
+            html! {
+                <RadioGroup name="radio" select_on_focus={false}>
+                    <RadioGroupItem value={"item1"}>
+                        <RadioGroupItemIndicator>{"Checked"}</RadioGroupItemIndicator>
+                    </RadioGroupItem>
+                    <RadioGroupItem value={"item2"}>
+                        <RadioGroupItemIndicator>{"Checked"}</RadioGroupItemIndicator>
+                    </RadioGroupItem>
+                </RadioGroup>
+            }
+        })
+        .await;
+
+        let radio_group = t.query_by_role("radiogroup").keydown("ArrowRight").await;
+        let radio_items = radio_group.query_all_by_role("radio");
+
+        for radio_item in &radio_items {
+            assert_eq!(
+                radio_item.attribute("aria-checked"),
+                "false".to_string().into()
+            );
+        }
+
+        let mut radio_items = radio_items.into_iter();
+        let radio_item1 = radio_items.next().expect("Expected the first radio item");
+        let radio_item2 = radio_items.next().expect("Expected the second radio item");
+
+        let radio_item2 = radio_item2.keydown(" ").await;
+
+        assert_eq!(
+            radio_item2.attribute("aria-checked"),
+            "true".to_string().into()
+        );
+
+        let radio_item1 = radio_item1.keydown("Enter").await;
+
+        assert_eq!(
+            radio_item1.attribute("aria-checked"),
+            "true".to_string().into()
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_radio_group_should_select_on_click_with_roving_focus() {
+        let t = render!({
+            html! {
+                <RadioGroup name="radio">
+                    <RadioGroupItem value={"item1"} />
+                    <RadioGroupItem value={"item2"} />
+                </RadioGroup>
+            }
+        })
+        .await;
+
+        let radio_items = t.query_all_by_role("radio");
+        assert_eq!(radio_items.len(), 2);
+
+        let radio_item2 = radio_items[1].clone().click().await;
+
+        assert_eq!(
+            radio_item2.attribute("aria-checked"),
+            "true".to_string().into()
+        );
+        assert_eq!(
+            radio_items[0].attribute("aria-checked"),
+            "false".to_string().into()
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_radio_group_should_select_on_click_without_roving_focus() {
+        let t = render!({
+            html! {
+                <RadioGroup name="radio" roving_focus={false}>
+                    <RadioGroupItem value={"item1"} />
+                    <RadioGroupItem value={"item2"} />
+                </RadioGroup>
+            }
+        })
+        .await;
+
+        let radio_group = t.query_by_role("radiogroup");
+        assert!(radio_group.exists());
+
+        let radio_items = t.query_all_by_role("radio");
+        assert_eq!(radio_items.len(), 2);
+
+        let radio_item2 = radio_items[1].clone().click().await;
+
+        assert_eq!(
+            radio_item2.attribute("aria-checked"),
+            "true".to_string().into()
+        );
+        assert_eq!(
+            radio_items[0].attribute("aria-checked"),
+            "false".to_string().into()
+        );
+
+        let radio_item1 = radio_items[0].clone().click().await;
+
+        assert_eq!(
+            radio_item1.attribute("aria-checked"),
+            "true".to_string().into()
+        );
+        assert_eq!(
+            radio_items[1].attribute("aria-checked"),
+            "false".to_string().into()
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_controlled_radio_group_label_click() {
+        let t = render!({
+            let value = use_state(|| AttrValue::from("item1"));
+
+            let on_value_change = use_callback(value.clone(), |next: AttrValue, value| {
+                value.set(next);
+            });
+
+            let reset = use_callback(value.clone(), |_: MouseEvent, value| {
+                value.set(AttrValue::from("item1"));
+            });
+
+            use_remember_value((*value).clone());
+
+            html! {
+                <>
+                    <RadioGroup value={(*value).clone()} {on_value_change}>
+                        <RadioGroupItem id="item1" value="item1" />
+                        <label for="item1">{"Label 1"}</label>
+                        <RadioGroupItem id="item2" value="item2" />
+                        <label for="item2">{"Label 2"}</label>
+                    </RadioGroup>
+                    <button onclick={reset}>{"Reset"}</button>
+                </>
+            }
+        })
+        .await;
+
+        // The label focuses and clicks the item it's for
+        t.query_by_text("Label 2").click().await;
+
+        assert_eq!(t.get_remembered_value::<AttrValue>(), "item2");
+        assert_eq!(
+            t.query_all_by_role("radio")[1].attribute("aria-checked"),
+            Some("true".to_string())
+        );
+
+        // The parent changing the value moves the selection along
+        t.query_by_text("Reset").click().await;
+
+        assert_eq!(t.get_remembered_value::<AttrValue>(), "item1");
+        assert_eq!(
+            t.query_all_by_role("radio")[0].attribute("aria-checked"),
+            Some("true".to_string())
+        );
+        assert_eq!(
+            t.query_all_by_role("radio")[1].attribute("aria-checked"),
+            Some("false".to_string())
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_controlled_radio_group_keeps_the_parent_value() {
+        let t = render!({
+            let value = use_state(|| AttrValue::from("item1"));
+            let changes = use_state(Vec::<AttrValue>::new);
+
+            // Records the request but never accepts it
+            let on_value_change = use_callback(changes.clone(), |next: AttrValue, changes| {
+                let mut next_changes = (**changes).clone();
+                next_changes.push(next);
+                changes.set(next_changes);
+            });
+
+            let select_item2 = use_callback(value.clone(), |_: MouseEvent, value| {
+                value.set(AttrValue::from("item2"));
+            });
+
+            use_remember_value((*changes).clone());
+
+            html! {
+                <>
+                    <RadioGroup value={(*value).clone()} {on_value_change}>
+                        <RadioGroupItem value="item1" />
+                        <RadioGroupItem value="item2" />
+                    </RadioGroup>
+                    <button onclick={select_item2}>{"Select item2"}</button>
+                </>
+            }
+        })
+        .await;
+
+        t.query_all_by_role("radio")[1].clone().click().await;
+
+        assert_eq!(
+            t.get_remembered_value::<Vec<AttrValue>>(),
+            vec![AttrValue::from("item2")]
+        );
+        assert_eq!(
+            t.query_all_by_role("radio")[0].attribute("aria-checked"),
+            Some("true".to_string())
+        );
+        assert_eq!(
+            t.query_all_by_role("radio")[1].attribute("aria-checked"),
+            Some("false".to_string())
+        );
+
+        // A value the parent passes moves the selection without being reported back
+        t.query_by_text("Select item2").click().await;
+
+        assert_eq!(
+            t.get_remembered_value::<Vec<AttrValue>>(),
+            vec![AttrValue::from("item2")]
+        );
+        assert_eq!(
+            t.query_all_by_role("radio")[1].attribute("aria-checked"),
+            Some("true".to_string())
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_radio_group_form_reset() {
+        let t = render!({
+            html! {
+                <form>
+                    <RadioGroup default_value="item1">
+                        <RadioGroupItem value="item1" />
+                        <RadioGroupItem value="item2" />
+                    </RadioGroup>
+                    <button type="reset">{"Reset"}</button>
+                </form>
+            }
+        })
+        .await;
+
+        t.query_all_by_role("radio")[1].clone().click().await;
+
+        assert_eq!(
+            t.query_all_by_role("radio")[1].attribute("aria-checked"),
+            Some("true".to_string())
+        );
+
+        t.query_by_text("Reset").click().await;
+
+        assert_eq!(
+            t.query_all_by_role("radio")[0].attribute("aria-checked"),
+            Some("true".to_string())
+        );
+        assert_eq!(
+            t.query_all_by_role("radio")[1].attribute("aria-checked"),
+            Some("false".to_string())
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_controlled_radio_group_form_reset() {
+        let t = render!({
+            let value = use_state(|| AttrValue::from("item2"));
+
+            let on_value_change = use_callback(value.clone(), |next: AttrValue, value| {
+                value.set(next);
+            });
+
+            use_remember_value((*value).clone());
+
+            html! {
+                <form>
+                    <RadioGroup value={(*value).clone()} default_value="item1" {on_value_change}>
+                        <RadioGroupItem value="item1" />
+                        <RadioGroupItem value="item2" />
+                    </RadioGroup>
+                    <button type="reset">{"Reset"}</button>
+                </form>
+            }
+        })
+        .await;
+
+        // The reset reaches the parent as a change to the default value
+        t.query_by_text("Reset").click().await;
+
+        assert_eq!(t.get_remembered_value::<AttrValue>(), "item1");
+        assert_eq!(
+            t.query_all_by_role("radio")[0].attribute("aria-checked"),
+            Some("true".to_string())
+        );
+    }
 }
@@ -0,0 +1,697 @@
+use std::collections::BTreeMap;
+use std::rc::Rc;
+use web_sys::wasm_bindgen::JsCast;
+use web_sys::Element;
+use yew::prelude::*;
+use yewlish_utils::enums::Orientation;
+
+/// Size bounds of a panel, in percent of the group
+#[derive(Clone, Debug, PartialEq)]
+struct PanelConstraints {
+    default_size: Option<f64>,
+    min_size: f64,
+    max_size: f64,
+}
+
+/// Panels without a default size share what the others leave, then the sizes are fitted into 100%.
+fn initial_layout(constraints: &[PanelConstraints]) -> Vec<f64> {
+    let fixed = constraints
+        .iter()
+        .filter_map(|constraints| constraints.default_size)
+        .sum::<f64>();
+
+    let flexible = constraints
+        .iter()
+        .filter(|constraints| constraints.default_size.is_none())
+        .count();
+
+    let share = if flexible > 0 {
+        ((100.0 - fixed) / flexible as f64).max(0.0)
+    } else {
+        0.0
+    };
+
+    let sizes = constraints
+        .iter()
+        .map(|constraints| {
+            constraints
+                .default_size
+                .unwrap_or(share)
+                .clamp(constraints.min_size, constraints.max_size)
+        })
+        .collect();
+
+    fit(sizes, constraints)
+}
+
+/// Spreads whatever the sizes miss or exceed of 100% over the panels, starting from the last
+/// one, as far as their bounds allow.
+fn fit(mut sizes: Vec<f64>, constraints: &[PanelConstraints]) -> Vec<f64> {
+    let mut remaining = 100.0 - sizes.iter().sum::<f64>();
+
+    for (size, constraints) in sizes.iter_mut().zip(constraints).rev() {
+        if remaining.abs() < f64::EPSILON {
+            break;
+        }
+
+        let next = (*size + remaining).clamp(constraints.min_size, constraints.max_size);
+        remaining -= next - *size;
+        *size = next;
+    }
+
+    sizes
+}
+
+/// Moves the boundary between the panels at `pivot` and `pivot + 1` by `delta` percent.
+///
+/// The panel the boundary moves away from grows up to its max size, while the panels on the
+/// other side shrink down to their min sizes, nearest first. The move stops short once either
+/// side runs out of room.
+fn resize(sizes: &[f64], constraints: &[PanelConstraints], pivot: usize, delta: f64) -> Vec<f64> {
+    let mut next = sizes.to_vec();
+
+    if pivot + 1 >= sizes.len() || delta == 0.0 {
+        return next;
+    }
+
+    let (growing, shrinking) = if delta > 0.0 {
+        (pivot, (pivot + 1..sizes.len()).collect::<Vec<_>>())
+    } else {
+        (pivot + 1, (0..=pivot).rev().collect::<Vec<_>>())
+    };
+
+    let room = constraints[growing].max_size - sizes[growing];
+
+    let available = shrinking
+        .iter()
+        .map(|&index| (sizes[index] - constraints[index].min_size).max(0.0))
+        .sum::<f64>();
+
+    let mut amount = delta.abs().min(room).min(available).max(0.0);
+    next[growing] += amount;
+
+    for index in shrinking {
+        let taken = (sizes[index] - constraints[index].min_size)
+            .max(0.0)
+            .min(amount);
+
+        next[index] -= taken;
+        amount -= taken;
+    }
+
+    next
+}
+
+/// The panels of a group keyed by their position among the group's children
+#[derive(Clone, Debug, Default, PartialEq)]
+struct ResizableLayout {
+    panels: BTreeMap<usize, PanelConstraints>,
+    sizes: Vec<f64>,
+}
+
+impl ResizableLayout {
+    fn constraints(&self) -> Vec<PanelConstraints> {
+        self.panels.values().cloned().collect()
+    }
+
+    fn size_of(&self, index: usize) -> Option<f64> {
+        let position = self.panels.keys().position(|key| *key == index)?;
+        self.sizes.get(position).copied()
+    }
+
+    /// The position of the panel right before the child at `index`, when a panel follows it too
+    fn pivot(&self, index: usize) -> Option<usize> {
+        let before = self.panels.keys().filter(|key| **key < index).count();
+        (before > 0 && before < self.panels.len()).then(|| before - 1)
+    }
+}
+
+enum ResizableLayoutAction {
+    Register(usize, PanelConstraints),
+    Unregister(usize),
+    Resize(Vec<f64>),
+}
+
+impl Reducible for ResizableLayout {
+    type Action = ResizableLayoutAction;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let mut panels = self.panels.clone();
+
+        match action {
+            ResizableLayoutAction::Register(index, constraints) => {
+                if panels.get(&index) == Some(&constraints) {
+                    return self;
+                }
+
+                panels.insert(index, constraints);
+            }
+            ResizableLayoutAction::Unregister(index) => {
+                if panels.remove(&index).is_none() {
+                    return self;
+                }
+            }
+            ResizableLayoutAction::Resize(sizes) => {
+                if sizes.len() != panels.len() || sizes == self.sizes {
+                    return self;
+                }
+
+                return Rc::new(Self { panels, sizes });
+            }
+        }
+
+        // A different set of panels starts over from their default sizes
+        let sizes = initial_layout(&panels.values().cloned().collect::<Vec<_>>());
+        Rc::new(Self { panels, sizes })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct ResizableContext {
+    orientation: Orientation,
+    keyboard_step: f64,
+    layout: ResizableLayout,
+    node_ref: NodeRef,
+    on_register: Callback<(usize, PanelConstraints)>,
+    on_unregister: Callback<usize>,
+    on_resize: Callback<Vec<f64>>,
+}
+
+/// Where a panel or a handle sits among the group's children, provided by the group
+#[derive(Clone, Debug, PartialEq)]
+struct ResizableItemIndex(usize);
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct ResizablePanelGroupProps {
+    #[prop_or_default]
+    pub children: Children,
+    #[prop_or_default]
+    pub class: Option<AttrValue>,
+    /// `Horizontal` lays the panels out side by side, `Vertical` stacks them
+    #[prop_or_default]
+    pub orientation: Orientation,
+    /// How far, in percent, an arrow key moves a focused handle
+    #[prop_or(10.0)]
+    pub keyboard_step: f64,
+    /// Called with the panel sizes, in percent, whenever they change
+    #[prop_or_default]
+    pub on_layout: Callback<Vec<f64>>,
+}
+
+/// A group of panels split by handles that resize them.
+///
+/// Handles resize the panels on either side of them with the pointer or, once focused, with
+/// the arrow keys, Home and End. Sizes are percentages of the group and stay within each
+/// panel's `min_size` and `max_size`: shrinking past a panel's minimum goes on to shrink the
+/// panels behind it. Adding or removing panels resets the layout to their default sizes.
+///
+/// # Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use resizable::{ResizableHandle, ResizablePanel, ResizablePanelGroup};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <ResizablePanelGroup class="h-64">
+///             <ResizablePanel default_size={25.0} min_size={10.0}>{"Sidebar"}</ResizablePanel>
+///             <ResizableHandle />
+///             <ResizablePanel>{"Content"}</ResizablePanel>
+///         </ResizablePanelGroup>
+///     }
+/// }
+/// ```
+#[function_component(ResizablePanelGroup)]
+pub fn resizable_panel_group(props: &ResizablePanelGroupProps) -> Html {
+    let layout = use_reducer(ResizableLayout::default);
+    let node_ref = use_node_ref();
+
+    let on_register = use_callback(
+        layout.dispatcher(),
+        |(index, constraints): (usize, PanelConstraints), layout| {
+            layout.dispatch(ResizableLayoutAction::Register(index, constraints));
+        },
+    );
+
+    let on_unregister = use_callback(layout.dispatcher(), |index: usize, layout| {
+        layout.dispatch(ResizableLayoutAction::Unregister(index));
+    });
+
+    let on_resize = use_callback(layout.dispatcher(), |sizes: Vec<f64>, layout| {
+        layout.dispatch(ResizableLayoutAction::Resize(sizes));
+    });
+
+    use_effect_with(layout.sizes.clone(), {
+        let on_layout = props.on_layout.clone();
+
+        move |sizes| {
+            if !sizes.is_empty() {
+                on_layout.emit(sizes.clone());
+            }
+        }
+    });
+
+    let flex_direction = match props.orientation {
+        Orientation::Horizontal => "row",
+        Orientation::Vertical => "column",
+    };
+
+    let context = ResizableContext {
+        orientation: props.orientation.clone(),
+        keyboard_step: props.keyboard_step,
+        layout: (*layout).clone(),
+        node_ref: node_ref.clone(),
+        on_register,
+        on_unregister,
+        on_resize,
+    };
+
+    html! {
+        <ContextProvider<ResizableContext> {context}>
+            <div
+                ref={node_ref}
+                class={&props.class}
+                style={format!("display: flex; flex-direction: {flex_direction};")}
+                data-resizable-panel-group=""
+                data-orientation={props.orientation.clone()}
+            >
+                {for props.children.iter().enumerate().map(|(index, child)| html! {
+                    <ContextProvider<ResizableItemIndex> context={ResizableItemIndex(index)}>
+                        {child}
+                    </ContextProvider<ResizableItemIndex>>
+                })}
+            </div>
+        </ContextProvider<ResizableContext>>
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct ResizablePanelProps {
+    #[prop_or_default]
+    pub children: Children,
+    #[prop_or_default]
+    pub class: Option<AttrValue>,
+    /// In percent of the group, panels without it share the remaining space
+    #[prop_or_default]
+    pub default_size: Option<f64>,
+    #[prop_or(0.0)]
+    pub min_size: f64,
+    #[prop_or(100.0)]
+    pub max_size: f64,
+}
+
+#[function_component(ResizablePanel)]
+pub fn resizable_panel(props: &ResizablePanelProps) -> Html {
+    let context = use_context::<ResizableContext>()
+        .expect("ResizablePanel must be a child of ResizablePanelGroup");
+
+    let ResizableItemIndex(index) = use_context::<ResizableItemIndex>()
+        .expect("ResizablePanel must be a child of ResizablePanelGroup");
+
+    let min_size = props.min_size.clamp(0.0, 100.0);
+
+    let constraints = PanelConstraints {
+        default_size: props.default_size,
+        min_size,
+        max_size: props.max_size.clamp(min_size, 100.0),
+    };
+
+    use_effect_with(
+        (
+            index,
+            constraints,
+            context.on_register.clone(),
+            context.on_unregister.clone(),
+        ),
+        |(index, constraints, on_register, on_unregister)| {
+            on_register.emit((*index, constraints.clone()));
+
+            let index = *index;
+            let on_unregister = on_unregister.clone();
+
+            move || {
+                on_unregister.emit(index);
+            }
+        },
+    );
+
+    // Until the group has measured out the layout the panels share the space evenly
+    let size = context.layout.size_of(index);
+    let flex_grow = size.unwrap_or(1.0);
+
+    html! {
+        <div
+            class={&props.class}
+            style={format!("flex: {flex_grow} 1 0px; overflow: hidden;")}
+            data-resizable-panel=""
+            data-size={size.map(|size| format!("{size:.1}"))}
+        >
+            {props.children.clone()}
+        </div>
+    }
+}
+
+/// The drag in progress, resolved against the layout it started from
+#[derive(Debug, PartialEq)]
+struct DragStart {
+    position: f64,
+    group_size: f64,
+    sizes: Vec<f64>,
+}
+
+fn pointer_position(event: &PointerEvent, orientation: &Orientation) -> f64 {
+    match orientation {
+        Orientation::Horizontal => f64::from(event.client_x()),
+        Orientation::Vertical => f64::from(event.client_y()),
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct ResizableHandleProps {
+    /// E.g. a grip icon, the handle renders nothing on its own
+    #[prop_or_default]
+    pub children: Children,
+    #[prop_or_default]
+    pub class: Option<AttrValue>,
+    #[prop_or_default]
+    pub disabled: bool,
+}
+
+/// Resizes the panels right before and after it within a `ResizablePanelGroup`.
+#[function_component(ResizableHandle)]
+pub fn resizable_handle(props: &ResizableHandleProps) -> Html {
+    let context = use_context::<ResizableContext>()
+        .expect("ResizableHandle must be a child of ResizablePanelGroup");
+
+    let ResizableItemIndex(index) = use_context::<ResizableItemIndex>()
+        .expect("ResizableHandle must be a child of ResizablePanelGroup");
+
+    let drag = use_mut_ref(|| None::<DragStart>);
+    let dragging = use_state_eq(|| false);
+
+    let onpointerdown = use_callback(
+        (
+            context.clone(),
+            drag.clone(),
+            dragging.setter(),
+            props.disabled,
+        ),
+        |event: PointerEvent, (context, drag, set_dragging, disabled)| {
+            if *disabled || !event.is_primary() || event.button() != 0 {
+                return;
+            }
+
+            let Some(group) = context.node_ref.cast::<Element>() else {
+                return;
+            };
+
+            let rect = group.get_bounding_client_rect();
+
+            let group_size = match context.orientation {
+                Orientation::Horizontal => rect.width(),
+                Orientation::Vertical => rect.height(),
+            };
+
+            if group_size <= 0.0 {
+                return;
+            }
+
+            // Keeps the moves coming even once the pointer outruns the handle
+            if let Some(handle) = event
+                .current_target()
+                .and_then(|target| target.dyn_into::<Element>().ok())
+            {
+                if let Err(error) = handle.set_pointer_capture(event.pointer_id()) {
+                    log::error!("Failed to capture the pointer: {error:?}");
+                }
+            }
+
+            event.prevent_default();
+
+            *drag.borrow_mut() = Some(DragStart {
+                position: pointer_position(&event, &context.orientation),
+                group_size,
+                sizes: context.layout.sizes.clone(),
+            });
+
+            set_dragging.set(true);
+        },
+    );
+
+    let onpointermove = use_callback(
+        (context.clone(), drag.clone(), index),
+        |event: PointerEvent, (context, drag, index)| {
+            let drag = drag.borrow();
+
+            let (Some(drag), Some(pivot)) = (drag.as_ref(), context.layout.pivot(*index)) else {
+                return;
+            };
+
+            let delta = (pointer_position(&event, &context.orientation) - drag.position)
+                / drag.group_size
+                * 100.0;
+
+            context.on_resize.emit(resize(
+                &drag.sizes,
+                &context.layout.constraints(),
+                pivot,
+                delta,
+            ));
+        },
+    );
+
+    let onpointerup = use_callback(
+        (drag.clone(), dragging.setter()),
+        |event: PointerEvent, (drag, set_dragging)| {
+            if drag.borrow_mut().take().is_none() {
+                return;
+            }
+
+            if let Some(handle) = event
+                .current_target()
+                .and_then(|target| target.dyn_into::<Element>().ok())
+            {
+                if handle.has_pointer_capture(event.pointer_id()) {
+                    let _ = handle.release_pointer_capture(event.pointer_id());
+                }
+            }
+
+            set_dragging.set(false);
+        },
+    );
+
+    let onkeydown = use_callback(
+        (context.clone(), index, props.disabled),
+        |event: KeyboardEvent, (context, index, disabled)| {
+            let Some(pivot) = context.layout.pivot(*index).filter(|_| !*disabled) else {
+                return;
+            };
+
+            let step = context.keyboard_step;
+
+            let delta = match (event.key().as_str(), &context.orientation) {
+                ("ArrowLeft", Orientation::Horizontal) | ("ArrowUp", Orientation::Vertical) => {
+                    -step
+                }
+                ("ArrowRight", Orientation::Horizontal) | ("ArrowDown", Orientation::Vertical) => {
+                    step
+                }
+                ("Home", _) => -100.0,
+                ("End", _) => 100.0,
+                _ => return,
+            };
+
+            event.prevent_default();
+
+            context.on_resize.emit(resize(
+                &context.layout.sizes,
+                &context.layout.constraints(),
+                pivot,
+                delta,
+            ));
+        },
+    );
+
+    // The handle's value is the size of the panel it follows
+    let pivot = context.layout.pivot(index);
+    let constraints = context.layout.constraints();
+    let value = pivot.and_then(|pivot| context.layout.sizes.get(pivot).copied());
+    let bounds = pivot.and_then(|pivot| constraints.get(pivot));
+
+    // A separator splitting panels side by side is itself vertical
+    let aria_orientation = match context.orientation {
+        Orientation::Horizontal => "vertical",
+        Orientation::Vertical => "horizontal",
+    };
+
+    let cursor = match context.orientation {
+        Orientation::Horizontal => "col-resize",
+        Orientation::Vertical => "row-resize",
+    };
+
+    html! {
+        <div
+            role="separator"
+            class={&props.class}
+            style={format!("flex: none; touch-action: none; user-select: none; cursor: {cursor};")}
+            tabindex={(!props.disabled).then_some("0")}
+            aria-orientation={aria_orientation}
+            aria-valuenow={value.map(|value| format!("{value:.0}"))}
+            aria-valuemin={bounds.map(|bounds| format!("{:.0}", bounds.min_size))}
+            aria-valuemax={bounds.map(|bounds| format!("{:.0}", bounds.max_size))}
+            aria-disabled={props.disabled.then_some("true")}
+            data-resizable-handle=""
+            data-state={if *dragging { "dragging" } else { "inactive" }}
+            data-orientation={context.orientation.clone()}
+            data-disabled={props.disabled.then_some("")}
+            {onpointerdown}
+            {onpointermove}
+            onpointerup={onpointerup.clone()}
+            onpointercancel={onpointerup}
+            {onkeydown}
+        >
+            {props.children.clone()}
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+    use yewlish_testing_tools::*;
+
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    fn constraints(default_size: Option<f64>, min_size: f64, max_size: f64) -> PanelConstraints {
+        PanelConstraints {
+            default_size,
+            min_size,
+            max_size,
+        }
+    }
+
+    #[test]
+    fn test_initial_layout() {
+        assert_eq!(
+            initial_layout(&[
+                constraints(Some(20.0), 0.0, 100.0),
+                constraints(None, 0.0, 100.0),
+                constraints(None, 0.0, 100.0),
+            ]),
+            vec![20.0, 40.0, 40.0]
+        );
+
+        // The last panel can't take all of what's left, the one before it takes the rest
+        assert_eq!(
+            initial_layout(&[
+                constraints(Some(20.0), 0.0, 100.0),
+                constraints(Some(20.0), 0.0, 100.0),
+                constraints(Some(20.0), 0.0, 30.0),
+            ]),
+            vec![20.0, 50.0, 30.0]
+        );
+    }
+
+    #[test]
+    fn test_resize() {
+        let bounds = [
+            constraints(None, 10.0, 100.0),
+            constraints(None, 20.0, 100.0),
+            constraints(None, 10.0, 100.0),
+        ];
+
+        assert_eq!(
+            resize(&[30.0, 40.0, 30.0], &bounds, 0, 10.0),
+            vec![40.0, 30.0, 30.0]
+        );
+
+        // Past its minimum the next panel stops shrinking and the one after it takes over
+        assert_eq!(
+            resize(&[30.0, 40.0, 30.0], &bounds, 0, 30.0),
+            vec![60.0, 20.0, 20.0]
+        );
+
+        // Every panel after the handle is at its minimum
+        assert_eq!(
+            resize(&[30.0, 40.0, 30.0], &bounds, 0, 100.0),
+            vec![70.0, 20.0, 10.0]
+        );
+
+        assert_eq!(
+            resize(&[30.0, 40.0, 30.0], &bounds, 1, -50.0),
+            vec![10.0, 20.0, 70.0]
+        );
+    }
+
+    #[test]
+    fn test_resize_respects_the_max_size() {
+        let bounds = [constraints(None, 0.0, 60.0), constraints(None, 0.0, 100.0)];
+
+        assert_eq!(resize(&[50.0, 50.0], &bounds, 0, 30.0), vec![60.0, 40.0]);
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_resizable_default_layout() {
+        let t = render!({
+            html! {
+                <ResizablePanelGroup>
+                    <ResizablePanel default_size={30.0}>{"First"}</ResizablePanel>
+                    <ResizableHandle />
+                    <ResizablePanel>{"Second"}</ResizablePanel>
+                </ResizablePanelGroup>
+            }
+        })
+        .await;
+
+        let handle = t.query_by_role("separator");
+
+        assert_eq!(handle.attribute("aria-valuenow"), Some("30".to_string()));
+        assert_eq!(
+            handle.attribute("aria-orientation"),
+            Some("vertical".to_string())
+        );
+
+        assert_eq!(
+            t.query_by_text("First").attribute("data-size"),
+            Some("30.0".to_string())
+        );
+
+        assert_eq!(
+            t.query_by_text("Second").attribute("data-size"),
+            Some("70.0".to_string())
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_resizable_keyboard() {
+        let t = render!({
+            let layout = use_state(Vec::<f64>::new);
+
+            let on_layout = use_callback(layout.setter(), |sizes: Vec<f64>, set_layout| {
+                set_layout.set(sizes);
+            });
+
+            use_remember_value((*layout).clone());
+
+            html! {
+                <ResizablePanelGroup orientation={Orientation::Vertical} {on_layout}>
+                    <ResizablePanel default_size={50.0} max_size={70.0}>{"First"}</ResizablePanel>
+                    <ResizableHandle />
+                    <ResizablePanel>{"Second"}</ResizablePanel>
+                </ResizablePanelGroup>
+            }
+        })
+        .await;
+
+        t.query_by_role("separator").keydown("ArrowDown").await;
+        assert_eq!(t.get_remembered_value::<Vec<f64>>(), vec![60.0, 40.0]);
+
+        t.query_by_role("separator").keydown("End").await;
+        assert_eq!(t.get_remembered_value::<Vec<f64>>(), vec![70.0, 30.0]);
+
+        t.query_by_role("separator").keydown("Home").await;
+        assert_eq!(t.get_remembered_value::<Vec<f64>>(), vec![0.0, 100.0]);
+    }
+}
@@ -22,54 +22,59 @@ impl NodeListIntoVec for NodeList {
 
 pub static FOCUSABLE_ELEMENTS: [&str; 5] = ["button", "a", "input", "select", "textarea"];
 
-pub static FOCUSABLE_SELECTOR: &str =
-    "button, a, input, select, textarea, [tabindex]:not([tabindex='-1'])";
+/// Media elements are only focusable while they show their controls
+pub static FOCUSABLE_MEDIA_ELEMENTS: [&str; 2] = ["audio", "video"];
+
+pub static FOCUSABLE_SELECTOR: &str = "button, a, input, select, textarea, audio[controls], \
+    video[controls], [contenteditable]:not([contenteditable='false']), \
+    [tabindex]:not([tabindex='-1'])";
 
 pub static SKIP_FOCUS_ATTRIBUTES: [&str; 3] = ["disabled", "hidden", "aria-hidden"];
 
 #[must_use]
 pub fn is_focusable(element: &HtmlElement) -> bool {
-    let is_focusable_element =
-        FOCUSABLE_ELEMENTS.contains(&element.tag_name().to_lowercase().as_str());
-
-    let has_no_skip_attributes = SKIP_FOCUS_ATTRIBUTES
+    let has_skip_attributes = SKIP_FOCUS_ATTRIBUTES
         .iter()
-        .all(|&attr| element.get_attribute(attr).is_none());
+        .any(|&attr| element.get_attribute(attr).is_some());
 
-    let has_valid_tabindex = element
+    let tabindex = element
         .get_attribute("tabindex")
-        .map_or(false, |tabindex| tabindex != "-1");
+        .and_then(|tabindex| tabindex.trim().parse::<i32>().ok());
 
-    (is_focusable_element || has_valid_tabindex) && has_no_skip_attributes
-}
+    // A negative tabindex takes the element out of the tab order whatever it is
+    if has_skip_attributes || tabindex.is_some_and(|tabindex| tabindex < 0) {
+        return false;
+    }
 
-#[must_use]
-pub fn get_focusable_element(element: &Element) -> Option<HtmlElement> {
-    if let Ok(element) = <web_sys::Element as Clone>::clone(element).dyn_into::<HtmlElement>() {
-        if is_focusable(&element) {
-            return Some(element);
-        }
+    let tag_name = element.tag_name().to_lowercase();
 
-        let children = element.children();
+    let is_focusable_element = FOCUSABLE_ELEMENTS.contains(&tag_name.as_str());
 
-        for index in 0..children.length() {
-            let child_option = children.item(index);
-            let child = match child_option {
-                Some(child) => child.dyn_into::<HtmlElement>().ok(),
-                None => None,
-            };
+    let is_focusable_media =
+        FOCUSABLE_MEDIA_ELEMENTS.contains(&tag_name.as_str()) && element.has_attribute("controls");
 
-            if let Some(child) = child {
-                if is_focusable(&child) {
-                    return Some(child);
-                }
-            }
+    let is_content_editable = element
+        .get_attribute("contenteditable")
+        .is_some_and(|value| value != "false");
 
-            continue;
+    is_focusable_element || is_focusable_media || is_content_editable || tabindex.is_some()
+}
+
+/// Returns `element` if it's focusable, otherwise its first focusable descendant.
+#[must_use]
+pub fn get_focusable_element(element: &Element) -> Option<HtmlElement> {
+    if let Some(element) = element.dyn_ref::<HtmlElement>() {
+        if is_focusable(element) {
+            return Some(element.clone());
         }
     }
 
-    None
+    element
+        .query_selector_all(FOCUSABLE_SELECTOR)
+        .ok()?
+        .into_vec()
+        .into_iter()
+        .find(is_focusable)
 }
 
 pub fn focus_child(child: Option<Element>) {
@@ -90,33 +95,54 @@ pub fn focus_child(child: Option<Element>) {
     }
 }
 
+/// Returns the items a roving focus group moves between: the descendants of `group` matching
+/// `item_selector`, or its direct children without one.
+#[must_use]
+pub fn get_roving_items(group: &Element, item_selector: Option<&str>) -> Vec<Element> {
+    let Some(item_selector) = item_selector else {
+        let children = group.children();
+        return (0..children.length())
+            .filter_map(|index| children.item(index))
+            .collect();
+    };
+
+    let Ok(node_list) = group.query_selector_all(item_selector) else {
+        log::error!("Invalid item selector: {item_selector}");
+        return Vec::new();
+    };
+
+    (0..node_list.length())
+        .filter_map(|index| node_list.item(index))
+        .filter_map(|node| node.dyn_into::<Element>().ok())
+        .collect()
+}
+
+/// Returns the focusable descendants of `container` in DOM order.
 #[must_use]
-pub fn get_all_focusable_elements() -> Vec<HtmlElement> {
-    match window() {
-        Some(window) => match window.document() {
-            Some(document) => match document.query_selector_all(FOCUSABLE_SELECTOR) {
-                Ok(node_list) => node_list
-                    .into_vec()
-                    .iter()
-                    .filter_map(|element| element.dyn_ref::<HtmlElement>())
-                    .filter(|element| {
-                        SKIP_FOCUS_ATTRIBUTES
-                            .iter()
-                            .all(|attr| element.get_attribute(attr).is_none())
-                    })
-                    .cloned()
-                    .collect::<Vec<HtmlElement>>(),
-                Err(_) => Vec::new(),
-            },
-            None => Vec::new(),
-        },
-        None => Vec::new(),
+pub fn get_all_focusable_elements(container: &Element) -> Vec<HtmlElement> {
+    match container.query_selector_all(FOCUSABLE_SELECTOR) {
+        Ok(node_list) => node_list
+            .into_vec()
+            .into_iter()
+            .filter(is_focusable)
+            .collect(),
+        Err(_) => Vec::new(),
     }
 }
 
+/// Returns every focusable element of the document in DOM order.
+#[must_use]
+pub fn get_document_focusable_elements() -> Vec<HtmlElement> {
+    window()
+        .and_then(|window| window.document())
+        .and_then(|document| document.document_element())
+        .map(|root| get_all_focusable_elements(&root))
+        .unwrap_or_default()
+}
+
 #[must_use]
 pub fn get_next_focusable_element(current_element: &HtmlElement) -> HtmlElement {
-    let all_focusable_elements = get_all_focusable_elements();
+    let all_focusable_elements = get_document_focusable_elements();
 
     let current_index = all_focusable_elements
         .iter()
@@ -134,7 +160,7 @@ pub fn get_next_focusable_element(current_element: &HtmlElement) -> HtmlElement
 
 #[must_use]
 pub fn get_prev_focusable_element(current_element: &HtmlElement) -> HtmlElement {
-    let all_focusable_elements = get_all_focusable_elements();
+    let all_focusable_elements = get_document_focusable_elements();
 
     let current_index = all_focusable_elements
         .iter()
@@ -149,3 +175,107 @@ pub fn get_prev_focusable_element(current_element: &HtmlElement) -> HtmlElement
 
     all_focusable_elements[prev_index].clone()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    fn fixture() -> Element {
+        let container = gloo_utils::document().create_element("div").unwrap();
+
+        container.set_inner_html(
+            r##"
+                <div id="plain"></div>
+                <button id="button"></button>
+                <button id="disabled" disabled></button>
+                <button id="negative" tabindex="-1"></button>
+                <input id="hidden" hidden />
+                <div id="tabbable" tabindex="0"></div>
+                <div id="editable" contenteditable="true"></div>
+                <div id="not-editable" contenteditable="false"></div>
+                <audio id="audio"></audio>
+                <video id="video" controls></video>
+                <span><a id="nested" href="#"></a></span>
+            "##,
+        );
+
+        container
+    }
+
+    fn ids(elements: &[HtmlElement]) -> Vec<String> {
+        elements.iter().map(|element| element.id()).collect()
+    }
+
+    #[wasm_bindgen_test]
+    fn test_is_focusable() {
+        let container = fixture();
+
+        let is_focusable_by_id = |id: &str| {
+            let element = container
+                .query_selector(&format!("#{id}"))
+                .unwrap()
+                .unwrap()
+                .dyn_into::<HtmlElement>()
+                .unwrap();
+
+            is_focusable(&element)
+        };
+
+        assert!(is_focusable_by_id("button"));
+        assert!(is_focusable_by_id("tabbable"));
+        assert!(is_focusable_by_id("editable"));
+        assert!(is_focusable_by_id("video"));
+        assert!(is_focusable_by_id("nested"));
+
+        assert!(!is_focusable_by_id("plain"));
+        assert!(!is_focusable_by_id("disabled"));
+        assert!(!is_focusable_by_id("negative"));
+        assert!(!is_focusable_by_id("hidden"));
+        assert!(!is_focusable_by_id("not-editable"));
+        assert!(!is_focusable_by_id("audio"));
+    }
+
+    #[wasm_bindgen_test]
+    fn test_get_all_focusable_elements_in_dom_order() {
+        let container = fixture();
+
+        assert_eq!(
+            ids(&get_all_focusable_elements(&container)),
+            vec!["button", "tabbable", "editable", "video", "nested"]
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn test_get_roving_items() {
+        let container = fixture();
+
+        assert_eq!(get_roving_items(&container, None).len(), 11);
+        assert_eq!(
+            get_roving_items(&container, Some("a, [tabindex]"))
+                .iter()
+                .map(Element::id)
+                .collect::<Vec<_>>(),
+            vec!["negative", "tabbable", "nested"]
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn test_get_focusable_element_searches_descendants() {
+        let container = fixture();
+
+        assert_eq!(
+            get_focusable_element(&container).map(|element| element.id()),
+            Some("button".to_string())
+        );
+
+        let span = container.query_selector("span").unwrap().unwrap();
+
+        assert_eq!(
+            get_focusable_element(&span).map(|element| element.id()),
+            Some("nested".to_string())
+        );
+    }
+}
@@ -72,10 +72,11 @@ impl RovingIterator for IndexRoving {
     }
 }
 
+/// Starts at the first item in DOM order whatever the direction, `dir` is applied per move.
 #[hook]
-pub fn use_roving_iterator(length: u32, r#loop: bool, dir: &Dir) -> Rc<RefCell<IndexRoving>> {
+pub fn use_roving_iterator(length: u32, r#loop: bool) -> Rc<RefCell<IndexRoving>> {
     use_mut_ref(|| IndexRoving {
-        current: if *dir == Dir::Ltr { 0 } else { length - 1 },
+        current: 0,
         length,
         r#loop,
     })
@@ -3,11 +3,12 @@ pub mod hooks;
 
 use helpers::*;
 use hooks::use_roving_iterator::*;
-use web_sys::{wasm_bindgen::JsCast, HtmlElement};
+use std::{cell::RefCell, rc::Rc};
+use web_sys::{Element, HtmlElement};
 use yew::prelude::*;
 use yewlish_utils::{
     enums::{Dir, Orientation},
-    hooks::{use_children_as_html_collection, use_keydown},
+    hooks::use_keydown,
 };
 
 #[derive(Clone, Debug, PartialEq, Properties)]
@@ -23,71 +24,127 @@ pub struct RovingFocusProps {
     pub r#loop: bool,
     #[prop_or_default]
     pub role: Option<AttrValue>,
+    #[prop_or_default]
+    pub aria_invalid: Option<AttrValue>,
+    #[prop_or_default]
+    pub aria_describedby: Option<AttrValue>,
+    #[prop_or_default]
+    pub aria_orientation: Option<AttrValue>,
+    /// Attached to the group element instead of an internal ref, keep it stable across renders
+    #[prop_or_default]
+    pub r#ref: Option<NodeRef>,
+    /// Moves the focus between the descendants matching this selector, e.g. `[role=menuitem]`,
+    /// instead of the direct children, for items that wrap their control in layout markup
+    #[prop_or_default]
+    pub item_selector: Option<AttrValue>,
+    /// Receives the arrow keys across the orientation, e.g. ArrowLeft and ArrowRight of a vertical
+    /// group, instead of moving the focus with them
+    #[prop_or_default]
+    pub on_cross_axis_key: Option<Callback<KeyboardEvent>>,
+}
+
+/// Resolves the items of the group and fits the iterator to them, they might have changed since
+/// the last key press
+fn get_items(
+    node_ref: &NodeRef,
+    item_selector: Option<&str>,
+    roving_iterator: &Rc<RefCell<IndexRoving>>,
+) -> Vec<Element> {
+    let Some(group) = node_ref.cast::<Element>() else {
+        return Vec::new();
+    };
+
+    let items = get_roving_items(&group, item_selector);
+
+    if !items.is_empty() {
+        let mut roving_iterator = roving_iterator.borrow_mut();
+        roving_iterator.length = items.len() as u32;
+        roving_iterator.current = roving_iterator.current.min(roving_iterator.length - 1);
+    }
+
+    items
 }
 
 #[function_component(RovingFocus)]
 pub fn roving_focus(props: &RovingFocusProps) -> Html {
-    let roving_iterator =
-        use_roving_iterator(props.children.len() as u32, props.r#loop, &props.dir);
-    let node_ref = use_node_ref();
-    let children_as_html_collection = use_children_as_html_collection(node_ref.clone());
+    let roving_iterator = use_roving_iterator(props.children.len() as u32, props.r#loop);
+    let internal_ref = use_node_ref();
+    let node_ref = props.r#ref.clone().unwrap_or(internal_ref);
     let is_focus_entered = use_mut_ref(|| false);
 
     let navigation_handler = {
         let is_focus_entered = is_focus_entered.clone();
-        let children_as_html_collection = children_as_html_collection.clone();
         let roving_iterator = roving_iterator.clone();
+        let node_ref = node_ref.clone();
         let orientation = props.orientation.clone();
         let dir = props.dir.clone();
+        let item_selector = props.item_selector.clone();
+        let on_cross_axis_key = props.on_cross_axis_key.clone();
 
         move |event: KeyboardEvent| {
-            let children_as_html_collection = children_as_html_collection.borrow();
-            let children = children_as_html_collection.as_ref();
+            if let Some(on_cross_axis_key) = &on_cross_axis_key {
+                let cross_axis_keys = match orientation {
+                    Orientation::Horizontal => ["ArrowUp", "ArrowDown"],
+                    Orientation::Vertical => ["ArrowLeft", "ArrowRight"],
+                };
 
-            if children.is_none() {
-                return;
+                if cross_axis_keys.contains(&event.key().as_str()) {
+                    on_cross_axis_key.emit(event);
+                    return;
+                }
             }
 
-            let children = children.unwrap();
+            let items = get_items(&node_ref, item_selector.as_deref(), &roving_iterator);
+
+            if items.is_empty() {
+                return;
+            }
 
             let next_index = match event.key().as_str() {
                 "ArrowDown" => match orientation {
-                    Orientation::Vertical => roving_iterator.borrow_mut().next(&dir),
-                    Orientation::Horizontal => roving_iterator.borrow_mut().prev(&dir),
+                    Orientation::Vertical => roving_iterator.borrow_mut().next(&Dir::Ltr),
+                    Orientation::Horizontal => roving_iterator.borrow_mut().prev(&Dir::Ltr),
                 },
                 "ArrowUp" => match orientation {
-                    Orientation::Vertical => roving_iterator.borrow_mut().prev(&dir),
-                    Orientation::Horizontal => roving_iterator.borrow_mut().next(&dir),
+                    Orientation::Vertical => roving_iterator.borrow_mut().prev(&Dir::Ltr),
+                    Orientation::Horizontal => roving_iterator.borrow_mut().next(&Dir::Ltr),
                 },
+                // Items are walked in DOM order, `dir` only flips the horizontal arrows, so in
+                // RTL ArrowLeft advances to the next item
                 "ArrowLeft" => roving_iterator.borrow_mut().prev(&dir),
                 "ArrowRight" => roving_iterator.borrow_mut().next(&dir),
-                "Home" => roving_iterator.borrow_mut().first(&dir),
-                "End" => roving_iterator.borrow_mut().last(&dir),
+                "Home" => roving_iterator.borrow_mut().first(&Dir::Ltr),
+                "End" => roving_iterator.borrow_mut().last(&Dir::Ltr),
                 "Tab" => {
-                    let last_focusable_element_index = if event.shift_key() {
-                        0
-                    } else {
-                        roving_iterator.borrow().length - 1
-                    };
-
-                    children
-                        .item(last_focusable_element_index)
-                        .and_then(|element| element.dyn_into::<HtmlElement>().ok())
-                        .and_then(|html_element| get_focusable_element(&html_element))
-                        .map(|html_element| {
-                            if event.shift_key() {
-                                get_prev_focusable_element(&html_element)
+                    let current_child = items
+                        .get(roving_iterator.borrow().current as usize)
+                        .and_then(get_focusable_element);
+
+                    // Step over the remaining children of the group starting from the focused one
+                    let outside_focusable_element = current_child.and_then(|current_child| {
+                        let group = node_ref.cast::<HtmlElement>()?;
+                        let mut candidate = current_child;
+
+                        for _ in 0..get_document_focusable_elements().len() {
+                            candidate = if event.shift_key() {
+                                get_prev_focusable_element(&candidate)
                             } else {
-                                get_next_focusable_element(&html_element)
+                                get_next_focusable_element(&candidate)
+                            };
+
+                            if !group.contains(Some(&candidate)) {
+                                return Some(candidate);
                             }
-                        })
-                        .and_then(|next_outside_focusable_element| {
-                            next_outside_focusable_element.focus().err()
-                        })
-                        .map_or_else(
-                            || {},
-                            |error| log::error!("Failed to focus the element: {:?}", error),
-                        );
+                        }
+
+                        None
+                    });
+
+                    if let Some(element) = outside_focusable_element {
+                        if let Err(error) = element.focus() {
+                            log::error!("Failed to focus the element: {:?}", error);
+                        }
+                    }
 
                     *is_focus_entered.borrow_mut() = false;
                     None
@@ -96,7 +153,7 @@ pub fn roving_focus(props: &RovingFocusProps) -> Html {
             };
 
             if let Some(next_index) = next_index {
-                focus_child(children.item(next_index));
+                focus_child(items.get(next_index as usize).cloned());
             }
         }
     };
@@ -117,31 +174,198 @@ pub fn roving_focus(props: &RovingFocusProps) -> Html {
     let focus_last_focused_child = use_callback(
         (
             roving_iterator.clone(),
-            children_as_html_collection.clone(),
+            node_ref.clone(),
+            props.item_selector.clone(),
             is_focus_entered.clone(),
         ),
-        move |_event: FocusEvent,
-              (roving_iterator, children_as_html_collection, is_focus_entered)| {
+        move |_event: FocusEvent, (roving_iterator, node_ref, item_selector, is_focus_entered)| {
             if *is_focus_entered.borrow() {
                 return;
             }
 
-            let children_as_html_collection = children_as_html_collection.borrow();
-            let children = children_as_html_collection.as_ref();
+            let items = get_items(node_ref, item_selector.as_deref(), roving_iterator);
 
-            if children.is_none() {
+            if items.is_empty() {
                 return;
             }
 
-            let children = children.unwrap();
-            focus_child(children.item(roving_iterator.borrow().current));
+            focus_child(
+                items
+                    .get(roving_iterator.borrow().current as usize)
+                    .cloned(),
+            );
             *is_focus_entered.borrow_mut() = true;
         },
     );
 
     html! {
-        <div role={props.role.clone()} class={&props.class} data-orientation={props.orientation.clone()} ref={node_ref} onfocusin={&focus_last_focused_child} onkeydown={&navigate_through_children}>
+        <div role={props.role.clone()} aria-invalid={props.aria_invalid.clone()} aria-describedby={props.aria_describedby.clone()} aria-orientation={props.aria_orientation.clone()} class={&props.class} data-orientation={props.orientation.clone()} ref={node_ref} onfocusin={&focus_last_focused_child} onkeydown={&navigate_through_children}>
             {for props.children.iter()}
         </div>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+    use yewlish_testing_tools::TesterEvent;
+    use yewlish_testing_tools::*;
+
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    fn active_element_text() -> Option<String> {
+        gloo_utils::document()
+            .active_element()
+            .and_then(|element| element.text_content())
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_roving_focus_tab_exits_the_group() {
+        let t = render!({
+            html! {
+                <>
+                    <button>{"Before"}</button>
+                    <RovingFocus>
+                        <button>{"First"}</button>
+                        <button>{"Second"}</button>
+                        <button>{"Third"}</button>
+                    </RovingFocus>
+                    <button>{"After"}</button>
+                </>
+            }
+        })
+        .await;
+
+        // Entering the group focuses the first child
+        t.query_by_text("Second").focus().await;
+        assert_eq!(active_element_text(), Some("First".to_string()));
+
+        // Move to the second child, then Tab should leave the group forward
+        t.query_by_text("First").keydown("ArrowRight").await;
+        assert_eq!(active_element_text(), Some("Second".to_string()));
+
+        t.query_by_text("Second").keydown("Tab").await;
+        assert_eq!(active_element_text(), Some("After".to_string()));
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_roving_focus_shift_tab_exits_the_group() {
+        let t = render!({
+            html! {
+                <>
+                    <button>{"Before"}</button>
+                    <RovingFocus>
+                        <button>{"First"}</button>
+                        <button>{"Second"}</button>
+                        <button>{"Third"}</button>
+                    </RovingFocus>
+                    <button>{"After"}</button>
+                </>
+            }
+        })
+        .await;
+
+        t.query_by_text("First").focus().await;
+        t.query_by_text("First").keydown("End").await;
+        assert_eq!(active_element_text(), Some("Third".to_string()));
+
+        let event_init = web_sys::KeyboardEventInit::new();
+        event_init.set_bubbles(true);
+        event_init.set_cancelable(true);
+        event_init.set_key("Tab");
+        event_init.set_shift_key(true);
+
+        let event =
+            web_sys::KeyboardEvent::new_with_keyboard_event_init_dict("keydown", &event_init)
+                .unwrap();
+
+        gloo_utils::document()
+            .active_element()
+            .unwrap()
+            .dispatch_event(&event)
+            .unwrap();
+
+        assert_eq!(active_element_text(), Some("Before".to_string()));
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_roving_focus_rtl_arrow_left_moves_forward() {
+        let t = render!({
+            html! {
+                <RovingFocus orientation={Orientation::Horizontal} dir={Dir::Rtl}>
+                    <button>{"First"}</button>
+                    <button>{"Second"}</button>
+                    <button>{"Third"}</button>
+                </RovingFocus>
+            }
+        })
+        .await;
+
+        t.query_by_text("First").focus().await;
+        t.query_by_text("First").keydown("Home").await;
+        assert_eq!(active_element_text(), Some("First".to_string()));
+
+        t.query_by_text("First").keydown("ArrowLeft").await;
+        assert_eq!(active_element_text(), Some("Second".to_string()));
+
+        t.query_by_text("Second").keydown("ArrowRight").await;
+        assert_eq!(active_element_text(), Some("First".to_string()));
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_roving_focus_rtl_enters_at_the_first_item() {
+        let t = render!({
+            html! {
+                <RovingFocus orientation={Orientation::Horizontal} dir={Dir::Rtl}>
+                    <button>{"First"}</button>
+                    <button>{"Second"}</button>
+                    <button>{"Third"}</button>
+                </RovingFocus>
+            }
+        })
+        .await;
+
+        // Same as LTR, entering the group focuses the first child in DOM order
+        t.query_by_text("Third").focus().await;
+        assert_eq!(active_element_text(), Some("First".to_string()));
+
+        // ArrowRight moves backward and wraps around to the last child
+        t.query_by_text("First").keydown("ArrowRight").await;
+        assert_eq!(active_element_text(), Some("Third".to_string()));
+
+        t.query_by_text("Third").keydown("Home").await;
+        assert_eq!(active_element_text(), Some("First".to_string()));
+
+        t.query_by_text("First").keydown("End").await;
+        assert_eq!(active_element_text(), Some("Third".to_string()));
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_roving_focus_item_selector_matches_nested_items() {
+        let t = render!({
+            html! {
+                <RovingFocus orientation={Orientation::Vertical} item_selector="[role=menuitem]">
+                    <div>
+                        <button role="menuitem">{"First"}</button>
+                        <button>{"First info"}</button>
+                    </div>
+                    <div>
+                        <button role="menuitem">{"Second"}</button>
+                        <button>{"Second info"}</button>
+                    </div>
+                </RovingFocus>
+            }
+        })
+        .await;
+
+        t.query_by_text("First").focus().await;
+        assert_eq!(active_element_text(), Some("First".to_string()));
+
+        t.query_by_text("First").keydown("ArrowDown").await;
+        assert_eq!(active_element_text(), Some("Second".to_string()));
+
+        t.query_by_text("Second").keydown("ArrowDown").await;
+        assert_eq!(active_element_text(), Some("First".to_string()));
+    }
+}
@@ -0,0 +1,614 @@
+use web_sys::wasm_bindgen::{closure::Closure, JsCast};
+use web_sys::{Element, PointerEvent};
+use yew::prelude::*;
+use yewlish_utils::{
+    enums::Orientation,
+    hooks::{use_resize_observer, use_viewport_move},
+};
+
+#[derive(Clone, Default, Debug, PartialEq)]
+pub enum ScrollAreaOrientation {
+    #[default]
+    Vertical,
+    Horizontal,
+    Both,
+}
+
+impl ScrollAreaOrientation {
+    fn has_vertical(&self) -> bool {
+        matches!(self, Self::Vertical | Self::Both)
+    }
+
+    fn has_horizontal(&self) -> bool {
+        matches!(self, Self::Horizontal | Self::Both)
+    }
+}
+
+/// Controls when the custom scrollbars are shown.
+#[derive(Clone, Default, Debug, PartialEq)]
+pub enum ScrollAreaType {
+    /// Visible whenever the content overflows.
+    #[default]
+    Auto,
+    /// Always visible, even if the content fits.
+    Always,
+    /// Visible while the user is scrolling.
+    Scroll,
+    /// Visible while the pointer is over the scroll area or the user is scrolling.
+    Hover,
+}
+
+#[derive(Clone, Copy, Default, Debug, PartialEq)]
+struct ScrollMetrics {
+    scroll_top: f64,
+    scroll_left: f64,
+    scroll_height: f64,
+    scroll_width: f64,
+    client_height: f64,
+    client_width: f64,
+}
+
+impl ScrollMetrics {
+    fn overflows(&self, orientation: &Orientation) -> bool {
+        match orientation {
+            Orientation::Vertical => self.scroll_height > self.client_height,
+            Orientation::Horizontal => self.scroll_width > self.client_width,
+        }
+    }
+
+    /// Thumb size and offset as percentages of the track.
+    fn thumb(&self, orientation: &Orientation) -> (f64, f64) {
+        let (scroll_offset, scroll_size, client_size) = match orientation {
+            Orientation::Vertical => (self.scroll_top, self.scroll_height, self.client_height),
+            Orientation::Horizontal => (self.scroll_left, self.scroll_width, self.client_width),
+        };
+
+        if scroll_size <= 0.0 {
+            return (100.0, 0.0);
+        }
+
+        let size = (client_size / scroll_size * 100.0).min(100.0);
+        let offset = (scroll_offset / scroll_size * 100.0).clamp(0.0, 100.0 - size);
+
+        (size, offset)
+    }
+}
+
+impl From<&Element> for ScrollMetrics {
+    fn from(element: &Element) -> Self {
+        Self {
+            scroll_top: f64::from(element.scroll_top()),
+            scroll_left: f64::from(element.scroll_left()),
+            scroll_height: f64::from(element.scroll_height()),
+            scroll_width: f64::from(element.scroll_width()),
+            client_height: f64::from(element.client_height()),
+            client_width: f64::from(element.client_width()),
+        }
+    }
+}
+
+/// Hides the native scrollbars in WebKit/Blink, `scrollbar-width` covers the rest
+const VIEWPORT_STYLE: &str = "[data-scroll-area-viewport]::-webkit-scrollbar { display: none; }";
+
+/// Where a thumb drag started, so the moves can be applied relative to it.
+#[derive(Debug, PartialEq)]
+struct ThumbDrag {
+    orientation: Orientation,
+    pointer: f64,
+    scroll_offset: f64,
+    /// Viewport pixels scrolled per pixel the thumb moves along its track
+    ratio: f64,
+}
+
+fn pointer_position(event: &PointerEvent, orientation: &Orientation) -> f64 {
+    match orientation {
+        Orientation::Vertical => f64::from(event.client_y()),
+        Orientation::Horizontal => f64::from(event.client_x()),
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct ScrollAreaProps {
+    #[prop_or_default]
+    pub children: Children,
+    #[prop_or_default]
+    pub class: Option<AttrValue>,
+    #[prop_or_default]
+    pub viewport_class: Option<AttrValue>,
+    #[prop_or_default]
+    pub scrollbar_class: Option<AttrValue>,
+    #[prop_or_default]
+    pub thumb_class: Option<AttrValue>,
+    #[prop_or_default]
+    pub orientation: ScrollAreaOrientation,
+    #[prop_or_default]
+    pub r#type: ScrollAreaType,
+    /// How long the scrollbars stay visible after scrolling stops, in milliseconds
+    #[prop_or(600)]
+    pub scroll_hide_delay: i32,
+}
+
+/// A scroll container that replaces the native scrollbars with custom tracks and thumbs.
+///
+/// The scrollbars are absolutely positioned over the viewport and can be styled via
+/// `scrollbar_class`/`thumb_class` or the `--scroll-area-scrollbar-size` CSS variable.
+/// The thumbs can be dragged to scroll the viewport.
+///
+/// # Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use scroll_area::{ScrollArea, ScrollAreaOrientation, ScrollAreaType};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <ScrollArea
+///             class="h-64 w-64"
+///             orientation={ScrollAreaOrientation::Both}
+///             r#type={ScrollAreaType::Hover}
+///         >
+///             {for (0..100).map(|index| html! { <p>{index}</p> })}
+///         </ScrollArea>
+///     }
+/// }
+/// ```
+#[function_component(ScrollArea)]
+pub fn scroll_area(props: &ScrollAreaProps) -> Html {
+    let viewport_ref = use_node_ref();
+    let content_ref = use_node_ref();
+    let metrics = use_state_eq(ScrollMetrics::default);
+    let scrolling = use_state_eq(|| false);
+    let hovered = use_state_eq(|| false);
+    let hide_timeout_id = use_mut_ref(|| None::<i32>);
+    let thumb_drag = use_mut_ref(|| None::<ThumbDrag>);
+
+    let measure = use_callback(
+        (viewport_ref.clone(), metrics.setter()),
+        |(), (viewport_ref, set_metrics)| {
+            if let Some(viewport) = viewport_ref.cast::<Element>() {
+                set_metrics.set(ScrollMetrics::from(&viewport));
+            }
+        },
+    );
+
+    use_viewport_move(&viewport_ref, measure.clone());
+
+    // Content may grow or shrink without the viewport itself being resized
+    use_resize_observer(&content_ref, measure.reform(|_| ()));
+
+    use_effect_with(hide_timeout_id.clone(), |hide_timeout_id| {
+        let hide_timeout_id = hide_timeout_id.clone();
+
+        move || {
+            if let (Some(timeout_id), Some(window)) =
+                (hide_timeout_id.borrow_mut().take(), web_sys::window())
+            {
+                window.clear_timeout_with_handle(timeout_id);
+            }
+        }
+    });
+
+    let onscroll = use_callback(
+        (
+            measure.clone(),
+            scrolling.setter(),
+            hide_timeout_id.clone(),
+            props.scroll_hide_delay,
+        ),
+        |_event: Event, (measure, set_scrolling, hide_timeout_id, scroll_hide_delay)| {
+            measure.emit(());
+            set_scrolling.set(true);
+
+            let Some(window) = web_sys::window() else {
+                return;
+            };
+
+            if let Some(timeout_id) = hide_timeout_id.borrow_mut().take() {
+                window.clear_timeout_with_handle(timeout_id);
+            }
+
+            let set_scrolling = set_scrolling.clone();
+            let callback = Closure::once_into_js(move || set_scrolling.set(false));
+
+            match window.set_timeout_with_callback_and_timeout_and_arguments_0(
+                callback.unchecked_ref(),
+                *scroll_hide_delay,
+            ) {
+                Ok(timeout_id) => {
+                    *hide_timeout_id.borrow_mut() = Some(timeout_id);
+                }
+                Err(err) => {
+                    log::error!("Failed to set timeout {err:?}");
+                }
+            }
+        },
+    );
+
+    let onpointerenter = use_callback(hovered.setter(), |_event: PointerEvent, set_hovered| {
+        set_hovered.set(true);
+    });
+
+    let onpointerleave = use_callback(hovered.setter(), |_event: PointerEvent, set_hovered| {
+        set_hovered.set(false);
+    });
+
+    let on_thumb_pointerdown = use_callback(
+        (viewport_ref.clone(), thumb_drag.clone()),
+        |(event, orientation): (PointerEvent, Orientation), (viewport_ref, thumb_drag)| {
+            if !event.is_primary() || event.button() != 0 {
+                return;
+            }
+
+            let Some(viewport) = viewport_ref.cast::<Element>() else {
+                return;
+            };
+
+            let Some(thumb) = event
+                .current_target()
+                .and_then(|target| target.dyn_into::<Element>().ok())
+            else {
+                return;
+            };
+
+            let Some(track) = thumb.parent_element() else {
+                return;
+            };
+
+            let rect = track.get_bounding_client_rect();
+            let metrics = ScrollMetrics::from(&viewport);
+
+            let (track_size, scroll_size, scroll_offset) = match orientation {
+                Orientation::Vertical => (rect.height(), metrics.scroll_height, metrics.scroll_top),
+                Orientation::Horizontal => {
+                    (rect.width(), metrics.scroll_width, metrics.scroll_left)
+                }
+            };
+
+            if track_size <= 0.0 {
+                return;
+            }
+
+            // Keeps the moves coming even once the pointer outruns the thumb
+            if let Err(error) = thumb.set_pointer_capture(event.pointer_id()) {
+                log::error!("Failed to capture the pointer: {error:?}");
+            }
+
+            event.prevent_default();
+
+            *thumb_drag.borrow_mut() = Some(ThumbDrag {
+                pointer: pointer_position(&event, &orientation),
+                scroll_offset,
+                ratio: scroll_size / track_size,
+                orientation,
+            });
+        },
+    );
+
+    let on_thumb_pointermove = use_callback(
+        (viewport_ref.clone(), thumb_drag.clone()),
+        |event: PointerEvent, (viewport_ref, thumb_drag)| {
+            let thumb_drag = thumb_drag.borrow();
+
+            let (Some(drag), Some(viewport)) =
+                (thumb_drag.as_ref(), viewport_ref.cast::<Element>())
+            else {
+                return;
+            };
+
+            let offset = drag.scroll_offset
+                + (pointer_position(&event, &drag.orientation) - drag.pointer) * drag.ratio;
+
+            // The viewport clamps the offset itself and measures again on scroll
+            match drag.orientation {
+                Orientation::Vertical => viewport.set_scroll_top(offset.round() as i32),
+                Orientation::Horizontal => viewport.set_scroll_left(offset.round() as i32),
+            }
+        },
+    );
+
+    let on_thumb_pointerup = use_callback(thumb_drag.clone(), |event: PointerEvent, thumb_drag| {
+        if thumb_drag.borrow_mut().take().is_none() {
+            return;
+        }
+
+        if let Some(thumb) = event
+            .current_target()
+            .and_then(|target| target.dyn_into::<Element>().ok())
+        {
+            if thumb.has_pointer_capture(event.pointer_id()) {
+                let _ = thumb.release_pointer_capture(event.pointer_id());
+            }
+        }
+    });
+
+    let render_scrollbar = |orientation: Orientation| -> Html {
+        let overflows = metrics.overflows(&orientation);
+
+        let visible = match props.r#type {
+            ScrollAreaType::Always => true,
+            ScrollAreaType::Auto => overflows,
+            ScrollAreaType::Scroll => overflows && *scrolling,
+            ScrollAreaType::Hover => overflows && (*hovered || *scrolling),
+        };
+
+        if !visible {
+            return html! {};
+        }
+
+        let (size, offset) = metrics.thumb(&orientation);
+
+        let (scrollbar_style, thumb_style) = match orientation {
+            Orientation::Vertical => (
+                "position: absolute; top: 0; right: 0; bottom: 0; width: var(--scroll-area-scrollbar-size, 10px);",
+                format!("position: absolute; left: 0; right: 0; top: {offset}%; height: {size}%; touch-action: none;"),
+            ),
+            Orientation::Horizontal => (
+                "position: absolute; left: 0; right: 0; bottom: 0; height: var(--scroll-area-scrollbar-size, 10px);",
+                format!("position: absolute; top: 0; bottom: 0; left: {offset}%; width: {size}%; touch-action: none;"),
+            ),
+        };
+
+        html! {
+            <div
+                aria-hidden="true"
+                class={&props.scrollbar_class}
+                style={scrollbar_style}
+                data-scroll-area-scrollbar=""
+                data-orientation={orientation.clone()}
+            >
+                <div
+                    class={&props.thumb_class}
+                    style={thumb_style}
+                    data-scroll-area-thumb=""
+                    data-orientation={orientation.clone()}
+                    onpointerdown={on_thumb_pointerdown.reform(move |event| (event, orientation.clone()))}
+                    onpointermove={on_thumb_pointermove.clone()}
+                    onpointerup={on_thumb_pointerup.clone()}
+                    onpointercancel={on_thumb_pointerup.clone()}
+                />
+            </div>
+        }
+    };
+
+    let overflow_x = if props.orientation.has_horizontal() {
+        "scroll"
+    } else {
+        "hidden"
+    };
+
+    let overflow_y = if props.orientation.has_vertical() {
+        "scroll"
+    } else {
+        "hidden"
+    };
+
+    html! {
+        <div
+            class={&props.class}
+            style="position: relative; overflow: hidden;"
+            {onpointerenter}
+            {onpointerleave}
+        >
+            <style>{VIEWPORT_STYLE}</style>
+
+            <div
+                ref={viewport_ref}
+                class={&props.viewport_class}
+                style={format!("width: 100%; height: 100%; overflow-x: {overflow_x}; overflow-y: {overflow_y}; scrollbar-width: none;")}
+                data-scroll-area-viewport=""
+                {onscroll}
+            >
+                <div
+                    ref={content_ref}
+                    style="min-width: 100%; display: table;"
+                    data-scroll-area-content=""
+                >
+                    {for props.children.iter()}
+                </div>
+            </div>
+
+            if props.orientation.has_vertical() {
+                {render_scrollbar(Orientation::Vertical)}
+            }
+
+            if props.orientation.has_horizontal() {
+                {render_scrollbar(Orientation::Horizontal)}
+            }
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+    use yewlish_testing_tools::*;
+
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    const STYLE: &str = ".area { width: 100px; height: 100px; } .row { height: 400px; }";
+
+    fn viewport() -> Element {
+        web_sys::window()
+            .and_then(|window| window.document())
+            .and_then(|document| document.query_selector("[data-scroll-area-viewport]").ok())
+            .flatten()
+            .expect("Failed to find the viewport")
+    }
+
+    fn thumb_style(t: &Tester) -> Option<String> {
+        t.query_by_selector("[data-scroll-area-thumb]")
+            .attribute("style")
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_scroll_area_thumb_follows_the_viewport() {
+        let t = render!({
+            html! {
+                <>
+                    <style>{STYLE}</style>
+                    <ScrollArea class="area">
+                        <div class="row" />
+                    </ScrollArea>
+                </>
+            }
+        })
+        .await;
+
+        assert!(
+            t.wait_for(500.0, || {
+                thumb_style(&t).is_some_and(|style| style.contains("height: 25%"))
+            })
+            .await
+        );
+
+        assert_eq!(
+            t.query_by_selector("[data-scroll-area-scrollbar]")
+                .attribute("data-orientation"),
+            Some("vertical".to_string())
+        );
+        assert!(!t
+            .query_by_selector("[data-scroll-area-scrollbar][data-orientation='horizontal']")
+            .exists());
+
+        viewport().set_scroll_top(150);
+
+        assert!(
+            t.wait_for(500.0, || {
+                thumb_style(&t).is_some_and(|style| style.contains("top: 37.5%"))
+            })
+            .await
+        );
+    }
+
+    fn dispatch_thumb_pointer(name: &str, client_y: i32) {
+        let thumb = web_sys::window()
+            .and_then(|window| window.document())
+            .and_then(|document| document.query_selector("[data-scroll-area-thumb]").ok())
+            .flatten()
+            .expect("Failed to find the thumb");
+
+        let init = web_sys::PointerEventInit::new();
+        init.set_bubbles(true);
+        init.set_cancelable(true);
+        init.set_is_primary(true);
+        init.set_client_y(client_y);
+
+        let event = web_sys::PointerEvent::new_with_event_init_dict(name, &init)
+            .expect("Failed to create the pointer event");
+
+        thumb
+            .dispatch_event(&event)
+            .expect("Failed to dispatch the pointer event");
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_scroll_area_thumb_drag_scrolls_the_viewport() {
+        let t = render!({
+            html! {
+                <>
+                    <style>{STYLE}</style>
+                    <ScrollArea class="area">
+                        <div class="row" />
+                    </ScrollArea>
+                </>
+            }
+        })
+        .await;
+
+        assert!(
+            t.wait_for(500.0, || {
+                thumb_style(&t).is_some_and(|style| style.contains("height: 25%"))
+            })
+            .await
+        );
+
+        // The track is 100px long and the content 400px, so each pixel scrolls 4
+        dispatch_thumb_pointer("pointerdown", 10);
+        dispatch_thumb_pointer("pointermove", 40);
+
+        assert_eq!(viewport().scroll_top(), 120);
+
+        assert!(
+            t.wait_for(500.0, || {
+                thumb_style(&t).is_some_and(|style| style.contains("top: 30%"))
+            })
+            .await
+        );
+
+        dispatch_thumb_pointer("pointerup", 40);
+        dispatch_thumb_pointer("pointermove", 90);
+
+        assert_eq!(viewport().scroll_top(), 120);
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_scroll_area_scroll_type_hides_the_scrollbar_after_scrolling() {
+        let t = render!({
+            html! {
+                <>
+                    <style>{STYLE}</style>
+                    <ScrollArea
+                        class="area"
+                        r#type={ScrollAreaType::Scroll}
+                        scroll_hide_delay={50}
+                    >
+                        <div class="row" />
+                    </ScrollArea>
+                </>
+            }
+        })
+        .await;
+
+        assert!(!t.query_by_selector("[data-scroll-area-scrollbar]").exists());
+
+        viewport().set_scroll_top(100);
+
+        assert!(
+            t.wait_for(500.0, || {
+                t.query_by_selector("[data-scroll-area-scrollbar]").exists()
+            })
+            .await
+        );
+
+        assert!(
+            t.wait_for(500.0, || {
+                !t.query_by_selector("[data-scroll-area-scrollbar]").exists()
+            })
+            .await
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_scroll_area_always_type_shows_the_scrollbar_without_overflow() {
+        let t = render!({
+            html! {
+                <>
+                    <style>{STYLE}</style>
+                    <ScrollArea class="area" r#type={ScrollAreaType::Always}>
+                        <p>{"Short"}</p>
+                    </ScrollArea>
+                    <ScrollArea class="area">
+                        <p>{"Short"}</p>
+                    </ScrollArea>
+                </>
+            }
+        })
+        .await;
+
+        assert!(
+            t.wait_for(500.0, || {
+                thumb_style(&t).is_some_and(|style| style.contains("height: 100%"))
+            })
+            .await
+        );
+
+        // The auto scroll area has nothing to scroll, so only the first one has a scrollbar
+        assert_eq!(
+            t.query_all_by_selector("[data-scroll-area-scrollbar]")
+                .len(),
+            1
+        );
+    }
+}
@@ -1,18 +1,70 @@
-use yew::prelude::*;
+use yew::{html::IntoPropValue, prelude::*};
 use yewlish_utils::enums::Orientation;
 
+#[derive(Clone, Default, Debug, PartialEq)]
+pub enum SeparatorVariant {
+    #[default]
+    Solid,
+    Dashed,
+    Gradient,
+}
+
+impl IntoPropValue<Option<AttrValue>> for SeparatorVariant {
+    fn into_prop_value(self) -> Option<AttrValue> {
+        match self {
+            Self::Solid => Some("solid".into()),
+            Self::Dashed => Some("dashed".into()),
+            Self::Gradient => Some("gradient".into()),
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Properties)]
 pub struct SeparatorProps {
+    #[prop_or_default]
+    pub children: Children,
     #[prop_or_default]
     pub class: Option<AttrValue>,
     #[prop_or_default]
     pub orientation: Orientation,
     #[prop_or_default]
+    pub variant: SeparatorVariant,
+    #[prop_or_default]
     pub decorative: bool,
 }
 
 #[function_component(Separator)]
 pub fn separator(props: &SeparatorProps) -> Html {
+    if !props.children.is_empty() {
+        let flex_direction = match props.orientation {
+            Orientation::Horizontal => "row",
+            Orientation::Vertical => "column",
+        };
+
+        let line = html! {
+            <div
+                aria-hidden="true"
+                style="flex: 1;"
+                data-separator-line=""
+                data-orientation={props.orientation.clone()}
+                data-variant={props.variant.clone()}
+            />
+        };
+
+        return html! {
+            <div
+                class={&props.class}
+                style={format!("display: flex; flex-direction: {flex_direction}; align-items: center;")}
+                data-orientation={props.orientation.clone()}
+                data-variant={props.variant.clone()}
+            >
+                {line.clone()}
+                {props.children.clone()}
+                {line}
+            </div>
+        };
+    }
+
     let role = (!props.decorative).then_some("separator");
     let aria_orientation: Option<&str> = (!props.decorative).then_some(match props.orientation {
         Orientation::Horizontal => "vertical",
@@ -25,6 +77,99 @@ pub fn separator(props: &SeparatorProps) -> Html {
             class={&props.class}
             aria-orientation={aria_orientation}
             data-orientation={props.orientation.clone()}
+            data-variant={props.variant.clone()}
         />
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+    use yewlish_testing_tools::*;
+
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    async fn test_separator_without_children_is_a_separator() {
+        let t = render!({
+            html! {
+                <Separator class="separator" variant={SeparatorVariant::Dashed} />
+            }
+        })
+        .await;
+
+        let separator = t.query_by_role("separator");
+
+        assert!(separator.exists());
+        assert_eq!(
+            separator.attribute("data-variant"),
+            Some("dashed".to_string())
+        );
+        assert!(!t.query_by_selector("[data-separator-line]").exists());
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_labeled_separator_is_not_a_separator() {
+        let t = render!({
+            html! {
+                <Separator class="separator" variant={SeparatorVariant::Gradient}>
+                    {"Or"}
+                </Separator>
+            }
+        })
+        .await;
+
+        // The label is content, only the lines around it are decorative
+        assert!(!t.query_by_role("separator").exists());
+        assert!(t.query_by_text("Or").exists());
+
+        assert_eq!(
+            t.query_by_selector(".separator").attribute("data-variant"),
+            Some("gradient".to_string())
+        );
+
+        let lines = t.query_all_by_selector("[data-separator-line]");
+
+        assert_eq!(lines.len(), 2);
+
+        for line in lines {
+            assert_eq!(line.attribute("data-variant"), Some("gradient".to_string()));
+            assert_eq!(line.attribute("aria-hidden"), Some("true".to_string()));
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_labeled_separator_follows_the_orientation() {
+        let t = render!({
+            html! {
+                <>
+                    <Separator class="horizontal">{"Horizontal"}</Separator>
+                    <Separator class="vertical" orientation={Orientation::Vertical}>
+                        {"Vertical"}
+                    </Separator>
+                </>
+            }
+        })
+        .await;
+
+        let horizontal = t.query_by_selector(".horizontal");
+        let vertical = t.query_by_selector(".vertical");
+
+        assert!(horizontal
+            .attribute("style")
+            .is_some_and(|style| style.contains("flex-direction: row;")));
+        assert_eq!(
+            horizontal.attribute("data-orientation"),
+            Some("horizontal".to_string())
+        );
+
+        assert!(vertical
+            .attribute("style")
+            .is_some_and(|style| style.contains("flex-direction: column;")));
+        assert_eq!(
+            vertical.attribute("data-orientation"),
+            Some("vertical".to_string())
+        );
+    }
+}
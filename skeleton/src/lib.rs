@@ -0,0 +1,123 @@
+use yew::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct SkeletonProps {
+    /// The content the skeleton stands in for, rendered once `loading` is `false`
+    #[prop_or_default]
+    pub children: Children,
+    #[prop_or(true)]
+    pub loading: bool,
+    /// Any CSS length, e.g. `"12rem"` or `"100%"`
+    #[prop_or_default]
+    pub width: Option<AttrValue>,
+    /// Any CSS length, e.g. `"1em"` for a line of text
+    #[prop_or_default]
+    pub height: Option<AttrValue>,
+    /// Fully rounds the corners, e.g. for avatars
+    #[prop_or_default]
+    pub rounded: bool,
+    #[prop_or_default]
+    pub class: Option<AttrValue>,
+}
+
+/// A placeholder block shown while content loads.
+///
+/// It only renders the block with `data-state="loading"`, the pulse is left to the styles,
+/// e.g. `[data-state="loading"] { animation: pulse 2s infinite; }`. The block is hidden from
+/// assistive technologies, so mark the region that loads with `aria-busy` if it needs to be
+/// announced.
+///
+/// # Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use skeleton::Skeleton;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let loading = use_state(|| true);
+///
+///     html! {
+///         <Skeleton loading={*loading} width="12rem" height="1em">
+///             <p>{"Loaded content"}</p>
+///         </Skeleton>
+///     }
+/// }
+/// ```
+#[function_component(Skeleton)]
+pub fn skeleton(props: &SkeletonProps) -> Html {
+    if !props.loading {
+        return html! { {props.children.clone()} };
+    }
+
+    let mut style = String::new();
+
+    if let Some(width) = &props.width {
+        style.push_str(&format!("width: {width};"));
+    }
+
+    if let Some(height) = &props.height {
+        style.push_str(&format!("height: {height};"));
+    }
+
+    if props.rounded {
+        style.push_str("border-radius: 9999px;");
+    }
+
+    html! {
+        <div
+            class={&props.class}
+            style={(!style.is_empty()).then_some(style)}
+            aria-hidden="true"
+            aria-busy="true"
+            data-state="loading"
+            data-rounded={props.rounded.then_some("")}
+        />
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+    use yewlish_testing_tools::*;
+
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    async fn test_skeleton_loading() {
+        let t = render!({
+            html! {
+                <Skeleton width="10px" height="4px" rounded={true}>
+                    <p>{"Content"}</p>
+                </Skeleton>
+            }
+        })
+        .await;
+
+        let block = t.query_by_selector("[data-state='loading']");
+
+        assert_eq!(block.attribute("aria-hidden"), Some("true".to_string()));
+        assert_eq!(block.attribute("aria-busy"), Some("true".to_string()));
+        assert_eq!(
+            block.attribute("style"),
+            Some("width: 10px;height: 4px;border-radius: 9999px;".to_string())
+        );
+        assert!(!t.query_by_text("Content").exists());
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_skeleton_loaded_renders_children() {
+        let t = render!({
+            html! {
+                <Skeleton loading={false}>
+                    <p>{"Content"}</p>
+                </Skeleton>
+            }
+        })
+        .await;
+
+        assert!(t.query_by_text("Content").exists());
+        assert!(!t.query_by_selector("[data-state='loading']").exists());
+    }
+}
@@ -77,7 +77,7 @@ pub fn popover_page() -> Html {
             <Wrapper title="Popover">
                 <Section title="Default">
                     <Popover>
-                        <PopoverTrigger class="flex justify-center gap-x-2" render_as={Callback::from(move |PopoverTriggerRenderAsProps { class, children, toggle, is_open }| {
+                        <PopoverTrigger class="flex justify-center gap-x-2" render_as={Callback::from(move |PopoverTriggerRenderAsProps { class, children, toggle, is_open, .. }| {
                             html! {
                                 <AttrReceiver name="popover-trigger">
                                     <label class={&class}>
@@ -132,7 +132,7 @@ pub fn popover_page() -> Html {
                 </Section>
                 // <Section title="With container">
                 //     <Popover>
-                //         <PopoverTrigger class="flex justify-center gap-x-2" render_as={Callback::from(move |PopoverTriggerRenderAsProps { class, children, toggle, is_open }| {
+                //         <PopoverTrigger class="flex justify-center gap-x-2" render_as={Callback::from(move |PopoverTriggerRenderAsProps { class, children, toggle, is_open, .. }| {
                 //             html! {
                 //                 <AttrReceiver name="popover-trigger">
                 //                     <label class={&class}>
@@ -192,7 +192,7 @@ pub fn popover_page() -> Html {
 
                 // <Section title="With render_as">
                 //     <Popover>
-                //         <PopoverTrigger class="flex justify-center gap-x-2" render_as={Callback::from(move |PopoverTriggerRenderAsProps { class, children, toggle, is_open }| {
+                //         <PopoverTrigger class="flex justify-center gap-x-2" render_as={Callback::from(move |PopoverTriggerRenderAsProps { class, children, toggle, is_open, .. }| {
                 //             html! {
                 //                 <AttrReceiver name="popover-trigger">
                 //                     <label class={&class}>
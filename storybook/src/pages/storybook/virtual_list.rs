@@ -11,6 +11,15 @@ pub fn virtual_list_page() -> Html {
     let item_height = 30.0;
     let viewport_height = 300.0;
 
+    // Infinite scroll: the next page is appended whenever the end of the list comes into view
+    let loaded_items = use_state(|| 50_usize);
+
+    let on_end_reached = use_callback(loaded_items.clone(), |(), loaded_items| {
+        if **loaded_items < 1_000 {
+            loaded_items.set(**loaded_items + 50);
+        }
+    });
+
     let render_item = Callback::from(move |index: usize| {
         html! {
             <div style="height: 30px; border-bottom: 1px solid #ccc; padding: 5px;">
@@ -36,7 +45,7 @@ pub fn virtual_list_page() -> Html {
         <Wrapper title="Toggle">
             <Section title="Default">
                 <Popover>
-                    <PopoverTrigger class="flex justify-center gap-x-2" render_as={Callback::from(move |PopoverTriggerRenderAsProps { class, children, toggle, is_open }| {
+                    <PopoverTrigger class="flex justify-center gap-x-2" render_as={Callback::from(move |PopoverTriggerRenderAsProps { class, children, toggle, is_open, .. }| {
                         html! {
                             <AttrReceiver name="popover-trigger">
                                 <label class={&class}>
@@ -64,6 +73,18 @@ pub fn virtual_list_page() -> Html {
                     </PopoverContent>
                 </Popover>
             </Section>
+
+            <Section title="Infinite scroll">
+                <div class="min-w-md">
+                    <VirtualList
+                        total_items={*loaded_items}
+                        item_height={item_height}
+                        viewport_height={viewport_height}
+                        render_item={&render_item}
+                        {on_end_reached}
+                    />
+                </div>
+            </Section>
         </Wrapper>
     }
 }
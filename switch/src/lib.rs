@@ -4,7 +4,10 @@ use yew::prelude::*;
 use yewlish_attr_passer::{attributify, AttrPasser, AttrReceiver};
 use yewlish_utils::{
     helpers::combine_handlers::combine_handlers,
-    hooks::{use_conditional_attr, use_controllable_state},
+    hooks::{
+        use_conditional_attr, use_controllable_reducer, use_form_reset,
+        UseControllableReducerHandle,
+    },
 };
 
 #[derive(Clone, Debug, PartialEq, Properties)]
@@ -30,7 +33,17 @@ pub struct SwitchRenderAsProps {
     #[prop_or_default]
     pub readonly: bool,
     #[prop_or_default]
+    pub invalid: bool,
+    #[prop_or_default]
+    pub error_id: Option<AttrValue>,
+    #[prop_or_default]
+    pub loading: bool,
+    /// Toggles the switch, does nothing while it is `readonly`, `disabled` or `loading`
+    #[prop_or_default]
     pub toggle: Callback<()>,
+    /// The `onclick` prop combined with `toggle`, attach it to the rendered element
+    #[prop_or_default]
+    pub onclick: Callback<MouseEvent>,
 }
 
 #[derive(Clone, Debug, PartialEq, Properties)]
@@ -61,6 +74,16 @@ pub struct SwitchProps {
     pub onclick: Option<Callback<MouseEvent>>,
     #[prop_or_default]
     pub readonly: bool,
+    /// Sets `aria-invalid`, e.g. from `use_validation`
+    #[prop_or_default]
+    pub invalid: bool,
+    /// Id of the element describing the error, set as `aria-describedby`
+    #[prop_or_default]
+    pub error_id: Option<AttrValue>,
+    /// Marks the switch as pending, e.g. while the new value is being saved: it's `aria-busy`,
+    /// ignores toggles and its thumb shows the `loading_indicator`
+    #[prop_or_default]
+    pub loading: bool,
     #[prop_or_default]
     pub render_as: Option<Callback<SwitchRenderAsProps, Html>>,
 }
@@ -69,10 +92,13 @@ pub struct SwitchProps {
 pub struct SwitchContext {
     pub(crate) checked: bool,
     pub(crate) disabled: bool,
+    pub(crate) loading: bool,
 }
 
 pub enum SwitchAction {
     Toggle,
+    Set(bool),
+    SetLoading(bool),
 }
 
 impl Reducible for SwitchContext {
@@ -85,6 +111,16 @@ impl Reducible for SwitchContext {
                 ..(*self).clone()
             }
             .into(),
+            SwitchAction::Set(checked) => SwitchContext {
+                checked,
+                ..(*self).clone()
+            }
+            .into(),
+            SwitchAction::SetLoading(loading) => SwitchContext {
+                loading,
+                ..(*self).clone()
+            }
+            .into(),
         }
     }
 }
@@ -93,35 +129,46 @@ type ReducibleSwitchContext = UseReducerHandle<SwitchContext>;
 
 #[function_component(Switch)]
 pub fn switch(props: &SwitchProps) -> Html {
-    let (checked, dispatch) = use_controllable_state(
+    let UseControllableReducerHandle {
+        value: checked,
+        dispatch,
+        reducer: context_value,
+        reset,
+    } = use_controllable_reducer(
         props.default_checked,
         props.checked,
         props.on_checked_change.clone(),
+        |checked| SwitchContext {
+            checked,
+            disabled: props.disabled,
+            loading: props.loading,
+        },
+        |context| context.checked,
+        SwitchAction::Set,
     );
 
-    let context_value = use_reducer(|| SwitchContext {
-        checked: *checked.borrow(),
-        disabled: props.disabled,
-    });
-
     use_effect_with(
-        (*checked.borrow(), context_value.clone()),
-        |(checked, context_value)| {
-            if *checked != context_value.checked {
-                context_value.dispatch(SwitchAction::Toggle);
+        (props.loading, context_value.clone()),
+        |(loading, context_value)| {
+            if context_value.loading != *loading {
+                context_value.dispatch(SwitchAction::SetLoading(*loading));
             }
         },
     );
 
     let toggle = use_callback(
-        (dispatch.clone(), context_value.clone(), props.readonly),
-        move |(), (dispatch, context_value, readonly)| {
-            if *readonly {
+        (
+            dispatch.clone(),
+            props.readonly,
+            props.disabled,
+            props.loading,
+        ),
+        move |(), (dispatch, readonly, disabled, loading)| {
+            if *readonly || *disabled || *loading {
                 return;
             }
 
             dispatch.emit(Box::new(|prev_state| !prev_state));
-            context_value.dispatch(SwitchAction::Toggle);
         },
     );
 
@@ -129,6 +176,14 @@ pub fn switch(props: &SwitchProps) -> Html {
         toggle.emit(());
     });
 
+    let onclick = use_memo(
+        (props.onclick.clone(), toggle_on_click),
+        |(onclick, toggle_on_click)| {
+            combine_handlers(onclick.clone(), Some(toggle_on_click.clone()))
+        },
+    );
+
+    use_form_reset(&props.r#ref, reset);
     use_conditional_attr(props.r#ref.clone(), "data-disabled", None, props.disabled);
 
     let element = if let Some(render_as) = &props.render_as {
@@ -143,12 +198,17 @@ pub fn switch(props: &SwitchProps) -> Html {
             name: props.name.clone(),
             value: props.value.clone(),
             readonly: props.readonly,
+            invalid: props.invalid,
+            error_id: props.error_id.clone(),
+            loading: props.loading,
             toggle: toggle.clone(),
+            onclick: (*onclick).clone(),
         })
     } else {
         html! {
             <AttrReceiver name="switch">
                 <button
+                    ref={props.r#ref.clone()}
                     id={&props.id}
                     class={&props.class}
                     type="button"
@@ -156,7 +216,10 @@ pub fn switch(props: &SwitchProps) -> Html {
                     disabled={props.disabled}
                     name={&props.name}
                     value={&props.value}
-                    onclick={&combine_handlers(props.onclick.clone(), toggle_on_click.into())}
+                    aria-invalid={props.invalid.then_some("true")}
+                    aria-describedby={&props.error_id}
+                    aria-busy={props.loading.then_some("true")}
+                    onclick={(*onclick).clone()}
                 >
                     {for props.children.iter()}
                 </button>
@@ -171,6 +234,7 @@ pub fn switch(props: &SwitchProps) -> Html {
                 "aria-required" => props.required.then_some("true").unwrap_or_default(),
                 "data-state" => if *checked.borrow() { "checked" } else { "unchecked" },
                 "data-disabled" => props.disabled.to_string(),
+                "data-loading" => props.loading.to_string(),
             }>
                 {element}
             </AttrPasser>
@@ -182,6 +246,9 @@ pub fn switch(props: &SwitchProps) -> Html {
 pub struct SwitchThumbProps {
     #[prop_or_default]
     pub class: Option<AttrValue>,
+    /// Rendered within the thumb while the switch is `loading`, e.g. a spinner
+    #[prop_or_default]
+    pub loading_indicator: Option<Html>,
 }
 
 #[function_component(SwitchThumb)]
@@ -202,7 +269,12 @@ pub fn switch_thumb(props: &SwitchThumbProps) -> Html {
             class={&props.class}
             data-state={*data_state}
             data-disabled={context.disabled.to_string()}
-        ></div>
+            data-loading={context.loading.to_string()}
+        >
+            if context.loading {
+                {props.loading_indicator.clone()}
+            }
+        </div>
     }
 }
 
@@ -212,6 +284,7 @@ mod tests {
     use wasm_bindgen_test::*;
     use yewlish_testing_tools::TesterEvent;
     use yewlish_testing_tools::*;
+    use yewlish_utils::hooks::{use_validation, Rule};
 
     wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
 
@@ -245,6 +318,52 @@ mod tests {
         assert_eq!(switch.attribute("aria-checked"), "false".to_string().into());
     }
 
+    #[wasm_bindgen_test]
+    async fn test_switch_should_surface_validation_errors() {
+        let t = render!({
+            let checked = use_state(|| false);
+
+            let validation = use_validation(
+                *checked,
+                vec![Rule::predicate(
+                    |checked: &bool| *checked,
+                    "Accept the terms",
+                )],
+            );
+
+            let on_checked_change = use_callback(checked.setter(), |next, set_checked| {
+                set_checked.set(next);
+            });
+
+            html! {
+                <>
+                    <Switch
+                        checked={*checked}
+                        {on_checked_change}
+                        invalid={!validation.valid}
+                        error_id="terms-error"
+                    >
+                        <SwitchThumb />
+                    </Switch>
+                    <p id="terms-error">{validation.errors.join(", ")}</p>
+                </>
+            }
+        })
+        .await;
+
+        let switch = t.query_by_role("switch");
+        assert_eq!(switch.attribute("aria-invalid"), "true".to_string().into());
+        assert_eq!(
+            switch.attribute("aria-describedby"),
+            "terms-error".to_string().into()
+        );
+        assert!(t.query_by_text("Accept the terms").exists());
+
+        let switch = switch.click().await;
+        assert_eq!(switch.attribute("aria-invalid"), None);
+        assert!(!t.query_by_text("Accept the terms").exists());
+    }
+
     #[wasm_bindgen_test]
     async fn test_switch_default_checked() {
         let t = render!({
@@ -521,6 +640,102 @@ mod tests {
         assert_eq!(switch.attribute("aria-checked"), "false".to_string().into());
     }
 
+    #[wasm_bindgen_test]
+    async fn test_switch_custom_onclick_fires_alongside_the_toggle() {
+        let t = render!({
+            let clicks = use_state(|| 0);
+
+            let onclick = use_callback(clicks.clone(), |_: MouseEvent, clicks| {
+                clicks.set(**clicks + 1);
+            });
+
+            use_remember_value(*clicks);
+
+            html! {
+                <Switch {onclick}>
+                    <SwitchThumb />
+                </Switch>
+            }
+        })
+        .await;
+
+        let switch = t.query_by_role("switch").click().await;
+
+        assert_eq!(switch.attribute("aria-checked"), "true".to_string().into());
+        assert_eq!(t.get_remembered_value::<i32>(), 1);
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_switch_render_as_combines_onclick_and_honors_readonly() {
+        let t = render!({
+            let clicks = use_state(|| 0);
+            let readonly = use_state(|| false);
+
+            let onclick = use_callback(clicks.clone(), |_: MouseEvent, clicks| {
+                clicks.set(**clicks + 1);
+            });
+
+            let make_readonly = use_callback(readonly.setter(), |_: MouseEvent, set_readonly| {
+                set_readonly.set(true);
+            });
+
+            use_remember_value(*clicks);
+
+            html! {
+                <>
+                    <button data-testid="make-readonly" onclick={make_readonly}>
+                        {"Make readonly"}
+                    </button>
+                    <Switch
+                        {onclick}
+                        readonly={*readonly}
+                        render_as={Callback::from(|props: SwitchRenderAsProps| html! {
+                            <div
+                                data-testid="custom"
+                                data-checked={props.checked.to_string()}
+                                onclick={props.onclick}
+                            />
+                        })}
+                    />
+                </>
+            }
+        })
+        .await;
+
+        let custom = t.query_by_testid("custom").click().await;
+        assert_eq!(custom.attribute("data-checked"), "true".to_string().into());
+        assert_eq!(t.get_remembered_value::<i32>(), 1);
+
+        t.query_by_testid("make-readonly").click().await;
+
+        // The custom handler still runs, the toggle doesn't
+        let custom = custom.click().await;
+        assert_eq!(custom.attribute("data-checked"), "true".to_string().into());
+        assert_eq!(t.get_remembered_value::<i32>(), 2);
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_switch_disabled_toggle_does_nothing() {
+        let t = render!({
+            html! {
+                <Switch
+                    disabled=true
+                    render_as={Callback::from(|props: SwitchRenderAsProps| html! {
+                        <div
+                            data-testid="custom"
+                            data-checked={props.checked.to_string()}
+                            onclick={props.toggle.reform(|_: MouseEvent| ())}
+                        />
+                    })}
+                />
+            }
+        })
+        .await;
+
+        let custom = t.query_by_testid("custom").click().await;
+        assert_eq!(custom.attribute("data-checked"), "false".to_string().into());
+    }
+
     #[wasm_bindgen_test]
     async fn test_switch_thumb_data_state() {
         let t = render!({
@@ -547,4 +762,73 @@ mod tests {
         // Thumb should now reflect the checked state
         assert_eq!(thumb.attribute("data-state"), "checked".to_string().into());
     }
+
+    #[wasm_bindgen_test]
+    async fn test_switch_loading() {
+        let t = render!({
+            let loading = use_state(|| true);
+
+            let done = use_callback(loading.clone(), |_: MouseEvent, loading| {
+                loading.set(false);
+            });
+
+            html! {
+                <>
+                    <button onclick={done}>{"Done"}</button>
+                    <Switch loading={*loading}>
+                        <SwitchThumb
+                            class={"thumb-class"}
+                            loading_indicator={html! { <span>{"Saving"}</span> }}
+                        />
+                    </Switch>
+                </>
+            }
+        })
+        .await;
+
+        let switch = t.query_by_role("switch");
+        assert_eq!(switch.attribute("aria-busy"), "true".to_string().into());
+        assert_eq!(switch.attribute("data-loading"), "true".to_string().into());
+
+        let thumb = t.query_by_selector(".thumb-class");
+        assert_eq!(thumb.attribute("data-loading"), "true".to_string().into());
+        assert!(t.query_by_text("Saving").exists());
+
+        // Toggles are ignored while loading
+        let switch = switch.click().await;
+        assert_eq!(switch.attribute("aria-checked"), "false".to_string().into());
+
+        t.query_by_text("Done").click().await;
+
+        assert_eq!(t.query_by_role("switch").attribute("aria-busy"), None);
+        assert!(!t.query_by_text("Saving").exists());
+
+        let switch = t.query_by_role("switch").click().await;
+        assert_eq!(switch.attribute("aria-checked"), "true".to_string().into());
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_switch_form_reset() {
+        let t = render!({
+            html! {
+                <form>
+                    <Switch default_checked={true}>
+                        <SwitchThumb />
+                    </Switch>
+                    <button type="reset">{"Reset"}</button>
+                </form>
+            }
+        })
+        .await;
+
+        let switch = t.query_by_role("switch").click().await;
+        assert_eq!(switch.attribute("aria-checked"), "false".to_string().into());
+
+        t.query_by_text("Reset").click().await;
+
+        assert_eq!(
+            t.query_by_role("switch").attribute("aria-checked"),
+            "true".to_string().into()
+        );
+    }
 }
@@ -1,7 +1,7 @@
 use std::any::Any;
 use std::cell::RefCell;
 use std::collections::HashMap;
-use std::fmt::Debug;
+use std::fmt::{Debug, Formatter};
 use std::rc::Rc;
 use yew::prelude::*;
 
@@ -13,29 +13,51 @@ enum DataStatus {
     Claimed(SynchiSubscriber, Box<dyn Any>),
 }
 
-thread_local! {
-    static SYNCHI: Rc<RefCell<SynchiStore>> = Rc::new(RefCell::new(HashMap::new()));
-    static SYNCHI_COUNTERS: RefCell<HashMap<&'static str, usize>> = RefCell::new(HashMap::new());
-    static SYNCHI_SUBSCRIBERS: RefCell<HashMap<&'static str, Vec<SynchiSubscriber>>> =
-        RefCell::new(HashMap::new());
+/// A store of channels, channels with the same name only meet when they share a scope.
+///
+/// Hooks without a scope use the global one, `SynchiProvider` creates a fresh scope for its subtree.
+#[derive(Clone, Default)]
+pub struct SynchiScope {
+    store: Rc<RefCell<SynchiStore>>,
+    counters: Rc<RefCell<HashMap<&'static str, usize>>>,
+    subscribers: Rc<RefCell<HashMap<&'static str, Vec<SynchiSubscriber>>>>,
 }
 
-pub trait Merge {
-    fn merge(&self, other: &Self) -> Self;
+impl PartialEq for SynchiScope {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.store, &other.store)
+    }
 }
 
-fn register_channel<T>(name: &'static str, data: T) -> Option<usize>
-where
-    T: Any,
-{
-    SYNCHI.with(|store| {
-        let mut store = store.borrow_mut();
+impl Debug for SynchiScope {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SynchiScope")
+            .field("channels", &self.store.borrow().keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+thread_local! {
+    static SYNCHI: SynchiScope = SynchiScope::default();
+}
+
+impl SynchiScope {
+    /// The scope shared by everything that is not under a `SynchiProvider`.
+    pub fn global() -> Self {
+        SYNCHI.with(Clone::clone)
+    }
+
+    fn register_channel<T>(&self, name: &'static str, data: T) -> Option<usize>
+    where
+        T: Any,
+    {
+        let mut store = self.store.borrow_mut();
 
         if let Some(existing_channel) = store.get(name) {
             let mut channel = existing_channel.borrow_mut();
 
-            let index = SYNCHI_COUNTERS.with(|counters| {
-                let mut counters = counters.borrow_mut();
+            let index = {
+                let mut counters = self.counters.borrow_mut();
 
                 if let Some(counter) = counters.get_mut(name) {
                     *counter += 1;
@@ -44,16 +66,13 @@ where
                     counters.insert(name, 0);
                     0
                 }
-            });
+            };
 
             channel.insert(index, DataStatus::Free(Box::new(data)));
             return Some(index);
         }
 
-        SYNCHI_COUNTERS.with(|counters| {
-            let mut counters = counters.borrow_mut();
-            counters.insert(name, 0);
-        });
+        self.counters.borrow_mut().insert(name, 0);
 
         let new_channel = Rc::new(RefCell::new(HashMap::<usize, DataStatus>::from_iter(vec![
             (0, DataStatus::Free(Box::new(data))),
@@ -61,12 +80,10 @@ where
 
         store.insert(name, new_channel);
         Some(0)
-    })
-}
+    }
 
-fn unregister_channel(name: &'static str, index: usize) -> usize {
-    SYNCHI.with(|store| {
-        let store = store.borrow_mut();
+    fn unregister_channel(&self, name: &'static str, index: usize) -> usize {
+        let store = self.store.borrow_mut();
 
         if let Some(channel) = store.get(name) {
             let mut channel = channel.borrow_mut();
@@ -76,38 +93,36 @@ fn unregister_channel(name: &'static str, index: usize) -> usize {
         } else {
             panic!("Failed to get SYNCHI channel");
         }
-    })
-}
+    }
 
-fn subscribe_to_channel<T>(name: &'static str, indexes: Vec<usize>, callback: SynchiSubscriber)
-where
-    T: Any + Clone + Debug + Default + Merge,
-{
-    let mut no_new_subscriber = false;
+    fn remove_channel(&self, name: &'static str) {
+        if let Some(channel) = self.store.borrow_mut().remove(name) {
+            drop(channel);
+        }
+
+        self.counters.borrow_mut().remove(name);
+        self.subscribers.borrow_mut().remove(name);
+    }
 
-    SYNCHI_SUBSCRIBERS.with(|subscribers| {
-        let mut subscribers = subscribers.borrow_mut();
+    fn subscribe<T>(&self, name: &'static str, indexes: Vec<usize>, callback: SynchiSubscriber)
+    where
+        T: Any + Clone + Debug + Default + Merge,
+    {
+        {
+            let mut subscribers = self.subscribers.borrow_mut();
 
-        if let Some(existing_subscribers) = subscribers.get_mut(name) {
-            for existing_subscriber in existing_subscribers.iter() {
-                if existing_subscriber == &callback {
-                    no_new_subscriber = true;
+            if let Some(existing_subscribers) = subscribers.get_mut(name) {
+                if existing_subscribers.contains(&callback) {
                     return;
                 }
-            }
 
-            existing_subscribers.push(callback.clone());
-        } else {
-            subscribers.insert(name, vec![callback.clone()]);
+                existing_subscribers.push(callback.clone());
+            } else {
+                subscribers.insert(name, vec![callback.clone()]);
+            }
         }
-    });
 
-    if no_new_subscriber {
-        return;
-    }
-
-    SYNCHI.with(|store| {
-        let store = store.borrow_mut();
+        let store = self.store.borrow_mut();
 
         if let Some(channel) = store.get(name) {
             let mut channel = channel.borrow_mut();
@@ -137,54 +152,48 @@ where
 
             callback.emit(Box::new(merged_data));
         }
-    });
-}
-
-fn unsubscribe_from_channel<T>(name: &'static str, callback: SynchiSubscriber)
-where
-    T: Any + Clone + Debug + Default + Merge,
-{
-    SYNCHI_SUBSCRIBERS.with(|subscribers| {
-        let mut subscribers = subscribers.borrow_mut();
+    }
 
-        if let Some(existing_subscribers) = subscribers.get_mut(name) {
+    fn unsubscribe<T>(&self, name: &'static str, callback: SynchiSubscriber)
+    where
+        T: Any + Clone + Debug + Default + Merge,
+    {
+        if let Some(existing_subscribers) = self.subscribers.borrow_mut().get_mut(name) {
             existing_subscribers.retain(|subscriber| subscriber != &callback);
         }
-    });
 
-    SYNCHI.with(|store| {
-        let store = store.borrow_mut();
+        let store = self.store.borrow_mut();
 
         if let Some(channel) = store.get(name) {
             let mut channel = channel.borrow_mut();
 
-            for index in 0..channel.len() {
-                if let Some(data_status) = channel.get_mut(&index) {
-                    if let DataStatus::Claimed(subscriber, data) = data_status {
-                        if *subscriber == callback {
-                            if let Some(data) = data.downcast_ref::<T>() {
-                                *data_status = DataStatus::Free(Box::new(data.clone()));
-                            }
+            // Indexes are not contiguous once channels have been dropped, so walk all of them
+            for data_status in channel.values_mut() {
+                if let DataStatus::Claimed(subscriber, data) = data_status {
+                    if *subscriber == callback {
+                        if let Some(data) = data.downcast_ref::<T>() {
+                            *data_status = DataStatus::Free(Box::new(data.clone()));
                         }
                     }
                 }
             }
         }
-    });
-}
+    }
 
-fn notify_subscriber<T>(name: &'static str, callback: SynchiSubscriber)
-where
-    T: Any + Clone + Debug + Default + Merge,
-{
-    SYNCHI.with(|store| {
-        let store = store.borrow_mut();
+    fn notify<T>(&self, name: &'static str, callback: SynchiSubscriber)
+    where
+        T: Any + Clone + Debug + Default + Merge,
+    {
+        let store = self.store.borrow_mut();
 
         if let Some(channel) = store.get(name) {
             let mut channel = channel.borrow_mut();
             let mut merged_data = T::default();
 
-            for index in 0..channel.len() {
+            let mut indexes = channel.keys().copied().collect::<Vec<_>>();
+            indexes.sort_unstable();
+
+            for index in indexes {
                 if let Some(DataStatus::Claimed(subscriber, data)) = channel.get_mut(&index) {
                     if *subscriber == callback {
                         if let Some(data) = data.downcast_ref::<T>() {
@@ -196,7 +205,27 @@ where
 
             callback.emit(Box::new(merged_data));
         }
-    });
+    }
+}
+
+pub trait Merge {
+    fn merge(&self, other: &Self) -> Self;
+}
+
+#[cfg(test)]
+fn subscribe_to_channel<T>(name: &'static str, indexes: Vec<usize>, callback: SynchiSubscriber)
+where
+    T: Any + Clone + Debug + Default + Merge,
+{
+    SynchiScope::global().subscribe::<T>(name, indexes, callback);
+}
+
+#[cfg(test)]
+fn unsubscribe_from_channel<T>(name: &'static str, callback: SynchiSubscriber)
+where
+    T: Any + Clone + Debug + Default + Merge,
+{
+    SynchiScope::global().unsubscribe::<T>(name, callback);
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -206,6 +235,7 @@ where
 {
     pub name: &'static str,
     pub index: usize,
+    scope: SynchiScope,
     _marker: std::marker::PhantomData<T>,
 }
 
@@ -214,26 +244,10 @@ where
     T: Any + Clone + Default,
 {
     fn drop(&mut self) {
-        let channel_len = unregister_channel(self.name, self.index);
+        let channel_len = self.scope.unregister_channel(self.name, self.index);
 
         if channel_len == 0 {
-            SYNCHI.with(|store| {
-                let mut store = store.borrow_mut();
-
-                if let Some(channel) = store.remove(self.name) {
-                    drop(channel);
-                }
-            });
-
-            SYNCHI_COUNTERS.with(|counters| {
-                let mut counters = counters.borrow_mut();
-                counters.remove(self.name);
-            });
-
-            SYNCHI_SUBSCRIBERS.with(|subscribers| {
-                let mut subscribers = subscribers.borrow_mut();
-                subscribers.remove(self.name);
-            });
+            self.scope.remove_channel(self.name);
         }
     }
 }
@@ -243,22 +257,25 @@ where
     T: Any + Clone + Debug + Merge + Default,
 {
     pub fn new(name: &'static str) -> Self {
-        if let Some(index) = register_channel(name, T::default()) {
-            SynchiChannel {
-                name,
-                index,
-                _marker: std::marker::PhantomData,
-            }
-        } else {
-            panic!("Failed to register SYNCHI channel");
-        }
+        Self::new_in(SynchiScope::global(), name)
     }
 
     pub fn new_with_data(name: &'static str, data: T) -> Self {
-        if let Some(index) = register_channel(name, data) {
+        Self::new_with_data_in(SynchiScope::global(), name, data)
+    }
+
+    /// Registers the channel in the given scope instead of the global one.
+    pub fn new_in(scope: SynchiScope, name: &'static str) -> Self {
+        Self::new_with_data_in(scope, name, T::default())
+    }
+
+    /// Registers the channel with initial data in the given scope instead of the global one.
+    pub fn new_with_data_in(scope: SynchiScope, name: &'static str, data: T) -> Self {
+        if let Some(index) = scope.register_channel(name, data) {
             SynchiChannel {
                 name,
                 index,
+                scope,
                 _marker: std::marker::PhantomData,
             }
         } else {
@@ -267,37 +284,35 @@ where
     }
 
     pub fn pull(&self) -> T {
-        SYNCHI.with(|store| {
-            let store = store.borrow();
+        let store = self.scope.store.borrow();
 
-            if let Some(channel) = store.get(self.name) {
-                let channel = channel.borrow();
+        if let Some(channel) = store.get(self.name) {
+            let channel = channel.borrow();
 
-                if let Some(data) = channel.get(&self.index) {
-                    match data {
-                        DataStatus::Free(data) => {
-                            let data = data.downcast_ref::<T>().unwrap();
-                            data.clone()
-                        }
-                        DataStatus::Claimed(_, data) => {
-                            let data = data.downcast_ref::<T>().unwrap();
-                            data.clone()
-                        }
+            if let Some(data) = channel.get(&self.index) {
+                match data {
+                    DataStatus::Free(data) => {
+                        let data = data.downcast_ref::<T>().unwrap();
+                        data.clone()
+                    }
+                    DataStatus::Claimed(_, data) => {
+                        let data = data.downcast_ref::<T>().unwrap();
+                        data.clone()
                     }
-                } else {
-                    panic!("Failed to get SYNCHI channel data");
                 }
             } else {
-                panic!("Failed to get SYNCHI channel");
+                panic!("Failed to get SYNCHI channel data");
             }
-        })
+        } else {
+            panic!("Failed to get SYNCHI channel");
+        }
     }
 
     pub fn push(&self, data: T) {
         let mut target_subscriber = None::<SynchiSubscriber>;
 
-        SYNCHI.with(|store| {
-            let store = store.borrow_mut();
+        {
+            let store = self.scope.store.borrow_mut();
 
             if let Some(channel) = store.get(self.name) {
                 let mut channel = channel.borrow_mut();
@@ -320,14 +335,57 @@ where
             } else {
                 panic!("Failed to get SYNCHI channel");
             }
-        });
+        }
 
         if let Some(subscriber) = target_subscriber {
-            notify_subscriber::<T>(self.name, subscriber.clone());
+            self.scope.notify::<T>(self.name, subscriber.clone());
         }
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct SynchiProviderProps {
+    pub children: Children,
+}
+
+/// Gives its subtree a channel store of its own.
+///
+/// The `use_scoped_synchi_*` hooks below it don't see channels of the same name registered
+/// elsewhere, which keeps independent instances of a widget, or tests, from colliding.
+///
+/// # Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yewlish_synchi::SynchiProvider;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <>
+///             <SynchiProvider>{"First widget"}</SynchiProvider>
+///             <SynchiProvider>{"Second widget"}</SynchiProvider>
+///         </>
+///     }
+/// }
+/// ```
+#[function_component(SynchiProvider)]
+pub fn synchi_provider(props: &SynchiProviderProps) -> Html {
+    let scope = use_memo((), |()| SynchiScope::default());
+
+    html! {
+        <ContextProvider<SynchiScope> context={(*scope).clone()}>
+            {for props.children.iter()}
+        </ContextProvider<SynchiScope>>
+    }
+}
+
+/// Returns the scope of the nearest `SynchiProvider`, or the global scope outside of any.
+#[hook]
+pub fn use_synchi_scope() -> SynchiScope {
+    use_context::<SynchiScope>().unwrap_or_else(SynchiScope::global)
+}
+
 #[hook]
 pub fn use_synchi_channel<T>(name: &'static str) -> Rc<RefCell<SynchiChannel<T>>>
 where
@@ -346,6 +404,54 @@ where
 
 #[hook]
 pub fn use_synchi_channel_subscribe<T>(name: &'static str, indexes: Vec<usize>) -> UseStateHandle<T>
+where
+    T: Any + Clone + Default + PartialEq + Debug + Merge,
+{
+    use_synchi_channel_subscribe_in(SynchiScope::global(), name, indexes)
+}
+
+/// Like `use_synchi_channel`, but registers the channel in the nearest `SynchiProvider`.
+#[hook]
+pub fn use_scoped_synchi_channel<T>(name: &'static str) -> Rc<RefCell<SynchiChannel<T>>>
+where
+    T: Any + Clone + Default + Merge + Debug,
+{
+    let scope = use_synchi_scope();
+    use_mut_ref(|| SynchiChannel::<T>::new_in(scope, name))
+}
+
+/// Like `use_synchi_channel_with`, but registers the channel in the nearest `SynchiProvider`.
+#[hook]
+pub fn use_scoped_synchi_channel_with<T>(
+    name: &'static str,
+    data: T,
+) -> Rc<RefCell<SynchiChannel<T>>>
+where
+    T: Any + Clone + Default + PartialEq + Merge + Debug,
+{
+    let scope = use_synchi_scope();
+    use_mut_ref(|| SynchiChannel::<T>::new_with_data_in(scope, name, data.clone()))
+}
+
+/// Like `use_synchi_channel_subscribe`, but subscribes to the channel of the nearest `SynchiProvider`.
+#[hook]
+pub fn use_scoped_synchi_channel_subscribe<T>(
+    name: &'static str,
+    indexes: Vec<usize>,
+) -> UseStateHandle<T>
+where
+    T: Any + Clone + Default + PartialEq + Debug + Merge,
+{
+    let scope = use_synchi_scope();
+    use_synchi_channel_subscribe_in(scope, name, indexes)
+}
+
+#[hook]
+fn use_synchi_channel_subscribe_in<T>(
+    scope: SynchiScope,
+    name: &'static str,
+    indexes: Vec<usize>,
+) -> UseStateHandle<T>
 where
     T: Any + Clone + Default + PartialEq + Debug + Merge,
 {
@@ -363,13 +469,14 @@ where
         })
     };
 
-    subscribe_to_channel::<T>(name, indexes, subscriber.clone());
+    // Re-claim the indexes whenever they change, releasing the previous claim first
+    use_effect_with((scope, indexes), move |(scope, indexes)| {
+        scope.subscribe::<T>(name, indexes.clone(), subscriber.clone());
 
-    use_effect_with((), move |_| {
-        let subscriber = subscriber.clone();
+        let scope = scope.clone();
 
         move || {
-            unsubscribe_from_channel::<T>(name, subscriber);
+            scope.unsubscribe::<T>(name, subscriber);
         }
     });
 
@@ -693,4 +800,90 @@ mod tests {
         assert_eq!(received_data_2.borrow().len(), 1);
         assert_eq!(received_data_2.borrow()[0], MergeInt(43));
     }
+
+    #[test]
+    #[serial]
+    fn test_resubscribe_to_another_subset_of_indexes() {
+        let channel1 = SynchiChannel::<MergeInt>::new("test");
+        let channel2 = SynchiChannel::<MergeInt>::new("test");
+
+        channel1.push(MergeInt(1));
+        channel2.push(MergeInt(2));
+
+        let received_data = Rc::new(RefCell::new(Vec::new()));
+
+        let subscriber = {
+            let received_data = received_data.clone();
+
+            Callback::from(move |data: Box<dyn Any>| {
+                if let Some(data) = data.downcast_ref::<MergeInt>() {
+                    received_data.borrow_mut().push(data.clone());
+                } else {
+                    panic!("Failed to downcast SYNCHI channel data");
+                }
+            })
+        };
+
+        subscribe_to_channel::<MergeInt>("test", vec![0], subscriber.clone());
+        assert_eq!(received_data.borrow().last(), Some(&MergeInt(1)));
+
+        // Grow the subset
+        unsubscribe_from_channel::<MergeInt>("test", subscriber.clone());
+        subscribe_to_channel::<MergeInt>("test", vec![0, 1], subscriber.clone());
+        assert_eq!(received_data.borrow().last(), Some(&MergeInt(3)));
+
+        // Shrink the subset, the released index should not notify anymore
+        unsubscribe_from_channel::<MergeInt>("test", subscriber.clone());
+        subscribe_to_channel::<MergeInt>("test", vec![1], subscriber.clone());
+        assert_eq!(received_data.borrow().last(), Some(&MergeInt(2)));
+
+        let received_count = received_data.borrow().len();
+
+        channel1.push(MergeInt(10));
+        assert_eq!(received_data.borrow().len(), received_count);
+
+        channel2.push(MergeInt(20));
+        assert_eq!(received_data.borrow().last(), Some(&MergeInt(20)));
+
+        unsubscribe_from_channel::<MergeInt>("test", subscriber);
+    }
+
+    #[test]
+    fn test_scoped_channels_do_not_collide() {
+        let scope_1 = SynchiScope::default();
+        let scope_2 = SynchiScope::default();
+
+        let channel_1 = SynchiChannel::<MergeInt>::new_in(scope_1.clone(), "scoped");
+        let channel_2 = SynchiChannel::<MergeInt>::new_in(scope_2.clone(), "scoped");
+
+        // Both channels are the first of their name in their own scope
+        assert_eq!(channel_1.index, 0);
+        assert_eq!(channel_2.index, 0);
+
+        let received_data = Rc::new(RefCell::new(Vec::new()));
+
+        let subscriber = {
+            let received_data = received_data.clone();
+
+            Callback::from(move |data: Box<dyn Any>| {
+                if let Some(data) = data.downcast_ref::<MergeInt>() {
+                    received_data.borrow_mut().push(data.clone());
+                } else {
+                    panic!("Failed to downcast SYNCHI channel data");
+                }
+            })
+        };
+
+        scope_1.subscribe::<MergeInt>("scoped", vec![0], subscriber.clone());
+        assert_eq!(received_data.borrow().len(), 1);
+
+        channel_2.push(MergeInt(42));
+        assert_eq!(received_data.borrow().len(), 1);
+        assert_eq!(channel_1.pull(), MergeInt(0));
+
+        channel_1.push(MergeInt(43));
+        assert_eq!(received_data.borrow().last(), Some(&MergeInt(43)));
+
+        scope_1.unsubscribe::<MergeInt>("scoped", subscriber);
+    }
 }
@@ -41,6 +41,23 @@
 /// }
 /// ```
 ///
+/// # Providing contexts
+///
+/// Components that read a context can be rendered on their own by supplying the context
+/// values instead of the providers that normally create them. Each value is built in the
+/// wrapper component, so it can't borrow from the test, and each provider wraps the
+/// previous ones:
+///
+/// ```rust,ignore
+/// let t = render!(
+///     {
+///         html! { <ThemedButton /> }
+///     },
+///     contexts = [Theme => Theme::Dark, Locale => Locale::from("en")]
+/// )
+/// .await;
+/// ```
+///
 /// # Note
 ///
 /// The `render!` macro leverages `yew::Renderer` to wrap the component under test within a custom component.
@@ -67,6 +84,35 @@ macro_rules! render {
 
         render!($view, test_wrapper)
     }};
+    ($view:expr, contexts = [$($context_type:ty => $context:expr),+ $(,)?]) => {{
+        use $crate::yew::prelude::*;
+
+        #[derive(Clone, PartialEq, Properties)]
+        struct TestContextWrapperProps {
+            children: Children,
+        }
+
+        #[function_component(TestContextWrapper)]
+        fn test_context_wrapper(props: &TestContextWrapperProps) -> Html {
+            let view = html! {
+                <>
+                    { for props.children.iter() }
+                </>
+            };
+
+            $(
+                let view = html! {
+                    <ContextProvider<$context_type> context={$context}>
+                        {view}
+                    </ContextProvider<$context_type>>
+                };
+            )+
+
+            view
+        }
+
+        render!($view, TestContextWrapper)
+    }};
     ($view:expr, $wrapper:expr) => {{
         use std::any::Any;
         use std::cell::RefCell;
@@ -4,4 +4,7 @@ pub trait TesterEvent {
     fn click(self) -> Pin<Box<dyn Future<Output = Self>>>;
     fn keydown(self, key: &str) -> Pin<Box<dyn Future<Output = Self>>>;
     fn focus(self) -> Pin<Box<dyn Future<Output = Self>>>;
+    fn blur(self) -> Pin<Box<dyn Future<Output = Self>>>;
+    /// Presses Tab (or Shift+Tab) on the focused element and returns the element focused afterwards.
+    fn tab(self, shift: bool) -> Pin<Box<dyn Future<Output = Self>>>;
 }
@@ -1,6 +1,8 @@
 pub trait Extractor {
     fn attribute(&self, name: &str) -> Option<String>;
     fn text(&self) -> String;
+    /// Serialized markup of the element's children, empty when the element doesn't exist
+    fn html(&self) -> String;
     #[deprecated(since = "1.2.1", note = "Please use `get_remembered_value` instead")]
     fn get_state<T: Clone + 'static>(&self) -> T;
     fn get_remembered_value<T: Clone + 'static>(&self) -> T;
@@ -156,6 +156,32 @@ pub fn node_list_to_vec(node_list: &web_sys::NodeList) -> Vec<web_sys::Element>
         .collect()
 }
 
+const FOCUSABLE_SELECTOR: &str = "a[href], area[href], button:not([disabled]), \
+    input:not([disabled]):not([type='hidden']), select:not([disabled]), \
+    textarea:not([disabled]), iframe, [tabindex], [contenteditable='true']";
+
+/// Collects the elements of `document` reachable with Tab, in the order the browser visits them:
+/// positive `tabindex` values first, ascending, then the rest in document order.
+fn tab_sequence(document: &web_sys::Document) -> Vec<web_sys::HtmlElement> {
+    let Ok(node_list) = document.query_selector_all(FOCUSABLE_SELECTOR) else {
+        return vec![];
+    };
+
+    let mut elements = node_list_to_vec(&node_list)
+        .into_iter()
+        .filter_map(|element| element.dyn_into::<web_sys::HtmlElement>().ok())
+        .filter(|element| element.tab_index() >= 0)
+        .collect::<Vec<_>>();
+
+    // Stable, so elements sharing a `tabindex` keep their document order
+    elements.sort_by_key(|element| match element.tab_index() {
+        0 => i32::MAX,
+        tab_index => tab_index,
+    });
+
+    elements
+}
+
 #[derive(Clone)]
 pub struct Tester {
     root: Option<web_sys::Element>,
@@ -261,6 +287,24 @@ impl Tester {
             }
         }
     }
+
+    /// Asserts that the rendered markup matches `expected`, ignoring differences in whitespace.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the normalized markup differs.
+    #[track_caller]
+    pub fn assert_html_matches(&self, expected: &str) {
+        assert_eq!(normalize_html(&self.html()), normalize_html(expected));
+    }
+}
+
+/// Collapses whitespace runs into a single space and drops whitespace between tags.
+fn normalize_html(html: &str) -> String {
+    html.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .replace("> <", "><")
 }
 
 impl Query for Tester {
@@ -403,6 +447,93 @@ impl TesterEvent for Tester {
             }),
         }
     }
+
+    fn blur(self) -> Pin<Box<dyn Future<Output = Self>>> {
+        if let Some(root) = &self.root {
+            root.dyn_ref::<web_sys::HtmlElement>()
+                .expect("Failed to cast element to HtmlElement")
+                .blur()
+                .expect("Failed to blur element");
+        }
+
+        Box::pin(async move {
+            sleep(Duration::ZERO).await;
+            self
+        })
+    }
+
+    fn tab(self, shift: bool) -> Pin<Box<dyn Future<Output = Self>>> {
+        let focused = self.move_focus_by_tab(shift);
+
+        Box::pin(async move {
+            sleep(Duration::ZERO).await;
+
+            Self {
+                root: focused,
+                state: self.state,
+            }
+        })
+    }
+}
+
+impl Tester {
+    /// Dispatches a Tab keydown on the focused element and moves the focus along unless it was prevented,
+    /// wrapping around at both ends of the document.
+    fn move_focus_by_tab(&self, shift: bool) -> Option<web_sys::Element> {
+        let document = web_sys::window()
+            .and_then(|window| window.document())
+            .expect("Failed to get document");
+
+        let active = document.active_element();
+        let target = active.clone().or_else(|| document.body().map(Into::into));
+
+        let keydown_event_init_dict = web_sys::KeyboardEventInit::new();
+        keydown_event_init_dict.set_bubbles(true);
+        keydown_event_init_dict.set_cancelable(true);
+        keydown_event_init_dict.set_composed(true);
+        keydown_event_init_dict.set_key("Tab");
+        keydown_event_init_dict.set_shift_key(shift);
+
+        let keydown_event = web_sys::KeyboardEvent::new_with_keyboard_event_init_dict(
+            "keydown",
+            &keydown_event_init_dict,
+        )
+        .expect("Failed to create keydown event");
+
+        if let Some(target) = &target {
+            let _ = target
+                .dyn_ref::<web_sys::EventTarget>()
+                .expect("Failed to cast element to EventTarget")
+                .dispatch_event(&keydown_event);
+        }
+
+        // A focus trap or a roving focus took care of it
+        if keydown_event.default_prevented() {
+            return document.active_element();
+        }
+
+        let sequence = tab_sequence(&document);
+
+        if sequence.is_empty() {
+            return active;
+        }
+
+        let position = active.as_ref().and_then(|active| {
+            sequence
+                .iter()
+                .position(|element| AsRef::<web_sys::Element>::as_ref(element) == active)
+        });
+
+        let next = match (position, shift) {
+            (Some(index), false) => (index + 1) % sequence.len(),
+            (Some(index), true) => (index + sequence.len() - 1) % sequence.len(),
+            (None, false) => 0,
+            (None, true) => sequence.len() - 1,
+        };
+
+        sequence[next].focus().expect("Failed to focus element");
+        document.active_element()
+    }
 }
 
 impl Extractor for Tester {
@@ -420,6 +551,13 @@ impl Extractor for Tester {
         }
     }
 
+    fn html(&self) -> String {
+        match &self.root {
+            Some(root) => root.inner_html(),
+            None => String::new(),
+        }
+    }
+
     fn get_remembered_value<T: Clone + 'static>(&self) -> T {
         self.state.get()
     }
@@ -463,6 +601,41 @@ mod tests {
         assert_eq!(t.query_by_selector("#test").text(), "Hello");
     }
 
+    #[wasm_bindgen_test]
+    async fn test_extract_html() {
+        let t = render!({
+            html! {
+                <div id="test"><span class="label">{"Hello"}</span></div>
+            }
+        })
+        .await;
+
+        assert_eq!(
+            t.query_by_selector("#test").html(),
+            r#"<span class="label">Hello</span>"#
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_assert_html_matches() {
+        let t = render!({
+            html! {
+                <ul id="test" role="listbox">
+                    <li aria-selected="true">{"One"}</li>
+                    <li aria-selected="false">{"Two"}</li>
+                </ul>
+            }
+        })
+        .await;
+
+        t.query_by_selector("#test").assert_html_matches(
+            r#"
+                <li aria-selected="true">One</li>
+                <li aria-selected="false">Two</li>
+            "#,
+        );
+    }
+
     #[wasm_bindgen_test]
     async fn test_extract_attribute() {
         let t = render!({
@@ -514,6 +687,70 @@ mod tests {
         assert_eq!(button.text(), "Clicked");
     }
 
+    #[wasm_bindgen_test]
+    async fn test_focus_and_blur() {
+        let t = render!({
+            let focused = use_state(|| false);
+
+            let onfocus = use_callback(focused.setter(), |_: FocusEvent, set_focused| {
+                set_focused.set(true);
+            });
+
+            let onblur = use_callback(focused.setter(), |_: FocusEvent, set_focused| {
+                set_focused.set(false);
+            });
+
+            html! {
+                <button id="test" {onfocus} {onblur}>
+                    {if *focused { "Focused" } else { "Blurred" }}
+                </button>
+            }
+        })
+        .await;
+
+        let button = t.query_by_selector("#test").focus().await;
+        assert_eq!(button.text(), "Focused");
+
+        let button = button.blur().await;
+        assert_eq!(button.text(), "Blurred");
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_tab() {
+        let t = render!({
+            let on_trapped_keydown = use_callback((), |event: KeyboardEvent, ()| {
+                if event.key() == "Tab" {
+                    event.prevent_default();
+                }
+            });
+
+            html! {
+                <>
+                    <button id="first">{"First"}</button>
+                    <button id="disabled" disabled=true>{"Disabled"}</button>
+                    <div id="skipped" tabindex="-1">{"Skipped"}</div>
+                    <a id="second" href="#">{"Second"}</a>
+                    <input id="trap" onkeydown={on_trapped_keydown} />
+                </>
+            }
+        })
+        .await;
+
+        let first = t.query_by_selector("#first").focus().await;
+
+        let second = first.tab(false).await;
+        assert_eq!(second.attribute("id"), Some("second".to_string()));
+
+        let first = second.tab(true).await;
+        assert_eq!(first.attribute("id"), Some("first".to_string()));
+
+        let trap = t.query_by_selector("#trap").focus().await;
+
+        // The keydown handler prevents the default, so the focus stays put
+        let trap = trap.tab(false).await;
+        assert_eq!(trap.attribute("id"), Some("trap".to_string()));
+    }
+
     #[wasm_bindgen_test]
     async fn test_click_when_disabled() {
         let t = render!({
@@ -2814,6 +3051,38 @@ mod tests {
         );
     }
 
+    #[wasm_bindgen_test]
+    async fn test_render_with_contexts() {
+        #[derive(Clone, Debug, PartialEq)]
+        struct Theme(AttrValue);
+
+        #[derive(Clone, Debug, PartialEq)]
+        struct Locale(AttrValue);
+
+        #[function_component(Greeting)]
+        fn greeting() -> Html {
+            let theme = use_context::<Theme>().expect("Theme must be provided");
+            let locale = use_context::<Locale>().expect("Locale must be provided");
+
+            html! {
+                <p data-theme={theme.0}>{"Hello in "}{locale.0}</p>
+            }
+        }
+
+        let t = render!(
+            {
+                html! { <Greeting /> }
+            },
+            contexts = [Theme => Theme("dark".into()), Locale => Locale("en".into())]
+        )
+        .await;
+
+        let greeting = t.query_by_text("Hello in en");
+
+        assert!(greeting.exists());
+        assert_eq!(greeting.attribute("data-theme"), Some("dark".to_string()));
+    }
+
     #[wasm_bindgen_test]
     async fn test_render() {
         let t = render!({
@@ -0,0 +1,603 @@
+use std::fmt::{Display, Formatter};
+use std::rc::Rc;
+
+use web_sys::js_sys::Date;
+use web_sys::wasm_bindgen::{closure::Closure, JsCast};
+use web_sys::Element;
+use yew::prelude::*;
+use yewlish_presence::use_presence;
+
+#[derive(Clone, Default, Debug, PartialEq)]
+pub enum ToastVariant {
+    #[default]
+    Default,
+    Success,
+    Info,
+    Warning,
+    Error,
+}
+
+impl Display for ToastVariant {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ToastVariant::Default => write!(f, "default"),
+            ToastVariant::Success => write!(f, "success"),
+            ToastVariant::Info => write!(f, "info"),
+            ToastVariant::Warning => write!(f, "warning"),
+            ToastVariant::Error => write!(f, "error"),
+        }
+    }
+}
+
+#[derive(Clone, Default, Debug, PartialEq)]
+pub enum ToastPosition {
+    TopLeft,
+    TopCenter,
+    TopRight,
+    BottomLeft,
+    BottomCenter,
+    #[default]
+    BottomRight,
+}
+
+impl ToastPosition {
+    fn style(&self) -> &'static str {
+        match self {
+            ToastPosition::TopLeft => "top: 0; left: 0;",
+            ToastPosition::TopCenter => "top: 0; left: 50%; transform: translateX(-50%);",
+            ToastPosition::TopRight => "top: 0; right: 0;",
+            ToastPosition::BottomLeft => "bottom: 0; left: 0;",
+            ToastPosition::BottomCenter => "bottom: 0; left: 50%; transform: translateX(-50%);",
+            ToastPosition::BottomRight => "bottom: 0; right: 0;",
+        }
+    }
+}
+
+impl Display for ToastPosition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ToastPosition::TopLeft => write!(f, "top-left"),
+            ToastPosition::TopCenter => write!(f, "top-center"),
+            ToastPosition::TopRight => write!(f, "top-right"),
+            ToastPosition::BottomLeft => write!(f, "bottom-left"),
+            ToastPosition::BottomCenter => write!(f, "bottom-center"),
+            ToastPosition::BottomRight => write!(f, "bottom-right"),
+        }
+    }
+}
+
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct ToastOptions {
+    pub title: Option<AttrValue>,
+    pub description: Option<AttrValue>,
+    pub variant: ToastVariant,
+    /// How long the toast stays open in milliseconds, `Some(0)` keeps it open until dismissed.
+    /// Falls back to the provider's `duration`.
+    pub duration: Option<u32>,
+    /// Emits `true` once the toast is shown and `false` once it is dismissed
+    pub on_open_change: Callback<bool>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Toast {
+    pub id: usize,
+    pub options: ToastOptions,
+    pub open: bool,
+}
+
+#[derive(Clone, Default, Debug, PartialEq)]
+struct ToastQueue {
+    toasts: Vec<Toast>,
+    next_id: usize,
+}
+
+enum ToastAction {
+    Add(ToastOptions),
+    Dismiss(usize),
+    Remove(usize),
+}
+
+impl Reducible for ToastQueue {
+    type Action = ToastAction;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let mut next = (*self).clone();
+
+        match action {
+            ToastAction::Add(options) => {
+                next.toasts.push(Toast {
+                    id: next.next_id,
+                    options,
+                    open: true,
+                });
+
+                next.next_id += 1;
+            }
+            ToastAction::Dismiss(id) => {
+                let Some(toast) = next
+                    .toasts
+                    .iter_mut()
+                    .find(|toast| toast.id == id && toast.open)
+                else {
+                    return self;
+                };
+
+                toast.open = false;
+            }
+            ToastAction::Remove(id) => {
+                next.toasts.retain(|toast| toast.id != id);
+            }
+        }
+
+        next.into()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ToastContext {
+    pub toasts: Rc<Vec<Toast>>,
+    pub max_visible: usize,
+    pub duration: u32,
+    pub enqueue: Callback<ToastOptions>,
+    pub dismiss: Callback<usize>,
+    pub(crate) remove: Callback<usize>,
+}
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct ToastProviderProps {
+    #[prop_or_default]
+    pub children: Children,
+    /// How many toasts are shown at once, the rest wait in the queue
+    #[prop_or(3)]
+    pub max_visible: usize,
+    /// Default auto-dismiss delay in milliseconds
+    #[prop_or(5000)]
+    pub duration: u32,
+}
+
+/// Holds the queue of toasts shown by `Toaster` and enqueued with `use_toast`.
+///
+/// # Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use toast::{use_toast, ToastOptions, ToastProvider, ToastVariant, Toaster};
+///
+/// #[function_component(SaveButton)]
+/// fn save_button() -> Html {
+///     let toast = use_toast();
+///
+///     let onclick = Callback::from(move |_event: MouseEvent| {
+///         toast.emit(ToastOptions {
+///             title: Some("Saved".into()),
+///             variant: ToastVariant::Success,
+///             ..Default::default()
+///         });
+///     });
+///
+///     html! { <button {onclick}>{"Save"}</button> }
+/// }
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <ToastProvider>
+///             <SaveButton />
+///             <Toaster />
+///         </ToastProvider>
+///     }
+/// }
+/// ```
+#[function_component(ToastProvider)]
+pub fn toast_provider(props: &ToastProviderProps) -> Html {
+    let queue = use_reducer(ToastQueue::default);
+
+    let enqueue = use_callback(queue.dispatcher(), |options: ToastOptions, dispatcher| {
+        dispatcher.dispatch(ToastAction::Add(options));
+    });
+
+    let dismiss = use_callback(queue.dispatcher(), |id: usize, dispatcher| {
+        dispatcher.dispatch(ToastAction::Dismiss(id));
+    });
+
+    let remove = use_callback(queue.dispatcher(), |id: usize, dispatcher| {
+        dispatcher.dispatch(ToastAction::Remove(id));
+    });
+
+    let context = ToastContext {
+        toasts: Rc::new(queue.toasts.clone()),
+        max_visible: props.max_visible,
+        duration: props.duration,
+        enqueue,
+        dismiss,
+        remove,
+    };
+
+    html! {
+        <ContextProvider<ToastContext> {context}>
+            {for props.children.iter()}
+        </ContextProvider<ToastContext>>
+    }
+}
+
+/// Returns a callback that enqueues a toast in the nearest `ToastProvider`.
+#[hook]
+pub fn use_toast() -> Callback<ToastOptions> {
+    let context = use_context::<ToastContext>().expect("use_toast must be used in a ToastProvider");
+    context.enqueue
+}
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct ToastRenderAsProps {
+    pub id: usize,
+    pub title: Option<AttrValue>,
+    pub description: Option<AttrValue>,
+    pub variant: ToastVariant,
+    pub open: bool,
+    pub dismiss: Callback<()>,
+}
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct ToasterProps {
+    #[prop_or_default]
+    pub class: Option<AttrValue>,
+    #[prop_or_default]
+    pub toast_class: Option<AttrValue>,
+    #[prop_or_default]
+    pub position: ToastPosition,
+    #[prop_or(AttrValue::Static("Notifications"))]
+    pub label: AttrValue,
+    /// Element the toasts are portaled into, defaults to the document body
+    #[prop_or_default]
+    pub viewport: Option<Element>,
+    /// Renders the content of each toast, defaults to its title, description and a close button
+    #[prop_or_default]
+    pub render_as: Option<Callback<ToastRenderAsProps, Html>>,
+}
+
+/// The viewport that portals the visible toasts to a corner of the screen.
+#[function_component(Toaster)]
+pub fn toaster(props: &ToasterProps) -> Html {
+    let context = use_context::<ToastContext>().expect("Toaster must be a child of ToastProvider");
+
+    let Some(viewport) = props.viewport.clone().or_else(|| {
+        web_sys::window()
+            .and_then(|window| window.document())
+            .and_then(|document| document.body())
+            .map(Element::from)
+    }) else {
+        log::error!("Failed to get the toaster viewport");
+        return html! {};
+    };
+
+    create_portal(
+        html! {
+            <ol
+                role="region"
+                aria-label={&props.label}
+                tabindex="-1"
+                class={&props.class}
+                style={format!("position: fixed; z-index: 2147483647; list-style: none; margin: 0; {}", props.position.style())}
+                data-position={props.position.to_string()}
+            >
+                {for context.toasts.iter().take(context.max_visible).map(|toast| html! {
+                    <ToastItem
+                        key={toast.id}
+                        toast={toast.clone()}
+                        class={&props.toast_class}
+                        render_as={props.render_as.clone()}
+                    />
+                })}
+            </ol>
+        },
+        viewport,
+    )
+}
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+struct ToastItemProps {
+    toast: Toast,
+    class: Option<AttrValue>,
+    render_as: Option<Callback<ToastRenderAsProps, Html>>,
+}
+
+#[function_component(ToastItem)]
+fn toast_item(props: &ToastItemProps) -> Html {
+    let context =
+        use_context::<ToastContext>().expect("ToastItem must be a child of ToastProvider");
+    let node_ref = use_node_ref();
+    let presence = use_presence(props.toast.open, node_ref.clone());
+    let paused = use_state_eq(|| false);
+
+    let id = props.toast.id;
+    let duration = props.toast.options.duration.unwrap_or(context.duration);
+    let remaining = use_mut_ref(|| f64::from(duration));
+
+    use_effect_with(
+        (props.toast.open, props.toast.options.on_open_change.clone()),
+        |(open, on_open_change)| {
+            on_open_change.emit(*open);
+        },
+    );
+
+    // Drop the toast from the queue once its exit animation has finished
+    use_effect_with(
+        (*presence, props.toast.open, context.remove.clone()),
+        move |(presence, open, remove)| {
+            if !presence && !open {
+                remove.emit(id);
+            }
+        },
+    );
+
+    // Counts the remaining time down only while the toast is open and not hovered
+    use_effect_with(
+        (props.toast.open, *paused, context.dismiss.clone()),
+        move |(open, paused, dismiss)| {
+            let window = web_sys::window();
+            let started_at = Date::now();
+
+            let timeout_id = match (&window, *open && !*paused && duration > 0) {
+                (Some(window), true) => {
+                    let dismiss = dismiss.clone();
+                    let callback = Closure::once_into_js(move || dismiss.emit(id));
+
+                    match window.set_timeout_with_callback_and_timeout_and_arguments_0(
+                        callback.unchecked_ref(),
+                        remaining.borrow().max(0.0) as i32,
+                    ) {
+                        Ok(timeout_id) => Some(timeout_id),
+                        Err(err) => {
+                            log::error!("Failed to set timeout {err:?}");
+                            None
+                        }
+                    }
+                }
+                _ => None,
+            };
+
+            move || {
+                if let (Some(window), Some(timeout_id)) = (window, timeout_id) {
+                    window.clear_timeout_with_handle(timeout_id);
+                    *remaining.borrow_mut() -= Date::now() - started_at;
+                }
+            }
+        },
+    );
+
+    let onpointerenter = use_callback(paused.setter(), |_event: PointerEvent, set_paused| {
+        set_paused.set(true);
+    });
+
+    let onpointerleave = use_callback(paused.setter(), |_event: PointerEvent, set_paused| {
+        set_paused.set(false);
+    });
+
+    let dismiss = use_callback(context.dismiss.clone(), move |(), dismiss| {
+        dismiss.emit(id);
+    });
+
+    if !*presence {
+        return html! {};
+    }
+
+    let options = &props.toast.options;
+
+    let content = if let Some(render_as) = &props.render_as {
+        render_as.emit(ToastRenderAsProps {
+            id,
+            title: options.title.clone(),
+            description: options.description.clone(),
+            variant: options.variant.clone(),
+            open: props.toast.open,
+            dismiss: dismiss.clone(),
+        })
+    } else {
+        html! {
+            <>
+                if let Some(title) = &options.title {
+                    <div data-toast-title="">{title}</div>
+                }
+
+                if let Some(description) = &options.description {
+                    <div data-toast-description="">{description}</div>
+                }
+
+                <button
+                    type="button"
+                    aria-label="Close"
+                    data-toast-close=""
+                    onclick={dismiss.reform(|_event: MouseEvent| ())}
+                >
+                    {"×"}
+                </button>
+            </>
+        }
+    };
+
+    html! {
+        <li
+            ref={node_ref}
+            role={if options.variant == ToastVariant::Error { "alert" } else { "status" }}
+            aria-live={if options.variant == ToastVariant::Error { "assertive" } else { "polite" }}
+            aria-atomic="true"
+            class={&props.class}
+            data-state={if props.toast.open { "open" } else { "closed" }}
+            data-variant={options.variant.to_string()}
+            {onpointerenter}
+            {onpointerleave}
+        >
+            {content}
+        </li>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use wasm_bindgen_test::*;
+    use web_sys::HtmlElement;
+    use yewlish_testing_tools::*;
+
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    #[derive(Clone, Debug, PartialEq, Properties)]
+    struct EnqueueButtonProps {
+        options: ToastOptions,
+    }
+
+    /// Enqueues a copy of `options` titled "Toast N" on every click.
+    #[function_component(EnqueueButton)]
+    fn enqueue_button(props: &EnqueueButtonProps) -> Html {
+        let toast = use_toast();
+        let count = use_mut_ref(|| 0_usize);
+
+        let onclick = use_callback(
+            (toast, props.options.clone()),
+            move |_event: MouseEvent, (toast, options)| {
+                *count.borrow_mut() += 1;
+
+                toast.emit(ToastOptions {
+                    title: Some(format!("Toast {}", count.borrow()).into()),
+                    ..options.clone()
+                });
+            },
+        );
+
+        html! { <button {onclick}>{"Add"}</button> }
+    }
+
+    /// The toasts are portaled into the body, so they're looked up outside of the test root.
+    fn toasts(label: &str) -> Vec<Element> {
+        let Some(nodes) = web_sys::window()
+            .and_then(|window| window.document())
+            .and_then(|document| {
+                document
+                    .query_selector_all(&format!("ol[aria-label='{label}'] > li"))
+                    .ok()
+            })
+        else {
+            return vec![];
+        };
+
+        (0..nodes.length())
+            .filter_map(|index| nodes.item(index))
+            .filter_map(|node| node.dyn_into::<Element>().ok())
+            .collect()
+    }
+
+    fn titles(label: &str) -> Vec<String> {
+        toasts(label)
+            .iter()
+            .filter_map(|toast| toast.query_selector("[data-toast-title]").ok().flatten())
+            .filter_map(|title| title.text_content())
+            .collect()
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_toaster_limits_the_visible_toasts() {
+        let t = render!({
+            html! {
+                <ToastProvider max_visible={2} duration={0}>
+                    <EnqueueButton options={ToastOptions::default()} />
+                    <Toaster label="Queued" />
+                </ToastProvider>
+            }
+        })
+        .await;
+
+        let t = t.query_by_text("Add").click().await;
+        let t = t.query_by_text("Add").click().await;
+        let t = t.query_by_text("Add").click().await;
+
+        assert_eq!(titles("Queued"), vec!["Toast 1", "Toast 2"]);
+
+        toasts("Queued")[0]
+            .query_selector("[data-toast-close]")
+            .ok()
+            .flatten()
+            .and_then(|button| button.dyn_into::<HtmlElement>().ok())
+            .expect("Failed to find the close button")
+            .click();
+
+        // The waiting toast takes the dismissed one's place
+        assert!(
+            t.wait_for(200.0, || titles("Queued") == vec!["Toast 2", "Toast 3"])
+                .await
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_toast_dismisses_itself_after_the_duration() {
+        thread_local! {
+            static OPEN_CHANGES: RefCell<Vec<bool>> = const { RefCell::new(vec![]) };
+        }
+
+        let t = render!({
+            let options = ToastOptions {
+                variant: ToastVariant::Error,
+                duration: Some(50),
+                on_open_change: Callback::from(|open| {
+                    OPEN_CHANGES.with(|changes| changes.borrow_mut().push(open));
+                }),
+                ..Default::default()
+            };
+
+            html! {
+                <ToastProvider>
+                    <EnqueueButton {options} />
+                    <Toaster label="Timed" />
+                </ToastProvider>
+            }
+        })
+        .await;
+
+        let t = t.query_by_text("Add").click().await;
+
+        let toast = toasts("Timed");
+        assert_eq!(toast.len(), 1);
+        assert_eq!(toast[0].get_attribute("role").as_deref(), Some("alert"));
+        assert_eq!(
+            toast[0].get_attribute("data-variant").as_deref(),
+            Some("error")
+        );
+
+        assert!(t.wait_for(500.0, || toasts("Timed").is_empty()).await);
+        assert_eq!(
+            OPEN_CHANGES.with(|changes| changes.borrow().clone()),
+            vec![true, false]
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_toast_pauses_while_hovered() {
+        let t = render!({
+            let options = ToastOptions {
+                duration: Some(50),
+                ..Default::default()
+            };
+
+            html! {
+                <ToastProvider>
+                    <EnqueueButton {options} />
+                    <Toaster label="Paused" />
+                </ToastProvider>
+            }
+        })
+        .await;
+
+        let t = t.query_by_text("Add").click().await;
+
+        let event = web_sys::PointerEvent::new("pointerenter").expect("Failed to create the event");
+        let _ = toasts("Paused")[0].dispatch_event(&event);
+
+        // Well past the duration, the toast is still shown while the pointer stays on it
+        assert!(!t.wait_for(150.0, || toasts("Paused").is_empty()).await);
+
+        let event = web_sys::PointerEvent::new("pointerleave").expect("Failed to create the event");
+        let _ = toasts("Paused")[0].dispatch_event(&event);
+
+        assert!(t.wait_for(500.0, || toasts("Paused").is_empty()).await);
+    }
+}
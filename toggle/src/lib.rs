@@ -24,6 +24,10 @@ pub struct ToggleProps {
     pub on_click: Callback<MouseEvent>,
     #[prop_or_default]
     pub orientation: Orientation,
+    #[prop_or_default]
+    pub on_long_press: Option<Callback<PointerEvent>>,
+    #[prop_or_default]
+    pub long_press_duration: Option<u32>,
 }
 
 #[function_component(Toggle)]
@@ -34,7 +38,7 @@ pub fn toggle(props: &ToggleProps) -> Html {
         props.on_pressed_change.clone(),
     );
 
-    let toggle = use_callback((), move |_event: MouseEvent, _| {
+    let toggle = use_callback(dispatch.clone(), move |_event: MouseEvent, dispatch| {
         dispatch.emit(Box::new(move |prev_state| !prev_state));
     });
 
@@ -64,6 +68,8 @@ pub fn toggle(props: &ToggleProps) -> Html {
         class: props.class.clone(),
         disabled: props.disabled,
         on_click: props.on_click.clone(),
+        on_long_press: props.on_long_press.clone(),
+        long_press_duration: props.long_press_duration,
         ..Default::default()
     };
 
@@ -82,3 +88,57 @@ pub fn toggle(props: &ToggleProps) -> Html {
         </PrimitiveButton>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+    use yewlish_testing_tools::TesterEvent;
+    use yewlish_testing_tools::*;
+
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    async fn test_toggle_should_follow_controlled_pressed() {
+        #[function_component(ControlledToggle)]
+        fn controlled_toggle() -> Html {
+            let pressed = use_state(|| false);
+
+            let flip = use_callback(pressed.clone(), |_event: MouseEvent, pressed| {
+                pressed.set(!**pressed);
+            });
+
+            html! {
+                <>
+                    <button data-testid="flip" onclick={flip}>{"Flip"}</button>
+                    <Toggle pressed={*pressed}>{"Toggle"}</Toggle>
+                </>
+            }
+        }
+
+        let t = render!({
+            html! {
+                <ControlledToggle />
+            }
+        })
+        .await;
+
+        let toggle = t.query_by_selector("[aria-pressed]");
+        assert!(toggle.exists());
+
+        assert_eq!(toggle.attribute("aria-pressed"), "false".to_string().into());
+
+        // Flip the controlled value from the parent
+        let flip = t.query_by_testid("flip").click().await;
+        assert!(flip.exists());
+
+        let toggle = t.query_by_selector("[aria-pressed]");
+        assert_eq!(toggle.attribute("aria-pressed"), "true".to_string().into());
+
+        // And flip it back
+        t.query_by_testid("flip").click().await;
+
+        let toggle = t.query_by_selector("[aria-pressed]");
+        assert_eq!(toggle.attribute("aria-pressed"), "false".to_string().into());
+    }
+}
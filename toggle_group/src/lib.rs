@@ -1,9 +1,10 @@
-use std::{cell::RefCell, rc::Rc};
-
 use toggle::*;
 use yew::prelude::*;
 use yewlish_roving_focus::RovingFocus;
-use yewlish_utils::enums::{Dir, Orientation};
+use yewlish_utils::{
+    enums::{Dir, Orientation},
+    hooks::{use_controllable_vec_state_with_reset, use_form_reset, ControllableVecAction},
+};
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum ToggleGroupType {
@@ -11,62 +12,26 @@ pub enum ToggleGroupType {
     Checkbox,
 }
 
+impl ToggleGroupType {
+    /// The role of the group element, `radiogroup` when a single item can be pressed
+    #[must_use]
+    pub fn role(&self) -> &'static str {
+        match self {
+            ToggleGroupType::Radio => "radiogroup",
+            ToggleGroupType::Checkbox => "group",
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct ToggleGroupContext {
     pub(crate) r#type: ToggleGroupType,
-    pub(crate) value: Rc<RefCell<Vec<AttrValue>>>,
+    pub(crate) value: Vec<AttrValue>,
     pub(crate) disabled: bool,
     pub(crate) orientation: Orientation,
+    pub(crate) dispatch: Callback<ControllableVecAction<AttrValue>>,
 }
 
-pub enum ToggleGroupAction {
-    Activate(AttrValue),
-    Deactivate(AttrValue),
-    Replace(AttrValue),
-}
-
-impl Reducible for ToggleGroupContext {
-    type Action = ToggleGroupAction;
-
-    fn reduce(self: Rc<ToggleGroupContext>, action: Self::Action) -> Rc<ToggleGroupContext> {
-        match action {
-            ToggleGroupAction::Activate(value) => ToggleGroupContext {
-                value: Rc::new(RefCell::new(
-                    self.value
-                        .borrow()
-                        .iter()
-                        .cloned()
-                        .chain(std::iter::once(value))
-                        .collect(),
-                )),
-                ..(*self).clone()
-            }
-            .into(),
-
-            ToggleGroupAction::Deactivate(value) => ToggleGroupContext {
-                value: Rc::new(RefCell::new(
-                    self.value
-                        .borrow()
-                        .iter()
-                        .filter(|v| *v != &value)
-                        .cloned()
-                        .collect(),
-                )),
-                ..(*self).clone()
-            }
-            .into(),
-
-            ToggleGroupAction::Replace(value) => ToggleGroupContext {
-                value: Rc::new(RefCell::new(vec![value])),
-                ..(*self).clone()
-            }
-            .into(),
-        }
-    }
-}
-
-type ReducibleToggleGroupContext = UseReducerHandle<ToggleGroupContext>;
-
 #[derive(Clone, Debug, PartialEq, Properties)]
 pub struct ToggleGroupProps {
     #[prop_or_default]
@@ -95,25 +60,38 @@ pub struct ToggleGroupProps {
 
 #[function_component(ToggleGroup)]
 pub fn toggle_group(props: &ToggleGroupProps) -> Html {
-    let context_value = use_reducer(|| ToggleGroupContext {
+    if props.r#type == ToggleGroupType::Radio
+        && props.value.is_none()
+        && props.default_value.is_none()
+    {
+        log::warn!("ToggleGroup must have a default value for radio type");
+    }
+
+    let (value, dispatch, reset) = use_controllable_vec_state_with_reset(
+        props.default_value.clone(),
+        props.value.clone(),
+        props.on_value_change.clone(),
+    );
+
+    let node_ref = use_node_ref();
+    use_form_reset(&node_ref, reset);
+
+    let context_value = ToggleGroupContext {
         r#type: props.r#type.clone(),
-        value: Rc::new(RefCell::new(props.value.clone().unwrap_or(
-            props.default_value.clone().unwrap_or_else(|| {
-                if props.r#type == ToggleGroupType::Radio {
-                    log::warn!("ToggleGroup must have a default value for radio type");
-                }
-
-                vec![]
-            }),
-        ))),
+        value: value.borrow().clone(),
         disabled: props.disabled,
         orientation: props.orientation.clone(),
-    });
+        dispatch,
+    };
 
+    // Both modes render the same role and orientation attributes, so styles don't depend on roving focus
     if props.roving_focus {
         return html! {
-            <ContextProvider<ReducibleToggleGroupContext> context={context_value}>
+            <ContextProvider<ToggleGroupContext> context={context_value}>
                 <RovingFocus
+                    r#ref={node_ref}
+                    role={props.r#type.role()}
+                    aria_orientation={props.orientation.clone()}
                     class={&props.class}
                     orientation={props.orientation.clone()}
                     dir={props.dir.clone().unwrap_or(Dir::Ltr)}
@@ -121,16 +99,22 @@ pub fn toggle_group(props: &ToggleGroupProps) -> Html {
                 >
                     {for props.children.iter()}
                 </RovingFocus>
-            </ContextProvider<ReducibleToggleGroupContext>>
+            </ContextProvider<ToggleGroupContext>>
         };
     }
 
     html! {
-        <ContextProvider<ReducibleToggleGroupContext> context={context_value}>
-            <div class={&props.class} data-orientation={props.orientation.clone()}>
+        <ContextProvider<ToggleGroupContext> context={context_value}>
+            <div
+                ref={node_ref}
+                role={props.r#type.role()}
+                aria-orientation={props.orientation.clone()}
+                class={&props.class}
+                data-orientation={props.orientation.clone()}
+            >
                 {for props.children.iter()}
             </div>
-        </ContextProvider<ReducibleToggleGroupContext>>
+        </ContextProvider<ToggleGroupContext>>
     }
 }
 
@@ -143,30 +127,41 @@ pub struct ToggleGroupItemProps {
     pub disabled: bool,
     #[prop_or_default]
     pub class: Option<AttrValue>,
+    /// Called when the item is held down, e.g. to open a context menu, without toggling it
+    #[prop_or_default]
+    pub on_long_press: Option<Callback<PointerEvent>>,
+    #[prop_or_default]
+    pub long_press_duration: Option<u32>,
 }
 
 #[function_component(ToggleGroupItem)]
 pub fn toggle_group_item(props: &ToggleGroupItemProps) -> Html {
-    let context = use_context::<ReducibleToggleGroupContext>()
+    let context = use_context::<ToggleGroupContext>()
         .expect("ToggleGroupItem must be a child of ToggleGroup");
 
     let pressed = use_memo(
         (context.value.clone(), props.value.clone()),
-        |(context_value, props_value)| context_value.borrow().contains(props_value),
+        |(context_value, props_value)| context_value.contains(props_value),
     );
 
     let on_pressed_change = use_callback(
         (context.clone(), props.value.clone()),
         move |next_state: bool, (context, value)| {
             if context.r#type == ToggleGroupType::Radio && next_state {
-                context.dispatch(ToggleGroupAction::Replace(value.clone()));
+                context
+                    .dispatch
+                    .emit(ControllableVecAction::Replace(vec![value.clone()]));
                 return;
             }
 
             if next_state {
-                context.dispatch(ToggleGroupAction::Activate(value.clone()));
+                context
+                    .dispatch
+                    .emit(ControllableVecAction::Add(value.clone()));
             } else {
-                context.dispatch(ToggleGroupAction::Deactivate(value.clone()));
+                context
+                    .dispatch
+                    .emit(ControllableVecAction::Remove(value.clone()));
             }
         },
     );
@@ -178,8 +173,107 @@ pub fn toggle_group_item(props: &ToggleGroupItemProps) -> Html {
             on_pressed_change={&on_pressed_change}
             orientation={context.orientation.clone()}
             disabled={props.disabled || context.disabled}
+            on_long_press={props.on_long_press.clone()}
+            long_press_duration={props.long_press_duration}
         >
             {props.children.clone()}
         </Toggle>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+    use yewlish_testing_tools::*;
+
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    async fn test_toggle_group_attributes_with_roving_focus() {
+        let t = render!({
+            html! {
+                <ToggleGroup default_value={vec![AttrValue::from("a")]} orientation={Orientation::Vertical}>
+                    <ToggleGroupItem value="a">{"A"}</ToggleGroupItem>
+                    <ToggleGroupItem value="b">{"B"}</ToggleGroupItem>
+                </ToggleGroup>
+            }
+        })
+        .await;
+
+        let group = t.query_by_role("radiogroup");
+        assert!(group.exists());
+        assert_eq!(
+            group.attribute("data-orientation"),
+            Some("vertical".to_string())
+        );
+        assert_eq!(
+            group.attribute("aria-orientation"),
+            Some("vertical".to_string())
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_toggle_group_attributes_without_roving_focus() {
+        let t = render!({
+            html! {
+                <ToggleGroup r#type={ToggleGroupType::Checkbox} roving_focus={false}>
+                    <ToggleGroupItem value="a">{"A"}</ToggleGroupItem>
+                    <ToggleGroupItem value="b">{"B"}</ToggleGroupItem>
+                </ToggleGroup>
+            }
+        })
+        .await;
+
+        let group = t.query_by_role("group");
+        assert!(group.exists());
+        assert_eq!(
+            group.attribute("data-orientation"),
+            Some("horizontal".to_string())
+        );
+        assert_eq!(
+            group.attribute("aria-orientation"),
+            Some("horizontal".to_string())
+        );
+        assert!(!t.query_by_role("radiogroup").exists());
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_toggle_group_form_reset() {
+        let t = render!({
+            html! {
+                <form>
+                    <ToggleGroup r#type={ToggleGroupType::Checkbox} default_value={vec![AttrValue::from("a")]}>
+                        <ToggleGroupItem value="a">{"A"}</ToggleGroupItem>
+                        <ToggleGroupItem value="b">{"B"}</ToggleGroupItem>
+                    </ToggleGroup>
+                    <button type="reset">{"Reset"}</button>
+                </form>
+            }
+        })
+        .await;
+
+        t.query_by_text("A").click().await;
+        t.query_by_text("B").click().await;
+
+        assert_eq!(
+            t.query_by_text("A").attribute("aria-pressed"),
+            Some("false".to_string())
+        );
+        assert_eq!(
+            t.query_by_text("B").attribute("aria-pressed"),
+            Some("true".to_string())
+        );
+
+        t.query_by_text("Reset").click().await;
+
+        assert_eq!(
+            t.query_by_text("A").attribute("aria-pressed"),
+            Some("true".to_string())
+        );
+        assert_eq!(
+            t.query_by_text("B").attribute("aria-pressed"),
+            Some("false".to_string())
+        );
+    }
+}
@@ -0,0 +1,462 @@
+use web_sys::{wasm_bindgen::JsCast, Element, HtmlElement};
+use yew::prelude::*;
+use yewlish_utils::hooks::{use_controllable_state, use_keydown};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TreeViewContext {
+    pub selected: Vec<AttrValue>,
+    pub expanded: Vec<AttrValue>,
+    /// The item that takes part in the tab sequence, `None` until an item has been focused
+    pub focused: Option<AttrValue>,
+    pub multiple: bool,
+    pub on_select: Callback<AttrValue>,
+    pub on_expand: Callback<(AttrValue, bool)>,
+    pub on_focus: Callback<AttrValue>,
+}
+
+/// Where a `TreeItem` sits among its siblings, provided by its parent
+#[derive(Clone, Debug, PartialEq)]
+struct TreeItemPosition {
+    level: usize,
+    pos_in_set: usize,
+    set_size: usize,
+}
+
+fn render_items(children: &Children, level: usize) -> Html {
+    let set_size = children.len();
+
+    html! {
+        {for children.iter().enumerate().map(|(index, child)| html! {
+            <ContextProvider<TreeItemPosition> context={TreeItemPosition {
+                level,
+                pos_in_set: index + 1,
+                set_size,
+            }}>
+                {child}
+            </ContextProvider<TreeItemPosition>>
+        })}
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct TreeViewProps {
+    pub children: Children,
+    #[prop_or_default]
+    pub class: Option<AttrValue>,
+    #[prop_or_default]
+    pub label: Option<AttrValue>,
+    /// Lets several items be selected at once, Space and Enter toggle them instead of replacing the selection
+    #[prop_or_default]
+    pub multiple: bool,
+    #[prop_or_default]
+    pub selected: Option<Vec<AttrValue>>,
+    #[prop_or_default]
+    pub default_selected: Vec<AttrValue>,
+    #[prop_or_default]
+    pub on_selection_change: Callback<Vec<AttrValue>>,
+    #[prop_or_default]
+    pub expanded: Option<Vec<AttrValue>>,
+    #[prop_or_default]
+    pub default_expanded: Vec<AttrValue>,
+    #[prop_or_default]
+    pub on_expanded_change: Callback<Vec<AttrValue>>,
+}
+
+/// The items in document order, the ones inside collapsed items aren't rendered
+fn visible_items(tree: &Element) -> Vec<HtmlElement> {
+    let Ok(items) = tree.query_selector_all("[role='treeitem']") else {
+        return vec![];
+    };
+
+    (0..items.length())
+        .filter_map(|index| items.item(index))
+        .filter_map(|item| item.dyn_into::<HtmlElement>().ok())
+        .collect()
+}
+
+fn focus_item(item: &HtmlElement) {
+    if let Err(error) = item.focus() {
+        log::error!("Failed to focus the tree item: {error:?}");
+    }
+}
+
+/// A hierarchical list, e.g. a file explorer, navigated with the arrow keys.
+///
+/// Up and Down move between the visible items, Right expands an item or moves into it,
+/// Left collapses it or moves to its parent, Home and End jump to the first and the last item,
+/// and Space or Enter select the focused one.
+///
+/// # Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use tree_view::{TreeItem, TreeView};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <TreeView label="Files">
+///             <TreeItem value="src" label="src">
+///                 <TreeItem value="lib.rs" label="lib.rs" />
+///             </TreeItem>
+///             <TreeItem value="Cargo.toml" label="Cargo.toml" />
+///         </TreeView>
+///     }
+/// }
+/// ```
+#[function_component(TreeView)]
+pub fn tree_view(props: &TreeViewProps) -> Html {
+    let tree_ref = use_node_ref();
+    let focused = use_state_eq(|| None::<AttrValue>);
+
+    let (selected, dispatch_selected) = use_controllable_state(
+        Some(props.default_selected.clone()),
+        props.selected.clone(),
+        props.on_selection_change.clone(),
+    );
+
+    let (expanded, dispatch_expanded) = use_controllable_state(
+        Some(props.default_expanded.clone()),
+        props.expanded.clone(),
+        props.on_expanded_change.clone(),
+    );
+
+    let on_select = use_callback(
+        (dispatch_selected, props.multiple),
+        |value: AttrValue, (dispatch, multiple)| {
+            let multiple = *multiple;
+
+            dispatch.emit(Box::new(move |mut selected: Vec<AttrValue>| {
+                if !multiple {
+                    return vec![value.clone()];
+                }
+
+                if selected.contains(&value) {
+                    selected.retain(|selected| *selected != value);
+                } else {
+                    selected.push(value.clone());
+                }
+
+                selected
+            }));
+        },
+    );
+
+    let on_expand = use_callback(
+        dispatch_expanded,
+        |(value, open): (AttrValue, bool), dispatch| {
+            dispatch.emit(Box::new(move |mut expanded: Vec<AttrValue>| {
+                expanded.retain(|expanded| *expanded != value);
+
+                if open {
+                    expanded.push(value.clone());
+                }
+
+                expanded
+            }));
+        },
+    );
+
+    let on_focus = use_callback(focused.setter(), |value: AttrValue, set_focused| {
+        set_focused.set(Some(value));
+    });
+
+    let navigation_handler = {
+        let tree_ref = tree_ref.clone();
+        let on_select = on_select.clone();
+        let on_expand = on_expand.clone();
+
+        move |event: KeyboardEvent| {
+            let Some(tree) = tree_ref.cast::<Element>() else {
+                return;
+            };
+
+            let Some(current) = event
+                .target_dyn_into::<Element>()
+                .and_then(|target| target.closest("[role='treeitem']").ok().flatten())
+            else {
+                return;
+            };
+
+            let value = AttrValue::from(current.get_attribute("data-value").unwrap_or_default());
+            let items = visible_items(&tree);
+            let index = items.iter().position(|item| **item == current);
+
+            // Only items with children carry `aria-expanded`
+            let is_expanded = current.get_attribute("aria-expanded");
+
+            match event.key().as_str() {
+                "ArrowDown" => {
+                    if let Some(next) = index.and_then(|index| items.get(index + 1)) {
+                        focus_item(next);
+                    }
+                }
+                "ArrowUp" => {
+                    if let Some(prev) = index
+                        .and_then(|index| index.checked_sub(1))
+                        .and_then(|index| items.get(index))
+                    {
+                        focus_item(prev);
+                    }
+                }
+                "Home" => {
+                    if let Some(first) = items.first() {
+                        focus_item(first);
+                    }
+                }
+                "End" => {
+                    if let Some(last) = items.last() {
+                        focus_item(last);
+                    }
+                }
+                "ArrowRight" => match is_expanded.as_deref() {
+                    Some("false") => on_expand.emit((value, true)),
+                    Some("true") => {
+                        if let Some(first_child) = index.and_then(|index| items.get(index + 1)) {
+                            focus_item(first_child);
+                        }
+                    }
+                    _ => {}
+                },
+                "ArrowLeft" => {
+                    if is_expanded.as_deref() == Some("true") {
+                        on_expand.emit((value, false));
+                        return;
+                    }
+
+                    let parent = current
+                        .parent_element()
+                        .and_then(|parent| parent.closest("[role='treeitem']").ok().flatten())
+                        .and_then(|parent| parent.dyn_into::<HtmlElement>().ok());
+
+                    if let Some(parent) = parent {
+                        focus_item(&parent);
+                    }
+                }
+                " " | "Enter" => on_select.emit(value),
+                _ => {}
+            }
+        }
+    };
+
+    let navigate_through_items = use_keydown(
+        vec![
+            "ArrowDown".into(),
+            "ArrowUp".into(),
+            "ArrowRight".into(),
+            "ArrowLeft".into(),
+            "Home".into(),
+            "End".into(),
+            " ".into(),
+            "Enter".into(),
+        ],
+        navigation_handler,
+    );
+
+    let context_value = TreeViewContext {
+        selected: selected.borrow().clone(),
+        expanded: expanded.borrow().clone(),
+        focused: (*focused).clone(),
+        multiple: props.multiple,
+        on_select,
+        on_expand,
+        on_focus,
+    };
+
+    html! {
+        <ContextProvider<TreeViewContext> context={context_value}>
+            <ul
+                ref={tree_ref}
+                role="tree"
+                class={&props.class}
+                aria-label={&props.label}
+                aria-multiselectable={props.multiple.then_some("true")}
+                onkeydown={navigate_through_items}
+            >
+                {render_items(&props.children, 1)}
+            </ul>
+        </ContextProvider<TreeViewContext>>
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct TreeItemProps {
+    pub value: AttrValue,
+    pub label: Html,
+    /// Nested items, an item with children can be expanded and collapsed
+    #[prop_or_default]
+    pub children: Children,
+    #[prop_or_default]
+    pub class: Option<AttrValue>,
+}
+
+#[function_component(TreeItem)]
+pub fn tree_item(props: &TreeItemProps) -> Html {
+    let context = use_context::<TreeViewContext>().expect("TreeItem must be a child of TreeView");
+
+    let position = use_context::<TreeItemPosition>().expect("TreeItem must be a child of TreeView");
+
+    let has_children = !props.children.is_empty();
+    let is_expanded = has_children && context.expanded.contains(&props.value);
+    let is_selected = context.selected.contains(&props.value);
+
+    // Without a focused item, the first root item is the one reached with Tab
+    let is_tabbable = match &context.focused {
+        Some(focused) => *focused == props.value,
+        None => position.level == 1 && position.pos_in_set == 1,
+    };
+
+    let onclick = use_callback(
+        (
+            props.value.clone(),
+            context.on_select.clone(),
+            context.on_expand.clone(),
+            has_children,
+            is_expanded,
+        ),
+        |event: MouseEvent, (value, on_select, on_expand, has_children, is_expanded)| {
+            // Nested items are rendered inside of this one, don't handle their clicks twice
+            event.stop_propagation();
+
+            on_select.emit(value.clone());
+
+            if *has_children {
+                on_expand.emit((value.clone(), !is_expanded));
+            }
+        },
+    );
+
+    let onfocus = use_callback(
+        (props.value.clone(), context.on_focus.clone()),
+        |event: FocusEvent, (value, on_focus)| {
+            event.stop_propagation();
+            on_focus.emit(value.clone());
+        },
+    );
+
+    html! {
+        <li
+            role="treeitem"
+            class={&props.class}
+            data-value={props.value.clone()}
+            data-state={has_children.then_some(if is_expanded { "expanded" } else { "collapsed" })}
+            tabindex={if is_tabbable { "0" } else { "-1" }}
+            aria-level={position.level.to_string()}
+            aria-setsize={position.set_size.to_string()}
+            aria-posinset={position.pos_in_set.to_string()}
+            aria-expanded={has_children.then_some(if is_expanded { "true" } else { "false" })}
+            aria-selected={if context.multiple || is_selected { Some(is_selected.to_string()) } else { None }}
+            {onclick}
+            {onfocus}
+        >
+            {props.label.clone()}
+
+            if is_expanded {
+                <ul role="group">
+                    {render_items(&props.children, position.level + 1)}
+                </ul>
+            }
+        </li>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+    use yewlish_testing_tools::*;
+
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    #[function_component(Files)]
+    fn files() -> Html {
+        html! {
+            <TreeView label="Files">
+                <TreeItem value="src" label="src">
+                    <TreeItem value="lib.rs" label="lib.rs" />
+                    <TreeItem value="main.rs" label="main.rs" />
+                </TreeItem>
+                <TreeItem value="Cargo.toml" label="Cargo.toml" />
+            </TreeView>
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_tree_view_should_expand_and_collapse() {
+        let t = render!({
+            html! { <Files /> }
+        })
+        .await;
+
+        assert_eq!(t.query_all_by_role("treeitem").len(), 2);
+
+        let src = t.query_by_selector("[data-value='src']");
+        assert_eq!(src.attribute("aria-expanded"), Some("false".to_string()));
+        assert_eq!(src.attribute("aria-level"), Some("1".to_string()));
+        assert_eq!(src.attribute("aria-setsize"), Some("2".to_string()));
+        assert_eq!(src.attribute("aria-posinset"), Some("1".to_string()));
+
+        let src = src.keydown("ArrowRight").await;
+        assert_eq!(src.attribute("aria-expanded"), Some("true".to_string()));
+        assert_eq!(t.query_all_by_role("treeitem").len(), 4);
+
+        let main = t.query_by_selector("[data-value='main.rs']");
+        assert_eq!(main.attribute("aria-level"), Some("2".to_string()));
+        assert_eq!(main.attribute("aria-posinset"), Some("2".to_string()));
+
+        let src = src.keydown("ArrowLeft").await;
+        assert_eq!(src.attribute("aria-expanded"), Some("false".to_string()));
+        assert_eq!(t.query_all_by_role("treeitem").len(), 2);
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_tree_view_should_select_and_emit_changes() {
+        let t = render!({
+            let selection = use_state(Vec::<AttrValue>::new);
+            let expanded = use_state(Vec::<AttrValue>::new);
+
+            let on_selection_change = use_callback(selection.setter(), |next, set_selection| {
+                set_selection.set(next);
+            });
+
+            let on_expanded_change = use_callback(expanded.setter(), |next, set_expanded| {
+                set_expanded.set(next);
+            });
+
+            use_remember_value(((*selection).clone(), (*expanded).clone()));
+
+            html! {
+                <TreeView multiple=true {on_selection_change} {on_expanded_change}>
+                    <TreeItem value="src" label="src">
+                        <TreeItem value="lib.rs" label="lib.rs" />
+                    </TreeItem>
+                    <TreeItem value="Cargo.toml" label="Cargo.toml" />
+                </TreeView>
+            }
+        })
+        .await;
+
+        t.query_by_selector("[data-value='src']").click().await;
+        t.query_by_selector("[data-value='lib.rs']")
+            .keydown(" ")
+            .await;
+
+        let cargo = t
+            .query_by_selector("[data-value='Cargo.toml']")
+            .click()
+            .await;
+        assert_eq!(cargo.attribute("aria-selected"), Some("true".to_string()));
+
+        let (selection, expanded) = t.get_remembered_value::<(Vec<AttrValue>, Vec<AttrValue>)>();
+
+        assert_eq!(
+            selection,
+            vec![
+                AttrValue::from("src"),
+                AttrValue::from("lib.rs"),
+                AttrValue::from("Cargo.toml")
+            ]
+        );
+        assert_eq!(expanded, vec![AttrValue::from("src")]);
+    }
+}
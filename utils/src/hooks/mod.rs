@@ -1,17 +1,57 @@
+mod use_auto_id;
 mod use_children_as_html_collection;
+mod use_clipboard;
 mod use_conditional_attr;
+mod use_controllable_multiple;
+mod use_controllable_open;
+mod use_controllable_reducer;
 mod use_controllable_state;
+mod use_controllable_vec_state;
+mod use_escape_key;
+mod use_event_listener;
+mod use_form_reset;
+mod use_hover;
+mod use_id_link;
 mod use_interaction_outside;
 mod use_intersection_observer;
+mod use_interval;
+mod use_is_first_render;
 mod use_keydown;
+mod use_long_press;
 mod use_observe_move;
+mod use_previous;
+mod use_resize_observer;
+mod use_timeout;
+mod use_validation;
 mod use_viewport_move;
 
+pub use use_auto_id::{reset_auto_id_counter, use_auto_id};
 pub use use_children_as_html_collection::use_children_as_html_collection;
+pub use use_clipboard::{copy_to_clipboard, use_clipboard, ClipboardError};
 pub use use_conditional_attr::use_conditional_attr;
-pub use use_controllable_state::use_controllable_state;
-pub use use_interaction_outside::use_interaction_outside;
-pub use use_intersection_observer::use_intersection_observer;
+pub use use_controllable_multiple::use_controllable_multiple;
+pub use use_controllable_open::use_controllable_open;
+pub use use_controllable_reducer::{use_controllable_reducer, UseControllableReducerHandle};
+pub use use_controllable_state::{
+    use_controllable_state, use_controllable_state_eq, use_controllable_state_with_reset,
+};
+pub use use_controllable_vec_state::{
+    use_controllable_vec_state, use_controllable_vec_state_with_reset, ControllableVecAction,
+};
+pub use use_escape_key::{use_escape_key, EscapeKeyTarget};
+pub use use_event_listener::{use_event_listener, EventListenerTarget};
+pub use use_form_reset::use_form_reset;
+pub use use_hover::use_hover;
+pub use use_id_link::{use_id_link, FieldIds};
+pub use use_interaction_outside::{use_interaction_outside, InteractionOutsideLayer};
+pub use use_intersection_observer::{use_intersection_observer, use_on_intersect};
+pub use use_interval::{use_interval, UseIntervalHandle};
+pub use use_is_first_render::use_is_first_render;
 pub use use_keydown::use_keydown;
+pub use use_long_press::use_long_press;
 pub use use_observe_move::use_observe_move;
+pub use use_previous::use_previous;
+pub use use_resize_observer::use_resize_observer;
+pub use use_timeout::{use_timeout, UseTimeoutHandle};
+pub use use_validation::{use_validation, Rule, ValidationState};
 pub use use_viewport_move::use_viewport_move;
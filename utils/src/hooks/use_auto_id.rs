@@ -0,0 +1,86 @@
+use std::cell::Cell;
+use yew::prelude::*;
+
+thread_local! {
+    static AUTO_ID_COUNTER: Cell<usize> = const { Cell::new(0) };
+}
+
+fn next_auto_id() -> usize {
+    AUTO_ID_COUNTER.with(|counter| {
+        let id = counter.get();
+        counter.set(id + 1);
+        id
+    })
+}
+
+/// Resets the id counter.
+///
+/// Call it before each server side render, so the ids produced on the server
+/// match the ones produced by the client while hydrating.
+pub fn reset_auto_id_counter() {
+    AUTO_ID_COUNTER.with(|counter| counter.set(0));
+}
+
+/// Returns an id that is unique within the app and stable for the component's lifetime.
+///
+/// Ids are handed out in render order, which is the same on the server and on the client,
+/// so the generated markup stays hydration-safe.
+#[hook]
+pub fn use_auto_id(prefix: Option<&str>) -> AttrValue {
+    let prefix = prefix.unwrap_or("yewlish").to_string();
+    let id = use_state(move || AttrValue::from(format!("{prefix}-{}", next_auto_id())));
+
+    (*id).clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+    use yewlish_testing_tools::*;
+
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    async fn test_use_auto_id_is_unique_and_prefixed() {
+        let t = render!({
+            let first = use_auto_id(Some("field"));
+            let second = use_auto_id(None);
+            use_remember_value((first.clone(), second.clone()));
+
+            html! {
+                <div id={first}>{ "TEXT" }</div>
+            }
+        })
+        .await;
+
+        let (first, second) = t.get_remembered_value::<(AttrValue, AttrValue)>();
+
+        assert!(first.starts_with("field-"));
+        assert!(second.starts_with("yewlish-"));
+        assert_ne!(first, second);
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_use_auto_id_is_stable_between_renders() {
+        let t = render!({
+            let id = use_auto_id(None);
+            let counter = use_state(|| 0);
+            use_remember_value(id.clone());
+
+            let onclick = use_callback(counter.clone(), |_event: MouseEvent, counter| {
+                counter.set(**counter + 1);
+            });
+
+            html! {
+                <button {onclick}>{ *counter }</button>
+            }
+        })
+        .await;
+
+        let id = t.get_remembered_value::<AttrValue>();
+        t.query_by_role("button").click().await;
+
+        assert_eq!(t.get_remembered_value::<AttrValue>(), id);
+    }
+}
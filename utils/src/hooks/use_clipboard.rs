@@ -0,0 +1,108 @@
+use std::fmt::{Display, Formatter};
+use web_sys::wasm_bindgen::JsCast;
+use web_sys::{HtmlDocument, HtmlTextAreaElement};
+use yew::prelude::*;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ClipboardError {
+    /// Neither the Clipboard API nor the `execCommand` fallback is available
+    Unavailable,
+    /// The browser refused to write, e.g. the permission was denied
+    Rejected(String),
+}
+
+impl Display for ClipboardError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClipboardError::Unavailable => write!(f, "Clipboard is unavailable"),
+            ClipboardError::Rejected(reason) => write!(f, "Clipboard write was rejected: {reason}"),
+        }
+    }
+}
+
+/// Writes `text` to the clipboard.
+///
+/// Uses the async Clipboard API and falls back to copying from a hidden textarea with
+/// `execCommand` where the API is missing, e.g. outside secure contexts.
+///
+/// # Errors
+///
+/// Returns `ClipboardError::Rejected` when the browser refuses the write and
+/// `ClipboardError::Unavailable` when there is no way to reach the clipboard.
+pub async fn copy_to_clipboard(text: &str) -> Result<(), ClipboardError> {
+    let window = web_sys::window().ok_or(ClipboardError::Unavailable)?;
+    let clipboard = window.navigator().clipboard();
+
+    if clipboard.is_undefined() {
+        return copy_with_exec_command(text);
+    }
+
+    wasm_bindgen_futures::JsFuture::from(clipboard.write_text(text))
+        .await
+        .map(|_| ())
+        .map_err(|error| ClipboardError::Rejected(format!("{error:?}")))
+}
+
+fn copy_with_exec_command(text: &str) -> Result<(), ClipboardError> {
+    let rejected = |error| ClipboardError::Rejected(format!("{error:?}"));
+
+    let document = web_sys::window()
+        .and_then(|window| window.document())
+        .ok_or(ClipboardError::Unavailable)?;
+
+    let html_document = document
+        .dyn_ref::<HtmlDocument>()
+        .ok_or(ClipboardError::Unavailable)?;
+
+    let body = document.body().ok_or(ClipboardError::Unavailable)?;
+
+    let textarea = document
+        .create_element("textarea")
+        .map_err(rejected)?
+        .unchecked_into::<HtmlTextAreaElement>();
+
+    textarea.set_value(text);
+    textarea.set_read_only(true);
+    textarea
+        .set_attribute("style", "position: fixed; top: 0; left: 0; opacity: 0;")
+        .map_err(rejected)?;
+
+    body.append_child(&textarea).map_err(rejected)?;
+    textarea.select();
+
+    let copied = html_document.exec_command("copy");
+    textarea.remove();
+
+    match copied {
+        Ok(true) => Ok(()),
+        Ok(false) => Err(ClipboardError::Rejected(
+            "execCommand(\"copy\") returned false".to_string(),
+        )),
+        Err(error) => Err(rejected(error)),
+    }
+}
+
+/// Copies text to the clipboard.
+///
+/// Returns the outcome of the last copy, `Ok` with the copied text or the `ClipboardError`
+/// that prevented it, and a callback that copies the text it receives.
+#[hook]
+pub fn use_clipboard() -> (Option<Result<String, ClipboardError>>, Callback<String>) {
+    let result = use_state(|| None::<Result<String, ClipboardError>>);
+
+    let copy = use_callback(result.setter(), |text: String, set_result| {
+        let set_result = set_result.clone();
+
+        wasm_bindgen_futures::spawn_local(async move {
+            let result = copy_to_clipboard(&text).await.map(|()| text);
+
+            if let Err(error) = &result {
+                log::error!("Failed to copy to the clipboard: {error}");
+            }
+
+            set_result.set(Some(result));
+        });
+    });
+
+    ((*result).clone(), copy)
+}
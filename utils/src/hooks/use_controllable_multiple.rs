@@ -0,0 +1,137 @@
+use std::fmt::Debug;
+use yew::prelude::*;
+
+use crate::hooks::use_controllable_state;
+
+/// Toggles `value` within `selected`: in multiple mode it's added or removed, otherwise it
+/// replaces the selection, or clears it when it was the selected value.
+fn toggle_value<T: PartialEq>(mut selected: Vec<T>, value: T, multiple: bool) -> Vec<T> {
+    if selected.contains(&value) {
+        selected.retain(|selected| selected != &value);
+    } else if multiple {
+        selected.push(value);
+    } else {
+        selected = vec![value];
+    }
+
+    selected
+}
+
+/// A selection of values that may be controlled through `controlled`, e.g. the options of a
+/// listbox or a select.
+///
+/// Returns the selected values along with callbacks that toggle one value, replace the whole
+/// selection and clear it. Without `multiple` at most one value is selected, toggling another
+/// one replaces it. `on_change` receives the selection after every change, actions that leave it
+/// as it is report nothing.
+#[hook]
+pub fn use_controllable_multiple<T>(
+    initial: Option<Vec<T>>,
+    controlled: Option<Vec<T>>,
+    on_change: Callback<Vec<T>>,
+    multiple: bool,
+) -> (Vec<T>, Callback<T>, Callback<Vec<T>>, Callback<()>)
+where
+    T: Debug + PartialEq + Clone + 'static,
+{
+    let (selected, dispatch) = use_controllable_state(initial, controlled, on_change);
+
+    let set = use_callback(
+        (selected.clone(), dispatch.clone(), multiple),
+        |next: Vec<T>, (selected, dispatch, multiple)| {
+            let next = if *multiple {
+                next
+            } else {
+                next.into_iter().take(1).collect()
+            };
+
+            if *selected.borrow() != next {
+                dispatch.emit(Box::new(move |_| next.clone()));
+            }
+        },
+    );
+
+    let toggle = use_callback((dispatch, multiple), |value: T, (dispatch, multiple)| {
+        let multiple = *multiple;
+
+        dispatch.emit(Box::new(move |selected| {
+            toggle_value(selected, value.clone(), multiple)
+        }));
+    });
+
+    let clear = use_callback(set.clone(), |(), set| {
+        set.emit(Vec::new());
+    });
+
+    let selected = selected.borrow().clone();
+    (selected, toggle, set, clear)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+    use yewlish_testing_tools::*;
+
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    #[test]
+    fn test_toggle_value() {
+        assert_eq!(toggle_value(vec![1], 2, true), vec![1, 2]);
+        assert_eq!(toggle_value(vec![1, 2], 1, true), vec![2]);
+        assert_eq!(toggle_value(vec![1], 2, false), vec![2]);
+        assert_eq!(toggle_value(vec![1], 1, false), Vec::<i32>::new());
+        assert_eq!(toggle_value(Vec::new(), 1, false), vec![1]);
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_use_controllable_multiple() {
+        let t = render!({
+            let changes = use_mut_ref(Vec::<Vec<i32>>::new);
+
+            let on_change = use_callback(changes.clone(), |selected, changes| {
+                changes.borrow_mut().push(selected);
+            });
+
+            let (selected, toggle, set, clear) =
+                use_controllable_multiple(Some(vec![1]), None, on_change, true);
+
+            use_remember_value((selected, changes.borrow().clone()));
+
+            html! {
+                <>
+                    <button onclick={toggle.reform(|_: MouseEvent| 2)}>{"Toggle"}</button>
+                    <button onclick={set.reform(|_: MouseEvent| vec![3, 4])}>{"Set"}</button>
+                    <button onclick={clear.reform(|_: MouseEvent| ())}>{"Clear"}</button>
+                </>
+            }
+        })
+        .await;
+
+        assert_eq!(
+            t.get_remembered_value::<(Vec<i32>, Vec<Vec<i32>>)>(),
+            (vec![1], vec![])
+        );
+
+        t.query_by_text("Toggle").click().await;
+        assert_eq!(
+            t.get_remembered_value::<(Vec<i32>, Vec<Vec<i32>>)>().0,
+            vec![1, 2]
+        );
+
+        t.query_by_text("Set").click().await;
+        assert_eq!(
+            t.get_remembered_value::<(Vec<i32>, Vec<Vec<i32>>)>().0,
+            vec![3, 4]
+        );
+
+        t.query_by_text("Clear").click().await;
+        t.query_by_text("Clear").click().await;
+
+        // Clearing an empty selection doesn't report a change
+        assert_eq!(
+            t.get_remembered_value::<(Vec<i32>, Vec<Vec<i32>>)>(),
+            (vec![], vec![vec![1, 2], vec![3, 4], vec![]])
+        );
+    }
+}
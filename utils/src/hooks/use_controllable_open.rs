@@ -0,0 +1,98 @@
+use yew::prelude::*;
+
+use crate::hooks::use_controllable_state;
+
+/// The open state of an overlay, e.g. a popover, that may be controlled through `open`.
+///
+/// Returns whether it is open and a callback that opens or closes it, which reports the
+/// change through `on_open_change` and, while uncontrolled, re-renders with the new state.
+#[hook]
+pub fn use_controllable_open(
+    default_open: bool,
+    open: Option<bool>,
+    on_open_change: Callback<bool>,
+) -> (bool, Callback<bool>) {
+    let (is_open, dispatch) = use_controllable_state(Some(default_open), open, on_open_change);
+
+    let set_open = use_callback(dispatch, |open: bool, dispatch| {
+        dispatch.emit(Box::new(move |_| open));
+    });
+
+    let is_open = *is_open.borrow();
+    (is_open, set_open)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+    use yewlish_testing_tools::*;
+
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    async fn test_use_controllable_open_uncontrolled() {
+        let t = render!({
+            let changes = use_mut_ref(Vec::<bool>::new);
+
+            let on_open_change = use_callback(changes.clone(), |open, changes| {
+                changes.borrow_mut().push(open);
+            });
+
+            let (is_open, set_open) = use_controllable_open(false, None, on_open_change);
+            use_remember_value((is_open, changes.borrow().clone()));
+
+            html! {
+                <button onclick={set_open.reform(move |_: MouseEvent| !is_open)}>{"Toggle"}</button>
+            }
+        })
+        .await;
+
+        assert_eq!(
+            t.get_remembered_value::<(bool, Vec<bool>)>(),
+            (false, vec![])
+        );
+
+        let button = t.query_by_role("button").click().await;
+        assert_eq!(
+            t.get_remembered_value::<(bool, Vec<bool>)>(),
+            (true, vec![true])
+        );
+
+        button.click().await;
+        assert_eq!(
+            t.get_remembered_value::<(bool, Vec<bool>)>(),
+            (false, vec![true, false])
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_use_controllable_open_controlled() {
+        let t = render!({
+            let requested = use_state(|| None::<bool>);
+
+            let on_open_change = use_callback(requested.setter(), |open, set_requested| {
+                set_requested.set(Some(open));
+            });
+
+            let (is_open, set_open) = use_controllable_open(false, Some(true), on_open_change);
+            use_remember_value((is_open, *requested));
+
+            html! {
+                <button onclick={set_open.reform(|_: MouseEvent| false)}>{"Close"}</button>
+            }
+        })
+        .await;
+
+        assert_eq!(
+            t.get_remembered_value::<(bool, Option<bool>)>(),
+            (true, None)
+        );
+
+        t.query_by_role("button").click().await;
+        assert_eq!(
+            t.get_remembered_value::<(bool, Option<bool>)>().1,
+            Some(false)
+        );
+    }
+}
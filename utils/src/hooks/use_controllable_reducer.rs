@@ -0,0 +1,89 @@
+use std::cell::RefCell;
+use std::fmt::Debug;
+use std::rc::Rc;
+use yew::prelude::*;
+
+use crate::hooks::use_controllable_state_with_reset;
+
+type Dispatch<T> = Callback<Box<dyn Fn(T) -> T>>;
+
+/// The state of `use_controllable_reducer`
+pub struct UseControllableReducerHandle<T, R: Reducible> {
+    pub value: Rc<RefCell<T>>,
+    /// Changes the value and brings the reducer up to date with it
+    pub dispatch: Dispatch<T>,
+    /// Mirrors `value`, e.g. for a context shared with the component's children
+    pub reducer: UseReducerHandle<R>,
+    /// Restores `initial`, see `use_controllable_state_with_reset`
+    pub reset: Callback<()>,
+}
+
+/// Combines `use_controllable_state` with a reducer that mirrors the controllable value,
+/// e.g. a context shared with the component's children.
+///
+/// `init` builds the reducer state from the initial value, `select` reads the mirrored value
+/// back and `sync` turns a new value into the action that brings the reducer up to date.
+/// The reducer is synced both on dispatch and when the controlled value changes.
+#[hook]
+pub fn use_controllable_reducer<T, R>(
+    initial: Option<T>,
+    controlled: Option<T>,
+    on_change: Callback<T>,
+    init: impl FnOnce(T) -> R,
+    select: fn(&R) -> T,
+    sync: fn(T) -> R::Action,
+) -> UseControllableReducerHandle<T, R>
+where
+    T: Debug + Default + PartialEq + Clone + 'static,
+    R: Reducible + PartialEq + 'static,
+{
+    let (value, dispatch, reset) =
+        use_controllable_state_with_reset(initial, controlled, on_change);
+
+    let reducer = use_reducer({
+        let value = value.borrow().clone();
+        move || init(value)
+    });
+
+    use_effect_with(
+        (value.borrow().clone(), reducer.clone()),
+        move |(value, reducer)| {
+            if *value != select(reducer) {
+                reducer.dispatch(sync(value.clone()));
+            }
+        },
+    );
+
+    let dispatch = use_callback(
+        (dispatch, value.clone(), reducer.clone()),
+        move |new_state: Box<dyn Fn(T) -> T>, (dispatch, value, reducer)| {
+            dispatch.emit(new_state);
+
+            let value = value.borrow().clone();
+
+            if value != select(reducer) {
+                reducer.dispatch(sync(value));
+            }
+        },
+    );
+
+    let reset = use_callback(
+        (reset, value.clone(), reducer.clone()),
+        move |(), (reset, value, reducer)| {
+            reset.emit(());
+
+            let value = value.borrow().clone();
+
+            if value != select(reducer) {
+                reducer.dispatch(sync(value));
+            }
+        },
+    );
+
+    UseControllableReducerHandle {
+        value,
+        dispatch,
+        reducer,
+        reset,
+    }
+}
@@ -3,7 +3,19 @@ use std::fmt::Debug;
 use std::rc::Rc;
 use yew::prelude::*;
 
+use crate::hooks::use_previous;
+
 type Dispatch<T> = Callback<Box<dyn Fn(T) -> T>>;
+type Comparator<T> = Rc<dyn Fn(&T, &T) -> bool>;
+
+/// Describes the switch between controlled and uncontrolled, if the value went through one.
+fn mode_switch(was_controlled: Option<bool>, is_controlled: bool) -> Option<&'static str> {
+    match (was_controlled?, is_controlled) {
+        (true, false) => Some("from controlled to uncontrolled"),
+        (false, true) => Some("from uncontrolled to controlled"),
+        _ => None,
+    }
+}
 
 #[hook]
 pub fn use_controllable_state<T>(
@@ -14,6 +26,63 @@ pub fn use_controllable_state<T>(
 where
     T: Debug + Default + PartialEq + Clone + 'static,
 {
+    let (value, dispatch, _) = use_controllable_state_with_reset(initial, controlled, on_change);
+    (value, dispatch)
+}
+
+/// `use_controllable_state` comparing values with `eq` instead of `PartialEq`, e.g. only by id.
+///
+/// `eq` only decides whether a change reaches `on_change`: the value is still updated, e.g. with a
+/// new label under the same id, but `on_change` isn't called for it.
+#[hook]
+pub fn use_controllable_state_eq<T, F>(
+    initial: Option<T>,
+    controlled: Option<T>,
+    on_change: Callback<T>,
+    eq: F,
+) -> (Rc<RefCell<T>>, Dispatch<T>)
+where
+    T: Debug + Default + PartialEq + Clone + 'static,
+    F: Fn(&T, &T) -> bool + 'static,
+{
+    let (value, dispatch, _) =
+        use_controllable_state_with_comparator(initial, controlled, on_change, Some(Rc::new(eq)));
+
+    (value, dispatch)
+}
+
+/// `use_controllable_state` with a third callback that restores `initial`, e.g. on a form reset.
+///
+/// Uncontrolled, the value goes back to `initial` right away. Controlled, `on_change` receives
+/// `initial` and the parent decides whether to pass it back.
+#[hook]
+pub fn use_controllable_state_with_reset<T>(
+    initial: Option<T>,
+    controlled: Option<T>,
+    on_change: Callback<T>,
+) -> (Rc<RefCell<T>>, Dispatch<T>, Callback<()>)
+where
+    T: Debug + Default + PartialEq + Clone + 'static,
+{
+    use_controllable_state_with_comparator(initial, controlled, on_change, None)
+}
+
+#[hook]
+fn use_controllable_state_with_comparator<T>(
+    initial: Option<T>,
+    controlled: Option<T>,
+    on_change: Callback<T>,
+    comparator: Option<Comparator<T>>,
+) -> (Rc<RefCell<T>>, Dispatch<T>, Callback<()>)
+where
+    T: Debug + Default + PartialEq + Clone + 'static,
+{
+    // Read by the callbacks, so they don't need to be recreated for a new comparator
+    let comparator_ref = use_mut_ref(|| None::<Comparator<T>>);
+    *comparator_ref.borrow_mut() = comparator;
+
+    let reset_to = initial.clone().unwrap_or_default();
+
     let value: Rc<RefCell<T>> = use_mut_ref(|| {
         controlled
             .clone()
@@ -21,6 +90,20 @@ where
     });
 
     let trigger = use_force_update();
+    let was_controlled = use_previous(controlled.is_some());
+    let warned = use_mut_ref(|| false);
+
+    // The value stops or starts following the parent, which is rarely intended
+    if cfg!(debug_assertions) && !*warned.borrow() {
+        if let Some(mode_switch) = mode_switch(was_controlled, controlled.is_some()) {
+            log::warn!(
+                "A controllable {} changed {mode_switch}, pass `Some` on every render or on none of them",
+                std::any::type_name::<T>()
+            );
+
+            *warned.borrow_mut() = true;
+        }
+    }
 
     let dispatch = use_callback((value.clone(), controlled.is_some(), on_change), {
         let trigger = trigger.clone();
@@ -28,8 +111,16 @@ where
         move |new_state: Box<dyn Fn(T) -> T>, (value, is_controlled, on_change)| {
             let new_state = new_state(value.borrow().clone());
 
+            let unchanged = comparator_ref
+                .borrow()
+                .as_ref()
+                .is_some_and(|comparator| comparator(&value.borrow(), &new_state));
+
             value.replace(new_state.clone());
-            on_change.emit(new_state);
+
+            if !unchanged {
+                on_change.emit(new_state);
+            }
 
             if !is_controlled {
                 trigger.force_update();
@@ -51,5 +142,158 @@ where
         },
     );
 
-    (value, dispatch)
+    let reset = use_callback((dispatch.clone(), reset_to), |(), (dispatch, reset_to)| {
+        let reset_to = reset_to.clone();
+        dispatch.emit(Box::new(move |_| reset_to.clone()));
+    });
+
+    (value, dispatch, reset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+    use yewlish_testing_tools::*;
+
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    #[test]
+    fn test_mode_switch() {
+        assert_eq!(mode_switch(None, true), None);
+        assert_eq!(mode_switch(Some(true), true), None);
+        assert_eq!(mode_switch(Some(false), false), None);
+
+        assert_eq!(
+            mode_switch(Some(true), false),
+            Some("from controlled to uncontrolled")
+        );
+
+        assert_eq!(
+            mode_switch(Some(false), true),
+            Some("from uncontrolled to controlled")
+        );
+    }
+
+    #[derive(Clone, Debug, Default, PartialEq)]
+    struct Item {
+        id: u32,
+        label: &'static str,
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_use_controllable_state_eq() {
+        let t = render!({
+            let changes = use_mut_ref(Vec::<Item>::new);
+
+            let on_change = use_callback(changes.clone(), |item, changes| {
+                changes.borrow_mut().push(item);
+            });
+
+            let (value, dispatch) = use_controllable_state_eq(
+                Some(Item {
+                    id: 1,
+                    label: "One",
+                }),
+                None,
+                on_change,
+                |a: &Item, b: &Item| a.id == b.id,
+            );
+
+            use_remember_value((value.borrow().clone(), changes.borrow().clone()));
+
+            html! {
+                <>
+                    <button onclick={dispatch.reform(|_: MouseEvent| {
+                        Box::new(|_| Item { id: 1, label: "Renamed" }) as Box<dyn Fn(Item) -> Item>
+                    })}>{"Rename"}</button>
+                    <button onclick={dispatch.reform(|_: MouseEvent| {
+                        Box::new(|_| Item { id: 2, label: "Two" }) as Box<dyn Fn(Item) -> Item>
+                    })}>{"Replace"}</button>
+                </>
+            }
+        })
+        .await;
+
+        // Equal by id, the value is renamed without reaching `on_change`
+        t.query_by_text("Rename").click().await;
+
+        assert_eq!(
+            t.get_remembered_value::<(Item, Vec<Item>)>(),
+            (
+                Item {
+                    id: 1,
+                    label: "Renamed"
+                },
+                vec![]
+            )
+        );
+
+        t.query_by_text("Replace").click().await;
+
+        assert_eq!(
+            t.get_remembered_value::<(Item, Vec<Item>)>(),
+            (
+                Item {
+                    id: 2,
+                    label: "Two"
+                },
+                vec![Item {
+                    id: 2,
+                    label: "Two"
+                }]
+            )
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_use_controllable_state_eq_syncs_the_controlled_value() {
+        let t = render!({
+            let item = use_state(|| Item {
+                id: 1,
+                label: "One",
+            });
+
+            let changes = use_mut_ref(Vec::<Item>::new);
+
+            let on_change = use_callback(changes.clone(), |item, changes| {
+                changes.borrow_mut().push(item);
+            });
+
+            let (value, _) = use_controllable_state_eq(
+                None,
+                Some((*item).clone()),
+                on_change,
+                |a: &Item, b: &Item| a.id == b.id,
+            );
+
+            use_remember_value((value.borrow().clone(), changes.borrow().clone()));
+
+            let rename = use_callback(item.clone(), |_: MouseEvent, item| {
+                item.set(Item {
+                    id: 1,
+                    label: "Renamed",
+                });
+            });
+
+            html! {
+                <button onclick={rename}>{"Rename"}</button>
+            }
+        })
+        .await;
+
+        // Equal by id, the value follows the parent without echoing the rename back
+        t.query_by_text("Rename").click().await;
+
+        assert_eq!(
+            t.get_remembered_value::<(Item, Vec<Item>)>(),
+            (
+                Item {
+                    id: 1,
+                    label: "Renamed"
+                },
+                vec![]
+            )
+        );
+    }
 }
@@ -0,0 +1,120 @@
+use std::cell::RefCell;
+use std::fmt::Debug;
+use std::rc::Rc;
+use yew::prelude::*;
+
+use crate::hooks::use_controllable_state_with_reset;
+
+type Dispatch<T> = Callback<ControllableVecAction<T>>;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ControllableVecAction<T> {
+    /// Appends the value unless it's already in the collection
+    Add(T),
+    /// Removes every occurrence of the value
+    Remove(T),
+    /// Replaces the whole collection
+    Replace(Vec<T>),
+}
+
+/// `use_controllable_state` for collections, e.g. the pressed items of a group or a multi-select.
+///
+/// Returns the current values and a callback that applies a `ControllableVecAction` to them.
+/// `on_change` receives the whole collection after every action.
+#[hook]
+pub fn use_controllable_vec_state<T>(
+    initial: Option<Vec<T>>,
+    controlled: Option<Vec<T>>,
+    on_change: Callback<Vec<T>>,
+) -> (Rc<RefCell<Vec<T>>>, Dispatch<T>)
+where
+    T: Debug + PartialEq + Clone + 'static,
+{
+    let (value, dispatch, _) =
+        use_controllable_vec_state_with_reset(initial, controlled, on_change);
+    (value, dispatch)
+}
+
+/// `use_controllable_vec_state` with a third callback that restores `initial`, e.g. on a form
+/// reset, see `use_controllable_state_with_reset`.
+#[hook]
+pub fn use_controllable_vec_state_with_reset<T>(
+    initial: Option<Vec<T>>,
+    controlled: Option<Vec<T>>,
+    on_change: Callback<Vec<T>>,
+) -> (Rc<RefCell<Vec<T>>>, Dispatch<T>, Callback<()>)
+where
+    T: Debug + PartialEq + Clone + 'static,
+{
+    let (value, dispatch, reset) =
+        use_controllable_state_with_reset(initial, controlled, on_change);
+
+    let dispatch = use_callback(dispatch, |action: ControllableVecAction<T>, dispatch| {
+        dispatch.emit(Box::new(move |mut values: Vec<T>| {
+            match &action {
+                ControllableVecAction::Add(value) => {
+                    if !values.contains(value) {
+                        values.push(value.clone());
+                    }
+                }
+                ControllableVecAction::Remove(value) => {
+                    values.retain(|v| v != value);
+                }
+                ControllableVecAction::Replace(next) => {
+                    values.clone_from(next);
+                }
+            }
+
+            values
+        }));
+    });
+
+    (value, dispatch, reset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+    use yewlish_testing_tools::*;
+
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    async fn test_use_controllable_vec_state_applies_actions() {
+        let t = render!({
+            let (values, dispatch) =
+                use_controllable_vec_state(Some(vec![1]), None, Callback::noop());
+
+            use_remember_value(values.borrow().clone());
+
+            let add = dispatch.reform(|_event: MouseEvent| ControllableVecAction::Add(2));
+            let remove = dispatch.reform(|_event: MouseEvent| ControllableVecAction::Remove(1));
+            let replace =
+                dispatch.reform(|_event: MouseEvent| ControllableVecAction::Replace(vec![3, 4]));
+
+            html! {
+                <>
+                    <button onclick={add}>{ "Add" }</button>
+                    <button onclick={remove}>{ "Remove" }</button>
+                    <button onclick={replace}>{ "Replace" }</button>
+                </>
+            }
+        })
+        .await;
+
+        assert_eq!(t.get_remembered_value::<Vec<i32>>(), vec![1]);
+
+        t.query_by_text("Add").click().await;
+        assert_eq!(t.get_remembered_value::<Vec<i32>>(), vec![1, 2]);
+
+        t.query_by_text("Add").click().await;
+        assert_eq!(t.get_remembered_value::<Vec<i32>>(), vec![1, 2]);
+
+        t.query_by_text("Remove").click().await;
+        assert_eq!(t.get_remembered_value::<Vec<i32>>(), vec![2]);
+
+        t.query_by_text("Replace").click().await;
+        assert_eq!(t.get_remembered_value::<Vec<i32>>(), vec![3, 4]);
+    }
+}
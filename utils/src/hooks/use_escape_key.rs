@@ -0,0 +1,46 @@
+use crate::hooks::{use_event_listener, EventListenerTarget};
+use yew::prelude::*;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum EscapeKeyTarget {
+    Document,
+    Element(web_sys::Element),
+    Ref(NodeRef),
+}
+
+impl From<&web_sys::Element> for EscapeKeyTarget {
+    fn from(element: &web_sys::Element) -> Self {
+        EscapeKeyTarget::Element(element.clone())
+    }
+}
+
+impl From<&NodeRef> for EscapeKeyTarget {
+    fn from(node_ref: &NodeRef) -> Self {
+        EscapeKeyTarget::Ref(node_ref.clone())
+    }
+}
+
+impl From<EscapeKeyTarget> for EventListenerTarget {
+    fn from(target: EscapeKeyTarget) -> Self {
+        match target {
+            EscapeKeyTarget::Document => EventListenerTarget::Document,
+            EscapeKeyTarget::Element(element) => (&element).into(),
+            EscapeKeyTarget::Ref(node_ref) => EventListenerTarget::Ref(node_ref),
+        }
+    }
+}
+
+/// Calls `handler` whenever the Escape key is pressed within `target`.
+///
+/// The handler receives the original event, so it can call `prevent_default`
+/// to signal the caller that its default behavior (e.g. closing) should be skipped.
+#[hook]
+pub fn use_escape_key(target: EscapeKeyTarget, handler: Callback<KeyboardEvent>) {
+    let on_keydown = use_callback(handler, |event: KeyboardEvent, handler| {
+        if event.key() == "Escape" {
+            handler.emit(event);
+        }
+    });
+
+    use_event_listener(target, "keydown", on_keydown);
+}
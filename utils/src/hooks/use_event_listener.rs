@@ -0,0 +1,125 @@
+use web_sys::wasm_bindgen::prelude::Closure;
+use web_sys::wasm_bindgen::JsCast;
+use yew::prelude::*;
+
+/// Where `use_event_listener` attaches its listener
+#[derive(Clone, Debug, PartialEq)]
+pub enum EventListenerTarget {
+    Window,
+    Document,
+    Target(web_sys::EventTarget),
+    /// Resolved once the effect runs, i.e. after the element has been rendered
+    Ref(NodeRef),
+}
+
+impl From<&web_sys::Element> for EventListenerTarget {
+    fn from(element: &web_sys::Element) -> Self {
+        EventListenerTarget::Target(element.clone().into())
+    }
+}
+
+impl From<&web_sys::EventTarget> for EventListenerTarget {
+    fn from(event_target: &web_sys::EventTarget) -> Self {
+        EventListenerTarget::Target(event_target.clone())
+    }
+}
+
+impl From<&NodeRef> for EventListenerTarget {
+    fn from(node_ref: &NodeRef) -> Self {
+        EventListenerTarget::Ref(node_ref.clone())
+    }
+}
+
+impl EventListenerTarget {
+    fn resolve(&self) -> Option<web_sys::EventTarget> {
+        match self {
+            EventListenerTarget::Window => web_sys::window().map(Into::into),
+            EventListenerTarget::Document => web_sys::window()
+                .and_then(|window| window.document())
+                .map(Into::into),
+            EventListenerTarget::Target(event_target) => Some(event_target.clone()),
+            EventListenerTarget::Ref(node_ref) => node_ref.get().map(Into::into),
+        }
+    }
+}
+
+/// Listens to `event` on `target` for as long as the component is mounted.
+///
+/// The listener is re-attached whenever the target, the event name or the handler changes,
+/// so memoize the handler. Events that aren't an `E`, e.g. a plain `Event` dispatched as
+/// `keydown`, are skipped.
+#[hook]
+pub fn use_event_listener<T, E>(target: T, event: &str, handler: Callback<E>)
+where
+    T: Into<EventListenerTarget>,
+    E: JsCast + 'static,
+{
+    let target = target.into();
+
+    use_effect_with(
+        (target, event.to_string(), handler),
+        |(target, event, handler)| {
+            let event_target = target.resolve();
+            let handler = handler.clone();
+
+            let listener = Closure::wrap(Box::new(move |event: web_sys::Event| {
+                if let Ok(event) = event.dyn_into::<E>() {
+                    handler.emit(event);
+                }
+            }) as Box<dyn FnMut(_)>);
+
+            if let Some(event_target) = &event_target {
+                let _ = event_target
+                    .add_event_listener_with_callback(event, listener.as_ref().unchecked_ref());
+            }
+
+            let event = event.clone();
+
+            move || {
+                if let Some(event_target) = event_target {
+                    let _ = event_target.remove_event_listener_with_callback(
+                        &event,
+                        listener.as_ref().unchecked_ref(),
+                    );
+                }
+            }
+        },
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+    use yewlish_testing_tools::*;
+
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    async fn test_use_event_listener_on_ref() {
+        let t = render!({
+            let node_ref = use_node_ref();
+            let clicks = use_state(|| 0);
+
+            let on_click = use_callback(clicks.clone(), |_event: MouseEvent, clicks| {
+                clicks.set(**clicks + 1);
+            });
+
+            use_event_listener(&node_ref, "click", on_click);
+            use_remember_value(*clicks);
+
+            html! {
+                <button ref={node_ref}>{"Click"}</button>
+            }
+        })
+        .await;
+
+        assert_eq!(t.get_remembered_value::<i32>(), 0);
+
+        let button = t.query_by_role("button").click().await;
+        assert_eq!(t.get_remembered_value::<i32>(), 1);
+
+        button.click().await;
+        assert_eq!(t.get_remembered_value::<i32>(), 2);
+    }
+}
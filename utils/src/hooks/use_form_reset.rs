@@ -0,0 +1,97 @@
+use web_sys::wasm_bindgen::JsCast;
+use yew::prelude::*;
+
+use crate::hooks::{use_event_listener, EventListenerTarget};
+
+/// Calls `on_reset` when the form containing `node_ref` is reset, e.g. by a `<button type="reset">`.
+///
+/// Custom controls built on buttons aren't form-associated, so the browser doesn't restore
+/// them along with the native inputs. Resets whose default was prevented are skipped.
+#[hook]
+pub fn use_form_reset(node_ref: &NodeRef, on_reset: Callback<()>) {
+    let handler = use_callback(
+        (node_ref.clone(), on_reset),
+        |event: web_sys::Event, (node_ref, on_reset)| {
+            if event.default_prevented() {
+                return;
+            }
+
+            let Some(form) = event
+                .target()
+                .and_then(|target| target.dyn_into::<web_sys::Node>().ok())
+            else {
+                return;
+            };
+
+            if node_ref
+                .get()
+                .is_some_and(|node| form.contains(Some(&node)))
+            {
+                on_reset.emit(());
+            }
+        },
+    );
+
+    // `reset` bubbles, a single listener catches the forms rendered after this hook ran
+    use_event_listener(EventListenerTarget::Document, "reset", handler);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hooks::use_controllable_state_with_reset;
+    use wasm_bindgen_test::*;
+    use yewlish_testing_tools::*;
+
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    async fn test_use_form_reset_restores_the_initial_value() {
+        let t = render!({
+            let node_ref = use_node_ref();
+
+            let (value, dispatch, reset) =
+                use_controllable_state_with_reset(Some(1), None, Callback::noop());
+
+            use_form_reset(&node_ref, reset);
+            use_remember_value(*value.borrow());
+
+            let increment = dispatch.reform(|_event: MouseEvent| {
+                Box::new(|value: i32| value + 1) as Box<dyn Fn(i32) -> i32>
+            });
+
+            html! {
+                <>
+                    <form>
+                        <button type="button" ref={node_ref} onclick={increment}>
+                            {"Increment"}
+                        </button>
+                    </form>
+                    <form id="other" />
+                </>
+            }
+        })
+        .await;
+
+        t.query_by_text("Increment").click().await;
+        assert_eq!(t.get_remembered_value::<i32>(), 2);
+
+        let form = |selector: &str| -> web_sys::HtmlFormElement {
+            web_sys::window()
+                .and_then(|window| window.document())
+                .and_then(|document| document.query_selector(selector).ok())
+                .flatten()
+                .and_then(|element| element.dyn_into().ok())
+                .expect("Failed to find the form")
+        };
+
+        // Resetting another form leaves the value alone
+        form("#other").reset();
+        yew::platform::time::sleep(std::time::Duration::ZERO).await;
+        assert_eq!(t.get_remembered_value::<i32>(), 2);
+
+        form("form").reset();
+        yew::platform::time::sleep(std::time::Duration::ZERO).await;
+        assert_eq!(t.get_remembered_value::<i32>(), 1);
+    }
+}
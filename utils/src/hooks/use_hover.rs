@@ -0,0 +1,118 @@
+use web_sys::{Element, PointerEvent};
+use yew::prelude::*;
+
+use crate::hooks::use_event_listener;
+
+/// Whether the pointer is over the element of `node_ref`.
+///
+/// The listeners are attached once the element is rendered and moved over when `node_ref` ends up
+/// on another element, e.g. one rendered conditionally. The element unmounting resets the state.
+#[hook]
+pub fn use_hover(node_ref: &NodeRef) -> bool {
+    let is_hovered = use_state_eq(|| false);
+    let element = use_state_eq(|| None::<Element>);
+
+    use_effect({
+        let node_ref = node_ref.clone();
+        let element = element.clone();
+        let is_hovered = is_hovered.clone();
+
+        move || {
+            let current = node_ref.cast::<Element>();
+
+            if current.is_none() {
+                is_hovered.set(false);
+            }
+
+            element.set(current);
+        }
+    });
+
+    // Depending on the element makes the listeners resolve `node_ref` again once it changes
+    let on_enter = use_callback(
+        (is_hovered.setter(), (*element).clone()),
+        |_event: PointerEvent, (set_hovered, _)| {
+            set_hovered.set(true);
+        },
+    );
+
+    let on_leave = use_callback(
+        (is_hovered.setter(), (*element).clone()),
+        |_event: PointerEvent, (set_hovered, _)| {
+            set_hovered.set(false);
+        },
+    );
+
+    use_event_listener(node_ref, "pointerenter", on_enter);
+    use_event_listener(node_ref, "pointerleave", on_leave);
+
+    *is_hovered
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+    use yew::platform::time::sleep;
+    use yewlish_testing_tools::*;
+
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    async fn dispatch_pointer_event(event_type: &str) {
+        let event =
+            PointerEvent::new_with_event_init_dict(event_type, &web_sys::PointerEventInit::new())
+                .unwrap();
+
+        web_sys::window()
+            .and_then(|window| window.document())
+            .and_then(|document| document.query_selector("#target").ok())
+            .flatten()
+            .expect("Failed to find the target")
+            .dispatch_event(&event)
+            .unwrap();
+
+        sleep(std::time::Duration::ZERO).await;
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_use_hover_on_an_element_mounted_later() {
+        let t = render!({
+            let node_ref = use_node_ref();
+            let is_mounted = use_state(|| false);
+            let is_hovered = use_hover(&node_ref);
+
+            use_remember_value(is_hovered);
+
+            let mount = use_callback(is_mounted.clone(), |_: MouseEvent, is_mounted| {
+                is_mounted.set(!**is_mounted);
+            });
+
+            html! {
+                <>
+                    <button onclick={mount}>{"Mount"}</button>
+                    if *is_mounted {
+                        <div id="target" ref={node_ref}>{"Target"}</div>
+                    }
+                </>
+            }
+        })
+        .await;
+
+        assert!(!t.get_remembered_value::<bool>());
+
+        t.query_by_text("Mount").click().await;
+
+        dispatch_pointer_event("pointerenter").await;
+        assert!(t.get_remembered_value::<bool>());
+
+        dispatch_pointer_event("pointerleave").await;
+        assert!(!t.get_remembered_value::<bool>());
+
+        dispatch_pointer_event("pointerenter").await;
+        assert!(t.get_remembered_value::<bool>());
+
+        // Unmounting the hovered element resets the state
+        t.query_by_text("Mount").click().await;
+        assert!(!t.get_remembered_value::<bool>());
+    }
+}
@@ -0,0 +1,92 @@
+use yew::prelude::*;
+
+use crate::hooks::use_auto_id;
+
+/// Related ids of a form field, see `use_id_link`
+#[derive(Clone, Debug, PartialEq)]
+pub struct FieldIds {
+    /// The id of the control itself
+    pub field: AttrValue,
+    pub label: AttrValue,
+    pub description: AttrValue,
+    pub error: AttrValue,
+}
+
+impl FieldIds {
+    /// The value of the control's `aria-describedby`, listing the parts that are rendered
+    pub fn described_by(&self, description: bool, error: bool) -> Option<AttrValue> {
+        let ids = [
+            description.then_some(&self.description),
+            error.then_some(&self.error),
+        ];
+
+        let ids = ids
+            .into_iter()
+            .flatten()
+            .map(AttrValue::as_str)
+            .collect::<Vec<_>>();
+
+        (!ids.is_empty()).then(|| ids.join(" ").into())
+    }
+}
+
+/// Returns the ids linking a field to its label, description and error message, e.g. for
+/// `aria-labelledby` and `aria-describedby`.
+///
+/// They're derived from a single `use_auto_id` with `base` as prefix, so they stay stable and
+/// hydration-safe like it.
+#[hook]
+pub fn use_id_link(base: &str) -> FieldIds {
+    let id = use_auto_id(Some(base));
+
+    FieldIds {
+        label: format!("{id}-label").into(),
+        description: format!("{id}-description").into(),
+        error: format!("{id}-error").into(),
+        field: id,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+    use yewlish_testing_tools::*;
+
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    async fn test_use_id_link_derives_the_related_ids() {
+        let t = render!({
+            let ids = use_id_link("email");
+            use_remember_value(ids.clone());
+
+            html! {
+                <>
+                    <label id={&ids.label} for={&ids.field}>{"Email"}</label>
+                    <input
+                        id={&ids.field}
+                        aria-describedby={ids.described_by(true, false)}
+                    />
+                    <p id={&ids.description}>{"We never share it"}</p>
+                </>
+            }
+        })
+        .await;
+
+        let ids = t.get_remembered_value::<FieldIds>();
+
+        assert!(ids.field.starts_with("email-"));
+        assert_eq!(ids.label, format!("{}-label", ids.field));
+        assert_eq!(
+            ids.described_by(true, true),
+            Some(format!("{} {}", ids.description, ids.error).into())
+        );
+        assert_eq!(ids.described_by(false, false), None);
+
+        assert_eq!(
+            t.query_by_selector("input").attribute("aria-describedby"),
+            Some(ids.description.to_string())
+        );
+    }
+}
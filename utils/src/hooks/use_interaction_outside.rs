@@ -1,8 +1,11 @@
+use std::cell::RefCell;
 use std::rc::Rc;
 use web_sys::wasm_bindgen::prelude::Closure;
 use web_sys::wasm_bindgen::JsCast;
+use web_sys::Element;
 use yew::prelude::*;
 
+#[derive(Debug)]
 pub enum Node {
     Element(web_sys::Element),
     Ref(NodeRef),
@@ -20,65 +23,139 @@ impl From<&NodeRef> for Node {
     }
 }
 
+impl Node {
+    fn contains(&self, target: &Element) -> bool {
+        match self {
+            Node::Element(element) => element.contains(Some(target)),
+            Node::Ref(node_ref) => node_ref
+                .cast::<Element>()
+                .is_some_and(|node| node.contains(Some(target))),
+        }
+    }
+}
+
+/// The nodes watched by one `use_interaction_outside` along with the layers nested in it.
+///
+/// Portaled content isn't within the DOM of the content it's logically nested in, so every layer
+/// registers with the closest one provided through the context. Interactions within a nested
+/// layer, e.g. a select opened from a popover, aren't outside ones for its ancestors.
+#[derive(Clone, Debug, Default)]
+pub struct InteractionOutsideLayer {
+    nodes: Rc<RefCell<Vec<Node>>>,
+    children: Rc<RefCell<Vec<InteractionOutsideLayer>>>,
+}
+
+impl InteractionOutsideLayer {
+    fn register(&self, child: &InteractionOutsideLayer) {
+        self.children.borrow_mut().push(child.clone());
+    }
+
+    fn unregister(&self, child: &InteractionOutsideLayer) {
+        self.children.borrow_mut().retain(|layer| layer != child);
+    }
+
+    /// Whether `target` is within the nodes of this layer or of any layer nested in it
+    #[must_use]
+    pub fn contains(&self, target: &Element) -> bool {
+        self.nodes.borrow().iter().any(|node| node.contains(target))
+            || self
+                .children
+                .borrow()
+                .iter()
+                .any(|layer| layer.contains(target))
+    }
+}
+
+impl PartialEq for InteractionOutsideLayer {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.nodes, &other.nodes)
+    }
+}
+
+/// Calls `callback` on presses and focus landing outside of `nodes`.
+///
+/// Returns the layer of `nodes`, provide it to the children through a `ContextProvider` so the
+/// `use_interaction_outside` of their portaled content counts as inside this one.
 #[hook]
-pub fn use_interaction_outside<T>(nodes: Vec<Node>, callback: T)
+pub fn use_interaction_outside<T>(nodes: Vec<Node>, callback: T) -> InteractionOutsideLayer
 where
     T: Fn(Event) + 'static,
 {
+    let layer = (*use_state(InteractionOutsideLayer::default)).clone();
+    let parent = use_context::<InteractionOutsideLayer>();
+
+    *layer.nodes.borrow_mut() = nodes;
+
+    use_effect_with((parent, layer.clone()), |(parent, layer)| {
+        let parent = parent.clone();
+        let layer = layer.clone();
+
+        if let Some(parent) = &parent {
+            parent.register(&layer);
+        }
+
+        move || {
+            if let Some(parent) = parent {
+                parent.unregister(&layer);
+            }
+        }
+    });
+
     let callback = Callback::from(callback);
     let callback_ref = Rc::new(callback);
 
-    use_effect_with(callback_ref, move |callback_ref| {
-        let callback = callback_ref.clone();
-        let callback_ref = Rc::downgrade(callback_ref);
+    use_effect_with(callback_ref, {
+        let layer = layer.clone();
 
-        let closure = Closure::wrap(Box::new(move |event: web_sys::Event| {
-            let target = event.target_unchecked_into::<web_sys::Element>();
+        move |callback_ref| {
+            let callback = callback_ref.clone();
+            let callback_ref = Rc::downgrade(callback_ref);
 
-            if !nodes.iter().any(|node| match &node {
-                Node::Element(element) => element.contains(Some(&target)),
-                Node::Ref(node_ref) => {
-                    if let Some(node) = node_ref.cast::<web_sys::Element>() {
-                        node.contains(Some(&target))
-                    } else {
-                        false
-                    }
+            let closure = Closure::wrap(Box::new(move |event: web_sys::Event| {
+                let target = event.target_unchecked_into::<Element>();
+
+                if !layer.contains(&target) {
+                    callback.emit(event);
                 }
-            }) {
-                callback.emit(event);
-            }
-        }) as Box<dyn FnMut(_)>);
+            }) as Box<dyn FnMut(_)>);
 
-        if let Some(window) = web_sys::window() {
-            let _ = window
-                .add_event_listener_with_callback("mousedown", closure.as_ref().unchecked_ref());
+            if let Some(window) = web_sys::window() {
+                let _ = window.add_event_listener_with_callback(
+                    "mousedown",
+                    closure.as_ref().unchecked_ref(),
+                );
 
-            let _ = window
-                .add_event_listener_with_callback("touchstart", closure.as_ref().unchecked_ref());
+                let _ = window.add_event_listener_with_callback(
+                    "touchstart",
+                    closure.as_ref().unchecked_ref(),
+                );
 
-            let _ = window
-                .add_event_listener_with_callback("focusin", closure.as_ref().unchecked_ref());
-        }
+                let _ = window
+                    .add_event_listener_with_callback("focusin", closure.as_ref().unchecked_ref());
+            }
 
-        move || {
-            if callback_ref.upgrade().is_some() {
-                if let Some(window) = web_sys::window() {
-                    let _ = window.remove_event_listener_with_callback(
-                        "mousedown",
-                        closure.as_ref().unchecked_ref(),
-                    );
-
-                    let _ = window.remove_event_listener_with_callback(
-                        "touchstart",
-                        closure.as_ref().unchecked_ref(),
-                    );
-
-                    let _ = window.remove_event_listener_with_callback(
-                        "focusin",
-                        closure.as_ref().unchecked_ref(),
-                    );
+            move || {
+                if callback_ref.upgrade().is_some() {
+                    if let Some(window) = web_sys::window() {
+                        let _ = window.remove_event_listener_with_callback(
+                            "mousedown",
+                            closure.as_ref().unchecked_ref(),
+                        );
+
+                        let _ = window.remove_event_listener_with_callback(
+                            "touchstart",
+                            closure.as_ref().unchecked_ref(),
+                        );
+
+                        let _ = window.remove_event_listener_with_callback(
+                            "focusin",
+                            closure.as_ref().unchecked_ref(),
+                        );
+                    }
                 }
             }
         }
     });
+
+    layer
 }
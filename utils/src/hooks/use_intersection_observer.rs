@@ -13,16 +13,26 @@ type HandlerParams = (
     Callback<IntersectionObserverEntry>,
 );
 
+type ObserverCallback = Closure<dyn FnMut(js_sys::Array, IntersectionObserver)>;
+
 #[hook]
 pub fn use_intersection_observer_lazy() -> (
     Rc<RefCell<Option<IntersectionObserver>>>,
     Callback<HandlerParams>,
 ) {
     let intersection_observer = use_mut_ref(|| None::<IntersectionObserver>);
+    // Kept alive until the observer is disconnected, instead of being leaked
+    let observer_callback = use_mut_ref(|| None::<ObserverCallback>);
+
+    let create_intersection_observer = use_callback(intersection_observer.clone(), {
+        let observer_callback = observer_callback.clone();
+
+        move |(element_ref, options, handler): HandlerParams, intersection_observer| {
+            // The previous observer must stop before its callback is dropped
+            if let Some(previous) = intersection_observer.borrow_mut().take() {
+                previous.disconnect();
+            }
 
-    let create_intersection_observer = use_callback(
-        intersection_observer.clone(),
-        |(element_ref, options, handler): HandlerParams, intersection_observer| {
             let callback = Closure::wrap(Box::new(
                 move |entries: js_sys::Array, _: IntersectionObserver| {
                     for entry in entries.iter() {
@@ -54,9 +64,9 @@ pub fn use_intersection_observer_lazy() -> (
                 }
             }
 
-            callback.forget();
-        },
-    );
+            observer_callback.replace(Some(callback));
+        }
+    });
 
     {
         let intersection_observer = intersection_observer.clone();
@@ -66,6 +76,8 @@ pub fn use_intersection_observer_lazy() -> (
                 if let Some(ref observer) = *intersection_observer.borrow() {
                     observer.disconnect();
                 }
+
+                observer_callback.borrow_mut().take();
             }
         });
     }
@@ -91,3 +103,27 @@ pub fn use_intersection_observer(
 
     intersection_observer
 }
+
+/// Calls `on_intersect` with whether `element_ref` intersects the root of `options`, the viewport by default.
+///
+/// It fires once the element is observed and then whenever it enters or leaves the root, which makes it
+/// fit lazy-loaded images and infinite scroll sentinels. The latest `on_intersect` is always called.
+#[hook]
+pub fn use_on_intersect(
+    element_ref: &NodeRef,
+    options: Rc<Option<IntersectionObserverInit>>,
+    on_intersect: Callback<bool>,
+) {
+    let on_intersect_ref = use_mut_ref(Callback::noop);
+    *on_intersect_ref.borrow_mut() = on_intersect;
+
+    let handler = use_callback(
+        on_intersect_ref,
+        |entry: IntersectionObserverEntry, on_intersect_ref| {
+            let on_intersect = on_intersect_ref.borrow().clone();
+            on_intersect.emit(entry.is_intersecting());
+        },
+    );
+
+    use_intersection_observer(element_ref, options, handler);
+}
@@ -0,0 +1,108 @@
+use std::{cell::RefCell, rc::Rc, time::Duration};
+use yew::platform::{spawn_local, time::sleep};
+use yew::prelude::*;
+
+/// Controls the timer of `use_interval`
+#[derive(Clone, Debug, PartialEq)]
+pub struct UseIntervalHandle {
+    /// Stops the interval until the `period` changes
+    pub cancel: Callback<()>,
+}
+
+/// Calls `callback` every `period` while the component is mounted, e.g. a web socket heartbeat.
+///
+/// A new `period` starts the interval over, a new `callback` is called from the next tick on.
+#[hook]
+pub fn use_interval(callback: Callback<()>, period: Duration) -> UseIntervalHandle {
+    let callback_ref = use_mut_ref(|| callback.clone());
+    *callback_ref.borrow_mut() = callback;
+
+    // The running loop ends at its next tick once this moves on
+    let generation: Rc<RefCell<usize>> = use_mut_ref(|| 0);
+
+    use_effect_with(period, {
+        let generation = generation.clone();
+
+        move |period| {
+            let current = {
+                let mut generation = generation.borrow_mut();
+                *generation += 1;
+                *generation
+            };
+
+            let period = *period;
+
+            spawn_local({
+                let generation = generation.clone();
+
+                async move {
+                    loop {
+                        sleep(period).await;
+
+                        if *generation.borrow() != current {
+                            break;
+                        }
+
+                        let callback = callback_ref.borrow().clone();
+                        callback.emit(());
+                    }
+                }
+            });
+
+            move || {
+                *generation.borrow_mut() += 1;
+            }
+        }
+    });
+
+    let cancel = use_callback((), move |(), ()| {
+        *generation.borrow_mut() += 1;
+    });
+
+    UseIntervalHandle { cancel }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+    use yewlish_testing_tools::*;
+
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    async fn test_use_interval() {
+        let t = render!({
+            let ticks = use_mut_ref(|| 0);
+            let rerender = use_force_update();
+
+            let interval = use_interval(
+                Callback::from({
+                    let ticks = ticks.clone();
+
+                    move |()| {
+                        *ticks.borrow_mut() += 1;
+                        rerender.force_update();
+                    }
+                }),
+                Duration::from_millis(20),
+            );
+
+            use_remember_value(*ticks.borrow());
+
+            html! {
+                <button onclick={interval.cancel.reform(|_: MouseEvent| ())}>{"Cancel"}</button>
+            }
+        })
+        .await;
+
+        sleep(Duration::from_millis(110)).await;
+        assert!(t.get_remembered_value::<i32>() >= 3);
+
+        t.query_by_text("Cancel").click().await;
+        let ticks = t.get_remembered_value::<i32>();
+
+        sleep(Duration::from_millis(60)).await;
+        assert_eq!(t.get_remembered_value::<i32>(), ticks);
+    }
+}
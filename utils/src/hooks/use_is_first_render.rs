@@ -0,0 +1,41 @@
+use yew::prelude::*;
+
+/// Returns `true` during the component's first render only.
+///
+/// Handy for skipping `on_*_change` emissions while reconciling props on mount.
+#[hook]
+pub fn use_is_first_render() -> bool {
+    let is_first_render = use_mut_ref(|| true);
+
+    is_first_render.replace(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+    use yewlish_testing_tools::*;
+
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    async fn test_use_is_first_render() {
+        let t = render!({
+            let is_first_render = use_is_first_render();
+            let rerender = use_force_update();
+            use_remember_value(is_first_render);
+
+            let onclick = Callback::from(move |_event: MouseEvent| rerender.force_update());
+
+            html! {
+                <button {onclick}>{ "Rerender" }</button>
+            }
+        })
+        .await;
+
+        assert!(t.get_remembered_value::<bool>());
+
+        t.query_by_role("button").click().await;
+        assert!(!t.get_remembered_value::<bool>());
+    }
+}
@@ -0,0 +1,210 @@
+use std::{cell::RefCell, rc::Rc};
+use web_sys::wasm_bindgen::prelude::Closure;
+use web_sys::wasm_bindgen::JsCast;
+use yew::prelude::*;
+
+/// How far, in pixels, the pointer may travel before the press stops counting as a long press
+const MOVE_THRESHOLD: f64 = 10.0;
+
+struct PendingPress {
+    timeout_id: i32,
+    x: i32,
+    y: i32,
+}
+
+fn cancel(pending: &RefCell<Option<PendingPress>>) {
+    if let Some(pending) = pending.borrow_mut().take() {
+        if let Some(window) = web_sys::window() {
+            window.clear_timeout_with_handle(pending.timeout_id);
+        }
+    }
+}
+
+/// Calls `on_long_press` once the primary pointer has been held on `node_ref` for `duration` milliseconds.
+///
+/// Releasing the pointer, leaving the element or moving it further than a few pixels cancels the press.
+/// Passing `None` as the callback installs no listeners, which lets components expose long press optionally.
+#[hook]
+pub fn use_long_press<C>(node_ref: NodeRef, duration: u32, on_long_press: C)
+where
+    C: Into<Option<Callback<PointerEvent>>>,
+{
+    let on_long_press = on_long_press.into();
+    let enabled = on_long_press.is_some();
+
+    // Read when the press completes, so a new callback doesn't cancel a press in progress
+    let on_long_press_ref = use_mut_ref(|| None::<Callback<PointerEvent>>);
+    *on_long_press_ref.borrow_mut() = on_long_press;
+
+    use_effect_with(
+        (node_ref, duration, enabled),
+        move |(node_ref, duration, enabled)| {
+            let pending = Rc::new(RefCell::new(None::<PendingPress>));
+            let element = node_ref.cast::<web_sys::Element>().filter(|_| *enabled);
+            let mut listeners = Vec::new();
+
+            if let Some(element) = &element {
+                let start = {
+                    let pending = pending.clone();
+                    let duration = *duration;
+
+                    Closure::wrap(Box::new(move |event: PointerEvent| {
+                        if !event.is_primary() || event.button() != 0 {
+                            return;
+                        }
+
+                        cancel(&pending);
+
+                        let Some(window) = web_sys::window() else {
+                            return;
+                        };
+
+                        let x = event.client_x();
+                        let y = event.client_y();
+
+                        let callback = {
+                            let pending = pending.clone();
+                            let on_long_press_ref = on_long_press_ref.clone();
+
+                            Closure::once_into_js(move || {
+                                pending.borrow_mut().take();
+
+                                let on_long_press = on_long_press_ref.borrow().clone();
+
+                                if let Some(on_long_press) = on_long_press {
+                                    on_long_press.emit(event);
+                                }
+                            })
+                        };
+
+                        match window.set_timeout_with_callback_and_timeout_and_arguments_0(
+                            callback.unchecked_ref(),
+                            duration as i32,
+                        ) {
+                            Ok(timeout_id) => {
+                                *pending.borrow_mut() = Some(PendingPress { timeout_id, x, y });
+                            }
+                            Err(err) => {
+                                log::error!("Failed to set timeout {err:?}");
+                            }
+                        }
+                    }) as Box<dyn FnMut(_)>)
+                };
+
+                let stop = {
+                    let pending = pending.clone();
+
+                    Closure::wrap(Box::new(move |_event: PointerEvent| {
+                        cancel(&pending);
+                    }) as Box<dyn FnMut(_)>)
+                };
+
+                let r#move = {
+                    let pending = pending.clone();
+
+                    Closure::wrap(Box::new(move |event: PointerEvent| {
+                        let moved_away = pending.borrow().as_ref().is_some_and(|pending| {
+                            let dx = f64::from(event.client_x() - pending.x);
+                            let dy = f64::from(event.client_y() - pending.y);
+
+                            dx.hypot(dy) > MOVE_THRESHOLD
+                        });
+
+                        if moved_away {
+                            cancel(&pending);
+                        }
+                    }) as Box<dyn FnMut(_)>)
+                };
+
+                let stop = Rc::new(stop);
+
+                listeners.push(("pointerdown", Rc::new(start)));
+                listeners.push(("pointermove", Rc::new(r#move)));
+                listeners.push(("pointerup", stop.clone()));
+                listeners.push(("pointerleave", stop.clone()));
+                listeners.push(("pointercancel", stop));
+
+                for (event, listener) in &listeners {
+                    let _ = element.add_event_listener_with_callback(
+                        event,
+                        listener.as_ref().as_ref().unchecked_ref(),
+                    );
+                }
+            }
+
+            move || {
+                cancel(&pending);
+
+                if let Some(element) = element {
+                    for (event, listener) in &listeners {
+                        let _ = element.remove_event_listener_with_callback(
+                            event,
+                            listener.as_ref().as_ref().unchecked_ref(),
+                        );
+                    }
+                }
+            }
+        },
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+    use wasm_bindgen_test::*;
+    use yew::platform::time::sleep;
+    use yewlish_testing_tools::*;
+
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    fn dispatch_pointer_event(name: &str) {
+        let button = web_sys::window()
+            .and_then(|window| window.document())
+            .and_then(|document| document.query_selector("[data-testid='button']").ok())
+            .flatten()
+            .expect("Failed to find the button");
+
+        let init = web_sys::PointerEventInit::new();
+        init.set_bubbles(true);
+        init.set_is_primary(true);
+        init.set_button(0);
+
+        let event = PointerEvent::new_with_event_init_dict(name, &init)
+            .expect("Failed to create a pointer event");
+
+        button
+            .dispatch_event(&event)
+            .expect("Failed to dispatch the pointer event");
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_use_long_press_fires_after_the_duration() {
+        let t = render!({
+            let node_ref = use_node_ref();
+            let presses = use_state(|| 0);
+
+            let on_long_press = use_callback(presses.clone(), |_event: PointerEvent, presses| {
+                presses.set(**presses + 1);
+            });
+
+            use_long_press(node_ref.clone(), 20, on_long_press);
+            use_remember_value(*presses);
+
+            html! {
+                <button ref={node_ref} data-testid="button">{ "Hold" }</button>
+            }
+        })
+        .await;
+
+        dispatch_pointer_event("pointerdown");
+        sleep(Duration::from_millis(60)).await;
+        assert_eq!(t.get_remembered_value::<i32>(), 1);
+
+        // Releasing early cancels the press
+        dispatch_pointer_event("pointerdown");
+        dispatch_pointer_event("pointerup");
+        sleep(Duration::from_millis(60)).await;
+        assert_eq!(t.get_remembered_value::<i32>(), 1);
+    }
+}
@@ -0,0 +1,68 @@
+use yew::prelude::*;
+
+/// Returns the value this hook was called with before it last changed,
+/// or `None` until it changes for the first time.
+///
+/// Re-renders that pass an equal value keep the previous one.
+#[hook]
+pub fn use_previous<T>(value: T) -> Option<T>
+where
+    T: Clone + PartialEq + 'static,
+{
+    let state = use_mut_ref(|| (None::<T>, value.clone()));
+
+    {
+        let mut state = state.borrow_mut();
+
+        if state.1 != value {
+            state.0 = Some(std::mem::replace(&mut state.1, value));
+        }
+    }
+
+    let previous = state.borrow().0.clone();
+    previous
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+    use yewlish_testing_tools::*;
+
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    async fn test_use_previous_tracks_the_last_distinct_value() {
+        let t = render!({
+            let counter = use_state(|| 0);
+            let rerender = use_force_update();
+            let previous = use_previous(*counter);
+            use_remember_value(previous);
+
+            let increment = use_callback(counter.clone(), |_event: MouseEvent, counter| {
+                counter.set(**counter + 1);
+            });
+
+            let rerender = Callback::from(move |_event: MouseEvent| rerender.force_update());
+
+            html! {
+                <>
+                    <button onclick={increment}>{ "Increment" }</button>
+                    <button onclick={rerender}>{ "Rerender" }</button>
+                </>
+            }
+        })
+        .await;
+
+        assert_eq!(t.get_remembered_value::<Option<i32>>(), None);
+
+        t.query_by_text("Increment").click().await;
+        assert_eq!(t.get_remembered_value::<Option<i32>>(), Some(0));
+
+        t.query_by_text("Rerender").click().await;
+        assert_eq!(t.get_remembered_value::<Option<i32>>(), Some(0));
+
+        t.query_by_text("Increment").click().await;
+        assert_eq!(t.get_remembered_value::<Option<i32>>(), Some(1));
+    }
+}
@@ -0,0 +1,61 @@
+use web_sys::{
+    js_sys,
+    wasm_bindgen::{prelude::Closure, JsCast},
+    DomRect, Element, ResizeObserver, ResizeObserverEntry,
+};
+use yew::prelude::*;
+
+/// Observes the size of the referenced element and emits its content rect on every change.
+///
+/// The observer is attached once the element is mounted and disconnected on unmount.
+#[hook]
+pub fn use_resize_observer(node_ref: &NodeRef, callback: Callback<DomRect>) {
+    let callback_ref = use_mut_ref(|| callback.clone());
+    *callback_ref.borrow_mut() = callback;
+
+    use_effect_with(node_ref.clone(), move |node_ref| {
+        let handler = Closure::wrap(Box::new(move |entries: js_sys::Array, _: ResizeObserver| {
+            for entry in entries.iter() {
+                let Some(entry) = entry.dyn_ref::<ResizeObserverEntry>() else {
+                    continue;
+                };
+
+                let rect = entry.content_rect();
+
+                match DomRect::new_with_x_and_y_and_width_and_height(
+                    rect.x(),
+                    rect.y(),
+                    rect.width(),
+                    rect.height(),
+                ) {
+                    Ok(rect) => callback_ref.borrow().emit(rect),
+                    Err(err) => log::error!("Failed to create DomRect {err:?}"),
+                }
+            }
+        }) as Box<dyn FnMut(js_sys::Array, ResizeObserver)>);
+
+        let resize_observer = match ResizeObserver::new(handler.as_ref().unchecked_ref()) {
+            Ok(resize_observer) => {
+                if let Some(element) = node_ref.cast::<Element>() {
+                    resize_observer.observe(&element);
+                } else {
+                    log::error!("Failed to cast element to Element");
+                }
+
+                Some(resize_observer)
+            }
+            Err(err) => {
+                log::error!("Failed to create ResizeObserver {err:?}");
+                None
+            }
+        };
+
+        move || {
+            if let Some(resize_observer) = resize_observer {
+                resize_observer.disconnect();
+            }
+
+            drop(handler);
+        }
+    });
+}
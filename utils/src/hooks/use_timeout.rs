@@ -0,0 +1,123 @@
+use std::{cell::RefCell, rc::Rc, time::Duration};
+use yew::platform::{spawn_local, time::sleep};
+use yew::prelude::*;
+
+/// Controls the timer of `use_timeout`
+#[derive(Clone, Debug, PartialEq)]
+pub struct UseTimeoutHandle {
+    /// Starts the timer over with the full delay, also after it fired or was cancelled
+    pub reset: Callback<()>,
+    pub cancel: Callback<()>,
+}
+
+/// Starts a new timer and drops the pending one. A sleeping future can't be aborted, so a timer
+/// only fires if `generation` hasn't moved on meanwhile.
+fn start(generation: &Rc<RefCell<usize>>, callback: &Rc<RefCell<Callback<()>>>, delay: Duration) {
+    let current = {
+        let mut generation = generation.borrow_mut();
+        *generation += 1;
+        *generation
+    };
+
+    let generation = generation.clone();
+    let callback = callback.clone();
+
+    spawn_local(async move {
+        sleep(delay).await;
+
+        if *generation.borrow() == current {
+            let callback = callback.borrow().clone();
+            callback.emit(());
+        }
+    });
+}
+
+/// Calls `callback` once `delay` has passed since the component mounted.
+///
+/// A new `delay` starts the timer over, a new `callback` is picked up by the pending timer.
+/// Unmounting clears the timer, e.g. a toast that's dismissed before it auto-dismisses.
+#[hook]
+pub fn use_timeout(callback: Callback<()>, delay: Duration) -> UseTimeoutHandle {
+    let callback_ref = use_mut_ref(|| callback.clone());
+    *callback_ref.borrow_mut() = callback;
+
+    let generation = use_mut_ref(|| 0_usize);
+
+    use_effect_with(delay, {
+        let generation = generation.clone();
+        let callback_ref = callback_ref.clone();
+
+        move |delay| {
+            start(&generation, &callback_ref, *delay);
+
+            move || {
+                *generation.borrow_mut() += 1;
+            }
+        }
+    });
+
+    let reset = use_callback(delay, {
+        let generation = generation.clone();
+
+        move |(), delay| {
+            start(&generation, &callback_ref, *delay);
+        }
+    });
+
+    let cancel = use_callback((), move |(), ()| {
+        *generation.borrow_mut() += 1;
+    });
+
+    UseTimeoutHandle { reset, cancel }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+    use yewlish_testing_tools::*;
+
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    async fn test_use_timeout() {
+        let t = render!({
+            let fired = use_state(|| 0);
+
+            let timeout = use_timeout(
+                Callback::from({
+                    let fired = fired.clone();
+                    move |()| fired.set(*fired + 1)
+                }),
+                Duration::from_millis(50),
+            );
+
+            use_remember_value(*fired);
+
+            html! {
+                <>
+                    <button onclick={timeout.reset.reform(|_: MouseEvent| ())}>{"Reset"}</button>
+                    <button onclick={timeout.cancel.reform(|_: MouseEvent| ())}>{"Cancel"}</button>
+                </>
+            }
+        })
+        .await;
+
+        assert_eq!(t.get_remembered_value::<i32>(), 0);
+
+        sleep(Duration::from_millis(100)).await;
+        assert_eq!(t.get_remembered_value::<i32>(), 1);
+
+        // A cancelled timer doesn't fire
+        t.query_by_text("Reset").click().await;
+        t.query_by_text("Cancel").click().await;
+
+        sleep(Duration::from_millis(100)).await;
+        assert_eq!(t.get_remembered_value::<i32>(), 1);
+
+        t.query_by_text("Reset").click().await;
+
+        sleep(Duration::from_millis(100)).await;
+        assert_eq!(t.get_remembered_value::<i32>(), 2);
+    }
+}
@@ -0,0 +1,128 @@
+use std::fmt::{Debug, Formatter};
+use std::rc::Rc;
+use yew::prelude::*;
+
+type RuleFn<T> = dyn Fn(&T) -> Option<String>;
+
+/// A single validation check, returning the error message when the value fails it.
+pub struct Rule<T>(Rc<RuleFn<T>>);
+
+impl<T> Rule<T> {
+    pub fn new<F>(check: F) -> Self
+    where
+        F: Fn(&T) -> Option<String> + 'static,
+    {
+        Self(Rc::new(check))
+    }
+
+    /// Fails with `message` whenever `predicate` returns `false`.
+    pub fn predicate<F>(predicate: F, message: impl Into<String>) -> Self
+    where
+        F: Fn(&T) -> bool + 'static,
+    {
+        let message = message.into();
+        Self::new(move |value| (!predicate(value)).then(|| message.clone()))
+    }
+
+    #[must_use]
+    pub fn check(&self, value: &T) -> Option<String> {
+        (self.0)(value)
+    }
+}
+
+impl<T> Clone for Rule<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T> PartialEq for Rule<T> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl<T> Debug for Rule<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Rule").finish_non_exhaustive()
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ValidationState {
+    pub valid: bool,
+    /// Messages of the failed rules, in the order the rules were given
+    pub errors: Vec<String>,
+}
+
+/// Runs `rules` against `value` and collects the messages of the ones that fail.
+///
+/// Rules are evaluated on every render, so they may capture other state freely.
+/// Components surface the result through their `invalid` and `error_id` props,
+/// pointing `error_id` at the element that renders `errors`.
+#[hook]
+pub fn use_validation<T>(value: T, rules: Vec<Rule<T>>) -> ValidationState
+where
+    T: 'static,
+{
+    let errors = rules
+        .iter()
+        .filter_map(|rule| rule.check(&value))
+        .collect::<Vec<_>>();
+
+    ValidationState {
+        valid: errors.is_empty(),
+        errors,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+    use yewlish_testing_tools::*;
+
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    async fn test_use_validation_collects_failed_rules() {
+        let t = render!({
+            let value = use_state(String::new);
+
+            let validation = use_validation(
+                (*value).clone(),
+                vec![
+                    Rule::predicate(|value: &String| !value.is_empty(), "Required"),
+                    Rule::new(|value: &String| (value.len() < 3).then(|| "Too short".to_string())),
+                ],
+            );
+
+            use_remember_value(validation.clone());
+
+            let onclick = use_callback(value.clone(), |_: MouseEvent, value| {
+                value.set(format!("{}a", **value));
+            });
+
+            html! {
+                <button {onclick}>{"Type"}</button>
+            }
+        })
+        .await;
+
+        let validation = t.get_remembered_value::<ValidationState>();
+        assert!(!validation.valid);
+        assert_eq!(validation.errors, vec!["Required", "Too short"]);
+
+        let button = t.query_by_role("button");
+
+        let button = button.click().await;
+        assert_eq!(
+            t.get_remembered_value::<ValidationState>().errors,
+            vec!["Too short"]
+        );
+
+        let button = button.click().await;
+        button.click().await;
+        assert!(t.get_remembered_value::<ValidationState>().valid);
+    }
+}
@@ -1,6 +1,11 @@
+use std::rc::Rc;
 use web_sys::wasm_bindgen::JsCast;
 use web_sys::HtmlElement;
 use yew::prelude::*;
+use yewlish_utils::hooks::use_on_intersect;
+
+/// The smallest item height used when a non-positive one is passed, to keep the math finite
+const MIN_ITEM_HEIGHT: f64 = 1.0;
 
 #[derive(Properties, PartialEq)]
 pub struct VirtualListProps {
@@ -8,42 +13,177 @@ pub struct VirtualListProps {
     pub item_height: f64,
     pub viewport_height: f64,
     pub render_item: Callback<usize, Html>,
+    /// Scrolls the viewport just enough to reveal the item at this index
+    #[prop_or_default]
+    pub scroll_to_index: Option<usize>,
+    /// Called when the end of the list scrolls into view, e.g. to load the next page and raise `total_items`.
+    ///
+    /// It fires again once the end has left the viewport and comes back, so a page that doesn't fill the
+    /// viewport won't load the next one on its own
+    #[prop_or_default]
+    pub on_end_reached: Option<Callback<()>>,
+    /// Keeps the viewport at the end as items are appended, e.g. for logs.
+    ///
+    /// Scrolling up releases it until the viewport is scrolled back to the end
+    #[prop_or_default]
+    pub follow_tail: bool,
 }
 
 #[function_component(VirtualList)]
 pub fn virtual_list(props: &VirtualListProps) -> Html {
     let scroll_top = use_state(|| 0.0);
+    let viewport_ref = use_node_ref();
+    let sentinel_ref = use_node_ref();
+    // Whether the viewport was at the end when last scrolled, it starts there
+    let at_end = use_mut_ref(|| true);
+
+    let on_sentinel_intersect = use_callback(
+        props.on_end_reached.clone(),
+        |is_intersecting: bool, on_end_reached| {
+            if let Some(on_end_reached) = on_end_reached.as_ref().filter(|_| is_intersecting) {
+                on_end_reached.emit(());
+            }
+        },
+    );
+
+    use_on_intersect(&sentinel_ref, Rc::new(None), on_sentinel_intersect);
+
+    let is_valid_item_height = props.item_height.is_finite() && props.item_height > 0.0;
+
+    // Warns once per value instead of on every render, compared by bits so NaN stays equal
+    use_effect_with(props.item_height.to_bits(), move |item_height| {
+        if !is_valid_item_height {
+            log::warn!(
+                "VirtualList item_height must be positive, got {}; falling back to {MIN_ITEM_HEIGHT}",
+                f64::from_bits(*item_height)
+            );
+        }
+    });
+
+    let item_height = if is_valid_item_height {
+        props.item_height
+    } else {
+        MIN_ITEM_HEIGHT
+    };
+
+    let viewport_height = if props.viewport_height.is_finite() {
+        props.viewport_height.max(0.0)
+    } else {
+        0.0
+    };
+
+    use_effect_with(
+        (
+            props.scroll_to_index,
+            props.total_items,
+            item_height,
+            viewport_height,
+            viewport_ref.clone(),
+        ),
+        {
+            let scroll_top = scroll_top.clone();
+
+            move |(scroll_to_index, total_items, item_height, viewport_height, viewport_ref)| {
+                let (Some(index), Some(viewport)) =
+                    (scroll_to_index, viewport_ref.cast::<HtmlElement>())
+                else {
+                    return;
+                };
+
+                if index >= total_items {
+                    return;
+                }
+
+                let current = f64::from(viewport.scroll_top());
+
+                #[allow(clippy::cast_precision_loss)]
+                let item_top = *index as f64 * item_height;
+                let item_bottom = item_top + item_height;
+
+                let next = if item_top < current {
+                    item_top
+                } else if item_bottom > current + viewport_height {
+                    item_bottom - viewport_height
+                } else {
+                    return;
+                };
+
+                #[allow(clippy::cast_possible_truncation)]
+                viewport.set_scroll_top(next as i32);
+                scroll_top.set(next);
+            }
+        },
+    );
+
+    use_effect_with(
+        (
+            props.follow_tail,
+            props.total_items,
+            item_height,
+            viewport_height,
+            viewport_ref.clone(),
+        ),
+        {
+            let scroll_top = scroll_top.clone();
+            let at_end = at_end.clone();
+
+            move |(follow_tail, total_items, item_height, viewport_height, viewport_ref)| {
+                if !*follow_tail || !*at_end.borrow() {
+                    return;
+                }
+
+                let Some(viewport) = viewport_ref.cast::<HtmlElement>() else {
+                    return;
+                };
+
+                #[allow(clippy::cast_precision_loss)]
+                let end = (*total_items as f64 * item_height - viewport_height).max(0.0);
+
+                #[allow(clippy::cast_possible_truncation)]
+                viewport.set_scroll_top(end.ceil() as i32);
+                scroll_top.set(end);
+            }
+        },
+    );
 
     let total_items = props.total_items;
-    let item_height = props.item_height;
-    let viewport_height = props.viewport_height;
     let render_item = &props.render_item;
 
     #[allow(clippy::cast_precision_loss)]
     let total_height = (total_items as f64) * item_height;
-    let first_visible = (*scroll_top / item_height).floor() as usize;
-    let visible_count = (viewport_height / item_height).ceil() as usize + 1;
-    let last_visible = (first_visible + visible_count).min(total_items);
+    let first_visible = ((*scroll_top / item_height).floor().max(0.0) as usize).min(total_items);
+    let visible_count = ((viewport_height / item_height).ceil() as usize).saturating_add(1);
+    let last_visible = first_visible.saturating_add(visible_count).min(total_items);
 
     #[allow(clippy::cast_precision_loss)]
     let padding_top = first_visible as f64 * item_height;
 
-    let visible_items = (first_visible..last_visible)
-        .map(|index| render_item.emit(index))
-        .collect::<Html>();
+    let visible_items = if total_items == 0 {
+        Html::default()
+    } else {
+        (first_visible..last_visible)
+            .map(|index| render_item.emit(index))
+            .collect::<Html>()
+    };
 
-    let onscroll = use_callback((), {
+    let onscroll = use_callback(item_height, {
         let scroll_top = scroll_top.clone();
+        let at_end = at_end.clone();
 
-        move |event: Event, (): &()| {
-            let target = event.target().unwrap();
-            let scroll_top_value = target.unchecked_into::<HtmlElement>().scroll_top() as f64;
+        move |event: Event, item_height: &f64| {
+            let target = event.target().unwrap().unchecked_into::<HtmlElement>();
+            let scroll_top_value = target.scroll_top() as f64;
+            let end = f64::from(target.scroll_height() - target.client_height());
+
+            // Within an item of the end still counts, so a partial scroll doesn't release `follow_tail`
+            *at_end.borrow_mut() = end - scroll_top_value < *item_height;
             scroll_top.set(scroll_top_value);
         }
     });
 
     html! {
         <div
+            ref={viewport_ref}
             style={format!("height: {viewport_height}px; overflow-y: auto; position: relative;")}
             {onscroll}
         >
@@ -51,7 +191,153 @@ pub fn virtual_list(props: &VirtualListProps) -> Html {
                 <div style={format!("transform: translateY({padding_top}px);")}>
                     { visible_items }
                 </div>
+                // Sits at the end of the scroll height, observed for `on_end_reached`
+                <div
+                    ref={sentinel_ref}
+                    aria-hidden="true"
+                    style="position: absolute; bottom: 0; width: 100%; height: 1px;"
+                />
             </div>
         </div>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+    use yewlish_testing_tools::*;
+
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    async fn test_virtual_list_renders_nothing_without_items() {
+        let t = render!({
+            html! {
+                <VirtualList
+                    total_items={0}
+                    item_height={20.0}
+                    viewport_height={100.0}
+                    render_item={Callback::from(|index: usize| html! {
+                        <div role="listitem">{index}</div>
+                    })}
+                />
+            }
+        })
+        .await;
+
+        assert!(t.query_all_by_role("listitem").is_empty());
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_virtual_list_clamps_tiny_item_height() {
+        let t = render!({
+            html! {
+                <VirtualList
+                    total_items={10}
+                    item_height={0.0}
+                    viewport_height={100.0}
+                    render_item={Callback::from(|index: usize| html! {
+                        <div role="listitem">{index}</div>
+                    })}
+                />
+            }
+        })
+        .await;
+
+        // Every item fits into the viewport once the height is clamped
+        assert_eq!(t.query_all_by_role("listitem").len(), 10);
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_virtual_list_loads_more_at_the_end() {
+        let t = render!({
+            let total_items = use_state(|| 3);
+
+            let on_end_reached = use_callback(total_items.clone(), |(), total_items| {
+                if **total_items < 6 {
+                    total_items.set(**total_items + 3);
+                }
+            });
+
+            use_remember_value(*total_items);
+
+            html! {
+                <VirtualList
+                    total_items={*total_items}
+                    item_height={20.0}
+                    viewport_height={100.0}
+                    render_item={Callback::from(|index: usize| html! {
+                        <div role="listitem">{index}</div>
+                    })}
+                    {on_end_reached}
+                />
+            }
+        })
+        .await;
+
+        // The first page leaves the end in view, which loads the second one
+        assert!(
+            t.wait_for(1000.0, || t.get_remembered_value::<usize>() == 6)
+                .await
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_virtual_list_follows_the_tail() {
+        let t = render!({
+            let total_items = use_state(|| 10);
+
+            let append = use_callback(total_items.clone(), |_: MouseEvent, total_items| {
+                total_items.set(**total_items + 10);
+            });
+
+            html! {
+                <>
+                    <button onclick={append}>{"Append"}</button>
+                    <VirtualList
+                        total_items={*total_items}
+                        item_height={20.0}
+                        viewport_height={100.0}
+                        render_item={Callback::from(|index: usize| html! {
+                            <div role="listitem">{format!("Item {index}")}</div>
+                        })}
+                        follow_tail={true}
+                    />
+                </>
+            }
+        })
+        .await;
+
+        assert!(t.query_by_text("Item 9").exists());
+
+        t.query_by_text("Append").click().await;
+        assert!(
+            t.wait_for(1000.0, || t.query_by_text("Item 19").exists())
+                .await
+        );
+        assert!(!t.query_by_text("Item 0").exists());
+
+        // Scrolling up releases the tail
+        let viewport = web_sys::window()
+            .and_then(|window| window.document())
+            .and_then(|document| document.query_selector("[style*='overflow-y']").ok())
+            .flatten()
+            .and_then(|element| element.dyn_into::<HtmlElement>().ok())
+            .expect("Failed to find the viewport");
+
+        viewport.set_scroll_top(0);
+        viewport
+            .dispatch_event(&Event::new("scroll").unwrap())
+            .unwrap();
+
+        assert!(
+            t.wait_for(1000.0, || t.query_by_text("Item 0").exists())
+                .await
+        );
+
+        t.query_by_text("Append").click().await;
+        assert!(t.query_by_text("Item 0").exists());
+        assert!(!t.query_by_text("Item 29").exists());
+    }
+}